@@ -195,21 +195,36 @@ pub fn quote_arrow_deserializer(
                         InnerRepr::NativeIterable,
                     );
 
-                    quote! {
-                        let #data_dst = {
-                            // NOTE: `arrays_by_name` is a runtime collection of all of the input's
-                            // payload's struct fields, while `#field_name` is the field we're
-                            // looking for at comptime… there's no guarantee it's actually there at
-                            // runtime!
-                            if !arrays_by_name.contains_key(#field_name) {
-                                return Err(DeserializationError::missing_struct_field(
-                                    #quoted_self_datatype, #field_name,
-                                )).with_context(#obj_fqname);
+                    if obj_field.is_nullable {
+                        quote! {
+                            // NOTE: Nullable fields may be missing altogether, e.g. when the data
+                            // was written before they were introduced, in which case they are null.
+                            let #data_dst = if arrays_by_name.contains_key(#field_name) {
+                                let #data_src = &**arrays_by_name[#field_name];
+                                ::itertools::Either::Left(#quoted_deserializer)
+                            } else {
+                                ::itertools::Either::Right(
+                                    ::std::iter::repeat_with(|| None).take(#data_src.len()),
+                                )
                             }
+                        }
+                    } else {
+                        quote! {
+                            let #data_dst = {
+                                // NOTE: `arrays_by_name` is a runtime collection of all of the input's
+                                // payload's struct fields, while `#field_name` is the field we're
+                                // looking for at comptime… there's no guarantee it's actually there at
+                                // runtime!
+                                if !arrays_by_name.contains_key(#field_name) {
+                                    return Err(DeserializationError::missing_struct_field(
+                                        #quoted_self_datatype, #field_name,
+                                    )).with_context(#obj_fqname);
+                                }
 
-                            // NOTE: The indexing by name is safe: checked above.
-                            let #data_src = &**arrays_by_name[#field_name];
-                             #quoted_deserializer
+                                // NOTE: The indexing by name is safe: checked above.
+                                let #data_src = &**arrays_by_name[#field_name];
+                                 #quoted_deserializer
+                            }
                         }
                     }
                 });
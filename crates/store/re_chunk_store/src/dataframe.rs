@@ -35,8 +35,14 @@ pub enum SparseFillStrategy {
     /// The latest-at semantics are applied on the entire dataset as opposed to just the current
     /// view contents: it is possible to end up with values from outside the view!
     LatestAtGlobal,
-    //
-    // TODO(cmc): `LatestAtView`?
+
+    /// Fill null values using view-scope latest-at semantics.
+    ///
+    /// The latest-at semantics are only applied on the data that is part of the current view, i.e.
+    /// the data selected by both [`QueryExpression::view_contents`] and
+    /// [`QueryExpression::filtered_index_range`]: values from outside the view are never used,
+    /// even if that means leaving some cells empty.
+    LatestAtView,
 }
 
 impl std::fmt::Display for SparseFillStrategy {
@@ -44,6 +50,27 @@ impl std::fmt::Display for SparseFillStrategy {
         match self {
             Self::None => f.write_str("none"),
             Self::LatestAtGlobal => f.write_str("latest-at (global)"),
+            Self::LatestAtView => f.write_str("latest-at (view)"),
+        }
+    }
+}
+
+/// Specifies how the columns of [`QueryExpression::filtered_is_not_null`] are combined.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IsNotNullMode {
+    /// Keep rows where _any_ of the columns contains non-null data.
+    #[default]
+    Any,
+
+    /// Keep rows where _all_ of the columns contain non-null data.
+    All,
+}
+
+impl std::fmt::Display for IsNotNullMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Any => f.write_str("any"),
+            Self::All => f.write_str("all"),
         }
     }
 }
@@ -54,8 +81,8 @@ impl std::fmt::Display for SparseFillStrategy {
 ///
 /// Setting an entity's identifier to `None` means: everything.
 ///
-// TODO(cmc): we need to be able to build that easily in a command-line context, otherwise it's just
-// very annoying. E.g. `--with /world/points:[positions, radius] --with /cam:[pinhole]`.
+/// In a command-line context, each entry can be parsed from a string using [`Self::parse_entry`],
+/// e.g. `--with /world/points:[Points3D:positions, Points3D:radii] --with /cam`.
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ViewContentsSelector(pub BTreeMap<EntityPath, Option<BTreeSet<ComponentIdentifier>>>);
 
@@ -63,6 +90,66 @@ impl ViewContentsSelector {
     pub fn into_inner(self) -> BTreeMap<EntityPath, Option<BTreeSet<ComponentIdentifier>>> {
         self.0
     }
+
+    /// Parses a single view contents entry, in the form of either `/entity/path` (i.e. all
+    /// components of that entity) or `/entity/path:[Archetype:component, …]`.
+    pub fn parse_entry(
+        entry: &str,
+    ) -> Result<(EntityPath, Option<BTreeSet<ComponentIdentifier>>), ViewContentsSelectorParseError>
+    {
+        let entry = entry.trim();
+        if entry.is_empty() {
+            return Err(ViewContentsSelectorParseError::EmptyString);
+        }
+
+        // Component identifiers themselves contain colons (`Archetype:component`), so we look
+        // for the start of the component list specifically.
+        let Some(i) = entry.find(":[") else {
+            if entry.ends_with(']') {
+                return Err(ViewContentsSelectorParseError::FormatError(
+                    entry.to_owned(),
+                ));
+            }
+            return Ok((EntityPath::from(entry), None));
+        };
+
+        let (entity_path, components) = (&entry[..i], &entry[i + 1..]);
+        let Some(components) = components
+            .strip_prefix('[')
+            .and_then(|components| components.strip_suffix(']'))
+        else {
+            return Err(ViewContentsSelectorParseError::FormatError(
+                entry.to_owned(),
+            ));
+        };
+
+        if entity_path.is_empty() {
+            return Err(ViewContentsSelectorParseError::FormatError(
+                entry.to_owned(),
+            ));
+        }
+
+        let components = components
+            .split(',')
+            .map(str::trim)
+            .filter(|component| !component.is_empty())
+            .map(ComponentIdentifier::from)
+            .collect();
+
+        Ok((EntityPath::from(entity_path), Some(components)))
+    }
+}
+
+/// Errors that can occur when parsing a [`ViewContentsSelector`] entry.
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum ViewContentsSelectorParseError {
+    #[error("Expected view contents entry, found empty string")]
+    EmptyString,
+
+    #[error(
+        "Expected string in the form of `entity_path` or `entity_path:[component, …]`, got: {0}"
+    )]
+    FormatError(String),
 }
 
 impl Deref for ViewContentsSelector {
@@ -224,14 +311,21 @@ pub struct QueryExpression {
     /// Example: `[TimeInt(12), TimeInt(14)]`.
     pub using_index_values: Option<BTreeSet<IndexValue>>,
 
-    /// The component column used to filter out _rows_ from the view contents.
+    /// The component columns used to filter out _rows_ from the view contents.
     ///
-    /// Only rows where this column contains non-null data be kept in the final dataset.
+    /// Only rows where these columns contain non-null data will be kept in the final dataset,
+    /// see [`QueryExpression::filtered_is_not_null_mode`].
     ///
-    /// Example: `ComponentColumnSelector("Points3D:positions")`.
-    //
-    // TODO(cmc): multi-pov support
-    pub filtered_is_not_null: Option<ComponentColumnSelector>,
+    /// Defaults to an empty list, which means: no filtering.
+    ///
+    /// Example: `[ComponentColumnSelector("/cam/left:EncodedImage:blob"), ComponentColumnSelector("/cam/right:EncodedImage:blob")]`.
+    pub filtered_is_not_null: Vec<ComponentColumnSelector>,
+
+    /// Whether rows need non-null data in _any_ or _all_ of the [`QueryExpression::filtered_is_not_null`]
+    /// columns in order to be kept.
+    ///
+    /// Defaults to [`IsNotNullMode::Any`].
+    pub filtered_is_not_null_mode: IsNotNullMode,
 
     /// Specifies how null values should be filled in the returned dataframe.
     ///
@@ -471,6 +565,7 @@ impl ChunkStore {
             filtered_index_values: _,
             using_index_values: _,
             filtered_is_not_null: _,
+            filtered_is_not_null_mode: _,
             sparse_fill_strategy: _,
             selection: _,
         } = query;
@@ -508,3 +603,54 @@ impl ChunkStore {
         self.schema().filter_components(filter)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_view_contents_entry() {
+        assert_eq!(
+            ViewContentsSelector::parse_entry("/world/points"),
+            Ok((EntityPath::from("/world/points"), None)),
+        );
+
+        assert_eq!(
+            ViewContentsSelector::parse_entry("/world/points:[Points3D:positions, Points3D:radii]"),
+            Ok((
+                EntityPath::from("/world/points"),
+                Some(
+                    [
+                        ComponentIdentifier::from("Points3D:positions"),
+                        ComponentIdentifier::from("Points3D:radii"),
+                    ]
+                    .into_iter()
+                    .collect()
+                ),
+            )),
+        );
+
+        assert_eq!(
+            ViewContentsSelector::parse_entry("/cam:[]"),
+            Ok((EntityPath::from("/cam"), Some(BTreeSet::new()))),
+        );
+
+        assert_eq!(
+            ViewContentsSelector::parse_entry(""),
+            Err(ViewContentsSelectorParseError::EmptyString),
+        );
+
+        for invalid in [
+            ":[Points3D:positions]",
+            "/cam:[Pinhole:image_from_camera",
+            "/cam]",
+        ] {
+            assert_eq!(
+                ViewContentsSelector::parse_entry(invalid),
+                Err(ViewContentsSelectorParseError::FormatError(
+                    invalid.to_owned()
+                )),
+            );
+        }
+    }
+}
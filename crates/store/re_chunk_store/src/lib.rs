@@ -26,8 +26,8 @@ mod writes;
 
 pub use self::{
     dataframe::{
-        Index, IndexRange, IndexValue, QueryExpression, SparseFillStrategy, StaticColumnSelection,
-        ViewContentsSelector,
+        Index, IndexRange, IndexValue, IsNotNullMode, QueryExpression, SparseFillStrategy,
+        StaticColumnSelection, ViewContentsSelector, ViewContentsSelectorParseError,
     },
     events::{ChunkCompactionReport, ChunkStoreDiff, ChunkStoreDiffKind, ChunkStoreEvent},
     gc::{GarbageCollectionOptions, GarbageCollectionTarget},
@@ -6,7 +6,7 @@ use arrow::datatypes::DataType as ArrowDataType;
 use nohash_hasher::IntMap;
 
 use re_chunk::{Chunk, ChunkId, ComponentIdentifier, RowId, TimelineName};
-use re_log_types::{EntityPath, StoreId, StoreInfo, TimeInt, TimeType};
+use re_log_types::{EntityPath, ResolvedTimeRange, StoreId, StoreInfo, TimeInt, TimeType};
 use re_types_core::{ComponentDescriptor, ComponentType};
 
 use crate::{ChunkStoreChunkStats, ChunkStoreError, ChunkStoreResult};
@@ -72,16 +72,21 @@ pub struct ChunkStoreConfig {
     /// The default byte threshold is set to 8MiB, which is a reasonable unit of work when e.g.
     /// sending chunks over the network.
     pub chunk_max_rows_if_unsorted: u64,
-    //
-    // TODO(cmc): It could make sense to have time-range-based thresholds in here, since the time
-    // range covered by a chunk has direct effects on A) the complexity of backward walks and
-    // B) in downstream subscribers (e.g. the precision of the time panel).
-    //
-    // In practice this is highly recording-dependent, and would require either to make it
-    // user-configurable per-recording, or use heuristics to compute it on the fly.
-    //
-    // The added complexity just isn't worth it at the moment.
-    // Maybe at some point.
+
+    /// What is the threshold, per timeline, in terms of time span covered, after which a [`Chunk`]
+    /// cannot be compacted any further?
+    ///
+    /// Spans are expressed in the native unit of each timeline, i.e. nanoseconds for temporal
+    /// timelines and ticks for sequence timelines.
+    /// Timelines that are not listed here are not subject to any such threshold (the default).
+    ///
+    /// The time range covered by a chunk has direct effects on A) the complexity of backward walks
+    /// in latest-at queries and B) downstream subscribers (e.g. the precision of the time panel).
+    /// This is particularly relevant for recordings that mix sparse and dense data: without such a
+    /// threshold, a sparse entity logged at 1Hz ends up with chunks spanning hours of data.
+    ///
+    /// See also [`ChunkStoreConfig::parse_chunk_max_time_span`].
+    pub chunk_max_time_span_per_timeline: BTreeMap<TimelineName, u64>,
 }
 
 impl Default for ChunkStoreConfig {
@@ -92,37 +97,49 @@ impl Default for ChunkStoreConfig {
 }
 
 impl ChunkStoreConfig {
-    /// Default configuration, applicable to most use cases, according to empirical testing.
-    pub const DEFAULT: Self = Self {
-        enable_changelog: true,
+    // This gives us 96 bytes per row (assuming a default limit of 4096 rows), which is enough to
+    // fit a couple scalar columns, a RowId column, a handful of timeline columns, all the
+    // necessary offsets, etc.
+    //
+    // A few megabytes turned out to be way too costly to concatenate in real-time in the
+    // Viewer (see <https://github.com/rerun-io/rerun/issues/7222>).
+    const DEFAULT_CHUNK_MAX_BYTES: u64 = 12 * 8 * 4096;
+
+    // Empirical testing shows that 4096 is the threshold after which we really start to get
+    // dimishing returns space and compute wise.
+    const DEFAULT_CHUNK_MAX_ROWS: u64 = 4096;
 
-        // This gives us 96 bytes per row (assuming a default limit of 4096 rows), which is enough to
-        // fit a couple scalar columns, a RowId column, a handful of timeline columns, all the
-        // necessary offsets, etc.
-        //
-        // A few megabytes turned out to be way too costly to concatenate in real-time in the
-        // Viewer (see <https://github.com/rerun-io/rerun/issues/7222>).
-        chunk_max_bytes: 12 * 8 * 4096,
+    const DEFAULT_CHUNK_MAX_ROWS_IF_UNSORTED: u64 = 1024;
 
-        // Empirical testing shows that 4096 is the threshold after which we really start to get
-        // dimishing returns space and compute wise.
-        chunk_max_rows: 4096,
+    // NOTE: The constants below cannot use struct update syntax (`..Self::DEFAULT`), nor read
+    // fields from `Self::DEFAULT`, since `chunk_max_time_span_per_timeline` cannot be dropped in
+    // a const context.
 
-        chunk_max_rows_if_unsorted: 1024,
+    /// Default configuration, applicable to most use cases, according to empirical testing.
+    pub const DEFAULT: Self = Self {
+        enable_changelog: true,
+        chunk_max_bytes: Self::DEFAULT_CHUNK_MAX_BYTES,
+        chunk_max_rows: Self::DEFAULT_CHUNK_MAX_ROWS,
+        chunk_max_rows_if_unsorted: Self::DEFAULT_CHUNK_MAX_ROWS_IF_UNSORTED,
+        chunk_max_time_span_per_timeline: BTreeMap::new(),
     };
 
     /// [`Self::DEFAULT`], but with compaction entirely disabled.
     pub const COMPACTION_DISABLED: Self = Self {
+        enable_changelog: true,
         chunk_max_bytes: 0,
         chunk_max_rows: 0,
         chunk_max_rows_if_unsorted: 0,
-        ..Self::DEFAULT
+        chunk_max_time_span_per_timeline: BTreeMap::new(),
     };
 
     /// [`Self::DEFAULT`], but with changelog disabled.
     pub const CHANGELOG_DISABLED: Self = Self {
         enable_changelog: false,
-        ..Self::DEFAULT
+        chunk_max_bytes: Self::DEFAULT_CHUNK_MAX_BYTES,
+        chunk_max_rows: Self::DEFAULT_CHUNK_MAX_ROWS,
+        chunk_max_rows_if_unsorted: Self::DEFAULT_CHUNK_MAX_ROWS_IF_UNSORTED,
+        chunk_max_time_span_per_timeline: BTreeMap::new(),
     };
 
     /// All features disabled.
@@ -131,6 +148,7 @@ impl ChunkStoreConfig {
         chunk_max_bytes: 0,
         chunk_max_rows: 0,
         chunk_max_rows_if_unsorted: 0,
+        chunk_max_time_span_per_timeline: BTreeMap::new(),
     };
 
     /// Environment variable to configure [`Self::enable_changelog`].
@@ -147,6 +165,11 @@ impl ChunkStoreConfig {
     // NOTE: Shared with the same env-var on the batcher side, for consistency.
     pub const ENV_CHUNK_MAX_ROWS_IF_UNSORTED: &'static str = "RERUN_CHUNK_MAX_ROWS_IF_UNSORTED";
 
+    /// Environment variable to configure [`Self::chunk_max_time_span_per_timeline`].
+    ///
+    /// Expects a comma-separated list of `timeline=span`, see [`Self::parse_chunk_max_time_span`].
+    pub const ENV_CHUNK_MAX_TIME_SPAN: &'static str = "RERUN_CHUNK_MAX_TIME_SPAN";
+
     /// Creates a new `ChunkStoreConfig` using the default values, optionally overridden
     /// through the environment.
     ///
@@ -159,8 +182,8 @@ impl ChunkStoreConfig {
     /// Returns a copy of `self`, overriding existing fields with values from the environment if
     /// they are present.
     ///
-    /// See [`Self::ENV_STORE_ENABLE_CHANGELOG`], [`Self::ENV_CHUNK_MAX_BYTES`], [`Self::ENV_CHUNK_MAX_ROWS`]
-    /// and [`Self::ENV_CHUNK_MAX_ROWS_IF_UNSORTED`].
+    /// See [`Self::ENV_STORE_ENABLE_CHANGELOG`], [`Self::ENV_CHUNK_MAX_BYTES`], [`Self::ENV_CHUNK_MAX_ROWS`],
+    /// [`Self::ENV_CHUNK_MAX_ROWS_IF_UNSORTED`] and [`Self::ENV_CHUNK_MAX_TIME_SPAN`].
     pub fn apply_env(&self) -> ChunkStoreResult<Self> {
         let mut new = self.clone();
 
@@ -197,8 +220,61 @@ impl ChunkStoreConfig {
                 })?;
         }
 
+        if let Ok(s) = std::env::var(Self::ENV_CHUNK_MAX_TIME_SPAN) {
+            for threshold in s.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+                let (timeline, max_time_span) = Self::parse_chunk_max_time_span(threshold)
+                    .map_err(|err| ChunkStoreError::ParseConfig {
+                        name: Self::ENV_CHUNK_MAX_TIME_SPAN,
+                        value: s.clone(),
+                        err: err.into(),
+                    })?;
+                new.chunk_max_time_span_per_timeline
+                    .insert(timeline, max_time_span);
+            }
+        }
+
         Ok(new)
     }
+
+    /// Parses a single `timeline=span` entry for [`Self::chunk_max_time_span_per_timeline`].
+    ///
+    /// The span is either an integer in the native unit of the timeline (e.g. `frame_nr=100`), or
+    /// a human-readable duration for temporal timelines (e.g. `log_time=10s`, `sensor_time=250ms`).
+    pub fn parse_chunk_max_time_span(s: &str) -> Result<(TimelineName, u64), String> {
+        let Some((timeline, span)) = s.split_once('=') else {
+            return Err(format!("expected `timeline=span`, got {s:?}"));
+        };
+
+        let (timeline, span) = (timeline.trim(), span.trim());
+        if timeline.is_empty() {
+            return Err(format!("missing timeline name in {s:?}"));
+        }
+
+        let max_time_span = if let Ok(span) = span.parse::<u64>() {
+            span
+        } else {
+            let duration = span
+                .parse::<re_log_types::Duration>()
+                .map_err(|err| format!("invalid time span {span:?}: {err}"))?;
+            u64::try_from(duration.as_nanos())
+                .map_err(|_err| format!("time span must be positive, got {span:?}"))?
+        };
+
+        Ok((TimelineName::new(timeline), max_time_span))
+    }
+
+    /// Returns `true` if a [`Chunk`] covering `time_range` on `timeline` would exceed the
+    /// configured [`Self::chunk_max_time_span_per_timeline`].
+    #[inline]
+    pub(crate) fn exceeds_max_time_span(
+        &self,
+        timeline: &TimelineName,
+        time_range: ResolvedTimeRange,
+    ) -> bool {
+        self.chunk_max_time_span_per_timeline
+            .get(timeline)
+            .is_some_and(|&max_time_span| time_range.abs_length() > max_time_span)
+    }
 }
 
 #[test]
@@ -212,6 +288,7 @@ fn chunk_store_config() {
         std::env::set_var("RERUN_CHUNK_MAX_BYTES", "42");
         std::env::set_var("RERUN_CHUNK_MAX_ROWS", "666");
         std::env::set_var("RERUN_CHUNK_MAX_ROWS_IF_UNSORTED", "999");
+        std::env::set_var("RERUN_CHUNK_MAX_TIME_SPAN", "frame_nr=100, log_time=10s");
     };
 
     let config = ChunkStoreConfig::from_env().unwrap();
@@ -221,6 +298,12 @@ fn chunk_store_config() {
         chunk_max_bytes: 42,
         chunk_max_rows: 666,
         chunk_max_rows_if_unsorted: 999,
+        chunk_max_time_span_per_timeline: [
+            (TimelineName::new("frame_nr"), 100),
+            (TimelineName::new("log_time"), 10_000_000_000),
+        ]
+        .into_iter()
+        .collect(),
     };
 
     assert_eq!(expected, config);
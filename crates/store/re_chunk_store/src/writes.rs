@@ -467,6 +467,7 @@ impl ChunkStore {
                 chunk_max_bytes,
                 chunk_max_rows,
                 chunk_max_rows_if_unsorted,
+                chunk_max_time_span_per_timeline: _, // see below
             } = self.config;
 
             let total_bytes = <Chunk as SizeBytes>::total_size_bytes(chunk);
@@ -479,47 +480,72 @@ impl ChunkStore {
                 total_rows <= chunk_max_rows_if_unsorted
             };
 
-            if !(is_below_bytes_threshold && is_below_rows_threshold) {
+            let is_below_time_span_threshold =
+                chunk.timelines().iter().all(|(timeline, time_column)| {
+                    !self
+                        .config
+                        .exceeds_max_time_span(timeline, time_column.time_range())
+                });
+
+            if !(is_below_bytes_threshold
+                && is_below_rows_threshold
+                && is_below_time_span_threshold)
+            {
                 return None;
             }
         }
 
         let mut candidates_below_threshold: HashMap<ChunkId, bool> = HashMap::default();
-        let mut check_if_chunk_below_threshold =
-            |store: &Self, candidate_chunk_id: ChunkId| -> bool {
-                let ChunkStoreConfig {
-                    enable_changelog: _,
-                    chunk_max_bytes,
-                    chunk_max_rows,
-                    chunk_max_rows_if_unsorted,
-                } = store.config;
-
-                *candidates_below_threshold
-                    .entry(candidate_chunk_id)
-                    .or_insert_with(|| {
-                        store
-                            .chunks_per_chunk_id
-                            .get(&candidate_chunk_id)
-                            .is_some_and(|candidate| {
-                                if !chunk.concatenable(candidate) {
-                                    return false;
-                                }
-
-                                let total_bytes = <Chunk as SizeBytes>::total_size_bytes(chunk)
-                                    + <Chunk as SizeBytes>::total_size_bytes(candidate);
-                                let is_below_bytes_threshold = total_bytes <= chunk_max_bytes;
-
-                                let total_rows = (chunk.num_rows() + candidate.num_rows()) as u64;
-                                let is_below_rows_threshold = if candidate.is_time_sorted() {
-                                    total_rows <= chunk_max_rows
-                                } else {
-                                    total_rows <= chunk_max_rows_if_unsorted
-                                };
-
-                                is_below_bytes_threshold && is_below_rows_threshold
-                            })
-                    })
-            };
+        let mut check_if_chunk_below_threshold = |store: &Self,
+                                                  candidate_chunk_id: ChunkId|
+         -> bool {
+            let ChunkStoreConfig {
+                enable_changelog: _,
+                chunk_max_bytes,
+                chunk_max_rows,
+                chunk_max_rows_if_unsorted,
+                chunk_max_time_span_per_timeline: _, // see below
+            } = store.config;
+
+            *candidates_below_threshold
+                .entry(candidate_chunk_id)
+                .or_insert_with(|| {
+                    store
+                        .chunks_per_chunk_id
+                        .get(&candidate_chunk_id)
+                        .is_some_and(|candidate| {
+                            if !chunk.concatenable(candidate) {
+                                return false;
+                            }
+
+                            let total_bytes = <Chunk as SizeBytes>::total_size_bytes(chunk)
+                                + <Chunk as SizeBytes>::total_size_bytes(candidate);
+                            let is_below_bytes_threshold = total_bytes <= chunk_max_bytes;
+
+                            let total_rows = (chunk.num_rows() + candidate.num_rows()) as u64;
+                            let is_below_rows_threshold = if candidate.is_time_sorted() {
+                                total_rows <= chunk_max_rows
+                            } else {
+                                total_rows <= chunk_max_rows_if_unsorted
+                            };
+
+                            let is_below_time_span_threshold =
+                                chunk.timelines().iter().all(|(timeline, time_column)| {
+                                    let time_range = candidate.timelines().get(timeline).map_or(
+                                        time_column.time_range(),
+                                        |candidate| {
+                                            time_column.time_range().union(candidate.time_range())
+                                        },
+                                    );
+                                    !store.config.exceeds_max_time_span(timeline, time_range)
+                                });
+
+                            is_below_bytes_threshold
+                                && is_below_rows_threshold
+                                && is_below_time_span_threshold
+                        })
+                })
+        };
 
         let mut candidates: HashMap<ChunkId, u64> = HashMap::default();
 
@@ -927,6 +953,64 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn compaction_max_time_span() -> anyhow::Result<()> {
+        re_log::setup_logging();
+
+        let timeline_frame = Timeline::new_sequence("frame");
+
+        let mut store = ChunkStore::new(
+            re_log_types::StoreId::random(re_log_types::StoreKind::Recording),
+            ChunkStoreConfig {
+                chunk_max_time_span_per_timeline: std::iter::once((*timeline_frame.name(), 5))
+                    .collect(),
+                ..ChunkStoreConfig::DEFAULT
+            },
+        );
+
+        let entity_path = EntityPath::from("this/that");
+        let points = &[MyPoint::new(1.0, 1.0)];
+
+        let new_chunk = |frames: &[i64]| -> anyhow::Result<Arc<Chunk>> {
+            let mut builder = Chunk::builder(entity_path.clone());
+            for &frame in frames {
+                builder = builder.with_component_batches(
+                    RowId::new(),
+                    [(timeline_frame, frame)],
+                    [(MyPoints::descriptor_points(), points as _)],
+                );
+            }
+            Ok(Arc::new(builder.build()?))
+        };
+
+        // Spans 0..=4 once compacted: below threshold.
+        store.insert_chunk(&new_chunk(&[0, 2])?)?;
+        store.insert_chunk(&new_chunk(&[3, 4])?)?;
+        assert_eq!(1, store.chunks_per_chunk_id.len());
+
+        // Would span 0..=6 once compacted: above threshold.
+        store.insert_chunk(&new_chunk(&[5, 6])?)?;
+        assert_eq!(2, store.chunks_per_chunk_id.len());
+
+        // Spans 7..=20 on its own: above threshold, never compacted.
+        store.insert_chunk(&new_chunk(&[7, 20])?)?;
+        store.insert_chunk(&new_chunk(&[21])?)?;
+        assert_eq!(4, store.chunks_per_chunk_id.len());
+
+        let time_ranges = store
+            .chunks_per_chunk_id
+            .values()
+            .map(|chunk| {
+                let time_range = chunk.timelines()[timeline_frame.name()].time_range();
+                (time_range.min().as_i64(), time_range.max().as_i64())
+            })
+            .sorted()
+            .collect_vec();
+        assert_eq!(vec![(0, 4), (5, 6), (7, 20), (21, 21)], time_ranges);
+
+        Ok(())
+    }
+
     #[test]
     fn no_components() -> anyhow::Result<()> {
         re_log::setup_logging();
@@ -1180,6 +1264,7 @@ mod tests {
                     chunk_max_bytes: u64::MAX,
                     chunk_max_rows: u64::MAX,
                     chunk_max_rows_if_unsorted: u64::MAX,
+                    chunk_max_time_span_per_timeline: Default::default(),
                 },
             );
 
@@ -1209,6 +1294,7 @@ mod tests {
                     chunk_max_bytes: u64::MAX,
                     chunk_max_rows: u64::MAX,
                     chunk_max_rows_if_unsorted: u64::MAX,
+                    chunk_max_time_span_per_timeline: Default::default(),
                 },
             );
 
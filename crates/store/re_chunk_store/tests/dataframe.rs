@@ -120,7 +120,8 @@ fn schema_for_query() -> anyhow::Result<()> {
         filtered_index_range: None,
         filtered_index_values: None,
         using_index_values: None,
-        filtered_is_not_null: None,
+        filtered_is_not_null: Vec::new(),
+        filtered_is_not_null_mode: re_chunk_store::IsNotNullMode::Any,
         sparse_fill_strategy: re_chunk_store::SparseFillStrategy::None,
         selection: None,
     };
@@ -185,7 +186,8 @@ fn schema_static_columns() -> anyhow::Result<()> {
         filtered_index_range: None,
         filtered_index_values: None,
         using_index_values: None,
-        filtered_is_not_null: None,
+        filtered_is_not_null: Vec::new(),
+        filtered_is_not_null_mode: re_chunk_store::IsNotNullMode::Any,
         sparse_fill_strategy: re_chunk_store::SparseFillStrategy::None,
         selection: None,
     };
@@ -12,8 +12,14 @@ use re_log_types::{ArrowMsg, EntityPath, LogMsg, TimePoint};
 mod load_file;
 mod loader_archetype;
 mod loader_directory;
+mod loader_mjcf;
+mod loader_point_cloud;
 mod loader_rrd;
+mod loader_sdf;
+mod loader_table;
+mod loader_text;
 mod loader_urdf;
+mod robot_description;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub mod lerobot;
@@ -25,20 +31,29 @@ pub mod loader_lerobot;
 #[cfg(not(target_arch = "wasm32"))]
 mod loader_external;
 
+#[cfg(not(target_arch = "wasm32"))]
+mod loader_mcap;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod ros2;
+
 pub use self::{
     load_file::load_from_file_contents, loader_archetype::ArchetypeLoader,
-    loader_directory::DirectoryLoader, loader_rrd::RrdLoader, loader_urdf::UrdfDataLoader,
-    loader_urdf::UrdfTree,
+    loader_directory::DirectoryLoader, loader_mjcf::MjcfLoader,
+    loader_point_cloud::PointCloudLoader, loader_rrd::RrdLoader, loader_sdf::SdfLoader,
+    loader_table::TABLE_SIDECAR_EXTENSION, loader_table::TableLoader, loader_text::TextLoader,
+    loader_urdf::UrdfDataLoader, loader_urdf::UrdfTree,
 };
 
 #[cfg(not(target_arch = "wasm32"))]
 pub use self::{
-    load_file::load_from_path,
+    load_file::{forward_stream, load_from_path},
     loader_external::{
         EXTERNAL_DATA_LOADER_INCOMPATIBLE_EXIT_CODE, EXTERNAL_DATA_LOADER_PREFIX, ExternalLoader,
         iter_external_loaders,
     },
     loader_lerobot::LeRobotDatasetLoader,
+    loader_mcap::McapLoader,
 };
 
 pub mod external {
@@ -90,6 +105,11 @@ pub struct DataLoaderSettings {
 
     /// At what time(s) should the data be logged to?
     pub timepoint: Option<TimePoint>,
+
+    /// The frame rate of raw H.264/H.265 bitstreams, which don't carry any timestamps.
+    ///
+    /// If `None`, the video demuxer's default frame rate is used.
+    pub video_frame_rate: Option<f64>,
 }
 
 impl DataLoaderSettings {
@@ -103,6 +123,7 @@ impl DataLoaderSettings {
             force_store_info: false,
             entity_path_prefix: Default::default(),
             timepoint: Default::default(),
+            video_frame_rate: Default::default(),
         }
     }
 
@@ -116,6 +137,7 @@ impl DataLoaderSettings {
             force_store_info: _,
             entity_path_prefix,
             timepoint,
+            video_frame_rate: _,
         } = self;
 
         let mut args = Vec::new();
@@ -217,8 +239,11 @@ pub type DataLoaderName = String;
 ///     - [3D models]
 ///     - [Images]
 ///     - [Point clouds]
-///     - [Text files]
-/// - [`DirectoryLoader`] for recursively loading folders.
+/// - [`UrdfDataLoader`], [`MjcfLoader`] and [`SdfLoader`] for [Robot descriptions].
+/// - [`McapLoader`] for [MCAP files] (native only).
+/// - [`TableLoader`] for [Tables].
+/// - [`TextLoader`] for [Text files], and as a catch-all for any other UTF-8 file.
+/// - [`DirectoryLoader`] for recursively loading folders and [Archives].
 /// - [`ExternalLoader`], which looks for user-defined data loaders in $PATH.
 ///
 /// ## Registering custom loaders
@@ -235,15 +260,28 @@ pub type DataLoaderName = String;
 /// with a [`DataLoaderError::Incompatible`] error.
 ///
 /// Iff all [`DataLoader`]s (including custom and external ones) return with a [`DataLoaderError::Incompatible`]
-/// error, the Viewer will show an error message to the user indicating that the file type is not
-/// supported.
+/// error, the file is loaded as a plain text document if it looks like UTF-8 text.
+/// Otherwise, the Viewer will show an error message to the user indicating that the file type is
+/// not supported.
 ///
 /// On native, [`DataLoader`]s are executed in parallel.
 ///
+/// ## Streaming
+///
+/// On native, a [`DataLoader`] can opt into producing its data lazily by implementing
+/// [`DataLoader::stream_from_path`], which is then preferred over [`DataLoader::load_from_path`].
+/// The returned [`LoadedDataStream`] is only pulled from as fast as the data gets consumed
+/// downstream, so that the beginning of large files can be shown while the rest is still being
+/// parsed, without ever buffering the whole file in memory.
+///
 /// [Rerun files]: crate::SUPPORTED_RERUN_EXTENSIONS
 /// [3D models]: crate::SUPPORTED_MESH_EXTENSIONS
 /// [Images]: crate::SUPPORTED_IMAGE_EXTENSIONS
 /// [Point clouds]: crate::SUPPORTED_POINT_CLOUD_EXTENSIONS
+/// [Robot descriptions]: crate::SUPPORTED_ROBOT_DESCRIPTION_EXTENSIONS
+/// [MCAP files]: crate::SUPPORTED_MCAP_EXTENSIONS
+/// [Tables]: crate::SUPPORTED_TABLE_EXTENSIONS
+/// [Archives]: crate::SUPPORTED_ARCHIVE_EXTENSIONS
 /// [Text files]: crate::SUPPORTED_TEXT_EXTENSIONS
 //
 // TODO(#4525): `DataLoader`s should support arbitrary URIs
@@ -284,6 +322,35 @@ pub trait DataLoader: Send + Sync {
         tx: std::sync::mpsc::Sender<LoadedData>,
     ) -> Result<(), DataLoaderError>;
 
+    /// Lazily loads data from a file on the local filesystem, in time order.
+    ///
+    /// When implemented, this is preferred over [`DataLoader::load_from_path`] by the file
+    /// loading machinery: the returned stream is pulled from on a dedicated thread, and only as
+    /// fast as the data gets consumed (e.g. ingested by the viewer).
+    /// Implementers should therefore do as little work as possible upfront, and parse the file
+    /// incrementally as the stream gets polled.
+    ///
+    /// Implementers should still implement [`DataLoader::load_from_path`], e.g. by draining the
+    /// stream with [`forward_stream`].
+    ///
+    /// ## Error handling
+    ///
+    /// Implementers should make sure to fail early (and thus synchronously) when possible (e.g.
+    /// didn't even manage to open the file).
+    /// Errors yielded by the stream itself are logged, and don't interrupt it.
+    ///
+    /// If a [`DataLoader`] has no interest in the given file, or doesn't support streaming at all
+    /// (the default), it should fail with a [`DataLoaderError::Incompatible`] error.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn stream_from_path(
+        &self,
+        settings: &DataLoaderSettings,
+        path: std::path::PathBuf,
+    ) -> Result<LoadedDataStream, DataLoaderError> {
+        _ = settings;
+        Err(DataLoaderError::Incompatible(path))
+    }
+
     /// Loads data from in-memory file contents and sends it to `tx`.
     ///
     /// This is generally called when opening files via drag-and-drop or when using the web viewer.
@@ -354,6 +421,10 @@ impl DataLoaderError {
     }
 }
 
+/// A lazy stream of [`LoadedData`], see [`DataLoader::stream_from_path`].
+#[cfg(not(target_arch = "wasm32"))]
+pub type LoadedDataStream = Box<dyn Iterator<Item = Result<LoadedData, DataLoaderError>> + Send>;
+
 /// What [`DataLoader`]s load.
 ///
 /// This makes it trivial for [`DataLoader`]s to build the data in whatever form is
@@ -399,11 +470,18 @@ static BUILTIN_LOADERS: Lazy<Vec<Arc<dyn DataLoader>>> = Lazy::new(|| {
         Arc::new(RrdLoader) as Arc<dyn DataLoader>,
         Arc::new(ArchetypeLoader),
         Arc::new(DirectoryLoader),
+        Arc::new(PointCloudLoader),
         #[cfg(not(target_arch = "wasm32"))]
         Arc::new(LeRobotDatasetLoader),
         #[cfg(not(target_arch = "wasm32"))]
         Arc::new(ExternalLoader),
         Arc::new(UrdfDataLoader),
+        Arc::new(MjcfLoader),
+        Arc::new(SdfLoader),
+        #[cfg(not(target_arch = "wasm32"))]
+        Arc::new(McapLoader),
+        Arc::new(TableLoader),
+        Arc::new(TextLoader),
     ]
 });
 
@@ -451,17 +529,47 @@ pub const SUPPORTED_IMAGE_EXTENSIONS: &[&str] = &[
     "pbm", "pgm", "png", "ppm", "tga", "tif", "tiff", "webp",
 ];
 
-pub const SUPPORTED_VIDEO_EXTENSIONS: &[&str] = &["mp4"];
+pub const SUPPORTED_VIDEO_EXTENSIONS: &[&str] =
+    &["264", "265", "h264", "h265", "hevc", "mkv", "mp4", "webm"];
 
 pub const SUPPORTED_MESH_EXTENSIONS: &[&str] = &["glb", "gltf", "obj", "stl"];
 
-// TODO(#4532): `.ply` data loader should support 2D point cloud & meshes
-pub const SUPPORTED_POINT_CLOUD_EXTENSIONS: &[&str] = &["ply"];
+/// Point clouds: PLY files are loaded by the [`ArchetypeLoader`], everything else by the
+/// [`PointCloudLoader`].
+pub const SUPPORTED_POINT_CLOUD_EXTENSIONS: &[&str] = &["las", "laz", "pcd", "ply", "xyz"];
 
 pub const SUPPORTED_RERUN_EXTENSIONS: &[&str] = &["rbl", "rrd"];
 
-// TODO(#4555): Add catch-all builtin `DataLoader` for text files
-pub const SUPPORTED_TEXT_EXTENSIONS: &[&str] = &["txt", "md"];
+/// Robot descriptions, see [`UrdfDataLoader`], [`MjcfLoader`] and [`SdfLoader`].
+///
+/// `xml` is only supported for MuJoCo models.
+pub const SUPPORTED_ROBOT_DESCRIPTION_EXTENSIONS: &[&str] =
+    &["mjcf", "sdf", "urdf", "world", "xml"];
+
+/// MCAP files, e.g. ROS 2 bags, see [`McapLoader`].
+///
+/// Only supported on native.
+#[cfg(not(target_arch = "wasm32"))]
+pub const SUPPORTED_MCAP_EXTENSIONS: &[&str] = &["mcap"];
+
+/// MCAP files, e.g. ROS 2 bags, see `McapLoader`.
+///
+/// Only supported on native.
+#[cfg(target_arch = "wasm32")]
+pub const SUPPORTED_MCAP_EXTENSIONS: &[&str] = &[];
+
+/// Parquet and Arrow IPC tables, see [`TableLoader`].
+pub const SUPPORTED_TABLE_EXTENSIONS: &[&str] = &["arrow", "feather", "ipc", "parquet"];
+
+/// Archives whose files get loaded individually, see [`DirectoryLoader`].
+///
+/// `gz` is only supported for gzipped tarballs, i.e. `.tar.gz`.
+pub const SUPPORTED_ARCHIVE_EXTENSIONS: &[&str] = &["gz", "tar", "tgz", "zip"];
+
+/// Any other UTF-8 file is loaded as plain text as a last resort, see [`TextLoader`].
+pub const SUPPORTED_TEXT_EXTENSIONS: &[&str] = &[
+    "csv", "json", "log", "md", "toml", "tsv", "txt", "yaml", "yml",
+];
 
 /// All file extension supported by our builtin [`DataLoader`]s.
 pub fn supported_extensions() -> impl Iterator<Item = &'static str> {
@@ -471,6 +579,10 @@ pub fn supported_extensions() -> impl Iterator<Item = &'static str> {
         .chain(SUPPORTED_VIDEO_EXTENSIONS)
         .chain(SUPPORTED_MESH_EXTENSIONS)
         .chain(SUPPORTED_POINT_CLOUD_EXTENSIONS)
+        .chain(SUPPORTED_ROBOT_DESCRIPTION_EXTENSIONS)
+        .chain(SUPPORTED_MCAP_EXTENSIONS)
+        .chain(SUPPORTED_TABLE_EXTENSIONS)
+        .chain(SUPPORTED_ARCHIVE_EXTENSIONS)
         .chain(SUPPORTED_TEXT_EXTENSIONS)
         .copied()
 }
@@ -482,5 +594,9 @@ pub fn is_supported_file_extension(extension: &str) -> bool {
         || SUPPORTED_MESH_EXTENSIONS.contains(&extension)
         || SUPPORTED_POINT_CLOUD_EXTENSIONS.contains(&extension)
         || SUPPORTED_RERUN_EXTENSIONS.contains(&extension)
+        || SUPPORTED_ROBOT_DESCRIPTION_EXTENSIONS.contains(&extension)
+        || SUPPORTED_MCAP_EXTENSIONS.contains(&extension)
+        || SUPPORTED_TABLE_EXTENSIONS.contains(&extension)
+        || SUPPORTED_ARCHIVE_EXTENSIONS.contains(&extension)
         || SUPPORTED_TEXT_EXTENSIONS.contains(&extension)
 }
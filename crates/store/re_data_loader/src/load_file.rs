@@ -6,6 +6,9 @@ use re_smart_channel::Sender;
 
 use crate::{DataLoader as _, DataLoaderError, LoadedData, RrdLoader};
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::LoadedDataStream;
+
 // ---
 
 /// Loads the given `path` using all [`crate::DataLoader`]s available.
@@ -15,12 +18,15 @@ use crate::{DataLoader as _, DataLoaderError, LoadedData, RrdLoader};
 /// Synchronously checks whether the file exists and can be loaded. Beyond that, all
 /// errors are asynchronous and handled directly by the [`crate::DataLoader`]s themselves
 /// (i.e. they're logged).
+///
+/// If `tx` was created with [`re_smart_channel::smart_channel_with_byte_budget`], the
+/// [streaming loaders](crate::DataLoader::stream_from_path) only make progress as fast as `tx`
+/// gets drained.
 #[cfg(not(target_arch = "wasm32"))]
 pub fn load_from_path(
     settings: &crate::DataLoaderSettings,
     file_source: FileSource,
     path: &std::path::Path,
-    // NOTE: This channel must be unbounded since we serialize all operations when running on wasm.
     tx: &Sender<LogMsg>,
 ) -> Result<(), DataLoaderError> {
     re_tracing::profile_function!(path.to_string_lossy());
@@ -106,20 +112,55 @@ pub(crate) fn prepare_store_info(
     })
 }
 
+/// How many [`LoadedData`] can be buffered between the [`crate::DataLoader`]s and whoever consumes
+/// their output, before [streaming loaders](crate::DataLoader::stream_from_path) get paused.
+#[cfg(not(target_arch = "wasm32"))]
+const LOADED_DATA_CHANNEL_CAPACITY: usize = 64;
+
 /// Loads the data at `path` using all available [`crate::DataLoader`]s.
 ///
 /// On success, returns a channel with all the [`LoadedData`]:
 /// - On native, this is filled asynchronously from other threads.
 /// - On wasm, this is pre-filled synchronously.
 ///
+/// On native, the channel is bounded: [streaming loaders](crate::DataLoader::stream_from_path)
+/// only make progress as the channel gets drained.
+///
 /// There is only one way this function can return an error: not a single [`crate::DataLoader`]
-/// (whether it is builtin, custom or external) was capable of loading the data, in which case
-/// [`DataLoaderError::Incompatible`] will be returned.
+/// (whether it is builtin, custom or external) was capable of loading the data, and the data
+/// doesn't look like text either, in which case [`DataLoaderError::Incompatible`] will be returned.
 #[cfg(not(target_arch = "wasm32"))]
 pub(crate) fn load(
     settings: &crate::DataLoaderSettings,
     path: &std::path::Path,
     contents: Option<std::borrow::Cow<'_, [u8]>>,
+) -> Result<std::sync::mpsc::Receiver<LoadedData>, DataLoaderError> {
+    // Prevent passing RRD paths to external (and other) loaders.
+    // See <https://github.com/rerun-io/rerun/issues/6530>.
+    let loaders = {
+        use crate::DataLoader as _;
+        use rayon::iter::Either;
+
+        let extension = crate::extension(path);
+        if crate::SUPPORTED_RERUN_EXTENSIONS.contains(&extension.as_str()) {
+            Either::Left(
+                crate::iter_loaders().filter(|loader| loader.name() == crate::RrdLoader.name()),
+            )
+        } else {
+            Either::Right(crate::iter_loaders())
+        }
+    };
+
+    load_with_loaders(settings, path, contents, loaders)
+}
+
+/// Same as [`load`], but only considers the given `loaders`.
+#[cfg(not(target_arch = "wasm32"))]
+fn load_with_loaders(
+    settings: &crate::DataLoaderSettings,
+    path: &std::path::Path,
+    contents: Option<std::borrow::Cow<'_, [u8]>>,
+    loaders: impl IntoIterator<Item = std::sync::Arc<dyn crate::DataLoader>>,
 ) -> Result<std::sync::mpsc::Receiver<LoadedData>, DataLoaderError> {
     re_tracing::profile_function!(path.display().to_string());
 
@@ -128,6 +169,10 @@ pub(crate) fn load(
         contents.map(|contents| std::sync::Arc::new(Cow::Owned(contents.into_owned())));
 
     let rx_loader = {
+        // Streaming loaders send directly into the bounded output channel, while regular loaders
+        // get their own unbounded channel (which is what the `DataLoader` API expects), forwarded
+        // into the output channel further down.
+        let (tx_output, rx_output) = std::sync::mpsc::sync_channel(LOADED_DATA_CHANNEL_CAPACITY);
         let (tx_loader, rx_loader) = std::sync::mpsc::channel();
 
         let any_compatible_loader = {
@@ -135,23 +180,6 @@ pub(crate) fn load(
             struct CompatibleLoaderFound;
             let (tx_feedback, rx_feedback) = std::sync::mpsc::channel::<CompatibleLoaderFound>();
 
-            // Prevent passing RRD paths to external (and other) loaders.
-            // See <https://github.com/rerun-io/rerun/issues/6530>.
-            let loaders = {
-                use crate::DataLoader as _;
-                use rayon::iter::Either;
-
-                let extension = crate::extension(path);
-                if crate::SUPPORTED_RERUN_EXTENSIONS.contains(&extension.as_str()) {
-                    Either::Left(
-                        crate::iter_loaders()
-                            .filter(|loader| loader.name() == crate::RrdLoader.name()),
-                    )
-                } else {
-                    Either::Right(crate::iter_loaders())
-                }
-            };
-
             for loader in loaders {
                 let loader = std::sync::Arc::clone(&loader);
 
@@ -159,6 +187,7 @@ pub(crate) fn load(
                 let path = path.to_owned();
                 let contents = contents.clone(); // arc
 
+                let tx_output = tx_output.clone();
                 let tx_loader = tx_loader.clone();
                 let tx_feedback = tx_feedback.clone();
 
@@ -179,13 +208,23 @@ pub(crate) fn load(
                             }
                             re_log::error!(?path, loader = loader.name(), %err, "Failed to load data");
                         }
-                    } else if let Err(err) =
-                        loader.load_from_path(&settings, path.clone(), tx_loader)
-                    {
-                        if err.is_incompatible() {
-                            return;
+                    } else {
+                        let result = match loader.stream_from_path(&settings, path.clone()) {
+                            Ok(stream) => spawn_stream(loader.name(), &path, stream, move |data| {
+                                tx_output.send(data).is_ok()
+                            }),
+                            Err(err) if err.is_incompatible() => {
+                                loader.load_from_path(&settings, path.clone(), tx_loader)
+                            }
+                            Err(err) => Err(err),
+                        };
+
+                        if let Err(err) = result {
+                            if err.is_incompatible() {
+                                return;
+                            }
+                            re_log::error!(?path, loader = loader.name(), %err, "Failed to load data from file");
                         }
-                        re_log::error!(?path, loader = loader.name(), %err, "Failed to load data from file");
                     }
 
                     re_log::debug!(loader = loader.name(), ?path, "compatible loader found");
@@ -197,12 +236,41 @@ pub(crate) fn load(
 
             drop(tx_feedback);
 
-            rx_feedback.recv() == Ok(CompatibleLoaderFound)
+            let any_compatible_loader = rx_feedback.recv() == Ok(CompatibleLoaderFound);
+
+            // Last resort: anything that looks like text can at least be shown as such.
+            any_compatible_loader || {
+                if let Some(contents) = contents.as_deref() {
+                    crate::TextLoader
+                        .load_fallback_from_file_contents(settings, path, contents, &tx_loader)
+                } else {
+                    crate::TextLoader.load_fallback_from_path(settings, path, tx_loader.clone())
+                }
+            }
         };
 
-        // Implicitly closing `tx_loader`!
+        // Implicitly closing `tx_loader` and `tx_output`!
+
+        if any_compatible_loader {
+            // NOTE: This blocks whenever the output channel is full, it must run on a dedicated
+            // thread, not the shared rayon thread pool.
+            std::thread::Builder::new()
+                .name(format!("forward_loaded_data({path:?})"))
+                .spawn(move || {
+                    for data in rx_loader {
+                        if tx_output.send(data).is_err() {
+                            break; // The other end has decided to hang up, not our problem.
+                        }
+                    }
+                })
+                .map_err(|err| {
+                    DataLoaderError::Other(anyhow::anyhow!(
+                        "Failed to spawn IO thread for {path:?}: {err}"
+                    ))
+                })?;
+        }
 
-        any_compatible_loader.then_some(rx_loader)
+        any_compatible_loader.then_some(rx_output)
     };
 
     if let Some(rx_loader) = rx_loader {
@@ -212,13 +280,69 @@ pub(crate) fn load(
     }
 }
 
+/// Drains a [`LoadedDataStream`] into `tx` on a dedicated thread, logging any error along the way.
+///
+/// This is the simplest way to implement [`crate::DataLoader::load_from_path`] for loaders that
+/// support [streaming](crate::DataLoader::stream_from_path).
+#[cfg(not(target_arch = "wasm32"))]
+pub fn forward_stream(
+    loader_name: crate::DataLoaderName,
+    filepath: &std::path::Path,
+    stream: LoadedDataStream,
+    tx: std::sync::mpsc::Sender<LoadedData>,
+) -> Result<(), DataLoaderError> {
+    spawn_stream(loader_name, filepath, stream, move |data| {
+        tx.send(data).is_ok()
+    })
+}
+
+/// Pulls from `stream` on a dedicated thread, until it is exhausted or `send` returns `false`.
+#[cfg(not(target_arch = "wasm32"))]
+fn spawn_stream(
+    loader_name: crate::DataLoaderName,
+    filepath: &std::path::Path,
+    stream: LoadedDataStream,
+    mut send: impl FnMut(LoadedData) -> bool + Send + 'static,
+) -> Result<(), DataLoaderError> {
+    // NOTE: This is IO bound and blocks on the consumer, it must run on a dedicated thread, not
+    // the shared rayon thread pool.
+    std::thread::Builder::new()
+        .name(format!("stream({filepath:?})"))
+        .spawn({
+            let filepath = filepath.to_owned();
+            move || {
+                re_tracing::profile_function!(filepath.display().to_string());
+
+                for data in stream {
+                    match data {
+                        Ok(data) => {
+                            if !send(data) {
+                                break; // The other end has decided to hang up, not our problem.
+                            }
+                        }
+                        Err(err) => {
+                            re_log::error!(?filepath, loader = loader_name, %err, "Failed to load data from file");
+                        }
+                    }
+                }
+            }
+        })
+        .map_err(|err| {
+            DataLoaderError::Other(anyhow::anyhow!(
+                "Failed to spawn IO thread for {filepath:?}: {err}"
+            ))
+        })?;
+
+    Ok(())
+}
+
 /// Loads the data at `path` using all available [`crate::DataLoader`]s.
 ///
 /// On success, returns a channel (pre-filled synchronously) with all the [`LoadedData`].
 ///
 /// There is only one way this function can return an error: not a single [`crate::DataLoader`]
-/// (whether it is builtin, custom or external) was capable of loading the data, in which case
-/// [`DataLoaderError::Incompatible`] will be returned.
+/// (whether it is builtin, custom or external) was capable of loading the data, and the data
+/// doesn't look like text either, in which case [`DataLoaderError::Incompatible`] will be returned.
 #[cfg(target_arch = "wasm32")]
 #[allow(clippy::needless_pass_by_value)]
 pub(crate) fn load(
@@ -253,6 +377,13 @@ pub(crate) fn load(
             .reduce(|any_compatible, is_compatible| any_compatible || is_compatible)
             .unwrap_or(false);
 
+        // Last resort: anything that looks like text can at least be shown as such.
+        let any_compatible_loader = any_compatible_loader
+            || contents.as_deref().is_some_and(|contents| {
+                crate::TextLoader
+                    .load_fallback_from_file_contents(settings, path, contents, &tx_loader)
+            });
+
         // Implicitly closing `tx_loader`!
 
         any_compatible_loader.then_some(rx_loader)
@@ -320,6 +451,9 @@ pub(crate) fn send(
                         continue;
                     }
                 };
+
+                // Blocks if `tx` has a byte budget that is used up, which in turn pauses streaming
+                // loaders once the channel coming from the loaders is full.
                 tx.send(msg).ok();
             }
 
@@ -350,7 +484,8 @@ pub(crate) fn send(
 }
 
 // NOTE:
-// - On native, we parallelize using `rayon`.
+// - On native, we forward on a dedicated thread, since it blocks on both the loaders and the
+//   consumer: it must not run on the shared rayon thread pool.
 // - On wasm, we serialize everything, which works because the data-loading channels are unbounded.
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -358,7 +493,12 @@ fn spawn<F>(f: F)
 where
     F: FnOnce() + Send + 'static,
 {
-    rayon::spawn(f);
+    if let Err(err) = std::thread::Builder::new()
+        .name("send_loaded_data".to_owned())
+        .spawn(f)
+    {
+        re_log::error!(%err, "Failed to spawn thread to forward loaded data");
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -368,3 +508,108 @@ where
 {
     f();
 }
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use re_log_types::{StoreId, StoreKind};
+    use re_smart_channel::{SmartChannelSource, SmartMessageSource};
+
+    use super::*;
+    use crate::DataLoader;
+
+    /// Streams empty chunks forever, for files with an `endless` extension.
+    struct EndlessLoader {
+        /// How many items were pulled from the stream.
+        num_pulled: Arc<AtomicUsize>,
+    }
+
+    impl DataLoader for EndlessLoader {
+        fn name(&self) -> crate::DataLoaderName {
+            "test.EndlessLoader".into()
+        }
+
+        fn load_from_path(
+            &self,
+            settings: &crate::DataLoaderSettings,
+            path: std::path::PathBuf,
+            tx: std::sync::mpsc::Sender<LoadedData>,
+        ) -> Result<(), DataLoaderError> {
+            let stream = self.stream_from_path(settings, path.clone())?;
+            crate::forward_stream(self.name(), &path, stream, tx)
+        }
+
+        fn stream_from_path(
+            &self,
+            settings: &crate::DataLoaderSettings,
+            path: std::path::PathBuf,
+        ) -> Result<LoadedDataStream, DataLoaderError> {
+            if crate::extension(&path) != "endless" {
+                return Err(DataLoaderError::Incompatible(path));
+            }
+
+            let name = self.name();
+            let store_id = settings.store_id.clone();
+            let num_pulled = Arc::clone(&self.num_pulled);
+            Ok(Box::new(std::iter::repeat_with(move || {
+                num_pulled.fetch_add(1, Ordering::Relaxed);
+                let chunk = re_chunk::Chunk::builder("endless").build()?;
+                Ok(LoadedData::Chunk(name.clone(), store_id.clone(), chunk))
+            })))
+        }
+
+        fn load_from_file_contents(
+            &self,
+            _settings: &crate::DataLoaderSettings,
+            path: std::path::PathBuf,
+            _contents: Cow<'_, [u8]>,
+            _tx: std::sync::mpsc::Sender<LoadedData>,
+        ) -> Result<(), DataLoaderError> {
+            Err(DataLoaderError::Incompatible(path))
+        }
+    }
+
+    #[test]
+    fn test_streaming_backpressure() {
+        let path = std::path::Path::new("test.endless");
+        let num_pulled = Arc::new(AtomicUsize::new(0));
+        let loader: Arc<dyn DataLoader> = Arc::new(EndlessLoader {
+            num_pulled: Arc::clone(&num_pulled),
+        });
+
+        let settings =
+            crate::DataLoaderSettings::recommended(StoreId::random(StoreKind::Recording));
+        let rx_loader = load_with_loaders(&settings, path, None, [loader]).unwrap();
+
+        // Every message weighs a single byte, so that the budget is a number of messages.
+        let max_queued_messages = 16;
+        let (tx, rx) = re_smart_channel::smart_channel_with_byte_budget(
+            SmartMessageSource::File(path.to_owned()),
+            SmartChannelSource::File(path.to_owned()),
+            max_queued_messages,
+            |_msg: &LogMsg| 1,
+        );
+        send(settings, FileSource::Cli, rx_loader, &tx);
+
+        let num_received = 10;
+        for _ in 0..num_received {
+            rx.recv().unwrap();
+        }
+
+        // Give the stream a chance to run ahead, if it were able to.
+        std::thread::sleep(std::time::Duration::from_millis(100));
+
+        // Only as much as fits in both channels (+ the items blocked on sending into each of them)
+        // is ever pulled.
+        let num_pulled = num_pulled.load(Ordering::Relaxed);
+        assert!(
+            num_pulled
+                <= num_received + LOADED_DATA_CHANNEL_CAPACITY + max_queued_messages as usize + 2,
+            "pulled {num_pulled} items"
+        );
+    }
+}
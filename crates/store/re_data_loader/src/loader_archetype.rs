@@ -4,7 +4,7 @@ use re_chunk::{Chunk, RowId};
 use re_log_types::{EntityPath, TimePoint};
 use re_types::ComponentBatch;
 use re_types::archetypes::{AssetVideo, VideoFrameReference};
-use re_types::components::VideoTimestamp;
+use re_types::components::{MediaType, VideoTimestamp};
 
 use crate::{DataLoader, DataLoaderError, LoadedData};
 
@@ -54,7 +54,14 @@ impl DataLoader for ArchetypeLoader {
         tx: std::sync::mpsc::Sender<LoadedData>,
     ) -> Result<(), crate::DataLoaderError> {
         let extension = crate::extension(&filepath);
-        if !crate::is_supported_file_extension(&extension) {
+        if !crate::is_supported_file_extension(&extension)
+            || crate::loader_point_cloud::is_point_cloud_loader_extension(&extension)
+            || crate::SUPPORTED_ROBOT_DESCRIPTION_EXTENSIONS.contains(&extension.as_str())
+            || crate::SUPPORTED_MCAP_EXTENSIONS.contains(&extension.as_str())
+            || crate::SUPPORTED_TABLE_EXTENSIONS.contains(&extension.as_str())
+            || crate::SUPPORTED_ARCHIVE_EXTENSIONS.contains(&extension.as_str())
+            || crate::SUPPORTED_TEXT_EXTENSIONS.contains(&extension.as_str())
+        {
             return Err(crate::DataLoaderError::Incompatible(filepath.clone()));
         }
 
@@ -109,6 +116,7 @@ impl DataLoader for ArchetypeLoader {
                 timepoint,
                 &entity_path,
                 contents.into_owned(),
+                settings.video_frame_rate,
             )?);
         } else if crate::SUPPORTED_MESH_EXTENSIONS.contains(&extension.as_str()) {
             re_log::debug!(?filepath, loader = self.name(), "Loading 3D model…",);
@@ -119,16 +127,12 @@ impl DataLoader for ArchetypeLoader {
                 contents.into_owned(),
             )?);
         } else if crate::SUPPORTED_POINT_CLOUD_EXTENSIONS.contains(&extension.as_str()) {
-            re_log::debug!(?filepath, loader = self.name(), "Loading 3D point cloud…",);
+            re_log::debug!(
+                ?filepath,
+                loader = self.name(),
+                "Loading point cloud or mesh…",
+            );
             rows.extend(load_point_cloud(timepoint, entity_path, &contents)?);
-        } else if crate::SUPPORTED_TEXT_EXTENSIONS.contains(&extension.as_str()) {
-            re_log::debug!(?filepath, loader = self.name(), "Loading text document…",);
-            rows.extend(load_text_document(
-                filepath,
-                timepoint,
-                entity_path,
-                contents.into_owned(),
-            )?);
         }
 
         let store_id = settings
@@ -179,6 +183,7 @@ fn load_video(
     mut timepoint: TimePoint,
     entity_path: &EntityPath,
     contents: Vec<u8>,
+    frame_rate: Option<f64>,
 ) -> Result<impl ExactSizeIterator<Item = Chunk> + use<>, DataLoaderError> {
     re_tracing::profile_function!();
 
@@ -188,7 +193,14 @@ fn load_video(
         re_log_types::TimeCell::ZERO_DURATION,
     );
 
-    let video_asset = AssetVideo::new(contents);
+    // Raw bitstreams carry no timestamps, their frame rate is passed along as a parameter.
+    let media_type = MediaType::guess_from_path(filepath).map(|media_type| match frame_rate {
+        Some(frame_rate) if [MediaType::H264, MediaType::H265].contains(&media_type.as_str()) => {
+            MediaType::from(format!("{media_type}; framerate={frame_rate}"))
+        }
+        _ => media_type,
+    });
+    let video_asset = AssetVideo::from_file_contents(contents, media_type);
 
     let video_frame_reference_chunk = match video_asset.read_frame_timestamps_nanos() {
         Ok(frame_timestamps_nanos) => {
@@ -279,13 +291,23 @@ fn load_point_cloud(
 ) -> Result<impl ExactSizeIterator<Item = Chunk> + use<>, DataLoaderError> {
     re_tracing::profile_function!();
 
+    use re_types::ply::PlyContents;
+
     let rows = [
         {
-            // TODO(#4532): `.ply` data loader should support 2D point cloud & meshes
-            let points3d = re_types::archetypes::Points3D::from_file_contents(contents)?;
-            Chunk::builder(entity_path)
-                .with_archetype(RowId::new(), timepoint, &points3d)
-                .build()?
+            let builder = Chunk::builder(entity_path);
+            match PlyContents::from_file_contents(contents)? {
+                PlyContents::Points2D(points2d) => {
+                    builder.with_archetype(RowId::new(), timepoint, &points2d)
+                }
+                PlyContents::Points3D(points3d) => {
+                    builder.with_archetype(RowId::new(), timepoint, &points3d)
+                }
+                PlyContents::Mesh3D(mesh3d) => {
+                    builder.with_archetype(RowId::new(), timepoint, &mesh3d)
+                }
+            }
+            .build()?
         },
         //
     ];
@@ -293,26 +315,38 @@ fn load_point_cloud(
     Ok(rows.into_iter())
 }
 
-fn load_text_document(
-    filepath: std::path::PathBuf,
-    timepoint: TimePoint,
-    entity_path: EntityPath,
-    contents: Vec<u8>,
-) -> Result<impl ExactSizeIterator<Item = Chunk>, DataLoaderError> {
-    re_tracing::profile_function!();
-
-    let rows = [
-        {
-            let arch = re_types::archetypes::TextDocument::from_file_contents(
-                contents,
-                re_types::components::MediaType::guess_from_path(filepath),
-            )?;
-            Chunk::builder(entity_path)
-                .with_archetype(RowId::new(), timepoint, &arch)
-                .build()?
-        },
-        //
-    ];
+#[cfg(test)]
+mod tests {
+    use re_log_types::TimelineName;
+
+    use super::*;
+
+    #[test]
+    fn test_raw_video_frame_rate() {
+        let h264_stream = [
+            &[0, 0, 0, 1, 0x67, 0x42, 0x00, 0x1E, 0xF4, 0x0A, 0x0F, 0xC8][..], // SPS
+            &[0, 0, 0, 1, 0x68, 0xCE, 0x38, 0x80],                             // PPS
+            &[0, 0, 1, 0x65, 0x88, 0x84],                                      // IDR slice
+            &[0, 0, 1, 0x41, 0x9A],                                            // P slice
+        ]
+        .concat();
+
+        let frame_times = |frame_rate| {
+            let chunks = load_video(
+                std::path::Path::new("camera.h264"),
+                TimePoint::default(),
+                &EntityPath::from("camera"),
+                h264_stream.clone(),
+                frame_rate,
+            )
+            .unwrap()
+            .collect::<Vec<_>>();
+            chunks[1].timelines()[&TimelineName::new("video")]
+                .times_raw()
+                .to_vec()
+        };
 
-    Ok(rows.into_iter())
+        assert_eq!(frame_times(Some(50.0)), vec![0, 20_000_000]);
+        assert_eq!(frame_times(Some(25.0)), vec![0, 40_000_000]);
+    }
 }
@@ -1,5 +1,8 @@
 /// Recursively loads entire directories, using the appropriate [`crate::DataLoader`]:s for each
 /// files within.
+///
+/// Archives (`.zip`, `.tar`, `.tar.gz`, `.tgz`) are handled the same way: each file in the archive
+/// is dispatched to the other loaders, with its entity path derived from its archive-internal path.
 //
 // TODO(cmc): There are a lot more things than can be done be done when it comes to the semantics
 // of a folder, e.g.: HIVE-like partitioning, similarly named files with different indices and/or
@@ -21,7 +24,21 @@ impl crate::DataLoader for DirectoryLoader {
         tx: std::sync::mpsc::Sender<crate::LoadedData>,
     ) -> Result<(), crate::DataLoaderError> {
         if dirpath.is_file() {
-            return Err(crate::DataLoaderError::Incompatible(dirpath.clone()));
+            if ArchiveKind::from_path(&dirpath).is_none() {
+                return Err(crate::DataLoaderError::Incompatible(dirpath.clone()));
+            }
+
+            let contents = {
+                re_tracing::profile_scope!("fs::read");
+                std::fs::read(&dirpath)?
+            };
+
+            return self.load_from_file_contents(
+                settings,
+                dirpath,
+                std::borrow::Cow::Owned(contents),
+                tx,
+            );
         }
 
         if crate::lerobot::is_lerobot_dataset(&dirpath) {
@@ -43,6 +60,15 @@ impl crate::DataLoader for DirectoryLoader {
             };
 
             let filepath = entry.path();
+
+            // Sidecars are picked up by the `TableLoader` and `UrdfDataLoader` when loading the
+            // table or robot itself.
+            if crate::loader_table::is_table_sidecar_file(filepath)
+                || crate::loader_urdf::is_joint_states_sidecar_file(filepath)
+            {
+                continue;
+            }
+
             if filepath.is_file() {
                 let settings = settings.clone();
                 let filepath = filepath.to_owned();
@@ -76,15 +102,492 @@ impl crate::DataLoader for DirectoryLoader {
         Ok(())
     }
 
-    #[inline]
     fn load_from_file_contents(
         &self,
-        _settings: &crate::DataLoaderSettings,
-        path: std::path::PathBuf,
-        _contents: std::borrow::Cow<'_, [u8]>,
-        _tx: std::sync::mpsc::Sender<crate::LoadedData>,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        contents: std::borrow::Cow<'_, [u8]>,
+        tx: std::sync::mpsc::Sender<crate::LoadedData>,
     ) -> Result<(), crate::DataLoaderError> {
-        // TODO(cmc): This could make sense to implement for e.g. archive formats (zip, tar, …)
-        Err(crate::DataLoaderError::Incompatible(path))
+        let Some(kind) = ArchiveKind::from_path(&filepath) else {
+            return Err(crate::DataLoaderError::Incompatible(filepath));
+        };
+
+        re_log::debug!(?filepath, loader = self.name(), "Loading archive…");
+
+        let settings = settings.clone();
+        let contents = contents.into_owned();
+
+        let load = move || {
+            re_tracing::profile_function!(filepath.display().to_string());
+            let mut budget = ArchiveBudget::new(MAX_ARCHIVE_DECOMPRESSED_SIZE);
+            if let Err(err) = load_archive(
+                &settings,
+                kind,
+                &contents,
+                ArchiveLocation::TOP_LEVEL,
+                &mut budget,
+                &tx,
+            ) {
+                re_log::error!(?filepath, %err, "Failed to load archive");
+            }
+        };
+
+        // NOTE: Just like for directories, this must run on a dedicated thread to avoid a
+        // deadlock: `load` waits for loaders running on the common rayon thread pool, which is
+        // where we're being called from.
+        #[cfg(not(target_arch = "wasm32"))]
+        std::thread::Builder::new()
+            .name("load_archive".to_owned())
+            .spawn(load)?;
+
+        // On wasm everything runs synchronously anyway.
+        #[cfg(target_arch = "wasm32")]
+        load();
+
+        Ok(())
+    }
+}
+
+// ---
+
+/// The archive formats supported by the [`DirectoryLoader`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArchiveKind {
+    Zip,
+    Tar,
+    TarGz,
+}
+
+impl ArchiveKind {
+    fn from_path(filepath: &std::path::Path) -> Option<Self> {
+        let filename = filepath.file_name()?.to_str()?.to_ascii_lowercase();
+
+        if filename.ends_with(".zip") {
+            Some(Self::Zip)
+        } else if filename.ends_with(".tar") {
+            Some(Self::Tar)
+        } else if filename.ends_with(".tar.gz") || filename.ends_with(".tgz") {
+            Some(Self::TarGz)
+        } else {
+            None
+        }
+    }
+}
+
+/// Maximum total number of bytes that can be decompressed out of a single archive, nested
+/// archives included.
+///
+/// Protects against decompression bombs.
+const MAX_ARCHIVE_DECOMPRESSED_SIZE: u64 = 8 * 1024 * 1024 * 1024;
+
+/// Maximum depth of archives within archives.
+const MAX_ARCHIVE_NESTING_DEPTH: usize = 4;
+
+/// Keeps track of how much data can still be extracted out of an archive.
+///
+/// The archive headers are untrusted: entry sizes are never used for allocating, and the budget is
+/// enforced on the actual number of decompressed bytes.
+#[derive(Debug)]
+struct ArchiveBudget {
+    max_bytes: u64,
+    remaining_bytes: u64,
+}
+
+impl ArchiveBudget {
+    fn new(max_bytes: u64) -> Self {
+        Self {
+            max_bytes,
+            remaining_bytes: max_bytes,
+        }
+    }
+
+    /// Reads the whole entry, failing if it would exceed the remaining budget.
+    fn read_entry(
+        &mut self,
+        entry: impl std::io::Read,
+        entry_path: &std::path::Path,
+    ) -> anyhow::Result<Vec<u8>> {
+        use std::io::Read as _;
+
+        use anyhow::Context as _;
+
+        let mut contents = Vec::new();
+        entry
+            .take(self.remaining_bytes.saturating_add(1))
+            .read_to_end(&mut contents)
+            .with_context(|| format!("Failed to decompress {entry_path:?}"))?;
+
+        let num_bytes = contents.len() as u64;
+        anyhow::ensure!(
+            num_bytes <= self.remaining_bytes,
+            "Archive exceeds the maximum decompressed size of {} bytes (while reading {entry_path:?})",
+            self.max_bytes,
+        );
+        self.remaining_bytes -= num_bytes;
+
+        Ok(contents)
+    }
+}
+
+/// Where an archive lives relative to the top-level archive being loaded.
+#[derive(Debug, Clone, Copy)]
+struct ArchiveLocation<'a> {
+    /// Path of the archive within its parent archive, `None` for the top-level archive.
+    ///
+    /// Entries of nested archives are prefixed with it, so that two nested archives with the same
+    /// contents don't end up on the same entity paths.
+    prefix: Option<&'a std::path::Path>,
+
+    /// How many archives this one is nested in.
+    depth: usize,
+}
+
+impl ArchiveLocation<'_> {
+    const TOP_LEVEL: Self = Self {
+        prefix: None,
+        depth: 0,
+    };
+}
+
+/// Walks all the files in the archive and dispatches each of them to the appropriate loaders.
+///
+/// Archives nested in the archive are walked recursively, up to [`MAX_ARCHIVE_NESTING_DEPTH`].
+///
+/// Returns `Ok(false)` if `tx` gets disconnected.
+fn load_archive(
+    settings: &crate::DataLoaderSettings,
+    kind: ArchiveKind,
+    contents: &[u8],
+    location: ArchiveLocation<'_>,
+    budget: &mut ArchiveBudget,
+    tx: &std::sync::mpsc::Sender<crate::LoadedData>,
+) -> anyhow::Result<bool> {
+    use anyhow::Context as _;
+
+    match kind {
+        ArchiveKind::Zip => {
+            let mut archive = zip::ZipArchive::new(std::io::Cursor::new(contents))
+                .context("Failed to read zip archive")?;
+
+            for index in 0..archive.len() {
+                let file = archive
+                    .by_index(index)
+                    .with_context(|| format!("Failed to read zip entry #{index}"))?;
+                if !file.is_file() {
+                    continue;
+                }
+
+                // Rejects absolute paths and paths escaping the archive, e.g. `../foo.png`.
+                let Some(entry_path) = file.enclosed_name().map(ToOwned::to_owned) else {
+                    re_log::warn!(
+                        name = file.name(),
+                        "Skipping zip entry with an invalid path"
+                    );
+                    continue;
+                };
+
+                let entry_contents = budget.read_entry(file, &entry_path)?;
+
+                if !load_archive_entry(settings, &entry_path, entry_contents, location, budget, tx)
+                {
+                    return Ok(false);
+                }
+            }
+
+            Ok(true)
+        }
+
+        ArchiveKind::Tar => load_tar_archive(settings, contents, location, budget, tx),
+
+        ArchiveKind::TarGz => load_tar_archive(
+            settings,
+            flate2::read::GzDecoder::new(contents),
+            location,
+            budget,
+            tx,
+        ),
+    }
+}
+
+fn load_tar_archive(
+    settings: &crate::DataLoaderSettings,
+    reader: impl std::io::Read,
+    location: ArchiveLocation<'_>,
+    budget: &mut ArchiveBudget,
+    tx: &std::sync::mpsc::Sender<crate::LoadedData>,
+) -> anyhow::Result<bool> {
+    use anyhow::Context as _;
+
+    let mut archive = tar::Archive::new(reader);
+
+    for entry in archive.entries().context("Failed to read tar archive")? {
+        let entry = entry.context("Failed to read tar entry")?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+
+        let entry_path = entry.path().context("Invalid tar entry path")?.into_owned();
+        if entry_path.is_absolute()
+            || entry_path
+                .components()
+                .any(|c| c == std::path::Component::ParentDir)
+        {
+            re_log::warn!(?entry_path, "Skipping tar entry with an invalid path");
+            continue;
+        }
+
+        let entry_contents = budget.read_entry(entry, &entry_path)?;
+
+        if !load_archive_entry(settings, &entry_path, entry_contents, location, budget, tx) {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+/// Loads a single file from an archive, using its archive-internal path as file path.
+///
+/// Returns `false` if `tx` got disconnected.
+fn load_archive_entry(
+    settings: &crate::DataLoaderSettings,
+    entry_path: &std::path::Path,
+    contents: Vec<u8>,
+    location: ArchiveLocation<'_>,
+    budget: &mut ArchiveBudget,
+    tx: &std::sync::mpsc::Sender<crate::LoadedData>,
+) -> bool {
+    // Sidecars are picked up by the `TableLoader` and `UrdfDataLoader` when loading the table or
+    // robot itself.
+    if crate::loader_table::is_table_sidecar_file(entry_path)
+        || crate::loader_urdf::is_joint_states_sidecar_file(entry_path)
+    {
+        return true;
+    }
+
+    let entry_path = match location.prefix {
+        Some(prefix) => prefix.join(entry_path),
+        None => entry_path.to_owned(),
+    };
+
+    // Nested archives are walked right here rather than going through `load_file::load`, so that
+    // they share the nesting depth and the size budget of their parent.
+    if let Some(kind) = ArchiveKind::from_path(&entry_path) {
+        if MAX_ARCHIVE_NESTING_DEPTH <= location.depth {
+            re_log::warn!(
+                ?entry_path,
+                "Skipping archive nested more than {MAX_ARCHIVE_NESTING_DEPTH} levels deep"
+            );
+            return true;
+        }
+
+        let location = ArchiveLocation {
+            prefix: Some(&entry_path),
+            depth: location.depth + 1,
+        };
+
+        return match load_archive(settings, kind, &contents, location, budget, tx) {
+            Ok(connected) => connected,
+            Err(err) => {
+                re_log::error!(?entry_path, %err, "Failed to load nested archive");
+                true
+            }
+        };
+    }
+
+    let data = match crate::load_file::load(
+        settings,
+        &entry_path,
+        Some(std::borrow::Cow::Owned(contents)),
+    ) {
+        Ok(data) => data,
+        Err(err) if err.is_incompatible() => {
+            re_log::debug!(?entry_path, "No loader for archive entry, skipping");
+            return true;
+        }
+        Err(err) => {
+            re_log::error!(?entry_path, %err, "Failed to load archive entry");
+            return true;
+        }
+    };
+
+    for datum in data {
+        if tx.send(datum).is_err() {
+            return false;
+        }
+    }
+
+    true
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use std::io::Write as _;
+
+    use re_log_types::{EntityPath, StoreId, StoreKind};
+
+    use crate::{DataLoader as _, LoadedData};
+
+    use super::*;
+
+    fn tar(files: &[(&str, &[u8])]) -> std::io::Result<Vec<u8>> {
+        let mut builder = tar::Builder::new(Vec::new());
+        for (path, contents) in files {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(contents.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, path, *contents)?;
+        }
+        builder.into_inner()
+    }
+
+    fn zip(files: &[(&str, &[u8])]) -> zip::result::ZipResult<Vec<u8>> {
+        let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        for (path, contents) in files {
+            writer.start_file(*path, zip::write::FileOptions::default())?;
+            writer.write_all(contents)?;
+        }
+        Ok(writer.finish()?.into_inner())
+    }
+
+    fn loaded_entity_paths(
+        filepath: &str,
+        contents: Vec<u8>,
+    ) -> Result<Vec<EntityPath>, crate::DataLoaderError> {
+        let settings =
+            crate::DataLoaderSettings::recommended(StoreId::random(StoreKind::Recording));
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        DirectoryLoader.load_from_file_contents(
+            &settings,
+            filepath.into(),
+            std::borrow::Cow::Owned(contents),
+            tx,
+        )?;
+
+        let mut entity_paths = rx
+            .into_iter()
+            .filter_map(|data| match data {
+                LoadedData::Chunk(_, _, chunk) => Some(chunk.entity_path().clone()),
+                LoadedData::ArrowMsg(..) | LoadedData::LogMsg(..) => None,
+            })
+            .collect::<Vec<_>>();
+        entity_paths.sort();
+        entity_paths.dedup();
+        Ok(entity_paths)
+    }
+
+    #[test]
+    fn test_archive_kind() {
+        let kind = |path: &str| ArchiveKind::from_path(std::path::Path::new(path));
+
+        assert_eq!(kind("data/bundle.zip"), Some(ArchiveKind::Zip));
+        assert_eq!(kind("bundle.TAR"), Some(ArchiveKind::Tar));
+        assert_eq!(kind("bundle.tar.gz"), Some(ArchiveKind::TarGz));
+        assert_eq!(kind("bundle.tgz"), Some(ArchiveKind::TarGz));
+        assert_eq!(kind("recording.rrd.gz"), None);
+        assert_eq!(kind("data"), None);
+    }
+
+    #[test]
+    fn test_load_archives() {
+        let inner = tar(&[("notes/hello.md", b"# Hello")]).unwrap();
+        let archive = zip(&[
+            ("docs/readme.txt", b"hello world"),
+            ("nested/inner.tar", &inner),
+            ("../escaped.txt", b"should be skipped"),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            loaded_entity_paths("bundle.zip", archive).unwrap(),
+            vec![
+                EntityPath::from("docs/readme.txt"),
+                EntityPath::from("nested/inner.tar/notes/hello.md"),
+            ]
+        );
+
+        let archive = {
+            let mut encoder =
+                flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder
+                .write_all(&tar(&[("logs/run.log", b"started")]).unwrap())
+                .unwrap();
+            encoder.finish().unwrap()
+        };
+
+        assert_eq!(
+            loaded_entity_paths("bundle.tar.gz", archive).unwrap(),
+            vec![EntityPath::from("logs/run.log")]
+        );
+    }
+
+    #[test]
+    fn test_nested_archives() {
+        let inner = tar(&[("notes/hello.md", b"# Hello")]).unwrap();
+        let archive = tar(&[("a/inner.tar", &inner), ("b/inner.tar", &inner)]).unwrap();
+
+        // Identical nested archives must not collide.
+        assert_eq!(
+            loaded_entity_paths("bundle.tar", archive).unwrap(),
+            vec![
+                EntityPath::from("a/inner.tar/notes/hello.md"),
+                EntityPath::from("b/inner.tar/notes/hello.md"),
+            ]
+        );
+
+        let mut archive = tar(&[("deep.md", b"# Too deep")]).unwrap();
+        for _ in 0..=MAX_ARCHIVE_NESTING_DEPTH {
+            archive = tar(&[("nested.tar", &archive)]).unwrap();
+        }
+
+        assert_eq!(loaded_entity_paths("bundle.tar", archive).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_lying_archive_headers() {
+        let settings =
+            crate::DataLoaderSettings::recommended(StoreId::random(StoreKind::Recording));
+        let (tx, _rx) = std::sync::mpsc::channel();
+
+        // A 1 TiB entry that is actually a handful of bytes: must not be trusted for allocating.
+        let archive = {
+            let mut builder = tar::Builder::new(Vec::new());
+            let mut header = tar::Header::new_gnu();
+            header.set_size(1 << 40);
+            header.set_mode(0o644);
+            builder
+                .append_data(&mut header, "huge.txt", &b"not that huge"[..])
+                .unwrap();
+            builder.into_inner().unwrap()
+        };
+
+        let mut budget = ArchiveBudget::new(MAX_ARCHIVE_DECOMPRESSED_SIZE);
+        _ = load_archive(
+            &settings,
+            ArchiveKind::Tar,
+            &archive,
+            ArchiveLocation::TOP_LEVEL,
+            &mut budget,
+            &tx,
+        );
+        assert!(MAX_ARCHIVE_DECOMPRESSED_SIZE - budget.remaining_bytes <= archive.len() as u64);
+
+        // Decompressing more than the budget allows must fail, no matter what the headers say.
+        let archive = tar(&[("big.txt", &[b'a'; 4096])]).unwrap();
+
+        let mut budget = ArchiveBudget::new(1024);
+        let err = load_archive(
+            &settings,
+            ArchiveKind::Tar,
+            &archive,
+            ArchiveLocation::TOP_LEVEL,
+            &mut budget,
+            &tx,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("maximum decompressed size"));
     }
 }
@@ -1,7 +1,4 @@
-use std::{
-    sync::{Arc, mpsc::Sender},
-    thread,
-};
+use std::sync::{Arc, mpsc::Sender};
 
 use anyhow::{Context as _, anyhow};
 use arrow::{
@@ -32,7 +29,7 @@ use crate::lerobot::{
     is_v1_lerobot_dataset,
 };
 use crate::load_file::prepare_store_info;
-use crate::{DataLoader, DataLoaderError, LoadedData};
+use crate::{DataLoader, DataLoaderError, LoadedData, LoadedDataStream};
 
 /// Columns in the `LeRobot` dataset schema that we do not visualize in the viewer, and thus ignore.
 const LEROBOT_DATASET_IGNORED_COLUMNS: &[&str] =
@@ -58,13 +55,22 @@ impl DataLoader for LeRobotDatasetLoader {
         filepath: std::path::PathBuf,
         tx: Sender<LoadedData>,
     ) -> Result<(), DataLoaderError> {
+        let stream = self.stream_from_path(settings, filepath.clone())?;
+        crate::forward_stream(self.name(), &filepath, stream, tx)
+    }
+
+    fn stream_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+    ) -> Result<LoadedDataStream, DataLoaderError> {
         if !is_lerobot_dataset(&filepath) {
             return Err(DataLoaderError::Incompatible(filepath));
         }
 
         if is_v1_lerobot_dataset(&filepath) {
             re_log::error!("LeRobot 'v1.x' dataset format is unsupported.");
-            return Ok(());
+            return Ok(Box::new(std::iter::empty()));
         }
 
         let dataset = LeRobotDataset::load_from_directory(&filepath)
@@ -77,7 +83,7 @@ impl DataLoader for LeRobotDatasetLoader {
                 "LeRobot '{}' dataset format is unsupported.",
                 dataset.metadata.info.codebase_version
             );
-            return Ok(());
+            return Ok(Box::new(std::iter::empty()));
         }
 
         let application_id = settings
@@ -85,28 +91,13 @@ impl DataLoader for LeRobotDatasetLoader {
             .clone()
             .unwrap_or(ApplicationId(filepath.display().to_string()));
 
-        // NOTE(1): `spawn` is fine, this whole function is native-only.
-        // NOTE(2): this must spawned on a dedicated thread to avoid a deadlock!
-        // `load` will spawn a bunch of loaders on the common rayon thread pool and wait for
-        // their response via channels: we cannot be waiting for these responses on the
-        // common rayon thread pool.
-        thread::Builder::new()
-            .name(format!("load_and_stream({filepath:?}"))
-            .spawn({
-                move || {
-                    re_log::info!(
-                        "Loading LeRobot dataset from {:?}, with {} episode(s)",
-                        dataset.path,
-                        dataset.metadata.episodes.len(),
-                    );
-                    load_and_stream(&dataset, &application_id, &tx);
-                }
-            })
-            .with_context(|| {
-                format!("Failed to spawn IO thread to load LeRobot dataset {filepath:?} ")
-            })?;
+        re_log::info!(
+            "Loading LeRobot dataset from {:?}, with {} episode(s)",
+            dataset.path,
+            dataset.metadata.episodes.len(),
+        );
 
-        Ok(())
+        Ok(Box::new(stream_dataset(dataset, &application_id)))
     }
 
     fn load_from_file_contents(
@@ -120,87 +111,76 @@ impl DataLoader for LeRobotDatasetLoader {
     }
 }
 
-fn load_and_stream(
-    dataset: &LeRobotDataset,
+/// Lazily loads all the episodes of a dataset, one at a time, each to its own recording.
+///
+/// The [`SetStoreInfo`](`re_log_types::SetStoreInfo`) messages for all episodes are sent out
+/// first, so that the viewer knows about all of them right away.
+fn stream_dataset(
+    dataset: LeRobotDataset,
     application_id: &ApplicationId,
-    tx: &Sender<crate::LoadedData>,
-) {
-    // set up all recordings
-    let episodes = prepare_episode_chunks(dataset, application_id, tx);
-
-    for (episode, store_id) in &episodes {
-        // log episode data to its respective recording
-        match load_episode(dataset, *episode) {
-            Ok(chunks) => {
-                let recording_info = re_types::archetypes::RecordingInfo::new()
-                    .with_name(format!("Episode {}", episode.0));
-
-                let Ok(initial) = Chunk::builder(EntityPath::properties())
-                    .with_archetype(RowId::new(), TimePoint::STATIC, &recording_info)
-                    .build()
-                else {
-                    re_log::error!(
-                        "Failed to build recording properties chunk for episode {}",
-                        episode.0
-                    );
-                    return;
-                };
+) -> impl Iterator<Item = Result<LoadedData, DataLoaderError>> + use<> {
+    let loader_name = LeRobotDatasetLoader::name(&LeRobotDatasetLoader);
 
-                for chunk in std::iter::once(initial).chain(chunks.into_iter()) {
-                    let data = LoadedData::Chunk(
-                        LeRobotDatasetLoader::name(&LeRobotDatasetLoader),
-                        store_id.clone(),
-                        chunk,
-                    );
+    let episodes: Vec<(EpisodeIndex, StoreId)> = dataset
+        .metadata
+        .episodes
+        .iter()
+        .map(|episode| {
+            let store_id = StoreId::from_string(
+                re_log_types::StoreKind::Recording,
+                format!("episode_{}", episode.index.0),
+            );
+            (episode.index, store_id)
+        })
+        .collect();
 
-                    if tx.send(data).is_err() {
-                        break; // The other end has decided to hang up, not our problem.
-                    }
-                }
-            }
-            Err(err) => {
-                re_log::warn!(
-                    "Failed to load episode {} from LeRobot dataset: {err}",
-                    episode.0
-                );
-            }
-        }
-    }
-}
+    let store_infos: Vec<_> = episodes
+        .iter()
+        .map(|(_, store_id)| {
+            Ok(LoadedData::LogMsg(
+                loader_name.clone(),
+                prepare_store_info(
+                    application_id.clone(),
+                    store_id,
+                    re_log_types::FileSource::Sdk,
+                ),
+            ))
+        })
+        .collect();
 
-/// Prepare the viewer for all episodes, by sending out a [`SetStoreInfo`](`re_log_types::SetStoreInfo`)
-/// [`LogMsg`](`re_log_types::LogMsg`) for each episode.
-fn prepare_episode_chunks(
-    dataset: &LeRobotDataset,
-    application_id: &ApplicationId,
-    tx: &Sender<crate::LoadedData>,
-) -> Vec<(EpisodeIndex, StoreId)> {
-    let mut store_ids = vec![];
+    let episodes_data = episodes.into_iter().flat_map(move |(episode, store_id)| {
+        // NOTE: episodes are only loaded once the previous one has been entirely consumed.
+        let chunks = load_episode(&dataset, episode).and_then(|chunks| {
+            let recording_info = re_types::archetypes::RecordingInfo::new()
+                .with_name(format!("Episode {}", episode.0));
 
-    for episode in &dataset.metadata.episodes {
-        let episode = episode.index;
+            let initial = Chunk::builder(EntityPath::properties())
+                .with_archetype(RowId::new(), TimePoint::STATIC, &recording_info)
+                .build()?;
 
-        let store_id = StoreId::from_string(
-            re_log_types::StoreKind::Recording,
-            format!("episode_{}", episode.0),
-        );
-        let set_store_info = LoadedData::LogMsg(
-            LeRobotDatasetLoader::name(&LeRobotDatasetLoader),
-            prepare_store_info(
-                application_id.clone(),
-                &store_id,
-                re_log_types::FileSource::Sdk,
-            ),
-        );
+            Ok(std::iter::once(initial).chain(chunks))
+        });
 
-        if tx.send(set_store_info).is_err() {
-            break;
+        match chunks {
+            Ok(chunks) => {
+                let loader_name = loader_name.clone();
+                Either::Left(chunks.map(move |chunk| {
+                    Ok(LoadedData::Chunk(
+                        loader_name.clone(),
+                        store_id.clone(),
+                        chunk,
+                    ))
+                }))
+            }
+            Err(err) => Either::Right(std::iter::once(Err(anyhow!(
+                "Failed to load episode {} from LeRobot dataset: {err}",
+                episode.0
+            )
+            .into()))),
         }
+    });
 
-        store_ids.push((episode, store_id.clone()));
-    }
-
-    store_ids
+    store_infos.into_iter().chain(episodes_data)
 }
 
 /// Loads a single episode from a `LeRobot` dataset and converts it into a collection of Rerun chunks.
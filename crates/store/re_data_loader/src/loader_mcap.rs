@@ -0,0 +1,749 @@
+//! Loads [MCAP](https://mcap.dev/) files, as recorded by ROS 2.
+//!
+//! Every topic is logged to the entity of the same name, on a `log_time` timeline.
+//!
+//! Messages of the following ROS 2 types are converted to Rerun archetypes:
+//! * `sensor_msgs/msg/Image` → [`Image`] or [`DepthImage`]
+//! * `sensor_msgs/msg/CompressedImage` → [`EncodedImage`]
+//! * `sensor_msgs/msg/PointCloud2` → [`Points3D`]
+//! * `sensor_msgs/msg/Imu` → [`Scalars`], one child entity per measured quantity
+//! * `sensor_msgs/msg/CameraInfo` → [`Pinhole`]
+//! * `geometry_msgs/msg/TransformStamped` & `tf2_msgs/msg/TFMessage` → [`Transform3D`]
+//!
+//! Transforms are not logged to their topic: instead, all coordinate frames are laid out in a
+//! hierarchy under `/tf`, mirroring the transform tree found in the file (e.g. the transform from
+//! `base_link` to `camera` is logged to `/tf/map/base_link/camera`).
+//!
+//! Messages of any other type (or that fail to decode) are logged as-is, as [`AnyValues`].
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::{Arc, mpsc::Sender};
+
+use anyhow::Context as _;
+use arrow::array::{BinaryArray, StringArray};
+
+use re_chunk::{Chunk, ChunkBuilder, RowId};
+use re_log_types::{EntityPath, EntityPathPart, TimePoint, Timeline};
+use re_types::{
+    AnyValues, AsComponents,
+    archetypes::{DepthImage, EncodedImage, Image, Pinhole, Points3D, Scalars, Transform3D},
+    components::MediaType,
+    datatypes::{ChannelDatatype, ColorModel, Quaternion},
+};
+
+use crate::ros2::{self, CdrReader};
+use crate::{DataLoader, DataLoaderError, LoadedData};
+
+// ---
+
+/// The timeline all messages are logged on, based on the time at which they were recorded.
+const LOG_TIME_TIMELINE: &str = "log_time";
+
+/// The entity under which the transform tree is laid out.
+const TF_ENTITY: &str = "tf";
+
+/// A chunk is sent off once it reaches that many rows…
+const MAX_ROWS_PER_CHUNK: usize = 4096;
+
+/// …or that many bytes of message data.
+const MAX_BYTES_PER_CHUNK: usize = 8 * 1024 * 1024;
+
+/// A [`DataLoader`] for [MCAP](https://mcap.dev/) files.
+///
+/// See the [module-level documentation](self) for how messages are mapped to Rerun archetypes.
+pub struct McapLoader;
+
+impl DataLoader for McapLoader {
+    #[inline]
+    fn name(&self) -> String {
+        "rerun.data_loaders.Mcap".into()
+    }
+
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if filepath.is_dir() || !is_supported_mcap_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath));
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let contents = std::fs::read(&filepath)
+            .with_context(|| format!("Failed to read file {filepath:?}"))?;
+
+        load_mcap(&self.name(), settings, &filepath, &contents, &tx)
+    }
+
+    fn load_from_file_contents(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        contents: std::borrow::Cow<'_, [u8]>,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !is_supported_mcap_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath));
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        load_mcap(&self.name(), settings, &filepath, &contents, &tx)
+    }
+}
+
+fn is_supported_mcap_file(filepath: &std::path::Path) -> bool {
+    crate::SUPPORTED_MCAP_EXTENSIONS.contains(&crate::extension(filepath).as_str())
+}
+
+fn load_mcap(
+    loader_name: &str,
+    settings: &crate::DataLoaderSettings,
+    filepath: &std::path::Path,
+    contents: &[u8],
+    tx: &Sender<LoadedData>,
+) -> Result<(), DataLoaderError> {
+    re_tracing::profile_function!();
+
+    re_log::debug!(?filepath, loader = loader_name, "Loading MCAP file…");
+
+    let store_id = settings
+        .opened_store_id
+        .clone()
+        .unwrap_or_else(|| settings.store_id.clone());
+
+    let frame_tree = FrameTree::from_mcap(contents)
+        .with_context(|| format!("Failed to read MCAP file {filepath:?}"))?;
+
+    let mut batcher = ChunkBatcher {
+        loader_name,
+        store_id,
+        entity_path_prefix: settings.entity_path_prefix.clone(),
+        tx,
+        pending: BTreeMap::new(),
+        is_hung_up: false,
+    };
+
+    let timeline = Timeline::new_timestamp(LOG_TIME_TIMELINE);
+    let mut known_topics = HashSet::new();
+
+    for message in mcap::MessageStream::new(contents)
+        .with_context(|| format!("Failed to read MCAP file {filepath:?}"))?
+    {
+        let message =
+            message.with_context(|| format!("Failed to read MCAP message in {filepath:?}"))?;
+
+        let channel = &message.channel;
+        let entity_path = EntityPath::parse_forgiving(&channel.topic);
+
+        if known_topics.insert(channel.topic.clone()) {
+            batcher.send_chunk(
+                Chunk::builder(batcher.prefixed(entity_path.clone()))
+                    .with_archetype(RowId::new(), TimePoint::STATIC, &channel_info(channel))
+                    .build()?,
+            );
+        }
+
+        // NOTE: MCAP timestamps are nanoseconds since the epoch.
+        let timepoint = TimePoint::default().with(timeline, message.log_time as i64);
+
+        let logged = if channel.message_encoding == "cdr" {
+            log_ros2_message(
+                &mut batcher,
+                &frame_tree,
+                &entity_path,
+                &timepoint,
+                &message,
+            )
+        } else {
+            Ok(false)
+        };
+
+        let logged = logged.unwrap_or_else(|err| {
+            re_log::warn_once!(
+                "Failed to decode message on topic {:?}, logging it as raw data: {err}",
+                channel.topic
+            );
+            false
+        });
+
+        if !logged {
+            let schema_name = channel
+                .schema
+                .as_ref()
+                .map_or("mcap.Message", |schema| schema.name.as_str());
+            let values = AnyValues::new(schema_name).with_field(
+                "data",
+                Arc::new(BinaryArray::from_vec(vec![message.data.as_ref()])),
+            );
+            batcher.add(entity_path, &timepoint, &values, message.data.len())?;
+        }
+
+        if batcher.is_hung_up {
+            return Ok(()); // The other end has decided to hang up, not our problem.
+        }
+    }
+
+    batcher.flush_all()
+}
+
+/// Static information about an MCAP channel, logged once per topic.
+fn channel_info(channel: &mcap::Channel<'_>) -> AnyValues {
+    let schema = channel.schema.as_ref();
+    AnyValues::new("mcap.Channel")
+        .with_field(
+            "topic",
+            Arc::new(StringArray::from(vec![channel.topic.as_str()])),
+        )
+        .with_field(
+            "message_encoding",
+            Arc::new(StringArray::from(vec![channel.message_encoding.as_str()])),
+        )
+        .with_field(
+            "schema_name",
+            Arc::new(StringArray::from(vec![
+                schema.map(|schema| schema.name.as_str()),
+            ])),
+        )
+        .with_field(
+            "schema_encoding",
+            Arc::new(StringArray::from(vec![
+                schema.map(|schema| schema.encoding.as_str()),
+            ])),
+        )
+}
+
+/// Logs a CDR-encoded ROS 2 message, if its type is supported.
+///
+/// Returns `false` if the message type is not supported.
+fn log_ros2_message(
+    batcher: &mut ChunkBatcher<'_>,
+    frame_tree: &FrameTree,
+    entity_path: &EntityPath,
+    timepoint: &TimePoint,
+    message: &mcap::Message<'_>,
+) -> anyhow::Result<bool> {
+    let Some(schema) = message.channel.schema.as_ref() else {
+        return Ok(false);
+    };
+
+    let data = message.data.as_ref();
+    let num_bytes = data.len();
+    let reader = &mut CdrReader::new(data)?;
+
+    match ros2_message_type(&schema.name).as_str() {
+        "sensor_msgs/Image" => {
+            let image = ros2::Image::decode(reader)?;
+            let Some(converted) = convert_image(&image) else {
+                re_log::warn_once!(
+                    "Unsupported image encoding {:?} on topic {:?}",
+                    image.encoding,
+                    message.channel.topic
+                );
+                return Ok(false);
+            };
+            batcher.add(
+                entity_path.clone(),
+                timepoint,
+                converted.as_ref(),
+                num_bytes,
+            )?;
+        }
+
+        "sensor_msgs/CompressedImage" => {
+            let image = ros2::CompressedImage::decode(reader)?;
+            let Some(media_type) = compressed_image_media_type(&image) else {
+                re_log::warn_once!(
+                    "Unsupported compressed image format {:?} on topic {:?}",
+                    image.format,
+                    message.channel.topic
+                );
+                return Ok(false);
+            };
+
+            let image = EncodedImage::new(image.data.to_vec()).with_media_type(media_type);
+            batcher.add(entity_path.clone(), timepoint, &image, num_bytes)?;
+        }
+
+        "sensor_msgs/PointCloud2" => {
+            let cloud = ros2::PointCloud2::decode(reader)?;
+            let points = convert_point_cloud(&cloud)
+                .context("Point cloud has no x/y/z fields, or an invalid layout")?;
+            batcher.add(entity_path.clone(), timepoint, &points, num_bytes)?;
+        }
+
+        "sensor_msgs/Imu" => {
+            let imu = ros2::Imu::decode(reader)?;
+            for (name, scalars) in [
+                ("orientation", imu.orientation.to_xyzw_f32().to_vec()),
+                ("angular_velocity", imu.angular_velocity.to_f32().to_vec()),
+                (
+                    "linear_acceleration",
+                    imu.linear_acceleration.to_f32().to_vec(),
+                ),
+            ] {
+                batcher.add(
+                    entity_path.join(&EntityPath::from_single_string(name)),
+                    timepoint,
+                    &Scalars::new(scalars.into_iter().map(f64::from)),
+                    0,
+                )?;
+            }
+        }
+
+        "sensor_msgs/CameraInfo" => {
+            let info = ros2::CameraInfo::decode(reader)?;
+            let k = info.k.map(|value| value as f32);
+
+            // `K` is row-major, whereas `Pinhole` expects columns.
+            let pinhole =
+                Pinhole::new([[k[0], k[3], k[6]], [k[1], k[4], k[7]], [k[2], k[5], k[8]]])
+                    .with_resolution([info.width as f32, info.height as f32]);
+            batcher.add(entity_path.clone(), timepoint, &pinhole, 0)?;
+        }
+
+        "geometry_msgs/TransformStamped" => {
+            let transform = ros2::TransformStamped::decode(reader)?;
+            log_transform(batcher, frame_tree, timepoint, &transform)?;
+        }
+
+        "tf2_msgs/TFMessage" => {
+            for transform in ros2::TfMessage::decode(reader)?.transforms {
+                log_transform(batcher, frame_tree, timepoint, &transform)?;
+            }
+        }
+
+        _ => return Ok(false),
+    }
+
+    Ok(true)
+}
+
+/// Normalizes ROS 2 message type names, e.g. `sensor_msgs/msg/Image` becomes `sensor_msgs/Image`.
+fn ros2_message_type(schema_name: &str) -> String {
+    schema_name.replacen("/msg/", "/", 1)
+}
+
+fn log_transform(
+    batcher: &mut ChunkBatcher<'_>,
+    frame_tree: &FrameTree,
+    timepoint: &TimePoint,
+    transform: &ros2::TransformStamped,
+) -> Result<(), DataLoaderError> {
+    let rotation = Quaternion::from_xyzw(transform.rotation.to_xyzw_f32());
+    batcher.add(
+        frame_tree.entity_path(&transform.child_frame_id),
+        timepoint,
+        &Transform3D::from_translation_rotation(transform.translation.to_f32(), rotation),
+        0,
+    )
+}
+
+// --- Images ---
+
+/// Converts a raw ROS image, based on its [encoding](https://github.com/ros2/common_interfaces/blob/rolling/sensor_msgs/include/sensor_msgs/image_encodings.hpp).
+///
+/// Returns either an [`Image`] or a [`DepthImage`], or `None` for unsupported encodings.
+fn convert_image(image: &ros2::Image<'_>) -> Option<Box<dyn AsComponents>> {
+    enum Kind {
+        Color(ColorModel),
+        Depth { meter: f32 },
+    }
+
+    let (kind, datatype) = match image.encoding.as_str() {
+        "rgb8" => (Kind::Color(ColorModel::RGB), ChannelDatatype::U8),
+        "rgba8" => (Kind::Color(ColorModel::RGBA), ChannelDatatype::U8),
+        "bgr8" | "8UC3" => (Kind::Color(ColorModel::BGR), ChannelDatatype::U8),
+        "bgra8" | "8UC4" => (Kind::Color(ColorModel::BGRA), ChannelDatatype::U8),
+        "mono8" | "8UC1" => (Kind::Color(ColorModel::L), ChannelDatatype::U8),
+        "rgb16" => (Kind::Color(ColorModel::RGB), ChannelDatatype::U16),
+        "rgba16" => (Kind::Color(ColorModel::RGBA), ChannelDatatype::U16),
+        "bgr16" => (Kind::Color(ColorModel::BGR), ChannelDatatype::U16),
+        "bgra16" => (Kind::Color(ColorModel::BGRA), ChannelDatatype::U16),
+        "mono16" => (Kind::Color(ColorModel::L), ChannelDatatype::U16),
+
+        // By convention, integer depth images are in millimeters and float ones in meters.
+        "16UC1" => (Kind::Depth { meter: 1000.0 }, ChannelDatatype::U16),
+        "32FC1" => (Kind::Depth { meter: 1.0 }, ChannelDatatype::F32),
+        _ => return None,
+    };
+
+    let num_channels = match kind {
+        Kind::Color(color_model) => color_model.num_channels(),
+        Kind::Depth { .. } => 1,
+    };
+    let bytes_per_channel = datatype.bits() / 8;
+    let bytes = image_bytes(image, num_channels * bytes_per_channel, bytes_per_channel);
+    let resolution = [image.width, image.height];
+
+    Some(match kind {
+        Kind::Color(color_model) => Box::new(Image::from_color_model_and_bytes(
+            bytes,
+            resolution,
+            color_model,
+            datatype,
+        )),
+        Kind::Depth { meter } => Box::new(
+            DepthImage::from_data_type_and_bytes(bytes, resolution, datatype).with_meter(meter),
+        ),
+    })
+}
+
+/// The tightly packed, little-endian pixel data of an image.
+fn image_bytes(
+    image: &ros2::Image<'_>,
+    bytes_per_pixel: usize,
+    bytes_per_channel: usize,
+) -> Vec<u8> {
+    let row_len = image.width as usize * bytes_per_pixel;
+    let step = image.step as usize;
+
+    let mut bytes = if step > row_len {
+        image
+            .data
+            .chunks(step)
+            .take(image.height as usize)
+            .flat_map(|row| &row[..row_len.min(row.len())])
+            .copied()
+            .collect()
+    } else {
+        image.data.to_vec()
+    };
+
+    if image.is_bigendian && bytes_per_channel > 1 {
+        for value in bytes.chunks_exact_mut(bytes_per_channel) {
+            value.reverse();
+        }
+    }
+
+    bytes
+}
+
+/// The media type of a compressed image, based on its [format](https://github.com/ros2/common_interfaces/blob/rolling/sensor_msgs/msg/CompressedImage.msg).
+///
+/// Compressed depth images are not supported, as they come with a custom header.
+fn compressed_image_media_type(image: &ros2::CompressedImage<'_>) -> Option<MediaType> {
+    let format = image.format.to_lowercase();
+    if format.contains("compresseddepth") {
+        None
+    } else if format.contains("jpeg") || format.contains("jpg") {
+        Some(MediaType::jpeg())
+    } else if format.contains("png") {
+        Some(MediaType::png())
+    } else {
+        MediaType::guess_from_data(image.data)
+    }
+}
+
+// --- Point clouds ---
+
+/// Converts a point cloud, skipping points with non-finite positions.
+///
+/// Returns `None` if the point cloud doesn't have `x`, `y` and `z` fields.
+fn convert_point_cloud(cloud: &ros2::PointCloud2<'_>) -> Option<Points3D> {
+    let positions = itertools::izip!(
+        cloud.iter_field_f32(cloud.field("x")?)?,
+        cloud.iter_field_f32(cloud.field("y")?)?,
+        cloud.iter_field_f32(cloud.field("z")?)?,
+    )
+    .map(<[f32; 3]>::from);
+
+    // Colors are packed as BGR(A) bytes into a single 4-byte value.
+    let colors = cloud
+        .field("rgb")
+        .or_else(|| cloud.field("rgba"))
+        .filter(|field| field.datatype.size() == Some(4))
+        .and_then(|field| cloud.iter_field_bytes(field))
+        .map(|colors| {
+            colors.map(|bytes| {
+                let [b, g, r] = if cloud.is_bigendian {
+                    [bytes[3], bytes[2], bytes[1]]
+                } else {
+                    [bytes[0], bytes[1], bytes[2]]
+                };
+                [r, g, b]
+            })
+        });
+
+    let (positions, colors): (Vec<_>, Vec<_>) = match colors {
+        Some(colors) => positions
+            .zip(colors)
+            .filter(|(position, _)| position.iter().all(|value| value.is_finite()))
+            .map(|(position, color)| (position, Some(color)))
+            .unzip(),
+        None => positions
+            .filter(|position| position.iter().all(|value| value.is_finite()))
+            .map(|position| (position, None))
+            .unzip(),
+    };
+
+    let points = Points3D::new(positions);
+    let colors: Option<Vec<[u8; 3]>> = colors.into_iter().collect();
+    Some(match colors {
+        Some(colors) => points.with_colors(colors),
+        None => points,
+    })
+}
+
+// --- Transforms ---
+
+/// The parent of each coordinate frame, as found in all the transforms of an MCAP file.
+#[derive(Default)]
+struct FrameTree {
+    parents: HashMap<String, String>,
+}
+
+impl FrameTree {
+    fn from_mcap(contents: &[u8]) -> anyhow::Result<Self> {
+        re_tracing::profile_function!();
+
+        let mut tree = Self::default();
+        for message in mcap::MessageStream::new(contents)? {
+            let message = message?;
+            let channel = &message.channel;
+            let Some(schema) = channel.schema.as_ref() else {
+                continue;
+            };
+            if channel.message_encoding != "cdr" {
+                continue;
+            }
+
+            // Messages that fail to decode are reported when they are actually logged.
+            let Ok(reader) = &mut CdrReader::new(&message.data) else {
+                continue;
+            };
+            let transforms = match ros2_message_type(&schema.name).as_str() {
+                "geometry_msgs/TransformStamped" => ros2::TransformStamped::decode(reader)
+                    .map(|transform| vec![transform])
+                    .unwrap_or_default(),
+                "tf2_msgs/TFMessage" => ros2::TfMessage::decode(reader)
+                    .map(|tf| tf.transforms)
+                    .unwrap_or_default(),
+                _ => continue,
+            };
+
+            for transform in transforms {
+                tree.parents
+                    .insert(transform.child_frame_id, transform.header.frame_id);
+            }
+        }
+
+        Ok(tree)
+    }
+
+    /// The entity a coordinate frame is logged to, e.g. `/tf/map/base_link/camera`.
+    fn entity_path(&self, frame_id: &str) -> EntityPath {
+        let mut frames = vec![frame_id];
+        let mut visited = HashSet::from([frame_id]);
+        while let Some(parent) = self.parents.get(*frames.last().unwrap_or(&frame_id)) {
+            if !visited.insert(parent.as_str()) {
+                re_log::warn_once!("Cycle in transform tree around frame {frame_id:?}");
+                break;
+            }
+            frames.push(parent);
+        }
+
+        std::iter::once(TF_ENTITY)
+            .chain(frames.into_iter().rev())
+            .map(|frame| EntityPathPart::new(frame.trim_start_matches('/')))
+            .collect()
+    }
+}
+
+// --- Chunk batching ---
+
+/// Accumulates rows per entity, and sends them off as chunks when they grow big enough.
+struct ChunkBatcher<'a> {
+    loader_name: &'a str,
+    store_id: re_log_types::StoreId,
+    entity_path_prefix: Option<EntityPath>,
+    tx: &'a Sender<LoadedData>,
+    pending: BTreeMap<EntityPath, PendingChunk>,
+    is_hung_up: bool,
+}
+
+struct PendingChunk {
+    builder: ChunkBuilder,
+    num_rows: usize,
+    num_bytes: usize,
+}
+
+impl ChunkBatcher<'_> {
+    fn prefixed(&self, entity_path: EntityPath) -> EntityPath {
+        match &self.entity_path_prefix {
+            Some(prefix) => prefix.join(&entity_path),
+            None => entity_path,
+        }
+    }
+
+    fn add(
+        &mut self,
+        entity_path: EntityPath,
+        timepoint: &TimePoint,
+        archetype: &dyn AsComponents,
+        num_bytes: usize,
+    ) -> Result<(), DataLoaderError> {
+        let entity_path = self.prefixed(entity_path);
+        let pending = self
+            .pending
+            .entry(entity_path.clone())
+            .or_insert_with(|| PendingChunk {
+                builder: Chunk::builder(entity_path.clone()),
+                num_rows: 0,
+                num_bytes: 0,
+            });
+
+        let builder = std::mem::replace(&mut pending.builder, Chunk::builder(entity_path.clone()));
+        pending.builder = builder.with_archetype(RowId::new(), timepoint.clone(), archetype);
+        pending.num_rows += 1;
+        pending.num_bytes += num_bytes;
+
+        if MAX_ROWS_PER_CHUNK <= pending.num_rows || MAX_BYTES_PER_CHUNK <= pending.num_bytes {
+            if let Some(pending) = self.pending.remove(&entity_path) {
+                self.send_chunk(pending.builder.build()?);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn send_chunk(&mut self, chunk: Chunk) {
+        let data = LoadedData::Chunk(self.loader_name.to_owned(), self.store_id.clone(), chunk);
+        if self.tx.send(data).is_err() {
+            // The other end has decided to hang up: stop accumulating data.
+            self.pending.clear();
+            self.is_hung_up = true;
+        }
+    }
+
+    fn flush_all(&mut self) -> Result<(), DataLoaderError> {
+        for (_, pending) in std::mem::take(&mut self.pending) {
+            self.send_chunk(pending.builder.build()?);
+            if self.is_hung_up {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+// ---
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ros2::tests::CdrWriter;
+
+    fn write_mcap(messages: &[(&str, &str, u64, Vec<u8>)]) -> Vec<u8> {
+        let mut buffer = std::io::Cursor::new(Vec::new());
+        {
+            let mut writer = mcap::Writer::new(&mut buffer).unwrap();
+            let mut channels = HashMap::new();
+            for (sequence, (topic, schema_name, log_time, data)) in messages.iter().enumerate() {
+                let channel_id = *channels.entry(*topic).or_insert_with(|| {
+                    let schema_id = writer.add_schema(schema_name, "ros2msg", &[]).unwrap();
+                    writer
+                        .add_channel(schema_id, topic, "cdr", &BTreeMap::new())
+                        .unwrap()
+                });
+                writer
+                    .write_to_known_channel(
+                        &mcap::records::MessageHeader {
+                            channel_id,
+                            sequence: sequence as u32,
+                            log_time: *log_time,
+                            publish_time: *log_time,
+                        },
+                        data,
+                    )
+                    .unwrap();
+            }
+            writer.finish().unwrap();
+        }
+        buffer.into_inner()
+    }
+
+    fn load(contents: &[u8]) -> Vec<Chunk> {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let settings = crate::DataLoaderSettings::recommended(re_log_types::StoreId::random(
+            re_log_types::StoreKind::Recording,
+        ));
+        McapLoader
+            .load_from_file_contents(
+                &settings,
+                "test.mcap".into(),
+                std::borrow::Cow::Borrowed(contents),
+                tx,
+            )
+            .unwrap();
+
+        rx.into_iter()
+            .filter_map(|data| match data {
+                LoadedData::Chunk(_, _, chunk) => Some(chunk),
+                _ => None,
+            })
+            .filter(|chunk| !chunk.is_static())
+            .collect()
+    }
+
+    #[test]
+    fn test_load_ros2_messages() {
+        let image = CdrWriter::default()
+            .header(0, "camera")
+            .u32(1)
+            .u32(2)
+            .string("mono8")
+            .u8(0)
+            .u32(4) // padded rows
+            .bytes(&[1, 2, 0, 0])
+            .finish();
+        let tf = CdrWriter::default()
+            .u32(2)
+            .transform("map", "base_link", [1.0, 0.0, 0.0])
+            .transform("base_link", "camera", [0.0, 0.0, 1.0])
+            .finish();
+
+        let chunks = load(&write_mcap(&[
+            ("/camera/image", "sensor_msgs/msg/Image", 10, image.clone()),
+            ("/camera/image", "sensor_msgs/msg/Image", 20, image),
+            ("/tf", "tf2_msgs/msg/TFMessage", 10, tf),
+            ("/unknown", "my_msgs/msg/Custom", 30, vec![0, 1, 0, 0, 42]),
+        ]));
+
+        let entity_paths: Vec<_> = chunks
+            .iter()
+            .map(|chunk| (chunk.entity_path().to_string(), chunk.num_rows()))
+            .collect();
+        assert_eq!(
+            entity_paths,
+            vec![
+                ("/camera/image".to_owned(), 2),
+                ("/tf/map/base_link".to_owned(), 1),
+                ("/tf/map/base_link/camera".to_owned(), 1),
+                ("/unknown".to_owned(), 1),
+            ]
+        );
+
+        let image_chunk = &chunks[0];
+        assert!(
+            image_chunk
+                .timelines()
+                .contains_key(&LOG_TIME_TIMELINE.into())
+        );
+        let buffer = image_chunk
+            .component_batch::<re_types::components::ImageBuffer>(&Image::descriptor_buffer(), 0)
+            .unwrap()
+            .unwrap();
+        assert_eq!(buffer[0].0.as_ref(), &[1, 2]);
+
+        let unknown_chunk = &chunks[3];
+        assert!(
+            unknown_chunk
+                .components()
+                .keys()
+                .any(|descr| descr.archetype == Some("my_msgs/msg/Custom".into()))
+        );
+    }
+}
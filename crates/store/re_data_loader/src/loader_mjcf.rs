@@ -0,0 +1,607 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::mpsc::Sender,
+};
+
+use ahash::{HashMap, HashMapExt as _};
+use anyhow::Context as _;
+use roxmltree::Node;
+
+use re_chunk::EntityPath;
+use re_log_types::{EntityPathPart, StoreId};
+use re_types::{
+    AsComponents,
+    archetypes::{Asset3D, Boxes3D, Capsules3D, Cylinders3D, Ellipsoids3D, Transform3D},
+    components::{Color, MediaType},
+    external::glam,
+};
+
+use crate::{
+    DataLoader, DataLoaderError, LoadedData,
+    robot_description::{children, parse_floats, send_archetype, u8},
+};
+
+fn has_mjcf_extension(filepath: &Path) -> bool {
+    matches!(crate::extension(filepath).as_str(), "mjcf" | "xml")
+}
+
+/// A [`DataLoader`] for [MJCF](https://mujoco.readthedocs.io/en/stable/XMLreference.html) files,
+/// the XML model format of MuJoCo.
+///
+/// Logs the body hierarchy with static [`Transform3D`]s, and the geoms of each body as primitives
+/// or meshes. Joints, actuators, sites etc. are ignored.
+///
+/// `.xml` files are only picked up if their root element is `<mujoco>`.
+pub struct MjcfLoader;
+
+impl DataLoader for MjcfLoader {
+    fn name(&self) -> crate::DataLoaderName {
+        "rerun.data_loaders.Mjcf".into()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: PathBuf,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !has_mjcf_extension(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath));
+        }
+
+        let contents = {
+            re_tracing::profile_scope!("fs::read");
+            std::fs::read(&filepath)?
+        };
+
+        self.load_from_file_contents(settings, filepath, std::borrow::Cow::Owned(contents), tx)
+    }
+
+    fn load_from_file_contents(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: PathBuf,
+        contents: std::borrow::Cow<'_, [u8]>,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !has_mjcf_extension(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath));
+        }
+
+        // Plenty of `.xml` files aren't MuJoCo models: leave them to the other loaders.
+        let Some(doc) = std::str::from_utf8(&contents)
+            .ok()
+            .and_then(|text| roxmltree::Document::parse(text).ok())
+            .filter(|doc| doc.root_element().has_tag_name("mujoco"))
+        else {
+            return Err(DataLoaderError::Incompatible(filepath));
+        };
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let model = MjcfModel::new(&doc, filepath.parent());
+        model
+            .log(&tx, &settings.store_id)
+            .with_context(|| format!("Failed to load MJCF file {filepath:?}"))?;
+
+        Ok(())
+    }
+}
+
+// ---
+
+/// The `<default>` classes of a model, which provide default attribute values.
+struct DefaultClass<'a, 'input> {
+    parent: Option<&'a str>,
+    node: Node<'a, 'input>,
+}
+
+struct MjcfModel<'a, 'input> {
+    name: &'a str,
+    root: Node<'a, 'input>,
+
+    /// Directory that mesh files are relative to.
+    mesh_dir: Option<PathBuf>,
+
+    /// Whether angles are specified in degrees (the default) or radians.
+    degrees: bool,
+
+    /// Sequence of rotation axes used by the `euler` attribute.
+    euler_seq: &'a str,
+
+    defaults: HashMap<&'a str, DefaultClass<'a, 'input>>,
+    meshes: HashMap<String, Node<'a, 'input>>,
+    materials: HashMap<&'a str, Node<'a, 'input>>,
+}
+
+impl<'a, 'input> MjcfModel<'a, 'input> {
+    fn new(doc: &'a roxmltree::Document<'input>, model_dir: Option<&Path>) -> Self {
+        let root = doc.root_element();
+        let name = root.attribute("model").unwrap_or("mujoco");
+
+        let compiler = children(root, "compiler").next();
+        let compiler_attr = |name: &str| compiler.and_then(|compiler| compiler.attribute(name));
+
+        let mesh_dir = compiler_attr("meshdir")
+            .or_else(|| compiler_attr("assetdir"))
+            .map(PathBuf::from);
+        let mesh_dir = match (model_dir, mesh_dir) {
+            (Some(model_dir), Some(mesh_dir)) => Some(model_dir.join(mesh_dir)),
+            (model_dir, mesh_dir) => mesh_dir.or_else(|| model_dir.map(ToOwned::to_owned)),
+        };
+
+        let mut model = Self {
+            name,
+            root,
+            mesh_dir,
+            degrees: compiler_attr("angle") != Some("radian"),
+            euler_seq: compiler_attr("eulerseq").unwrap_or("xyz"),
+            defaults: HashMap::new(),
+            meshes: HashMap::new(),
+            materials: HashMap::new(),
+        };
+
+        for default in children(root, "default") {
+            model.collect_defaults(default, None);
+        }
+
+        for asset in children(root, "asset") {
+            for mesh in children(asset, "mesh") {
+                let name = mesh.attribute("name").map(ToOwned::to_owned).or_else(|| {
+                    // Meshes are named after their file by default.
+                    let file = Path::new(mesh.attribute("file")?);
+                    Some(file.file_stem()?.to_string_lossy().into_owned())
+                });
+                if let Some(name) = name {
+                    model.meshes.insert(name, mesh);
+                }
+            }
+
+            for material in children(asset, "material") {
+                if let Some(name) = material.attribute("name") {
+                    model.materials.insert(name, material);
+                }
+            }
+        }
+
+        model
+    }
+
+    fn collect_defaults(&mut self, node: Node<'a, 'input>, parent: Option<&'a str>) {
+        let class = node.attribute("class").unwrap_or("main");
+        self.defaults.insert(class, DefaultClass { parent, node });
+
+        for child in children(node, "default") {
+            self.collect_defaults(child, Some(class));
+        }
+    }
+
+    /// Looks up an attribute, falling back to the `<default>` class of the element.
+    fn attr(&self, node: Node<'a, 'input>, name: &str) -> Option<&'a str> {
+        if let Some(value) = node.attribute(name) {
+            return Some(value);
+        }
+
+        let mut class = node
+            .attribute("class")
+            .or_else(|| {
+                node.ancestors()
+                    .skip(1)
+                    .find_map(|ancestor| ancestor.attribute("childclass"))
+            })
+            .unwrap_or("main");
+
+        // Guard against cycles in malformed files.
+        for _ in 0..self.defaults.len() {
+            let default = self.defaults.get(class)?;
+            let value = children(default.node, node.tag_name().name())
+                .find_map(|element| element.attribute(name));
+            if value.is_some() {
+                return value;
+            }
+            class = default.parent?;
+        }
+
+        None
+    }
+
+    fn floats(&self, node: Node<'a, 'input>, name: &str) -> Option<Vec<f32>> {
+        self.attr(node, name).and_then(parse_floats)
+    }
+
+    fn angle(&self, angle: f32) -> f32 {
+        if self.degrees {
+            angle.to_radians()
+        } else {
+            angle
+        }
+    }
+
+    /// The pose of a body or geom relative to its parent.
+    fn pose(&self, node: Node<'a, 'input>) -> (glam::Vec3, glam::Quat) {
+        let translation = self
+            .floats(node, "pos")
+            .and_then(vec3)
+            .unwrap_or(glam::Vec3::ZERO);
+
+        let rotation = if let Some([w, x, y, z]) = self.floats(node, "quat").and_then(array) {
+            glam::Quat::from_xyzw(x, y, z, w).normalize()
+        } else if let Some([x, y, z, angle]) = self.floats(node, "axisangle").and_then(array) {
+            glam::Quat::from_axis_angle(glam::vec3(x, y, z).normalize_or_zero(), self.angle(angle))
+        } else if let Some(angles) = self.floats(node, "euler").and_then(vec3) {
+            self.euler_seq.chars().zip(angles.to_array()).fold(
+                glam::Quat::IDENTITY,
+                |rotation, (axis, angle)| {
+                    let step = glam::Quat::from_axis_angle(
+                        match axis.to_ascii_lowercase() {
+                            'x' => glam::Vec3::X,
+                            'y' => glam::Vec3::Y,
+                            _ => glam::Vec3::Z,
+                        },
+                        self.angle(angle),
+                    );
+                    // Lowercase axes rotate with the frame, uppercase ones are fixed.
+                    if axis.is_ascii_lowercase() {
+                        rotation * step
+                    } else {
+                        step * rotation
+                    }
+                },
+            )
+        } else if let Some([x0, x1, x2, y0, y1, y2]) = self.floats(node, "xyaxes").and_then(array) {
+            let x = glam::vec3(x0, x1, x2).normalize_or_zero();
+            let y =
+                (glam::vec3(y0, y1, y2) - x * x.dot(glam::vec3(y0, y1, y2))).normalize_or_zero();
+            glam::Quat::from_mat3(&glam::Mat3::from_cols(x, y, x.cross(y)))
+        } else if let Some(z) = self.floats(node, "zaxis").and_then(vec3) {
+            glam::Quat::from_rotation_arc(glam::Vec3::Z, z.normalize_or_zero())
+        } else {
+            glam::Quat::IDENTITY
+        };
+
+        (translation, rotation)
+    }
+
+    fn log(&self, tx: &Sender<LoadedData>, store_id: &StoreId) -> anyhow::Result<()> {
+        let root_path = EntityPath::from_single_string(self.name);
+
+        for worldbody in children(self.root, "worldbody") {
+            self.log_body_contents(tx, store_id, worldbody, &root_path)?;
+        }
+
+        Ok(())
+    }
+
+    /// Logs the geoms and child bodies of a `<body>`, `<frame>` or `<worldbody>`.
+    fn log_body_contents(
+        &self,
+        tx: &Sender<LoadedData>,
+        store_id: &StoreId,
+        body: Node<'a, 'input>,
+        body_path: &EntityPath,
+    ) -> anyhow::Result<()> {
+        let mut num_geoms = 0;
+        let mut num_bodies = 0;
+
+        for child in body.children().filter(Node::is_element) {
+            match child.tag_name().name() {
+                "geom" => {
+                    let name = child
+                        .attribute("name")
+                        .map_or_else(|| format!("geom_{num_geoms}"), ToOwned::to_owned);
+                    num_geoms += 1;
+
+                    self.log_geom(
+                        tx,
+                        store_id,
+                        child,
+                        &(body_path / EntityPathPart::new(name)),
+                    )?;
+                }
+
+                "body" | "frame" => {
+                    let name = child.attribute("name").map_or_else(
+                        || format!("{}_{num_bodies}", child.tag_name().name()),
+                        ToOwned::to_owned,
+                    );
+                    num_bodies += 1;
+
+                    let child_path = body_path / EntityPathPart::new(name);
+                    send_pose(tx, store_id, &child_path, self.pose(child), None)?;
+                    self.log_body_contents(tx, store_id, child, &child_path)?;
+                }
+
+                "include" => {
+                    re_log::warn_once!("MJCF <include> is not supported");
+                }
+
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn log_geom(
+        &self,
+        tx: &Sender<LoadedData>,
+        store_id: &StoreId,
+        geom: Node<'a, 'input>,
+        entity_path: &EntityPath,
+    ) -> anyhow::Result<()> {
+        let geom_type = self.attr(geom, "type").unwrap_or("sphere");
+        let size = self.floats(geom, "size").unwrap_or_default();
+        let size = |i: usize| size.get(i).copied().unwrap_or_default();
+
+        let color = self
+            .floats(geom, "rgba")
+            .or_else(|| {
+                let material = self.materials.get(self.attr(geom, "material")?)?;
+                self.floats(*material, "rgba")
+            })
+            .and_then(array)
+            .map(|[r, g, b, a]| Color::from_unmultiplied_rgba(u8(r), u8(g), u8(b), u8(a)));
+
+        // `fromto` overrides the pose of capsules & cylinders, which are centered on their frame.
+        let fromto = self
+            .floats(geom, "fromto")
+            .and_then(array)
+            .map(|[x0, y0, z0, x1, y1, z1]| (glam::vec3(x0, y0, z0), glam::vec3(x1, y1, z1)));
+        let (endpoints, pose) = match fromto {
+            Some(endpoints) if matches!(geom_type, "capsule" | "cylinder") => {
+                (endpoints, (glam::Vec3::ZERO, glam::Quat::IDENTITY))
+            }
+            _ => {
+                let half_length = glam::vec3(0.0, 0.0, size(1));
+                ((-half_length, half_length), self.pose(geom))
+            }
+        };
+
+        let archetype: Box<dyn AsComponents> = match geom_type {
+            "sphere" => Box::new(Ellipsoids3D::from_radii([size(0)]).with_colors(color)),
+
+            "ellipsoid" => Box::new(
+                Ellipsoids3D::from_half_sizes([[size(0), size(1), size(2)]]).with_colors(color),
+            ),
+
+            "box" => {
+                Box::new(Boxes3D::from_half_sizes([[size(0), size(1), size(2)]]).with_colors(color))
+            }
+
+            "capsule" => Box::new(
+                Capsules3D::from_endpoints_and_radii([endpoints.0], [endpoints.1], [size(0)])
+                    .with_colors(color),
+            ),
+
+            "cylinder" => {
+                let (start, end) = endpoints;
+                Box::new(
+                    Cylinders3D::from_lengths_and_radii([start.distance(end)], [size(0)])
+                        .with_centers([(start + end) * 0.5])
+                        .with_quaternions([glam::Quat::from_rotation_arc(
+                            glam::Vec3::Z,
+                            (end - start).try_normalize().unwrap_or(glam::Vec3::Z),
+                        )])
+                        .with_colors(color),
+                )
+            }
+
+            "plane" => {
+                // A size of zero means an infinite plane, which we can't show.
+                if size(0) <= 0.0 || size(1) <= 0.0 {
+                    return Ok(());
+                }
+                Box::new(Boxes3D::from_half_sizes([[size(0), size(1), 0.0]]).with_colors(color))
+            }
+
+            "mesh" => {
+                let Some(mesh) = self
+                    .attr(geom, "mesh")
+                    .and_then(|mesh| self.meshes.get(mesh))
+                else {
+                    re_log::warn!(?entity_path, "MJCF geom references an unknown mesh");
+                    return Ok(());
+                };
+
+                let Some(file) = mesh.attribute("file") else {
+                    re_log::warn_once!("MJCF meshes without a file are not supported");
+                    return Ok(());
+                };
+                let filepath = match &self.mesh_dir {
+                    Some(mesh_dir) => mesh_dir.join(file),
+                    None => PathBuf::from(file),
+                };
+
+                let contents = match read_asset(&filepath) {
+                    Ok(contents) => contents,
+                    Err(err) => {
+                        re_log::warn!(?filepath, %err, "Failed to load MJCF mesh");
+                        return Ok(());
+                    }
+                };
+
+                let mut asset =
+                    Asset3D::from_file_contents(contents, MediaType::guess_from_path(&filepath));
+                if let Some(color) = color {
+                    asset = asset.with_albedo_factor(color.0);
+                }
+
+                let scale = self.floats(*mesh, "scale").and_then(vec3);
+                send_pose(tx, store_id, entity_path, pose, scale)?;
+                return send_archetype(tx, &MjcfLoader, store_id, entity_path.clone(), &asset);
+            }
+
+            geom_type => {
+                re_log::warn_once!("MJCF geoms of type {geom_type:?} are not supported");
+                return Ok(());
+            }
+        };
+
+        send_pose(tx, store_id, entity_path, pose, None)?;
+        send_archetype(
+            tx,
+            &MjcfLoader,
+            store_id,
+            entity_path.clone(),
+            archetype.as_ref(),
+        )
+    }
+}
+
+/// Logs a static [`Transform3D`], unless it's the identity.
+fn send_pose(
+    tx: &Sender<LoadedData>,
+    store_id: &StoreId,
+    entity_path: &EntityPath,
+    (translation, rotation): (glam::Vec3, glam::Quat),
+    scale: Option<glam::Vec3>,
+) -> anyhow::Result<()> {
+    let scale = scale.filter(|scale| *scale != glam::Vec3::ONE);
+    if translation == glam::Vec3::ZERO && rotation == glam::Quat::IDENTITY && scale.is_none() {
+        return Ok(()); // avoid noise
+    }
+
+    let mut transform = Transform3D::from_translation(translation).with_quaternion(rotation);
+    if let Some(scale) = scale {
+        transform = transform.with_scale(scale);
+    }
+    send_archetype(tx, &MjcfLoader, store_id, entity_path.clone(), &transform)
+}
+
+fn array<const N: usize>(values: Vec<f32>) -> Option<[f32; N]> {
+    values.try_into().ok()
+}
+
+fn vec3(values: Vec<f32>) -> Option<glam::Vec3> {
+    array(values).map(glam::Vec3::from_array)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read_asset(filepath: &Path) -> anyhow::Result<Vec<u8>> {
+    Ok(std::fs::read(filepath)?)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn read_asset(filepath: &Path) -> anyhow::Result<Vec<u8>> {
+    anyhow::bail!("Loading mesh files is not supported in WebAssembly: {filepath:?}");
+}
+
+#[cfg(test)]
+mod tests {
+    use re_chunk::Chunk;
+    use re_types::{
+        Component, ComponentDescriptor,
+        components::{HalfSize3D, Length, RotationQuat, Translation3D},
+    };
+
+    use super::*;
+
+    const MJCF: &str = r#"<mujoco model="arm">
+  <default>
+    <geom rgba="1 0 0 1"/>
+    <default class="visual">
+      <geom type="box" size="0.1 0.2 0.3"/>
+    </default>
+  </default>
+  <worldbody>
+    <geom name="floor" type="plane" size="1 1 0.1"/>
+    <body name="upper" pos="0 0 1" euler="0 0 90" childclass="visual">
+      <geom name="shell"/>
+      <geom name="bone" type="capsule" fromto="0 0 0 0 0 0.5" size="0.05"/>
+      <body pos="0 0 0.5">
+        <geom type="sphere" size="0.1" class="main"/>
+      </body>
+    </body>
+  </worldbody>
+</mujoco>
+"#;
+
+    fn load(filepath: &str, contents: &str) -> Result<Vec<Chunk>, DataLoaderError> {
+        let settings = crate::DataLoaderSettings::recommended(StoreId::random(
+            re_log_types::StoreKind::Recording,
+        ));
+        let (tx, rx) = std::sync::mpsc::channel();
+        MjcfLoader.load_from_file_contents(
+            &settings,
+            filepath.into(),
+            std::borrow::Cow::Borrowed(contents.as_bytes()),
+            tx,
+        )?;
+
+        Ok(rx
+            .into_iter()
+            .filter_map(|data| match data {
+                LoadedData::Chunk(_, _, chunk) => Some(chunk),
+                LoadedData::ArrowMsg(..) | LoadedData::LogMsg(..) => None,
+            })
+            .collect())
+    }
+
+    fn component<C: Component>(
+        chunks: &[Chunk],
+        entity_path: &str,
+        descriptor: &ComponentDescriptor,
+    ) -> Option<Vec<C>> {
+        chunks
+            .iter()
+            .filter(|chunk| chunk.entity_path() == &EntityPath::from(entity_path))
+            .find_map(|chunk| chunk.component_batch::<C>(descriptor, 0)?.ok())
+    }
+
+    #[test]
+    fn test_load_mjcf() {
+        let chunks = load("arm.xml", MJCF).unwrap();
+
+        let translation = component::<Translation3D>(
+            &chunks,
+            "arm/upper",
+            &Transform3D::descriptor_translation(),
+        )
+        .unwrap();
+        assert_eq!(translation, vec![Translation3D::new(0.0, 0.0, 1.0)]);
+        let rotation =
+            component::<RotationQuat>(&chunks, "arm/upper", &Transform3D::descriptor_quaternion())
+                .unwrap();
+        assert!(
+            glam::Quat::from_array(rotation[0].0.xyzw())
+                .abs_diff_eq(glam::Quat::from_rotation_z(90f32.to_radians()), 1e-6)
+        );
+
+        // Geom type and size come from the `childclass` of the body, the color from its parent class.
+        let half_sizes = component::<HalfSize3D>(
+            &chunks,
+            "arm/upper/shell",
+            &Boxes3D::descriptor_half_sizes(),
+        )
+        .unwrap();
+        assert_eq!(half_sizes, vec![HalfSize3D::new(0.1, 0.2, 0.3)]);
+        let colors =
+            component::<Color>(&chunks, "arm/upper/shell", &Boxes3D::descriptor_colors()).unwrap();
+        assert_eq!(colors, vec![Color::from_rgb(255, 0, 0)]);
+
+        let lengths =
+            component::<Length>(&chunks, "arm/upper/bone", &Capsules3D::descriptor_lengths())
+                .unwrap();
+        assert_eq!(lengths, vec![Length::from(0.5)]);
+
+        assert!(
+            component::<HalfSize3D>(
+                &chunks,
+                "arm/upper/body_0/geom_0",
+                &Ellipsoids3D::descriptor_half_sizes()
+            )
+            .is_some()
+        );
+        assert!(
+            component::<HalfSize3D>(&chunks, "arm/floor", &Boxes3D::descriptor_half_sizes())
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_not_mjcf() {
+        let err = load("data.xml", "<root><child/></root>").unwrap_err();
+        assert!(err.is_incompatible());
+    }
+}
@@ -0,0 +1,1065 @@
+//! Loaders for the point cloud formats that don't map 1-to-1 to an archetype: PCD, LAS/LAZ & XYZ.
+//!
+//! (PLY files are handled by the [`crate::ArchetypeLoader`].)
+//!
+//! Every point cloud is logged as [`Points3D`].
+//! RGB(A) values are mapped to colors, while any other per-point scalar (e.g. `intensity`) is
+//! logged as an extra component next to the points.
+//! Point clouds without colors are colored by their intensity, if any.
+//!
+//! Large point clouds are split into several batches of at most [`POINTS_PER_BATCH`] points,
+//! each logged to its own child entity, rather than as a single giant row.
+
+use std::{path::PathBuf, sync::Arc, sync::mpsc::Sender};
+
+use anyhow::Context as _;
+
+use re_chunk::{Chunk, EntityPath, RowId, TimePoint};
+use re_log_types::EntityPathPart;
+use re_types::{
+    AnyValues, AsComponents as _,
+    archetypes::{Points3D, Transform3D},
+    components::Color,
+    external::glam,
+};
+
+use crate::{DataLoader, DataLoaderError, LoadedData};
+
+/// The maximum number of points logged in a single row.
+pub const POINTS_PER_BATCH: usize = 1 << 20;
+
+/// The point cloud extensions handled by the [`PointCloudLoader`].
+const POINT_CLOUD_LOADER_EXTENSIONS: &[&str] = &["las", "laz", "pcd", "xyz"];
+
+/// Is this a point cloud extension handled by the [`PointCloudLoader`] (as opposed to PLY)?
+pub(crate) fn is_point_cloud_loader_extension(extension: &str) -> bool {
+    POINT_CLOUD_LOADER_EXTENSIONS.contains(&extension)
+}
+
+/// A [`DataLoader`] for PCD (Point Cloud Library), LAS/LAZ (ASPRS lidar) and XYZ point clouds.
+///
+/// Points are logged as [`Points3D`], batched into several child entities for large clouds.
+/// See the module documentation for how colors and extra fields are mapped.
+pub struct PointCloudLoader;
+
+impl DataLoader for PointCloudLoader {
+    #[inline]
+    fn name(&self) -> crate::DataLoaderName {
+        "rerun.data_loaders.PointCloud".into()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: PathBuf,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !is_point_cloud_loader_extension(&crate::extension(&filepath)) {
+            return Err(DataLoaderError::Incompatible(filepath));
+        }
+
+        let contents = {
+            re_tracing::profile_scope!("fs::read");
+            std::fs::read(&filepath).with_context(|| format!("Failed to read file {filepath:?}"))?
+        };
+
+        self.load_from_file_contents(settings, filepath, std::borrow::Cow::Owned(contents), tx)
+    }
+
+    fn load_from_file_contents(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: PathBuf,
+        contents: std::borrow::Cow<'_, [u8]>,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        let extension = crate::extension(&filepath);
+        if !is_point_cloud_loader_extension(&extension) {
+            return Err(DataLoaderError::Incompatible(filepath));
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let (mut cloud, origin) = match extension.as_str() {
+            "pcd" => (parse_pcd(&contents)?, None),
+            "xyz" => (parse_xyz(&contents)?, None),
+            _ => {
+                let (cloud, origin) = parse_las(contents.into_owned())?;
+                (cloud, Some(origin))
+            }
+        };
+        cloud.fill_colors_from_intensity();
+
+        let store_id = settings
+            .opened_store_id
+            .clone()
+            .unwrap_or_else(|| settings.store_id.clone());
+        let entity_path = EntityPath::from_file_path(&filepath);
+
+        if let Some(origin) = origin {
+            // Georeferenced coordinates don't fit in `f32`: the points are relative to the
+            // center of the cloud instead, which is moved back into place by a transform.
+            let transform = Transform3D::from_translation(origin.as_vec3());
+            let chunk = Chunk::builder(entity_path.clone())
+                .with_archetype(RowId::new(), TimePoint::default(), &transform)
+                .build()?;
+            if tx
+                .send(LoadedData::Chunk(self.name(), store_id.clone(), chunk))
+                .is_err()
+            {
+                return Ok(()); // The other end has decided to hang up, not our problem.
+            }
+        }
+
+        for chunk in cloud.into_chunks(&entity_path, POINTS_PER_BATCH) {
+            if tx
+                .send(LoadedData::Chunk(self.name(), store_id.clone(), chunk?))
+                .is_err()
+            {
+                break; // The other end has decided to hang up, not our problem.
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// ---
+
+/// A decoded point cloud, before being batched into chunks.
+#[derive(Default)]
+struct PointCloud {
+    positions: Vec<[f32; 3]>,
+    colors: Option<Vec<Color>>,
+
+    /// Extra per-point scalar fields, e.g. `intensity`.
+    scalars: Vec<(String, Vec<f32>)>,
+}
+
+impl PointCloud {
+    /// Maps the `intensity` field, if any, to grayscale colors when there are no colors already.
+    fn fill_colors_from_intensity(&mut self) {
+        if self.colors.is_some() {
+            return;
+        }
+        let Some((_, intensities)) = self.scalars.iter().find(|(name, _)| name == "intensity")
+        else {
+            return;
+        };
+
+        let (min, max) = intensities
+            .iter()
+            .filter(|value| value.is_finite())
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), &value| {
+                (min.min(value), max.max(value))
+            });
+        let range = if max > min { max - min } else { 1.0 };
+
+        self.colors = Some(
+            intensities
+                .iter()
+                .map(|&intensity| {
+                    let gray = ((intensity - min) / range * 255.0).clamp(0.0, 255.0) as u8;
+                    Color::from_rgb(gray, gray, gray)
+                })
+                .collect(),
+        );
+    }
+
+    /// Splits the point cloud into chunks of at most `points_per_batch` points.
+    ///
+    /// A point cloud that fits in a single batch is logged to `entity_path` directly, otherwise
+    /// each batch gets its own child entity.
+    fn into_chunks(
+        self,
+        entity_path: &EntityPath,
+        points_per_batch: usize,
+    ) -> impl Iterator<Item = Result<Chunk, re_chunk::ChunkError>> + use<'_> {
+        let num_points = self.positions.len();
+        let num_batches = num_points.div_ceil(points_per_batch.max(1));
+
+        (0..num_batches).map(move |batch| {
+            let range =
+                batch * points_per_batch..usize::min((batch + 1) * points_per_batch, num_points);
+
+            let batch_entity_path = if num_batches == 1 {
+                entity_path.clone()
+            } else {
+                entity_path.join(&EntityPath::from(vec![EntityPathPart::new(format!(
+                    "batch_{batch}"
+                ))]))
+            };
+
+            let mut points = Points3D::new(self.positions[range.clone()].iter().copied());
+            if let Some(colors) = &self.colors {
+                points = points.with_colors(colors[range.clone()].iter().copied());
+            }
+
+            let extra_fields =
+                self.scalars
+                    .iter()
+                    .fold(AnyValues::default(), |values, (name, scalars)| {
+                        values.with_field(
+                            name,
+                            Arc::new(arrow::array::Float32Array::from(
+                                scalars[range.clone()].to_vec(),
+                            )),
+                        )
+                    });
+
+            Chunk::builder(batch_entity_path)
+                .with_serialized_batches(
+                    RowId::new(),
+                    TimePoint::default(),
+                    points
+                        .as_serialized_batches()
+                        .into_iter()
+                        .chain(extra_fields.as_serialized_batches()),
+                )
+                .build()
+        })
+    }
+}
+
+// --- PCD ---
+
+/// A field declared in the header of a PCD file.
+struct PcdField {
+    name: String,
+
+    /// Size of a single element, in bytes.
+    size: usize,
+
+    /// `F` (float), `I` (signed) or `U` (unsigned).
+    kind: char,
+
+    /// Number of elements, e.g. 33 for an FPFH histogram.
+    count: usize,
+}
+
+impl PcdField {
+    /// Is this a packed color field (`rgb` or `rgba`)?
+    fn is_color(&self) -> bool {
+        self.name == "rgb" || self.name == "rgba"
+    }
+
+    /// Decodes the first element of this field from its little-endian bytes.
+    ///
+    /// Packed colors are returned as their bit pattern.
+    fn decode(&self, bytes: &[u8]) -> Option<f64> {
+        if self.is_color() {
+            let bytes = bytes.get(..4)?;
+            return Some(u32::from_le_bytes(bytes.try_into().ok()?).into());
+        }
+
+        let bytes = bytes.get(..self.size)?;
+        Some(match (self.kind, self.size) {
+            ('F', 4) => f32::from_le_bytes(bytes.try_into().ok()?).into(),
+            ('F', 8) => f64::from_le_bytes(bytes.try_into().ok()?),
+            ('I', 1) => i8::from_le_bytes(bytes.try_into().ok()?).into(),
+            ('I', 2) => i16::from_le_bytes(bytes.try_into().ok()?).into(),
+            ('I', 4) => i32::from_le_bytes(bytes.try_into().ok()?).into(),
+            ('I', 8) => i64::from_le_bytes(bytes.try_into().ok()?) as f64,
+            ('U', 1) => bytes[0].into(),
+            ('U', 2) => u16::from_le_bytes(bytes.try_into().ok()?).into(),
+            ('U', 4) => u32::from_le_bytes(bytes.try_into().ok()?).into(),
+            ('U', 8) => u64::from_le_bytes(bytes.try_into().ok()?) as f64,
+            _ => return None,
+        })
+    }
+
+    /// Parses the first element of this field from its ascii representation.
+    ///
+    /// Packed colors are returned as their bit pattern.
+    fn parse(&self, token: &str) -> Option<f64> {
+        let value: f64 = token.parse().ok()?;
+        if self.is_color() && self.kind == 'F' {
+            Some((value as f32).to_bits().into())
+        } else {
+            Some(value)
+        }
+    }
+}
+
+/// Parses a PCD file, in any of the `ascii`, `binary` or `binary_compressed` encodings.
+///
+/// See <https://pointclouds.org/documentation/tutorials/pcd_file_format.html>.
+fn parse_pcd(contents: &[u8]) -> anyhow::Result<PointCloud> {
+    re_tracing::profile_function!();
+
+    let mut names = Vec::new();
+    let mut sizes = Vec::new();
+    let mut kinds = Vec::new();
+    let mut counts = Vec::new();
+    let mut width = None;
+    let mut height = 1;
+    let mut num_points = None;
+
+    let mut offset = 0;
+    let data_kind = loop {
+        let line_len = contents[offset..]
+            .iter()
+            .position(|&byte| byte == b'\n')
+            .map_or(contents.len() - offset, |len| len + 1);
+        let line = std::str::from_utf8(&contents[offset..offset + line_len])
+            .context("Invalid PCD header")?
+            .trim();
+        offset += line_len;
+
+        if line_len == 0 {
+            anyhow::bail!("PCD header is missing its DATA line");
+        }
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut tokens = line.split_whitespace();
+        let keyword = tokens.next().unwrap_or_default();
+        let values = tokens.collect::<Vec<_>>();
+        let parse_usizes = || -> anyhow::Result<Vec<usize>> {
+            values
+                .iter()
+                .map(|value| {
+                    value
+                        .parse()
+                        .with_context(|| format!("Invalid {keyword} in PCD header: {value:?}"))
+                })
+                .collect()
+        };
+
+        match keyword {
+            "FIELDS" => names = values.iter().map(|name| (*name).to_owned()).collect(),
+            "SIZE" => sizes = parse_usizes()?,
+            "TYPE" => {
+                kinds = values
+                    .iter()
+                    .map(|kind| kind.chars().next().unwrap_or('F'))
+                    .collect();
+            }
+            "COUNT" => counts = parse_usizes()?,
+            "WIDTH" => width = parse_usizes()?.first().copied(),
+            "HEIGHT" => height = parse_usizes()?.first().copied().unwrap_or(1),
+            "POINTS" => num_points = parse_usizes()?.first().copied(),
+            "DATA" => break values.first().copied().unwrap_or_default().to_owned(),
+            _ => {} // VERSION, VIEWPOINT
+        }
+    };
+
+    let fields = names
+        .into_iter()
+        .enumerate()
+        .map(|(i, name)| PcdField {
+            name,
+            size: sizes.get(i).copied().unwrap_or(4),
+            kind: kinds.get(i).copied().unwrap_or('F'),
+            count: counts.get(i).copied().unwrap_or(1),
+        })
+        .collect::<Vec<_>>();
+    let num_points = match num_points {
+        Some(num_points) => num_points,
+        None => width
+            .context("PCD header is missing its POINTS")?
+            .checked_mul(height)
+            .context("Invalid WIDTH & HEIGHT in PCD header")?,
+    };
+
+    // NOTE: The header cannot be trusted, so anything sized from it must be checked against the
+    // data that is actually there.
+    let point_size = fields
+        .iter()
+        .try_fold(0_usize, |point_size, field| {
+            let field_size = field
+                .size
+                .checked_mul(field.count)
+                .filter(|&size| size > 0)?;
+            point_size.checked_add(field_size)
+        })
+        .context("Invalid SIZE or COUNT in PCD header")?;
+    let data_size = point_size.checked_mul(num_points).with_context(|| {
+        format!("Invalid PCD header: {num_points} points of {point_size} bytes")
+    })?;
+
+    let data = &contents[offset..];
+    let columns = match data_kind.as_str() {
+        "ascii" => parse_pcd_ascii(&fields, num_points, data)?,
+
+        "binary" => {
+            anyhow::ensure!(
+                data.len() >= data_size,
+                "PCD file is truncated: expected {num_points} points of {point_size} bytes"
+            );
+
+            let mut field_offset = 0;
+            fields
+                .iter()
+                .map(|field| {
+                    let column = decode_pcd_column(field, num_points, |i| {
+                        data.get(i * point_size + field_offset..)
+                    });
+                    field_offset += field.size * field.count;
+                    column
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?
+        }
+
+        "binary_compressed" => {
+            let header = data
+                .get(..8)
+                .context("PCD file is missing its compressed data header")?;
+            let compressed_size = u32::from_le_bytes([header[0], header[1], header[2], header[3]]);
+            let uncompressed_size =
+                u32::from_le_bytes([header[4], header[5], header[6], header[7]]);
+            let compressed = data
+                .get(8..8 + compressed_size as usize)
+                .context("PCD file is truncated")?;
+            anyhow::ensure!(
+                uncompressed_size as usize == data_size,
+                "PCD file is corrupt: expected {data_size} bytes of field data, got {uncompressed_size}"
+            );
+            let data = lzf_decompress(compressed, data_size)
+                .context("Invalid LZF compressed data in PCD file")?;
+
+            // Compressed data is stored field by field rather than point by point.
+            let mut column_offset = 0;
+            fields
+                .iter()
+                .map(|field| {
+                    let stride = field.size * field.count;
+                    let column = decode_pcd_column(field, num_points, |i| {
+                        data.get(column_offset + i * stride..)
+                    });
+                    column_offset += stride * num_points;
+                    column
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?
+        }
+
+        _ => anyhow::bail!("Unsupported PCD data encoding: {data_kind:?}"),
+    };
+
+    point_cloud_from_pcd_columns(&fields, &columns)
+}
+
+/// Decodes the first element of `field` for every point, `point_bytes` returning the bytes
+/// starting at the field of the given point.
+fn decode_pcd_column<'a>(
+    field: &PcdField,
+    num_points: usize,
+    point_bytes: impl Fn(usize) -> Option<&'a [u8]>,
+) -> anyhow::Result<Vec<f64>> {
+    (0..num_points)
+        .map(|i| {
+            point_bytes(i)
+                .and_then(|bytes| field.decode(bytes))
+                .with_context(|| format!("Failed to decode PCD field {:?}", field.name))
+        })
+        .collect()
+}
+
+fn parse_pcd_ascii(
+    fields: &[PcdField],
+    num_points: usize,
+    data: &[u8],
+) -> anyhow::Result<Vec<Vec<f64>>> {
+    let data = std::str::from_utf8(data).context("Invalid PCD ascii data")?;
+
+    // Every point takes at least two bytes (a digit and a separator): don't trust the header with
+    // the capacity.
+    let capacity = num_points.min(data.len() / 2);
+    let mut columns = vec![Vec::with_capacity(capacity); fields.len()];
+    for line in data
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .take(num_points)
+    {
+        let mut tokens = line.split_whitespace();
+        for (field, column) in fields.iter().zip(&mut columns) {
+            let value = tokens
+                .next()
+                .and_then(|token| field.parse(token))
+                .with_context(|| format!("Invalid PCD ascii point: {line:?}"))?;
+            column.push(value);
+
+            // Skip the remaining elements of this field.
+            for _ in 1..field.count {
+                tokens.next();
+            }
+        }
+    }
+
+    Ok(columns)
+}
+
+fn point_cloud_from_pcd_columns(
+    fields: &[PcdField],
+    columns: &[Vec<f64>],
+) -> anyhow::Result<PointCloud> {
+    let column = |name: &str| {
+        fields
+            .iter()
+            .position(|field| field.name == name)
+            .map(|i| &columns[i])
+    };
+    let (Some(xs), Some(ys), Some(zs)) = (column("x"), column("y"), column("z")) else {
+        anyhow::bail!("PCD file has no x, y & z fields");
+    };
+
+    // Organized point clouds mark invalid points with NaNs.
+    let valid = (0..xs.len())
+        .filter(|&i| xs[i].is_finite() && ys[i].is_finite() && zs[i].is_finite())
+        .collect::<Vec<_>>();
+
+    let mut cloud = PointCloud {
+        positions: valid
+            .iter()
+            .map(|&i| [xs[i] as f32, ys[i] as f32, zs[i] as f32])
+            .collect(),
+        ..Default::default()
+    };
+
+    for (field, column) in fields.iter().zip(columns) {
+        if ["x", "y", "z"].contains(&field.name.as_str()) || field.count != 1 {
+            continue;
+        }
+
+        if field.is_color() {
+            let has_alpha = field.name == "rgba";
+            cloud.colors = Some(
+                valid
+                    .iter()
+                    .map(|&i| {
+                        let [b, g, r, a] = (column[i] as u32).to_le_bytes();
+                        Color::from_unmultiplied_rgba(r, g, b, if has_alpha { a } else { 255 })
+                    })
+                    .collect(),
+            );
+        } else {
+            cloud.scalars.push((
+                field.name.clone(),
+                valid.iter().map(|&i| column[i] as f32).collect(),
+            ));
+        }
+    }
+
+    Ok(cloud)
+}
+
+/// Decompresses LZF data, as used by the `binary_compressed` PCD encoding.
+///
+/// Fails if the output doesn't end up being exactly `uncompressed_size` bytes.
+fn lzf_decompress(input: &[u8], uncompressed_size: usize) -> Option<Vec<u8>> {
+    /// LZF cannot expand 3 bytes of input into more than 264 bytes of output.
+    const MAX_LZF_RATIO: usize = 88;
+
+    let mut output =
+        Vec::with_capacity(uncompressed_size.min(input.len().saturating_mul(MAX_LZF_RATIO)));
+
+    let mut i = 0;
+    while i < input.len() {
+        let control = input[i] as usize;
+        i += 1;
+
+        if control < 1 << 5 {
+            // Literal run.
+            let len = control + 1;
+            output.extend_from_slice(input.get(i..i + len)?);
+            i += len;
+        } else {
+            // Back reference.
+            let mut len = control >> 5;
+            if len == 7 {
+                len += *input.get(i)? as usize;
+                i += 1;
+            }
+            let len = len + 2;
+            let distance = ((control & 0x1f) << 8) + *input.get(i)? as usize + 1;
+            i += 1;
+
+            let start = output.len().checked_sub(distance)?;
+            for j in start..start + len {
+                output.push(output[j]);
+            }
+        }
+
+        if output.len() > uncompressed_size {
+            return None;
+        }
+    }
+
+    (output.len() == uncompressed_size).then_some(output)
+}
+
+// --- XYZ ---
+
+/// Parses an XYZ file: one point per line, as whitespace or comma separated values.
+///
+/// Supported layouts are `x y z`, `x y z intensity`, `x y z r g b` and `x y z intensity r g b`,
+/// with colors either in `0..=1` or `0..=255`.
+/// Lines with fewer than 3 values (e.g. the point count of `.pts` files) are ignored.
+fn parse_xyz(contents: &[u8]) -> anyhow::Result<PointCloud> {
+    re_tracing::profile_function!();
+
+    let contents = std::str::from_utf8(contents).context("XYZ files must be UTF-8")?;
+
+    let mut positions = Vec::new();
+    let mut intensities = Vec::new();
+    let mut rgbs = Vec::new();
+    let mut num_values = None;
+
+    for (line_number, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.starts_with('#') || line.starts_with("//") {
+            continue;
+        }
+
+        let values = line
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|token| !token.is_empty())
+            .map(str::parse::<f64>)
+            .collect::<Result<Vec<_>, _>>()
+            .with_context(|| format!("Invalid XYZ point on line {}", line_number + 1))?;
+        if values.len() < 3 {
+            continue;
+        }
+
+        let expected_num_values = *num_values.get_or_insert(values.len());
+        anyhow::ensure!(
+            values.len() == expected_num_values,
+            "Expected {expected_num_values} values on line {} of XYZ file, got {}",
+            line_number + 1,
+            values.len()
+        );
+
+        positions.push([values[0] as f32, values[1] as f32, values[2] as f32]);
+        match values.as_slice() {
+            [_, _, _] => {}
+            [_, _, _, intensity] => intensities.push(*intensity as f32),
+            [_, _, _, r, g, b] => rgbs.push([*r as f32, *g as f32, *b as f32]),
+            [_, _, _, intensity, r, g, b] => {
+                intensities.push(*intensity as f32);
+                rgbs.push([*r as f32, *g as f32, *b as f32]);
+            }
+            _ => {
+                anyhow::bail!("Unsupported XYZ layout with {expected_num_values} values per point")
+            }
+        }
+    }
+
+    let mut cloud = PointCloud {
+        positions,
+        ..Default::default()
+    };
+
+    if !rgbs.is_empty() {
+        let is_normalized = rgbs.iter().flatten().all(|&channel| channel <= 1.0);
+        let scale = if is_normalized { 255.0 } else { 1.0 };
+        cloud.colors = Some(
+            rgbs.into_iter()
+                .map(|rgb| {
+                    let [r, g, b] = rgb.map(|channel| (channel * scale).clamp(0.0, 255.0) as u8);
+                    Color::from_rgb(r, g, b)
+                })
+                .collect(),
+        );
+    }
+    if !intensities.is_empty() {
+        cloud.scalars.push(("intensity".to_owned(), intensities));
+    }
+
+    Ok(cloud)
+}
+
+// --- LAS ---
+
+/// Parses a LAS or LAZ file.
+///
+/// Returns the points relative to the center of their bounds, along with that center.
+fn parse_las(contents: Vec<u8>) -> anyhow::Result<(PointCloud, glam::DVec3)> {
+    re_tracing::profile_function!();
+
+    let contents_len = contents.len();
+    let mut reader =
+        las::Reader::new(std::io::Cursor::new(contents)).context("Failed to read LAS header")?;
+
+    let header = reader.header();
+    let bounds = header.bounds();
+    let origin = glam::dvec3(
+        (bounds.min.x + bounds.max.x) / 2.0,
+        (bounds.min.y + bounds.max.y) / 2.0,
+        (bounds.min.z + bounds.max.z) / 2.0,
+    );
+    let has_color = header.point_format().has_color;
+
+    // Don't trust the header's point count for allocating: there cannot be more points than what
+    // the file can hold (LAZ files can, but then the vectors will just grow).
+    let max_points = contents_len / usize::from(header.point_format().len()).max(1);
+    let num_points = usize::try_from(header.number_of_points())
+        .unwrap_or(usize::MAX)
+        .min(max_points);
+
+    let mut positions = Vec::with_capacity(num_points);
+    let mut intensities = Vec::with_capacity(num_points);
+    let mut classifications = Vec::with_capacity(num_points);
+    let mut rgbs = Vec::with_capacity(if has_color { num_points } else { 0 });
+
+    for point in reader.points() {
+        let point = point.context("Failed to read LAS point")?;
+
+        positions.push([
+            (point.x - origin.x) as f32,
+            (point.y - origin.y) as f32,
+            (point.z - origin.z) as f32,
+        ]);
+        intensities.push(f32::from(point.intensity));
+        classifications.push(f32::from(u8::from(point.classification)));
+        if let Some(color) = point.color {
+            rgbs.push([color.red, color.green, color.blue]);
+        }
+    }
+
+    let mut cloud = PointCloud {
+        positions,
+        colors: None,
+        scalars: vec![
+            ("intensity".to_owned(), intensities),
+            ("classification".to_owned(), classifications),
+        ],
+    };
+
+    if !rgbs.is_empty() {
+        // The spec says colors are 16 bits per channel, but many files only use the lower 8 bits.
+        let is_16_bits = rgbs.iter().flatten().any(|&channel| channel > 255);
+        let shift = if is_16_bits { 8 } else { 0 };
+        cloud.colors = Some(
+            rgbs.into_iter()
+                .map(|rgb| {
+                    let [r, g, b] = rgb.map(|channel| (channel >> shift) as u8);
+                    Color::from_rgb(r, g, b)
+                })
+                .collect(),
+        );
+    }
+
+    Ok((cloud, origin))
+}
+
+#[cfg(test)]
+mod tests {
+    use re_chunk::Chunk;
+    use re_log_types::{StoreId, StoreKind};
+    use re_types::components::Position3D;
+
+    use super::*;
+
+    fn load(filepath: &str, contents: &[u8]) -> anyhow::Result<Vec<Chunk>> {
+        let settings =
+            crate::DataLoaderSettings::recommended(StoreId::random(StoreKind::Recording));
+        let (tx, rx) = std::sync::mpsc::channel();
+        PointCloudLoader.load_from_file_contents(
+            &settings,
+            filepath.into(),
+            std::borrow::Cow::Borrowed(contents),
+            tx,
+        )?;
+
+        Ok(rx
+            .try_iter()
+            .filter_map(|data| match data {
+                LoadedData::Chunk(_, _, chunk) => Some(chunk),
+                _ => None,
+            })
+            .collect())
+    }
+
+    fn positions(chunk: &Chunk) -> Vec<Position3D> {
+        chunk
+            .component_batch::<Position3D>(&Points3D::descriptor_positions(), 0)
+            .and_then(Result::ok)
+            .unwrap_or_default()
+    }
+
+    fn colors(chunk: &Chunk) -> Vec<Color> {
+        chunk
+            .component_batch::<Color>(&Points3D::descriptor_colors(), 0)
+            .and_then(Result::ok)
+            .unwrap_or_default()
+    }
+
+    fn scalars(chunk: &Chunk, name: &str) -> Vec<f32> {
+        chunk
+            .components()
+            .iter()
+            .find(|(descr, _)| descr.component.as_str() == name)
+            .and_then(|(_, list_array)| {
+                list_array
+                    .values()
+                    .as_any()
+                    .downcast_ref::<arrow::array::Float32Array>()
+                    .map(|values| values.values().to_vec())
+            })
+            .unwrap_or_default()
+    }
+
+    const PCD_HEADER: &str = "\
+# .PCD v0.7 - Point Cloud Data file format
+VERSION 0.7
+FIELDS x y z intensity rgb
+SIZE 4 4 4 2 4
+TYPE F F F U F
+COUNT 1 1 1 1 1
+WIDTH 3
+HEIGHT 1
+VIEWPOINT 0 0 0 1 0 0 0
+POINTS 3
+";
+
+    /// `(x, y, z, intensity, rgb)`, the second point being invalid.
+    const PCD_POINTS: [(f32, f32, f32, u16, u32); 3] = [
+        (1.0, 2.0, 3.0, 10, 0x00ff_0000),
+        (f32::NAN, f32::NAN, f32::NAN, 20, 0x0000_ff00),
+        (4.0, 5.0, 6.0, 30, 0x0000_00ff),
+    ];
+
+    fn check_pcd(chunks: &[Chunk]) {
+        assert_eq!(chunks.len(), 1);
+        let chunk = &chunks[0];
+        assert_eq!(chunk.entity_path(), &EntityPath::from("cloud.pcd"));
+        assert_eq!(
+            positions(chunk),
+            vec![
+                Position3D::new(1.0, 2.0, 3.0),
+                Position3D::new(4.0, 5.0, 6.0)
+            ]
+        );
+        assert_eq!(
+            colors(chunk),
+            vec![Color::from_rgb(255, 0, 0), Color::from_rgb(0, 0, 255)]
+        );
+        assert_eq!(scalars(chunk, "intensity"), vec![10.0, 30.0]);
+    }
+
+    #[test]
+    fn test_load_pcd_ascii() -> anyhow::Result<()> {
+        let mut pcd = format!("{PCD_HEADER}DATA ascii\n");
+        for (x, y, z, intensity, rgb) in PCD_POINTS {
+            let rgb = f32::from_bits(rgb);
+            pcd += &format!("{x} {y} {z} {intensity} {rgb:e}\n");
+        }
+
+        check_pcd(&load("cloud.pcd", pcd.as_bytes())?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_pcd_binary() -> anyhow::Result<()> {
+        let mut pcd = format!("{PCD_HEADER}DATA binary\n").into_bytes();
+        for (x, y, z, intensity, rgb) in PCD_POINTS {
+            pcd.extend(x.to_le_bytes());
+            pcd.extend(y.to_le_bytes());
+            pcd.extend(z.to_le_bytes());
+            pcd.extend(intensity.to_le_bytes());
+            pcd.extend(rgb.to_le_bytes());
+        }
+
+        check_pcd(&load("cloud.pcd", &pcd)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_pcd_binary_compressed() -> anyhow::Result<()> {
+        let mut data = Vec::new();
+        data.extend(PCD_POINTS.iter().flat_map(|p| p.0.to_le_bytes()));
+        data.extend(PCD_POINTS.iter().flat_map(|p| p.1.to_le_bytes()));
+        data.extend(PCD_POINTS.iter().flat_map(|p| p.2.to_le_bytes()));
+        data.extend(PCD_POINTS.iter().flat_map(|p| p.3.to_le_bytes()));
+        data.extend(PCD_POINTS.iter().flat_map(|p| p.4.to_le_bytes()));
+
+        // LZF literal runs of at most 32 bytes.
+        let compressed = data
+            .chunks(32)
+            .flat_map(|run| std::iter::once(run.len() as u8 - 1).chain(run.iter().copied()))
+            .collect::<Vec<_>>();
+
+        let mut pcd = format!("{PCD_HEADER}DATA binary_compressed\n").into_bytes();
+        pcd.extend((compressed.len() as u32).to_le_bytes());
+        pcd.extend((data.len() as u32).to_le_bytes());
+        pcd.extend(compressed);
+
+        check_pcd(&load("cloud.pcd", &pcd)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lying_pcd_headers() -> anyhow::Result<()> {
+        let header = |points: &str, data: &str| {
+            let header = PCD_HEADER.replace("POINTS 3", &format!("POINTS {points}"));
+            format!("{header}DATA {data}\n")
+        };
+
+        // Claiming way more points than there are must neither allocate for them nor overflow.
+        let mut pcd = header("1000000000000", "ascii");
+        for (x, y, z, intensity, rgb) in PCD_POINTS {
+            let rgb = f32::from_bits(rgb);
+            pcd += &format!("{x} {y} {z} {intensity} {rgb:e}\n");
+        }
+        check_pcd(&load("cloud.pcd", pcd.as_bytes())?);
+
+        let pcd = format!("{}tiny", header("1000000000000", "binary"));
+        assert!(load("cloud.pcd", pcd.as_bytes()).is_err());
+
+        let pcd = format!("{}tiny", header(&usize::MAX.to_string(), "binary"));
+        assert!(load("cloud.pcd", pcd.as_bytes()).is_err());
+
+        let overflowing_header = PCD_HEADER
+            .replace("WIDTH 3", &format!("WIDTH {}", usize::MAX))
+            .replace("HEIGHT 1", "HEIGHT 2")
+            .replace("POINTS 3\n", "");
+        let pcd = format!("{overflowing_header}DATA binary\n");
+        assert!(load("cloud.pcd", pcd.as_bytes()).is_err());
+
+        let empty_field_header = PCD_HEADER.replace("COUNT 1 1 1 1 1", "COUNT 1 1 1 1 0");
+        let pcd = format!("{empty_field_header}DATA binary\n");
+        assert!(load("cloud.pcd", pcd.as_bytes()).is_err());
+
+        // A 4 GiB uncompressed size for a handful of compressed bytes.
+        let mut pcd = header("3", "binary_compressed").into_bytes();
+        pcd.extend(2_u32.to_le_bytes());
+        pcd.extend(u32::MAX.to_le_bytes());
+        pcd.extend([0x00, 0x00]);
+        assert!(load("cloud.pcd", &pcd).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lzf_decompress() {
+        // "abc", then a back reference of 3 bytes, 3 bytes back.
+        let compressed = [0x02, b'a', b'b', b'c', 0x20, 0x02];
+        assert_eq!(
+            lzf_decompress(&compressed, 6).as_deref(),
+            Some(b"abcabc".as_slice())
+        );
+        assert_eq!(lzf_decompress(&compressed, 7), None);
+        assert_eq!(lzf_decompress(&[0x20, 0x02], 3), None);
+    }
+
+    #[test]
+    fn test_load_xyz() -> anyhow::Result<()> {
+        let xyz = "# comment\n2\n0 0 0 0.5 1 0 0\n1,2,3,1.5,0,1,0\n";
+        let chunks = load("cloud.xyz", xyz.as_bytes())?;
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(
+            positions(&chunks[0]),
+            vec![
+                Position3D::new(0.0, 0.0, 0.0),
+                Position3D::new(1.0, 2.0, 3.0)
+            ]
+        );
+        assert_eq!(
+            colors(&chunks[0]),
+            vec![Color::from_rgb(255, 0, 0), Color::from_rgb(0, 255, 0)]
+        );
+        assert_eq!(scalars(&chunks[0], "intensity"), vec![0.5, 1.5]);
+
+        // Intensity only: mapped to grayscale colors.
+        let chunks = load("cloud.xyz", b"0 0 0 10\n1 1 1 20\n2 2 2 30\n")?;
+        assert_eq!(
+            colors(&chunks[0]),
+            vec![
+                Color::from_rgb(0, 0, 0),
+                Color::from_rgb(127, 127, 127),
+                Color::from_rgb(255, 255, 255)
+            ]
+        );
+
+        assert!(load("cloud.xyz", b"0 0 0\n1 1\n1 1 1 1\n").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_las() -> anyhow::Result<()> {
+        let mut builder = las::Builder::from((1, 2));
+        builder.point_format = las::point::Format::new(2)?;
+        let mut writer =
+            las::Writer::new(std::io::Cursor::new(Vec::new()), builder.into_header()?)?;
+        for (i, x) in [1000.0, 1002.0].into_iter().enumerate() {
+            writer.write_point(las::Point {
+                x,
+                y: 2000.0,
+                z: 10.0,
+                intensity: 100 * (i as u16 + 1),
+                classification: las::point::Classification::Ground,
+                color: Some(las::Color::new(65535, 0, 256 * i as u16)),
+                ..Default::default()
+            })?;
+        }
+        let las = writer.into_inner()?.into_inner();
+
+        let chunks = load("survey.las", &las)?;
+        assert_eq!(chunks.len(), 2);
+
+        let transform = chunks[0]
+            .component_batch::<re_types::components::Translation3D>(
+                &Transform3D::descriptor_translation(),
+                0,
+            )
+            .and_then(Result::ok)
+            .unwrap_or_default();
+        assert_eq!(
+            transform,
+            vec![re_types::components::Translation3D::new(
+                1001.0, 2000.0, 10.0
+            )]
+        );
+
+        let points = &chunks[1];
+        assert_eq!(
+            positions(points),
+            vec![
+                Position3D::new(-1.0, 0.0, 0.0),
+                Position3D::new(1.0, 0.0, 0.0)
+            ]
+        );
+        assert_eq!(
+            colors(points),
+            vec![Color::from_rgb(255, 0, 0), Color::from_rgb(255, 0, 1)]
+        );
+        assert_eq!(scalars(points, "intensity"), vec![100.0, 200.0]);
+        assert_eq!(scalars(points, "classification"), vec![2.0, 2.0]);
+
+        // A header claiming billions of points must not be trusted for allocating.
+        let mut lying = las.clone();
+        lying[107..111].copy_from_slice(&u32::MAX.to_le_bytes());
+        _ = load("survey.las", &lying);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_batching() -> anyhow::Result<()> {
+        let cloud = PointCloud {
+            positions: (0..5).map(|i| [i as f32, 0.0, 0.0]).collect(),
+            colors: None,
+            scalars: vec![("intensity".to_owned(), vec![0.0, 1.0, 2.0, 3.0, 4.0])],
+        };
+
+        let chunks = cloud
+            .into_chunks(&EntityPath::from("cloud"), 2)
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            chunks
+                .iter()
+                .map(|chunk| (chunk.entity_path().to_string(), positions(chunk).len()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("/cloud/batch_0".to_owned(), 2),
+                ("/cloud/batch_1".to_owned(), 2),
+                ("/cloud/batch_2".to_owned(), 1),
+            ]
+        );
+        assert_eq!(scalars(&chunks[2], "intensity"), vec![4.0]);
+        Ok(())
+    }
+}
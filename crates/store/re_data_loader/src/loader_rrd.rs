@@ -24,6 +24,16 @@ impl crate::DataLoader for RrdLoader {
         filepath: std::path::PathBuf,
         tx: std::sync::mpsc::Sender<crate::LoadedData>,
     ) -> Result<(), crate::DataLoaderError> {
+        let stream = self.stream_from_path(settings, filepath.clone())?;
+        crate::forward_stream(self.name(), &filepath, stream, tx)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn stream_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+    ) -> Result<crate::LoadedDataStream, crate::DataLoaderError> {
         use anyhow::Context as _;
 
         re_tracing::profile_function!(filepath.display().to_string());
@@ -52,24 +62,16 @@ impl crate::DataLoader for RrdLoader {
 
                 let decoder = Decoder::new(file)?;
 
-                // NOTE: This is IO bound, it must run on a dedicated thread, not the shared rayon thread pool.
-                std::thread::Builder::new()
-                    .name(format!("decode_and_stream({filepath:?})"))
-                    .spawn({
-                        let filepath = filepath.clone();
-                        let settings = settings.clone();
-                        move || {
-                            decode_and_stream(
-                                &filepath,
-                                &tx,
-                                decoder,
-                                settings.opened_application_id.as_ref(),
-                                // We never want to patch blueprints' store IDs, only their app IDs.
-                                None,
-                            );
-                        }
-                    })
-                    .with_context(|| format!("Failed to spawn IO thread for {filepath:?}"))?;
+                Ok(Box::new(
+                    decode(
+                        filepath,
+                        decoder,
+                        settings.opened_application_id.clone(),
+                        // We never want to patch blueprints' store IDs, only their app IDs.
+                        None,
+                    )
+                    .map(Ok),
+                ))
             }
 
             "rrd" => {
@@ -80,25 +82,16 @@ impl crate::DataLoader for RrdLoader {
                 })?;
                 let decoder = Decoder::new(retryable_reader)?;
 
-                // NOTE: This is IO bound, it must run on a dedicated thread, not the shared rayon thread pool.
-                std::thread::Builder::new()
-                    .name(format!("decode_and_stream({filepath:?})"))
-                    .spawn({
-                        let filepath = filepath.clone();
-                        move || {
-                            decode_and_stream(
-                                &filepath, &tx, decoder,
-                                // Never use import semantics for .rrd files
-                                None, None,
-                            );
-                        }
-                    })
-                    .with_context(|| format!("Failed to spawn IO thread for {filepath:?}"))?;
+                Ok(Box::new(
+                    decode(
+                        filepath, decoder, // Never use import semantics for .rrd files
+                        None, None,
+                    )
+                    .map(Ok),
+                ))
             }
             _ => unreachable!(),
         }
-
-        Ok(())
     }
 
     fn load_from_file_contents(
@@ -136,33 +129,36 @@ impl crate::DataLoader for RrdLoader {
         };
         let forced_recording_id = None;
 
-        decode_and_stream(
-            &filepath,
-            &tx,
+        for data in decode(
+            filepath,
             decoder,
-            forced_application_id,
+            forced_application_id.cloned(),
             forced_recording_id,
-        );
+        ) {
+            if tx.send(data).is_err() {
+                break; // The other end has decided to hang up, not our problem.
+            }
+        }
 
         Ok(())
     }
 }
 
-fn decode_and_stream<R: std::io::Read>(
-    filepath: &std::path::Path,
-    tx: &std::sync::mpsc::Sender<crate::LoadedData>,
+/// Lazily decodes all the messages from `decoder`, skipping (and logging) the ones that fail to decode.
+fn decode<R: std::io::Read>(
+    filepath: std::path::PathBuf,
     decoder: Decoder<R>,
-    forced_application_id: Option<&ApplicationId>,
-    forced_store_id: Option<&StoreId>,
-) {
-    re_tracing::profile_function!(filepath.display().to_string());
+    forced_application_id: Option<ApplicationId>,
+    forced_store_id: Option<StoreId>,
+) -> impl Iterator<Item = crate::LoadedData> {
+    decoder.filter_map(move |msg| {
+        re_tracing::profile_scope!("decode", filepath.display().to_string());
 
-    for msg in decoder {
         let msg = match msg {
             Ok(msg) => msg,
             Err(err) => {
                 re_log::warn_once!("Failed to decode message in {filepath:?}: {err}");
-                continue;
+                return None;
             }
         };
 
@@ -172,10 +168,10 @@ fn decode_and_stream<R: std::io::Read>(
                     re_log_types::LogMsg::SetStoreInfo(re_log_types::SetStoreInfo {
                         info: re_log_types::StoreInfo {
                             application_id: forced_application_id
-                                .cloned()
+                                .clone()
                                 .unwrap_or(set_store_info.info.application_id),
                             store_id: forced_store_id
-                                .cloned()
+                                .clone()
                                 .unwrap_or(set_store_info.info.store_id),
                             ..set_store_info.info
                         },
@@ -185,7 +181,7 @@ fn decode_and_stream<R: std::io::Read>(
 
                 re_log_types::LogMsg::ArrowMsg(store_id, arrow_msg) => {
                     re_log_types::LogMsg::ArrowMsg(
-                        forced_store_id.cloned().unwrap_or(store_id),
+                        forced_store_id.clone().unwrap_or(store_id),
                         arrow_msg,
                     )
                 }
@@ -198,11 +194,8 @@ fn decode_and_stream<R: std::io::Read>(
             msg
         };
 
-        let data = LoadedData::LogMsg(RrdLoader::name(&RrdLoader), msg);
-        if tx.send(data).is_err() {
-            break; // The other end has decided to hang up, not our problem.
-        }
-    }
+        Some(LoadedData::LogMsg(RrdLoader::name(&RrdLoader), msg))
+    })
 }
 
 // Retryable file reader that keeps retrying to read more data despite
@@ -408,4 +401,61 @@ mod tests {
         // Drop explicitly to make sure that rustc doesn't drop it earlier.
         drop(rrd_file_delete_guard);
     }
+
+    #[test]
+    fn test_stream_from_path() {
+        let rrd_file_path = std::path::PathBuf::from("testfile_stream.rrd");
+        let rrd_file_delete_guard = DeleteOnDrop {
+            path: rrd_file_path.clone(),
+        };
+        std::fs::remove_file(&rrd_file_path).ok(); // Remove the file just in case a previous test crashes hard.
+        let rrd_file = std::fs::File::create(&rrd_file_path).unwrap();
+
+        let mut encoder = DroppableEncoder::new(
+            re_build_info::CrateVersion::LOCAL,
+            re_log_encoding::EncodingOptions::PROTOBUF_UNCOMPRESSED,
+            rrd_file,
+        )
+        .unwrap();
+
+        let store_id = StoreId::random(StoreKind::Recording);
+        let messages = (0..5)
+            .map(|_| {
+                LogMsg::SetStoreInfo(SetStoreInfo {
+                    row_id: *RowId::new(),
+                    info: StoreInfo {
+                        application_id: ApplicationId("test".to_owned()),
+                        store_id: store_id.clone(),
+                        cloned_from: None,
+                        store_source: StoreSource::Unknown,
+                        store_version: Some(CrateVersion::LOCAL),
+                    },
+                })
+            })
+            .collect::<Vec<_>>();
+        for msg in &messages {
+            encoder.append(msg).unwrap();
+        }
+        encoder.finish().unwrap();
+        drop(encoder);
+
+        let settings = crate::DataLoaderSettings::recommended(store_id);
+        let decoded_messages = RrdLoader
+            .stream_from_path(&settings, rrd_file_path.clone())
+            .unwrap()
+            .map(|data| match data.unwrap() {
+                LoadedData::LogMsg(_, msg) => msg,
+                _ => panic!("expected a `LogMsg`"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(messages, decoded_messages);
+
+        assert!(
+            RrdLoader
+                .stream_from_path(&settings, "testfile.png".into())
+                .is_err_and(|err| err.is_incompatible())
+        );
+
+        drop(rrd_file_delete_guard);
+    }
 }
@@ -0,0 +1,649 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::mpsc::Sender,
+};
+
+use ahash::{HashMap, HashMapExt as _, HashSet};
+use anyhow::Context as _;
+use roxmltree::Node;
+
+use re_chunk::EntityPath;
+use re_log_types::{EntityPathPart, StoreId};
+use re_types::{
+    AsComponents,
+    archetypes::{Asset3D, Boxes3D, Capsules3D, Cylinders3D, Ellipsoids3D, Transform3D},
+    components::{Color, MediaType},
+    external::glam,
+};
+
+use crate::{
+    DataLoader, DataLoaderError, LoadedData,
+    robot_description::{children, parse_floats, send_archetype, u8},
+};
+
+fn has_sdf_extension(filepath: &Path) -> bool {
+    matches!(crate::extension(filepath).as_str(), "sdf" | "world")
+}
+
+/// A [`DataLoader`] for [SDFormat](http://sdformat.org/spec) files, the model & world format of
+/// Gazebo.
+///
+/// Links are logged as a hierarchy connected by their joints, the same way the
+/// [`crate::UrdfDataLoader`] does, with static [`Transform3D`]s.
+/// Visuals and collisions are logged as primitives or meshes.
+pub struct SdfLoader;
+
+impl DataLoader for SdfLoader {
+    fn name(&self) -> crate::DataLoaderName {
+        "rerun.data_loaders.Sdf".into()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: PathBuf,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !has_sdf_extension(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath));
+        }
+
+        let contents = {
+            re_tracing::profile_scope!("fs::read");
+            std::fs::read(&filepath)?
+        };
+
+        self.load_from_file_contents(settings, filepath, std::borrow::Cow::Owned(contents), tx)
+    }
+
+    fn load_from_file_contents(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: PathBuf,
+        contents: std::borrow::Cow<'_, [u8]>,
+        tx: Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !has_sdf_extension(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath));
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let text = std::str::from_utf8(&contents).context("SDF files must be UTF-8")?;
+        let doc = roxmltree::Document::parse(text)
+            .with_context(|| format!("Failed to parse SDF file {filepath:?}"))?;
+
+        let root = doc.root_element();
+        if !root.has_tag_name("sdf") {
+            return Err(anyhow::anyhow!("Expected an <sdf> root element in {filepath:?}").into());
+        }
+
+        let sdf_dir = filepath.parent();
+
+        for child in root.children().filter(Node::is_element) {
+            match child.tag_name().name() {
+                "model" => {
+                    log_model(&tx, &settings.store_id, sdf_dir, child, &EntityPath::root())?;
+                }
+
+                "world" => {
+                    let world_path =
+                        EntityPath::from_single_string(child.attribute("name").unwrap_or("world"));
+                    for model in children(child, "model") {
+                        log_model(&tx, &settings.store_id, sdf_dir, model, &world_path)?;
+                    }
+                    if children(child, "include").next().is_some() {
+                        re_log::warn_once!("SDF <include> is not supported");
+                    }
+                }
+
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// ---
+
+/// Parses a `<pose>`, i.e. `x y z roll pitch yaw` (or `x y z qx qy qz qw`).
+fn parse_pose(pose: Node<'_, '_>) -> glam::Affine3A {
+    let values = pose.text().and_then(parse_floats).unwrap_or_default();
+    let degrees = pose.attribute("degrees") == Some("true");
+
+    match values.as_slice() {
+        [x, y, z, roll, pitch, yaw] => {
+            let angle = |angle: f32| if degrees { angle.to_radians() } else { angle };
+            glam::Affine3A::from_rotation_translation(
+                glam::Quat::from_euler(
+                    glam::EulerRot::ZYX,
+                    angle(*yaw),
+                    angle(*pitch),
+                    angle(*roll),
+                ),
+                glam::vec3(*x, *y, *z),
+            )
+        }
+        [x, y, z, qx, qy, qz, qw] => glam::Affine3A::from_rotation_translation(
+            glam::Quat::from_xyzw(*qx, *qy, *qz, *qw).normalize(),
+            glam::vec3(*x, *y, *z),
+        ),
+        _ => glam::Affine3A::IDENTITY,
+    }
+}
+
+/// The frames of a model that poses can be expressed in.
+struct ModelFrames<'a, 'input> {
+    model_name: &'a str,
+
+    /// `<link>`s and `<frame>`s, by name.
+    frames: HashMap<&'a str, Node<'a, 'input>>,
+}
+
+impl<'a, 'input> ModelFrames<'a, 'input> {
+    fn new(model: Node<'a, 'input>) -> Self {
+        let frames = model
+            .children()
+            .filter(|child| child.has_tag_name("link") || child.has_tag_name("frame"))
+            .filter_map(|frame| Some((frame.attribute("name")?, frame)))
+            .collect();
+
+        Self {
+            model_name: model.attribute("name").unwrap_or_default(),
+            frames,
+        }
+    }
+
+    /// The pose of the given link or frame, relative to the model frame.
+    fn pose_in_model(&self, name: &'a str) -> glam::Affine3A {
+        let mut visited = HashSet::default();
+        self.pose_in_model_impl(name, &mut visited)
+    }
+
+    fn pose_in_model_impl(&self, name: &'a str, visited: &mut HashSet<&'a str>) -> glam::Affine3A {
+        if name == "__model__" || name == self.model_name || !visited.insert(name) {
+            return glam::Affine3A::IDENTITY;
+        }
+        let Some(frame) = self.frames.get(name) else {
+            re_log::warn_once!("Unknown SDF frame {name:?}");
+            return glam::Affine3A::IDENTITY;
+        };
+
+        let pose = children(*frame, "pose").next();
+        let local = pose.map_or(glam::Affine3A::IDENTITY, parse_pose);
+
+        // Frames are relative to the frame they're attached to by default, links to the model.
+        let relative_to = pose
+            .and_then(|pose| pose.attribute("relative_to"))
+            .or_else(|| frame.attribute("attached_to"))
+            .filter(|relative_to| !relative_to.is_empty());
+
+        match relative_to {
+            Some(relative_to) => self.pose_in_model_impl(relative_to, visited) * local,
+            None => local,
+        }
+    }
+}
+
+fn log_model(
+    tx: &Sender<LoadedData>,
+    store_id: &StoreId,
+    sdf_dir: Option<&Path>,
+    model: Node<'_, '_>,
+    parent_path: &EntityPath,
+) -> anyhow::Result<()> {
+    let model_name = model.attribute("name").unwrap_or("model");
+    let model_path = parent_path / EntityPathPart::new(model_name);
+
+    if let Some(pose) = children(model, "pose").next() {
+        send_transform(tx, store_id, &model_path, parse_pose(pose), None)?;
+    }
+
+    let frames = ModelFrames::new(model);
+
+    let mut joints_per_parent: HashMap<&str, Vec<(&str, &str)>> = HashMap::new();
+    let mut child_links = HashSet::default();
+    for joint in children(model, "joint") {
+        let link_of = |tag_name| {
+            children(joint, tag_name)
+                .next()
+                .and_then(|node| node.text())
+        };
+        let (Some(parent), Some(child)) = (link_of("parent"), link_of("child")) else {
+            continue;
+        };
+        let joint_name = joint.attribute("name").unwrap_or("joint");
+
+        // Joints to the world only anchor the model.
+        if parent != "world" {
+            joints_per_parent
+                .entry(parent.trim())
+                .or_default()
+                .push((joint_name, child.trim()));
+            child_links.insert(child.trim());
+        }
+    }
+
+    let links: HashMap<&str, Node<'_, '_>> = children(model, "link")
+        .filter_map(|link| Some((link.attribute("name")?, link)))
+        .collect();
+
+    let logger = ModelLogger {
+        tx,
+        store_id,
+        sdf_dir,
+        frames: &frames,
+        links: &links,
+        joints_per_parent: &joints_per_parent,
+    };
+
+    for link in children(model, "link") {
+        let link_name = link.attribute("name").unwrap_or_default();
+        if !child_links.contains(link_name) {
+            let link_path = &model_path / EntityPathPart::new(link_name);
+            send_transform(
+                tx,
+                store_id,
+                &link_path,
+                frames.pose_in_model(link_name),
+                None,
+            )?;
+            logger.log_link_tree(link_name, &link_path, &mut HashSet::default())?;
+        }
+    }
+
+    for nested_model in children(model, "model") {
+        log_model(tx, store_id, sdf_dir, nested_model, &model_path)?;
+    }
+
+    if children(model, "include").next().is_some() {
+        re_log::warn_once!("SDF <include> is not supported");
+    }
+
+    Ok(())
+}
+
+struct ModelLogger<'a, 'b, 'input> {
+    tx: &'b Sender<LoadedData>,
+    store_id: &'b StoreId,
+    sdf_dir: Option<&'b Path>,
+    frames: &'b ModelFrames<'a, 'input>,
+    links: &'b HashMap<&'a str, Node<'a, 'input>>,
+    joints_per_parent: &'b HashMap<&'a str, Vec<(&'a str, &'a str)>>,
+}
+
+impl<'a> ModelLogger<'a, '_, '_> {
+    fn log_link_tree(
+        &self,
+        link_name: &'a str,
+        link_path: &EntityPath,
+        visited: &mut HashSet<&'a str>,
+    ) -> anyhow::Result<()> {
+        if !visited.insert(link_name) {
+            re_log::warn_once!("Kinematic loop in SDF model at link {link_name:?}");
+            return Ok(());
+        }
+
+        if let Some(link) = self.links.get(link_name) {
+            self.log_link(*link, link_path)?;
+        }
+
+        let Some(joints) = self.joints_per_parent.get(link_name) else {
+            return Ok(());
+        };
+
+        let parent_pose = self.frames.pose_in_model(link_name);
+        for &(joint_name, child_name) in joints {
+            let joint_path = link_path / EntityPathPart::new(joint_name);
+            let child_pose = self.frames.pose_in_model(child_name);
+            send_transform(
+                self.tx,
+                self.store_id,
+                &joint_path,
+                parent_pose.inverse() * child_pose,
+                None,
+            )?;
+
+            self.log_link_tree(
+                child_name,
+                &(joint_path / EntityPathPart::new(child_name)),
+                visited,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn log_link(&self, link: Node<'_, '_>, link_path: &EntityPath) -> anyhow::Result<()> {
+        for tag_name in ["visual", "collision"] {
+            for (i, element) in children(link, tag_name).enumerate() {
+                let name = element
+                    .attribute("name")
+                    .map_or_else(|| format!("{tag_name}_{i}"), ToOwned::to_owned);
+                let entity_path = link_path / EntityPathPart::new(name);
+
+                let pose = children(element, "pose")
+                    .next()
+                    .map_or(glam::Affine3A::IDENTITY, parse_pose);
+
+                let color = children(element, "material")
+                    .next()
+                    .and_then(|material| {
+                        children(material, "diffuse")
+                            .chain(children(material, "ambient"))
+                            .find_map(|color| color.text().and_then(parse_floats))
+                    })
+                    .and_then(|rgba| match rgba.as_slice() {
+                        [r, g, b] => Some(Color::from_rgb(u8(*r), u8(*g), u8(*b))),
+                        [r, g, b, a] => Some(Color::from_unmultiplied_rgba(
+                            u8(*r),
+                            u8(*g),
+                            u8(*b),
+                            u8(*a),
+                        )),
+                        _ => None,
+                    });
+
+                if let Some(geometry) = children(element, "geometry").next() {
+                    self.log_geometry(&entity_path, pose, geometry, color)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn log_geometry(
+        &self,
+        entity_path: &EntityPath,
+        pose: glam::Affine3A,
+        geometry: Node<'_, '_>,
+        color: Option<Color>,
+    ) -> anyhow::Result<()> {
+        let Some(shape) = geometry.children().find(Node::is_element) else {
+            return Ok(());
+        };
+
+        let value = |tag_name: &str| -> Vec<f32> {
+            children(shape, tag_name)
+                .next()
+                .and_then(|node| node.text())
+                .and_then(parse_floats)
+                .unwrap_or_default()
+        };
+        let scalar = |tag_name: &str| value(tag_name).first().copied().unwrap_or_default();
+        let vec3 = |tag_name: &str| -> Option<glam::Vec3> {
+            <[f32; 3]>::try_from(value(tag_name))
+                .ok()
+                .map(glam::Vec3::from_array)
+        };
+
+        let archetype: Box<dyn AsComponents> = match shape.tag_name().name() {
+            "box" => Box::new(
+                Boxes3D::from_sizes([vec3("size").unwrap_or(glam::Vec3::ONE)]).with_colors(color),
+            ),
+
+            "sphere" => Box::new(Ellipsoids3D::from_radii([scalar("radius")]).with_colors(color)),
+
+            "ellipsoid" => Box::new(
+                Ellipsoids3D::from_half_sizes([vec3("radii").unwrap_or(glam::Vec3::ONE)])
+                    .with_colors(color),
+            ),
+
+            // Cylinders and capsules are centered on their frame, along Z.
+            "cylinder" => Box::new(
+                Cylinders3D::from_lengths_and_radii([scalar("length")], [scalar("radius")])
+                    .with_colors(color),
+            ),
+
+            "capsule" => {
+                let half_length = glam::vec3(0.0, 0.0, 0.5 * scalar("length"));
+                Box::new(
+                    Capsules3D::from_endpoints_and_radii(
+                        [-half_length],
+                        [half_length],
+                        [scalar("radius")],
+                    )
+                    .with_colors(color),
+                )
+            }
+
+            "plane" => {
+                let [width, height] = <[f32; 2]>::try_from(value("size")).unwrap_or([1.0, 1.0]);
+                let normal = vec3("normal").unwrap_or(glam::Vec3::Z);
+                Box::new(
+                    Boxes3D::from_sizes([[width, height, 0.0]])
+                        .with_quaternions([glam::Quat::from_rotation_arc(
+                            glam::Vec3::Z,
+                            normal.try_normalize().unwrap_or(glam::Vec3::Z),
+                        )])
+                        .with_colors(color),
+                )
+            }
+
+            "mesh" => {
+                let Some(uri) = children(shape, "uri").next().and_then(|uri| uri.text()) else {
+                    return Ok(());
+                };
+                let uri = uri.trim();
+
+                let contents = match self.load_resource(uri) {
+                    Ok(contents) => contents,
+                    Err(err) => {
+                        re_log::warn!(uri, %err, "Failed to load SDF mesh");
+                        return Ok(());
+                    }
+                };
+
+                let mut asset =
+                    Asset3D::from_file_contents(contents, MediaType::guess_from_path(uri));
+                if let Some(color) = color {
+                    asset = asset.with_albedo_factor(color.0);
+                }
+
+                send_transform(self.tx, self.store_id, entity_path, pose, vec3("scale"))?;
+                return send_archetype(
+                    self.tx,
+                    &SdfLoader,
+                    self.store_id,
+                    entity_path.clone(),
+                    &asset,
+                );
+            }
+
+            shape => {
+                re_log::warn_once!("SDF geometries of type {shape:?} are not supported");
+                return Ok(());
+            }
+        };
+
+        send_transform(self.tx, self.store_id, entity_path, pose, None)?;
+        send_archetype(
+            self.tx,
+            &SdfLoader,
+            self.store_id,
+            entity_path.clone(),
+            archetype.as_ref(),
+        )
+    }
+
+    /// Loads a mesh referenced by a `model://`, `package://` or `file://` URI, or a relative path.
+    fn load_resource(&self, uri: &str) -> anyhow::Result<Vec<u8>> {
+        if let Some(path) = uri.strip_prefix("model://") {
+            return read_model_resource(self.sdf_dir, path);
+        }
+
+        crate::loader_urdf::load_ros_resource(self.sdf_dir.map(ToOwned::to_owned).as_ref(), uri)
+    }
+}
+
+/// Resolves `model_name/rel/path`, the path of a `model://` URI.
+///
+/// Models are looked up next to the directory of the SDF file (i.e. the usual
+/// `models/<model_name>/model.sdf` layout), and in the Gazebo resource paths.
+#[cfg(not(target_arch = "wasm32"))]
+fn read_model_resource(sdf_dir: Option<&Path>, path: &str) -> anyhow::Result<Vec<u8>> {
+    let model_dirs = sdf_dir
+        .and_then(Path::parent)
+        .map(ToOwned::to_owned)
+        .into_iter()
+        .chain(
+            [
+                "GZ_SIM_RESOURCE_PATH",
+                "IGN_GAZEBO_RESOURCE_PATH",
+                "GAZEBO_MODEL_PATH",
+                "SDF_PATH",
+            ]
+            .into_iter()
+            .filter_map(std::env::var_os)
+            .flat_map(|paths| std::env::split_paths(&paths).collect::<Vec<_>>()),
+        );
+
+    for model_dir in model_dirs {
+        let candidate = model_dir.join(path);
+        if candidate.is_file() {
+            return std::fs::read(&candidate)
+                .with_context(|| format!("Failed to read file: {}", candidate.display()));
+        }
+    }
+
+    anyhow::bail!(
+        "Failed to resolve model://{path}, tried the SDF directory and the Gazebo resource paths"
+    );
+}
+
+#[cfg(target_arch = "wasm32")]
+fn read_model_resource(_sdf_dir: Option<&Path>, path: &str) -> anyhow::Result<Vec<u8>> {
+    anyhow::bail!("Loading model resources is not supported in WebAssembly: model://{path}");
+}
+
+/// Logs a static [`Transform3D`], unless it's the identity.
+fn send_transform(
+    tx: &Sender<LoadedData>,
+    store_id: &StoreId,
+    entity_path: &EntityPath,
+    pose: glam::Affine3A,
+    scale: Option<glam::Vec3>,
+) -> anyhow::Result<()> {
+    let scale = scale.filter(|scale| *scale != glam::Vec3::ONE);
+    if pose == glam::Affine3A::IDENTITY && scale.is_none() {
+        return Ok(()); // avoid noise
+    }
+
+    let (_, rotation, translation) = pose.to_scale_rotation_translation();
+    let mut transform = Transform3D::from_translation(translation).with_quaternion(rotation);
+    if let Some(scale) = scale {
+        transform = transform.with_scale(scale);
+    }
+    send_archetype(tx, &SdfLoader, store_id, entity_path.clone(), &transform)
+}
+
+#[cfg(test)]
+mod tests {
+    use re_chunk::Chunk;
+    use re_types::{
+        Component, ComponentDescriptor,
+        components::{HalfSize3D, Length, Translation3D},
+    };
+
+    use super::*;
+
+    const SDF: &str = r#"<?xml version="1.0"?>
+<sdf version="1.9">
+  <model name="robot">
+    <link name="base">
+      <pose>0 0 1 0 0 0</pose>
+      <visual name="body">
+        <geometry><box><size>1 2 3</size></box></geometry>
+        <material><diffuse>0 1 0 1</diffuse></material>
+      </visual>
+    </link>
+    <frame name="mount" attached_to="base">
+      <pose>0 0 0.5 0 0 0</pose>
+    </frame>
+    <link name="arm">
+      <pose relative_to="mount">1 0 0 0 0 0</pose>
+      <collision name="shape">
+        <geometry><cylinder><radius>0.1</radius><length>2</length></cylinder></geometry>
+      </collision>
+    </link>
+    <joint name="hinge" type="revolute">
+      <parent>base</parent>
+      <child>arm</child>
+    </joint>
+  </model>
+</sdf>
+"#;
+
+    fn load(contents: &str) -> Vec<Chunk> {
+        let settings = crate::DataLoaderSettings::recommended(StoreId::random(
+            re_log_types::StoreKind::Recording,
+        ));
+        let (tx, rx) = std::sync::mpsc::channel();
+        SdfLoader
+            .load_from_file_contents(
+                &settings,
+                "robot.sdf".into(),
+                std::borrow::Cow::Borrowed(contents.as_bytes()),
+                tx,
+            )
+            .unwrap();
+
+        rx.into_iter()
+            .filter_map(|data| match data {
+                LoadedData::Chunk(_, _, chunk) => Some(chunk),
+                LoadedData::ArrowMsg(..) | LoadedData::LogMsg(..) => None,
+            })
+            .collect()
+    }
+
+    fn component<C: Component>(
+        chunks: &[Chunk],
+        entity_path: &str,
+        descriptor: &ComponentDescriptor,
+    ) -> Option<Vec<C>> {
+        chunks
+            .iter()
+            .filter(|chunk| chunk.entity_path() == &EntityPath::from(entity_path))
+            .find_map(|chunk| chunk.component_batch::<C>(descriptor, 0)?.ok())
+    }
+
+    #[test]
+    fn test_load_sdf() {
+        let chunks = load(SDF);
+
+        let translation = |entity_path| {
+            component::<Translation3D>(&chunks, entity_path, &Transform3D::descriptor_translation())
+        };
+        assert_eq!(
+            translation("robot/base"),
+            Some(vec![Translation3D::new(0.0, 0.0, 1.0)])
+        );
+        // The pose of the child link, relative to its parent link.
+        assert_eq!(
+            translation("robot/base/hinge"),
+            Some(vec![Translation3D::new(1.0, 0.0, 0.5)])
+        );
+
+        let half_sizes = component::<HalfSize3D>(
+            &chunks,
+            "robot/base/body",
+            &Boxes3D::descriptor_half_sizes(),
+        )
+        .unwrap();
+        assert_eq!(half_sizes, vec![HalfSize3D::new(0.5, 1.0, 1.5)]);
+        let colors =
+            component::<Color>(&chunks, "robot/base/body", &Boxes3D::descriptor_colors()).unwrap();
+        assert_eq!(colors, vec![Color::from_rgb(0, 255, 0)]);
+
+        let lengths = component::<Length>(
+            &chunks,
+            "robot/base/hinge/arm/shape",
+            &Cylinders3D::descriptor_lengths(),
+        )
+        .unwrap();
+        assert_eq!(lengths, vec![Length::from(2.0)]);
+    }
+}
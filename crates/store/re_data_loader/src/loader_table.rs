@@ -0,0 +1,812 @@
+//! Loads Parquet and Arrow IPC tables as recordings.
+//!
+//! Each row of the table becomes a row of data, indexed by the table's index columns.
+//! By default, timestamp and duration columns are used as indices, and every other column is
+//! logged under an entity named after the file: numeric columns as [`Scalars`], each to their own
+//! child entity so that they can be plotted, and anything else as-is, as if logged with
+//! [`re_types::AnyValues`].
+//!
+//! This can be customized with a JSON sidecar file placed next to the table, named after it with an
+//! additional `.rerun.json` extension (e.g. `measurements.parquet.rerun.json`):
+//!
+//! ```json
+//! {
+//!     "entity_path": "/vehicle",
+//!     "index_columns": [
+//!         { "column": "timestamp" },
+//!         { "column": "frame", "timeline": "frame_nr" },
+//!         { "column": "ts_ns", "kind": "timestamp" }
+//!     ],
+//!     "columns": {
+//!         "speed": { "entity_path": "/vehicle/speed" },
+//!         "position": { "entity_path": "/vehicle/position", "component": "Points3D:positions" },
+//!         "comment": { "ignore": true }
+//!     }
+//! }
+//! ```
+//!
+//! Components can either be one of Rerun's builtin components, identified by
+//! `Archetype:component`, or any custom name, optionally with a `component_type`.
+//! Columns of lists are logged as one batch of instances per row.
+
+use std::sync::Arc;
+
+use ahash::HashMap;
+use anyhow::Context as _;
+use arrow::{
+    array::{
+        Array as _, ArrayRef, BooleanArray, Int64Array, ListArray, RecordBatch, RecordBatchReader,
+    },
+    buffer::OffsetBuffer,
+    compute::cast,
+    datatypes::{DataType, Field, TimeUnit},
+};
+
+use re_arrow_util::ArrowArrayDowncastRef as _;
+use re_chunk::{
+    Chunk, ChunkComponents, ChunkId, TimeColumn, TimelineName, external::nohash_hasher::IntMap,
+};
+use re_log_types::{EntityPath, TimeType, Timeline};
+use re_types::{ComponentDescriptor, archetypes::Scalars};
+
+use crate::{DataLoader, DataLoaderError, LoadedData};
+
+// ---
+
+/// The extension of the sidecar files used to configure the import of a table, see [`TableLoader`].
+pub const TABLE_SIDECAR_EXTENSION: &str = "rerun.json";
+
+/// Loads Parquet and Arrow IPC tables, mapping their columns to entities and components.
+///
+/// See the [module-level documentation](self) for how columns are mapped, and how to customize it.
+pub struct TableLoader;
+
+impl DataLoader for TableLoader {
+    #[inline]
+    fn name(&self) -> String {
+        "rerun.data_loaders.Table".into()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        tx: std::sync::mpsc::Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if filepath.is_dir() || !is_supported_table_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath));
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let config = TableConfig::from_sidecar(&filepath)?;
+        let file = std::fs::File::open(&filepath)
+            .with_context(|| format!("Failed to open file {filepath:?}"))?;
+
+        let reader: Box<dyn RecordBatchReader + Send> = if crate::extension(&filepath) == "parquet"
+        {
+            Box::new(
+                parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder::try_new(file)
+                    .and_then(|builder| builder.build())
+                    .with_context(|| format!("Failed to read Parquet file {filepath:?}"))?,
+            )
+        } else {
+            ipc_reader(std::io::BufReader::new(file))
+                .with_context(|| format!("Failed to read Arrow file {filepath:?}"))?
+        };
+
+        load_table(&self.name(), settings, &filepath, &config, reader, &tx)
+    }
+
+    fn load_from_file_contents(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        contents: std::borrow::Cow<'_, [u8]>,
+        tx: std::sync::mpsc::Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !is_supported_table_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath));
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        // There is no filesystem to look for a sidecar in.
+        let config = TableConfig::default();
+
+        let reader: Box<dyn RecordBatchReader + Send> = if crate::extension(&filepath) == "parquet"
+        {
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                Box::new(
+                    parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder::try_new(
+                        bytes::Bytes::from(contents.into_owned()),
+                    )
+                    .and_then(|builder| builder.build())
+                    .with_context(|| format!("Failed to read Parquet file {filepath:?}"))?,
+                )
+            }
+
+            #[cfg(target_arch = "wasm32")]
+            {
+                return Err(
+                    anyhow::anyhow!("Parquet files cannot be loaded on the web yet").into(),
+                );
+            }
+        } else {
+            ipc_reader(std::io::Cursor::new(contents.into_owned()))
+                .with_context(|| format!("Failed to read Arrow file {filepath:?}"))?
+        };
+
+        load_table(&self.name(), settings, &filepath, &config, reader, &tx)
+    }
+}
+
+fn is_supported_table_file(filepath: &std::path::Path) -> bool {
+    crate::SUPPORTED_TABLE_EXTENSIONS.contains(&crate::extension(filepath).as_str())
+}
+
+/// Is this the sidecar configuration file of a table?
+pub(crate) fn is_table_sidecar_file(filepath: &std::path::Path) -> bool {
+    filepath
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.strip_suffix(TABLE_SIDECAR_EXTENSION))
+        .and_then(|name| name.strip_suffix('.'))
+        .is_some_and(|table_name| is_supported_table_file(std::path::Path::new(table_name)))
+}
+
+/// Arrow IPC comes in two flavors: the file format (which starts with a magic number), and the
+/// streaming format.
+fn ipc_reader(
+    mut reader: impl std::io::Read + std::io::Seek + Send + 'static,
+) -> anyhow::Result<Box<dyn RecordBatchReader + Send>> {
+    const ARROW_MAGIC: &[u8] = b"ARROW1";
+
+    let mut magic = [0_u8; ARROW_MAGIC.len()];
+    let is_file_format = reader.read_exact(&mut magic).is_ok() && magic == ARROW_MAGIC;
+    reader.rewind()?;
+
+    Ok(if is_file_format {
+        Box::new(arrow::ipc::reader::FileReader::try_new(reader, None)?)
+    } else {
+        Box::new(arrow::ipc::reader::StreamReader::try_new(reader, None)?)
+    })
+}
+
+// ---
+
+/// How a table should be imported, as read from its sidecar file.
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TableConfig {
+    /// The entity under which all columns are logged by default.
+    ///
+    /// Defaults to the path of the file.
+    #[serde(default)]
+    entity_path: Option<String>,
+
+    /// The columns to use as timelines.
+    ///
+    /// If unspecified, all timestamp and duration columns are used.
+    /// If there are no index columns at all, the row number is used instead, see [`ROW_INDEX_TIMELINE`].
+    #[serde(default)]
+    index_columns: Option<Vec<IndexColumnConfig>>,
+
+    /// How specific columns should be logged.
+    #[serde(default)]
+    columns: HashMap<String, ColumnConfig>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct IndexColumnConfig {
+    /// The name of the column.
+    column: String,
+
+    /// The name of the timeline, defaults to the name of the column.
+    #[serde(default)]
+    timeline: Option<String>,
+
+    /// The type of the timeline, inferred from the type of the column if unspecified.
+    ///
+    /// Integer columns are interpreted as nanoseconds for timestamps and durations.
+    #[serde(default)]
+    kind: Option<IndexKind>,
+}
+
+#[derive(Debug, Clone, Copy, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum IndexKind {
+    Sequence,
+    Duration,
+    Timestamp,
+}
+
+impl From<IndexKind> for TimeType {
+    fn from(kind: IndexKind) -> Self {
+        match kind {
+            IndexKind::Sequence => Self::Sequence,
+            IndexKind::Duration => Self::DurationNs,
+            IndexKind::Timestamp => Self::TimestampNs,
+        }
+    }
+}
+
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ColumnConfig {
+    /// Don't log this column at all.
+    #[serde(default)]
+    ignore: bool,
+
+    /// The entity to log this column to.
+    #[serde(default)]
+    entity_path: Option<String>,
+
+    /// The component to log this column as, either a builtin `Archetype:component` or a custom name.
+    #[serde(default)]
+    component: Option<String>,
+
+    /// The type of a custom component.
+    #[serde(default)]
+    component_type: Option<String>,
+}
+
+impl TableConfig {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn from_sidecar(filepath: &std::path::Path) -> Result<Self, DataLoaderError> {
+        let mut sidecar_path = filepath.as_os_str().to_owned();
+        sidecar_path.push(".");
+        sidecar_path.push(TABLE_SIDECAR_EXTENSION);
+        let sidecar_path = std::path::PathBuf::from(sidecar_path);
+
+        if !sidecar_path.is_file() {
+            return Ok(Self::default());
+        }
+
+        re_log::debug!(?sidecar_path, "Using table sidecar");
+
+        let contents = std::fs::read(&sidecar_path)
+            .with_context(|| format!("Failed to read file {sidecar_path:?}"))?;
+        Ok(serde_json::from_slice(&contents)
+            .with_context(|| format!("Invalid table sidecar {sidecar_path:?}"))?)
+    }
+}
+
+// ---
+
+/// The timeline used when a table has no index columns.
+const ROW_INDEX_TIMELINE: &str = "row_index";
+
+fn load_table(
+    loader_name: &str,
+    settings: &crate::DataLoaderSettings,
+    filepath: &std::path::Path,
+    config: &TableConfig,
+    reader: Box<dyn RecordBatchReader + Send>,
+    tx: &std::sync::mpsc::Sender<LoadedData>,
+) -> Result<(), DataLoaderError> {
+    re_tracing::profile_function!();
+
+    re_log::debug!(?filepath, loader = loader_name, "Loading table…");
+
+    let prefix = |entity_path: EntityPath| match &settings.entity_path_prefix {
+        Some(prefix) => prefix.join(&entity_path),
+        None => entity_path,
+    };
+
+    let entity_path = prefix(config.entity_path.as_deref().map_or_else(
+        || EntityPath::from_file_path(filepath),
+        EntityPath::parse_forgiving,
+    ));
+
+    let mapping = TableMapping::new(config, &reader.schema(), &entity_path, prefix)?;
+
+    let store_id = settings
+        .opened_store_id
+        .clone()
+        .unwrap_or_else(|| settings.store_id.clone());
+
+    let mut row_offset = 0;
+    for batch in reader {
+        let batch = batch.with_context(|| format!("Failed to read table {filepath:?}"))?;
+        let num_rows = batch.num_rows();
+
+        for chunk in mapping.batch_to_chunks(&batch, row_offset)? {
+            let data = LoadedData::Chunk(loader_name.to_owned(), store_id.clone(), chunk);
+            if tx.send(data).is_err() {
+                return Ok(()); // The other end has decided to hang up, not our problem.
+            }
+        }
+
+        row_offset += num_rows as i64;
+    }
+
+    Ok(())
+}
+
+/// Where each column of a table ends up.
+struct TableMapping {
+    /// Column name and the timeline it is the index of.
+    indices: Vec<(String, Timeline)>,
+
+    /// Column name, and the entity and component it is logged as.
+    components: Vec<(String, EntityPath, ComponentMapping)>,
+}
+
+enum ComponentMapping {
+    /// Numeric values, logged as [`Scalars`].
+    Scalars,
+
+    /// Arbitrary values, logged as-is, possibly after being cast to the datatype of a builtin component.
+    Raw {
+        descriptor: ComponentDescriptor,
+        datatype: Option<DataType>,
+    },
+}
+
+impl TableMapping {
+    fn new(
+        config: &TableConfig,
+        schema: &arrow::datatypes::Schema,
+        entity_path: &EntityPath,
+        prefix: impl Fn(EntityPath) -> EntityPath,
+    ) -> anyhow::Result<Self> {
+        let indices = if let Some(index_columns) = &config.index_columns {
+            index_columns
+                .iter()
+                .map(|index| {
+                    let field = schema.field_with_name(&index.column)?;
+                    let time_type = match index.kind {
+                        Some(kind) => kind.into(),
+                        None => infer_time_type(field.data_type()).with_context(|| {
+                            format!(
+                                "Index column {:?} has unsupported type {}",
+                                index.column,
+                                field.data_type()
+                            )
+                        })?,
+                    };
+                    let timeline_name = index.timeline.as_deref().unwrap_or(&index.column);
+
+                    Ok((
+                        index.column.clone(),
+                        Timeline::new(timeline_name, time_type),
+                    ))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?
+        } else {
+            schema
+                .fields()
+                .iter()
+                .filter_map(|field| {
+                    let time_type = match field.data_type() {
+                        DataType::Timestamp(..) => TimeType::TimestampNs,
+                        DataType::Duration(..) => TimeType::DurationNs,
+                        _ => return None,
+                    };
+                    Some((
+                        field.name().clone(),
+                        Timeline::new(field.name().as_str(), time_type),
+                    ))
+                })
+                .collect()
+        };
+
+        let reflection = re_types::reflection::generate_reflection()?;
+
+        let mut components = Vec::new();
+        for field in schema.fields() {
+            let name = field.name();
+            if indices.iter().any(|(column, _)| column == name) {
+                continue;
+            }
+
+            let column_config = config.columns.get(name);
+            if column_config.is_some_and(|column_config| column_config.ignore) {
+                continue;
+            }
+
+            let column_entity_path = column_config
+                .and_then(|column_config| column_config.entity_path.as_deref())
+                .map(|path| prefix(EntityPath::parse_forgiving(path)));
+
+            let component = column_config.and_then(|column_config| {
+                column_config.component.as_deref().map(|component| {
+                    resolve_component(
+                        &reflection,
+                        component,
+                        column_config.component_type.as_deref(),
+                    )
+                })
+            });
+
+            let (column_entity_path, mapping) = match component {
+                Some(mapping) => (
+                    column_entity_path.unwrap_or_else(|| entity_path.clone()),
+                    mapping,
+                ),
+
+                // Every series needs its own entity to be plotted.
+                None if is_numeric(field.data_type()) => (
+                    column_entity_path.unwrap_or_else(|| {
+                        entity_path.join(&EntityPath::from_single_string(name.as_str()))
+                    }),
+                    ComponentMapping::Scalars,
+                ),
+
+                None => (
+                    column_entity_path.unwrap_or_else(|| entity_path.clone()),
+                    ComponentMapping::Raw {
+                        descriptor: ComponentDescriptor::partial(name.as_str()),
+                        datatype: None,
+                    },
+                ),
+            };
+
+            components.push((name.clone(), column_entity_path, mapping));
+        }
+
+        Ok(Self {
+            indices,
+            components,
+        })
+    }
+
+    fn batch_to_chunks(&self, batch: &RecordBatch, row_offset: i64) -> anyhow::Result<Vec<Chunk>> {
+        re_tracing::profile_function!();
+
+        let batch = self.drop_rows_without_index(batch)?;
+
+        let timelines = if self.indices.is_empty() {
+            let timeline = Timeline::new_sequence(ROW_INDEX_TIMELINE);
+            let times = (row_offset..row_offset + batch.num_rows() as i64).collect();
+            std::iter::once((
+                *timeline.name(),
+                TimeColumn::new(Some(true), timeline, times),
+            ))
+            .collect()
+        } else {
+            self.indices
+                .iter()
+                .map(|(column, timeline)| {
+                    let times = index_values(batch.column_by_name(column).with_context(|| {
+                        format!("Index column {column:?} is missing from the table")
+                    })?)
+                    .with_context(|| format!("Invalid index column {column:?}"))?;
+
+                    Ok((*timeline.name(), TimeColumn::new(None, *timeline, times)))
+                })
+                .collect::<anyhow::Result<IntMap<TimelineName, TimeColumn>>>()?
+        };
+
+        let mut components_per_entity: std::collections::BTreeMap<EntityPath, ChunkComponents> =
+            Default::default();
+        for (column, entity_path, mapping) in &self.components {
+            let Some(array) = batch.column_by_name(column) else {
+                continue;
+            };
+
+            let (descriptor, list_array) = match mapping {
+                ComponentMapping::Scalars => (
+                    Scalars::descriptor_scalars(),
+                    to_list_array(array, Some(&DataType::Float64))
+                        .with_context(|| format!("Column {column:?} cannot be cast to scalars"))?,
+                ),
+
+                ComponentMapping::Raw {
+                    descriptor,
+                    datatype,
+                } => (
+                    descriptor.clone(),
+                    to_list_array(array, datatype.as_ref()).with_context(|| {
+                        format!(
+                            "Column {column:?} cannot be cast to {}",
+                            descriptor.component
+                        )
+                    })?,
+                ),
+            };
+
+            components_per_entity
+                .entry(entity_path.clone())
+                .or_default()
+                .insert(descriptor, list_array);
+        }
+
+        components_per_entity
+            .into_iter()
+            .map(|(entity_path, components)| {
+                Ok(Chunk::from_auto_row_ids(
+                    ChunkId::new(),
+                    entity_path,
+                    timelines.clone(),
+                    components,
+                )?)
+            })
+            .collect()
+    }
+
+    /// Time columns cannot contain nulls: rows that have a null in any of their index columns are skipped.
+    fn drop_rows_without_index(&self, batch: &RecordBatch) -> anyhow::Result<RecordBatch> {
+        let mut is_valid = None::<BooleanArray>;
+        for (column, _) in &self.indices {
+            let Some(nulls) = batch
+                .column_by_name(column)
+                .and_then(|array| array.logical_nulls())
+            else {
+                continue;
+            };
+
+            let column_is_valid = BooleanArray::from(nulls.into_inner());
+            is_valid = Some(match is_valid {
+                Some(is_valid) => arrow::compute::and(&is_valid, &column_is_valid)?,
+                None => column_is_valid,
+            });
+        }
+
+        let Some(is_valid) = is_valid else {
+            return Ok(batch.clone());
+        };
+
+        re_log::warn_once!("Skipping table rows with null index values");
+        Ok(arrow::compute::filter_record_batch(batch, &is_valid)?)
+    }
+}
+
+/// Finds the builtin component identified by `Archetype:component`, if any, or creates a custom one.
+fn resolve_component(
+    reflection: &re_types::reflection::Reflection,
+    component: &str,
+    component_type: Option<&str>,
+) -> ComponentMapping {
+    if component_type.is_none() {
+        if let Some((archetype, field)) = component.split_once(':') {
+            let builtin = reflection
+                .archetypes
+                .iter()
+                .filter(|(archetype_name, _)| archetype_name.short_name() == archetype)
+                .find_map(|(archetype_name, reflection)| {
+                    let field = reflection.fields.iter().find(|f| f.name == field)?;
+                    Some(field.component_descriptor(*archetype_name))
+                });
+
+            if let Some(descriptor) = builtin {
+                let datatype = descriptor
+                    .component_type
+                    .and_then(|component_type| reflection.components.get(&component_type))
+                    .map(|component| component.datatype.clone());
+                return ComponentMapping::Raw {
+                    descriptor,
+                    datatype,
+                };
+            }
+        }
+    }
+
+    ComponentMapping::Raw {
+        descriptor: ComponentDescriptor {
+            archetype: None,
+            component: component.into(),
+            component_type: component_type.map(Into::into),
+        },
+        datatype: None,
+    }
+}
+
+fn infer_time_type(datatype: &DataType) -> Option<TimeType> {
+    match datatype {
+        DataType::Timestamp(..) | DataType::Date32 | DataType::Date64 => {
+            Some(TimeType::TimestampNs)
+        }
+        DataType::Duration(..) => Some(TimeType::DurationNs),
+        datatype if datatype.is_integer() => Some(TimeType::Sequence),
+        _ => None,
+    }
+}
+
+/// The values of an index column as nanoseconds for timestamps and durations, or as-is otherwise.
+fn index_values(array: &ArrayRef) -> anyhow::Result<arrow::buffer::ScalarBuffer<i64>> {
+    let array = match array.data_type() {
+        DataType::Timestamp(_, _) | DataType::Date32 | DataType::Date64 => {
+            cast(array, &DataType::Timestamp(TimeUnit::Nanosecond, None))?
+        }
+        DataType::Duration(_) => cast(array, &DataType::Duration(TimeUnit::Nanosecond))?,
+        _ => array.clone(),
+    };
+
+    let array = cast(&array, &DataType::Int64)?;
+    let array = array
+        .downcast_array_ref::<Int64Array>()
+        .context("expected integers")?;
+
+    Ok(array.values().clone())
+}
+
+fn is_numeric(datatype: &DataType) -> bool {
+    match datatype {
+        DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
+            field.data_type().is_numeric()
+        }
+        datatype => datatype.is_numeric(),
+    }
+}
+
+/// Turns a column into one batch of instances per row.
+///
+/// Lists are used as-is, any other value becomes a batch of a single instance.
+/// Values are cast to `datatype`, if specified.
+fn to_list_array(array: &ArrayRef, datatype: Option<&DataType>) -> anyhow::Result<ListArray> {
+    let cast_values = |values: &ArrayRef| -> anyhow::Result<ArrayRef> {
+        Ok(match datatype {
+            Some(datatype) if values.data_type() != datatype => cast(values, datatype)?,
+            _ => values.clone(),
+        })
+    };
+
+    match array.data_type() {
+        DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
+            let list_array = cast(array, &DataType::List(field.clone()))?;
+            let list_array = list_array
+                .downcast_array_ref::<ListArray>()
+                .context("expected a list")?;
+
+            let values = cast_values(list_array.values())?;
+            Ok(ListArray::try_new(
+                Arc::new(Field::new_list_field(values.data_type().clone(), true)),
+                list_array.offsets().clone(),
+                values,
+                list_array.nulls().cloned(),
+            )?)
+        }
+
+        _ => {
+            let values = cast_values(array)?;
+            Ok(ListArray::try_new(
+                Arc::new(Field::new_list_field(values.data_type().clone(), true)),
+                OffsetBuffer::from_lengths(std::iter::repeat_n(1, values.len())),
+                values.clone(),
+                values.logical_nulls(),
+            )?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::{
+        array::{Float32Array, StringArray, TimestampMillisecondArray},
+        datatypes::Schema,
+    };
+
+    use super::*;
+
+    fn table() -> RecordBatch {
+        let schema = Arc::new(Schema::new_with_metadata(
+            vec![
+                Field::new(
+                    "timestamp",
+                    DataType::Timestamp(TimeUnit::Millisecond, None),
+                    true,
+                ),
+                Field::new("speed", DataType::Float32, true),
+                Field::new("label", DataType::Utf8, true),
+            ],
+            Default::default(),
+        ));
+
+        RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(TimestampMillisecondArray::from(vec![
+                    Some(1),
+                    None,
+                    Some(3),
+                ])),
+                Arc::new(Float32Array::from(vec![Some(1.0), Some(2.0), None])),
+                Arc::new(StringArray::from(vec!["a", "b", "c"])),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn load(config: &TableConfig) -> Vec<Chunk> {
+        let batch = table();
+        let entity_path = EntityPath::from("table");
+        let mapping =
+            TableMapping::new(config, &batch.schema(), &entity_path, |path| path).unwrap();
+        mapping.batch_to_chunks(&batch, 0).unwrap()
+    }
+
+    #[test]
+    fn test_default_mapping() {
+        let chunks = load(&TableConfig::default());
+        assert_eq!(chunks.len(), 2);
+
+        let table = &chunks[0];
+        assert_eq!(table.entity_path(), &EntityPath::from("table"));
+        assert_eq!(
+            table.num_rows(),
+            2,
+            "the row without a timestamp is skipped"
+        );
+        let times = table.timelines()[&TimelineName::new("timestamp")].times_raw();
+        assert_eq!(times, &[1_000_000, 3_000_000]);
+        assert!(
+            table
+                .components()
+                .contains_key(&ComponentDescriptor::partial("label"))
+        );
+
+        let speed = &chunks[1];
+        assert_eq!(speed.entity_path(), &EntityPath::from("table/speed"));
+        let scalars = speed
+            .components()
+            .get(&Scalars::descriptor_scalars())
+            .unwrap();
+        assert_eq!(scalars.value_type(), DataType::Float64);
+        assert!(scalars.is_valid(0));
+        assert!(scalars.is_null(1));
+    }
+
+    #[test]
+    fn test_configured_mapping() {
+        let config: TableConfig = serde_json::from_str(
+            r#"{
+                "index_columns": [{ "column": "timestamp", "timeline": "time" }],
+                "columns": {
+                    "speed": { "entity_path": "/vehicle/speed" },
+                    "label": { "component": "TextLog:text" }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let chunks = load(&config);
+        assert_eq!(chunks.len(), 2);
+
+        let table = &chunks[0];
+        assert!(table.timelines().contains_key(&TimelineName::new("time")));
+        assert!(
+            table
+                .components()
+                .contains_key(&re_types::archetypes::TextLog::descriptor_text())
+        );
+
+        assert_eq!(chunks[1].entity_path(), &EntityPath::from("vehicle/speed"));
+    }
+
+    #[test]
+    fn test_row_index() {
+        let config: TableConfig = serde_json::from_str(
+            r#"{ "index_columns": [], "columns": { "label": { "ignore": true } } }"#,
+        )
+        .unwrap();
+
+        let chunks = load(&config);
+        let names: Vec<_> = chunks
+            .iter()
+            .map(|chunk| chunk.entity_path().clone())
+            .collect();
+        assert_eq!(
+            names,
+            vec![EntityPath::from("table"), EntityPath::from("table/speed")]
+        );
+
+        let times = chunks[1].timelines()[&TimelineName::new(ROW_INDEX_TIMELINE)].times_raw();
+        assert_eq!(times, &[0, 1, 2]);
+    }
+
+    #[test]
+    fn test_is_table_sidecar_file() {
+        let is_sidecar = |path: &str| is_table_sidecar_file(std::path::Path::new(path));
+
+        assert!(is_sidecar("data/measurements.parquet.rerun.json"));
+        assert!(is_sidecar("measurements.arrow.rerun.json"));
+        assert!(!is_sidecar("measurements.rerun.json"));
+        assert!(!is_sidecar("measurements.parquet"));
+        assert!(!is_sidecar("measurements.json"));
+    }
+}
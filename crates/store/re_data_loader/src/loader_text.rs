@@ -0,0 +1,337 @@
+use re_chunk::{Chunk, RowId};
+use re_log_types::{EntityPath, TimePoint};
+use re_types::archetypes::TextDocument;
+use re_types::components::MediaType;
+
+use crate::{DataLoader, DataLoaderError, LoadedData};
+
+// ---
+
+/// Loads text files as [`TextDocument`]s, with a media type guessed from their extension and contents.
+///
+/// Files with a [known text extension](crate::SUPPORTED_TEXT_EXTENSIONS) are always picked up by
+/// this loader.
+/// Any other UTF-8 file that no loader at all is compatible with is loaded as plain text as a last
+/// resort.
+///
+/// Only JSON is detected from the contents, see [`guess_text_media_type`]: YAML, TOML and CSV
+/// documents are only recognized by their extension, and are shown as plain text otherwise.
+pub struct TextLoader;
+
+impl DataLoader for TextLoader {
+    #[inline]
+    fn name(&self) -> String {
+        "rerun.data_loaders.Text".into()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        tx: std::sync::mpsc::Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if filepath.is_dir() || !is_supported_text_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath));
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        let contents = read_file(&filepath)?;
+        load_text_document(&self.name(), settings, &filepath, contents, &tx)
+    }
+
+    fn load_from_file_contents(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: std::path::PathBuf,
+        contents: std::borrow::Cow<'_, [u8]>,
+        tx: std::sync::mpsc::Sender<LoadedData>,
+    ) -> Result<(), DataLoaderError> {
+        if !is_supported_text_file(&filepath) {
+            return Err(DataLoaderError::Incompatible(filepath));
+        }
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        load_text_document(
+            &self.name(),
+            settings,
+            &filepath,
+            contents.into_owned(),
+            &tx,
+        )
+    }
+}
+
+impl TextLoader {
+    /// Loads the file at `filepath` as a plain text document, if it [looks like text](looks_like_text).
+    ///
+    /// This is the catch-all used when no other [`DataLoader`] is compatible with a file.
+    /// Only a prefix of the file is checked synchronously, the actual loading happens in the
+    /// background.
+    ///
+    /// Returns `false` if the file doesn't look like text.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn load_fallback_from_path(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: &std::path::Path,
+        tx: std::sync::mpsc::Sender<LoadedData>,
+    ) -> bool {
+        use std::io::Read as _;
+
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        if filepath.is_dir() {
+            return false;
+        }
+
+        let mut prefix = Vec::new();
+        let is_text = std::fs::File::open(filepath)
+            .and_then(|file| file.take(SNIFFING_PREFIX_LEN).read_to_end(&mut prefix))
+            .is_ok_and(|_| looks_like_text(&prefix));
+        if !is_text {
+            return false;
+        }
+
+        let name = self.name();
+        let settings = settings.clone();
+        let filepath = filepath.to_owned();
+        rayon::spawn(move || {
+            let result = read_file(&filepath).and_then(|contents| {
+                load_text_document(&name, &settings, &filepath, contents, &tx)
+            });
+            if let Err(err) = result {
+                re_log::error!(?filepath, loader = name, %err, "Failed to load data from file");
+            }
+        });
+
+        true
+    }
+
+    /// Loads `contents` as a plain text document, if it [looks like text](looks_like_text).
+    ///
+    /// This is the catch-all used when no other [`DataLoader`] is compatible with a file.
+    ///
+    /// Returns `false` if the contents don't look like text.
+    pub(crate) fn load_fallback_from_file_contents(
+        &self,
+        settings: &crate::DataLoaderSettings,
+        filepath: &std::path::Path,
+        contents: &[u8],
+        tx: &std::sync::mpsc::Sender<LoadedData>,
+    ) -> bool {
+        re_tracing::profile_function!(filepath.display().to_string());
+
+        if !looks_like_text(contents) {
+            return false;
+        }
+
+        let name = self.name();
+        if let Err(err) = load_text_document(&name, settings, filepath, contents.to_vec(), tx) {
+            re_log::error!(?filepath, loader = name, %err, "Failed to load data from file");
+        }
+
+        true
+    }
+}
+
+// ---
+
+/// How many bytes of a file are looked at to decide whether it is text or not.
+#[cfg(not(target_arch = "wasm32"))]
+const SNIFFING_PREFIX_LEN: u64 = 8 * 1024;
+
+fn is_supported_text_file(filepath: &std::path::Path) -> bool {
+    crate::SUPPORTED_TEXT_EXTENSIONS.contains(&crate::extension(filepath).as_str())
+}
+
+/// Does this look like the beginning of a UTF-8 text file?
+///
+/// The contents might have been truncated in the middle of a multi-byte character, which is fine.
+/// NUL bytes are a strong hint that this is actually a binary file.
+pub(crate) fn looks_like_text(contents: &[u8]) -> bool {
+    let is_utf8 = match std::str::from_utf8(contents) {
+        Ok(_) => true,
+        Err(err) => err.error_len().is_none(),
+    };
+
+    is_utf8 && !contents.contains(&0)
+}
+
+/// Guesses the media type of a text document.
+///
+/// The file extension is trusted if it maps to a known text media type; otherwise we sniff the
+/// contents for JSON, and fall back to plain text.
+///
+/// JSON is the only format that is sniffed: YAML, TOML and CSV are too permissive to be told apart
+/// from plain text reliably, so they are only recognized by their extension.
+pub(crate) fn guess_text_media_type(filepath: &std::path::Path, contents: &str) -> MediaType {
+    if let Some(media_type) = MediaType::guess_from_path(filepath)
+        .filter(|media_type| media_type.is_text() && media_type != &MediaType::plain_text())
+    {
+        return media_type;
+    }
+
+    let trimmed = contents.trim_start();
+    let looks_like_json = (trimmed.starts_with('{') || trimmed.starts_with('['))
+        && serde_json::from_str::<serde_json::Value>(contents).is_ok();
+
+    if looks_like_json {
+        MediaType::json()
+    } else {
+        MediaType::plain_text()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read_file(filepath: &std::path::Path) -> Result<Vec<u8>, DataLoaderError> {
+    use anyhow::Context as _;
+
+    re_tracing::profile_function!();
+    Ok(std::fs::read(filepath).with_context(|| format!("Failed to read file {filepath:?}"))?)
+}
+
+fn load_text_document(
+    loader_name: &str,
+    settings: &crate::DataLoaderSettings,
+    filepath: &std::path::Path,
+    contents: Vec<u8>,
+    tx: &std::sync::mpsc::Sender<LoadedData>,
+) -> Result<(), DataLoaderError> {
+    re_tracing::profile_function!();
+
+    let text = String::from_utf8(contents).map_err(anyhow::Error::from)?;
+    let media_type = guess_text_media_type(filepath, &text);
+
+    re_log::debug!(?filepath, loader = loader_name, %media_type, "Loading text document…");
+
+    let entity_path = EntityPath::from_file_path(filepath);
+    let arch = TextDocument::new(text).with_media_type(media_type);
+    let chunk = Chunk::builder(entity_path)
+        .with_archetype(RowId::new(), TimePoint::default(), &arch)
+        .build()?;
+
+    let store_id = settings
+        .opened_store_id
+        .clone()
+        .unwrap_or_else(|| settings.store_id.clone());
+    let data = LoadedData::Chunk(loader_name.to_owned(), store_id, chunk);
+    tx.send(data).ok(); // The other end might have decided to hang up, not our problem.
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_looks_like_text() {
+        assert!(looks_like_text(b""));
+        assert!(looks_like_text(b"hello world\n"));
+        assert!(looks_like_text("héllo wörld".as_bytes()));
+
+        // Truncated in the middle of a multi-byte character.
+        assert!(looks_like_text(&"é".as_bytes()[..1]));
+
+        assert!(!looks_like_text(b"hello\0world"));
+        assert!(!looks_like_text(&[0xFF, 0xFE, 0x00, 0x01]));
+        assert!(!looks_like_text(&[b'a', 0xC3, b'b']));
+    }
+
+    #[test]
+    fn test_guess_text_media_type() {
+        let guess = |path: &str, contents: &str| {
+            guess_text_media_type(std::path::Path::new(path), contents)
+        };
+
+        assert_eq!(guess("notes.md", "# Title"), MediaType::markdown());
+        assert_eq!(guess("config.json", "{}"), MediaType::json());
+        assert_eq!(guess("config.yaml", "a: 1"), MediaType::yaml());
+        assert_eq!(guess("config.yml", "a: 1"), MediaType::yaml());
+        assert_eq!(guess("Cargo.toml", "[package]"), MediaType::toml());
+        assert_eq!(guess("table.csv", "a,b\n1,2"), MediaType::csv());
+        assert_eq!(guess("notes.txt", "hello"), MediaType::plain_text());
+        assert_eq!(guess("run.log", "[INFO] hello"), MediaType::plain_text());
+
+        // Sniffed from the contents.
+        assert_eq!(guess("response", r#"{"a": [1, 2]}"#), MediaType::json());
+        assert_eq!(guess("response.txt", "[1, 2, 3]"), MediaType::json());
+        assert_eq!(guess("LICENSE", "Copyright"), MediaType::plain_text());
+
+        // Non-text media types are never trusted.
+        assert_eq!(guess("capture.bin", "hello"), MediaType::plain_text());
+
+        // Only JSON is sniffed, other formats need their extension.
+        assert_eq!(guess("config", "a: 1\nb: [2, 3]"), MediaType::plain_text());
+        assert_eq!(
+            guess("Pipfile", "[packages]\na = 1"),
+            MediaType::plain_text()
+        );
+        assert_eq!(guess("table", "a,b\n1,2"), MediaType::plain_text());
+    }
+
+    /// Extension-less files go through all the loaders before falling back to the [`TextLoader`].
+    #[test]
+    fn test_load_extensionless() {
+        let settings = crate::DataLoaderSettings::recommended(re_log_types::StoreId::random(
+            re_log_types::StoreKind::Recording,
+        ));
+
+        let load_media_type = |path: &std::path::Path, contents: Option<&[u8]>| {
+            let rx = crate::load_file::load(&settings, path, contents.map(Into::into))?;
+            let chunks = rx
+                .iter()
+                .filter_map(|data| match data {
+                    LoadedData::Chunk(_, _, chunk) => Some(chunk),
+                    LoadedData::ArrowMsg(..) | LoadedData::LogMsg(..) => None,
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(chunks.len(), 1);
+            assert_eq!(chunks[0].entity_path(), &EntityPath::from_file_path(path));
+
+            let media_type = chunks[0]
+                .component_mono::<MediaType>(&TextDocument::descriptor_media_type(), 0)
+                .unwrap()
+                .unwrap();
+            Ok::<_, DataLoaderError>(media_type)
+        };
+
+        // From memory…
+        let path = std::path::Path::new("response");
+        assert_eq!(
+            load_media_type(path, Some(br#"{"status": "ok"}"#)).unwrap(),
+            MediaType::json()
+        );
+        assert_eq!(
+            load_media_type(path, Some(b"status: ok")).unwrap(),
+            MediaType::plain_text()
+        );
+        assert!(matches!(
+            load_media_type(path, Some(b"\x00\x01\x02")),
+            Err(DataLoaderError::Incompatible(_))
+        ));
+
+        // …and from disk.
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("LICENSE");
+        std::fs::write(&path, "Copyright (c) 2025").unwrap();
+        assert_eq!(
+            load_media_type(&path, None).unwrap(),
+            MediaType::plain_text()
+        );
+
+        let path = dir.path().join("response");
+        std::fs::write(&path, "[1, 2, 3]").unwrap();
+        assert_eq!(load_media_type(&path, None).unwrap(), MediaType::json());
+
+        let path = dir.path().join("capture");
+        std::fs::write(&path, [0xFF, 0x00, 0x12]).unwrap();
+        assert!(matches!(
+            load_media_type(&path, None),
+            Err(DataLoaderError::Incompatible(_))
+        ));
+    }
+}
@@ -9,15 +9,15 @@ use itertools::Itertools as _;
 use urdf_rs::{Geometry, Joint, Link, Material, Robot, Vec3, Vec4};
 
 use re_chunk::{ChunkBuilder, ChunkId, EntityPath, RowId, TimePoint};
-use re_log_types::{EntityPathPart, StoreId};
+use re_log_types::{EntityPathPart, StoreId, TimeCell, TimelineName};
 use re_types::{
-    AsComponents, Component as _, ComponentDescriptor, SerializedComponentBatch,
-    archetypes::{Asset3D, Transform3D},
+    Component as _, ComponentDescriptor, SerializedComponentBatch,
+    archetypes::{Asset3D, Mesh3D, Transform3D},
     datatypes::Vec3D,
     external::glam,
 };
 
-use crate::{DataLoader, DataLoaderError, LoadedData};
+use crate::{DataLoader, DataLoaderError, LoadedData, robot_description::send_archetype};
 
 fn is_urdf_file(path: impl AsRef<Path>) -> bool {
     path.as_ref()
@@ -25,6 +25,19 @@ fn is_urdf_file(path: impl AsRef<Path>) -> bool {
         .is_some_and(|ext| ext.eq_ignore_ascii_case("urdf"))
 }
 
+/// Joint states can be provided in a CSV file next to the URDF file, e.g. `robot.urdf.joint_states.csv`.
+const JOINT_STATES_SIDECAR_EXTENSION: &str = "joint_states.csv";
+
+/// Is this the joint states sidecar file of a URDF file?
+pub(crate) fn is_joint_states_sidecar_file(filepath: &Path) -> bool {
+    filepath
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.strip_suffix(JOINT_STATES_SIDECAR_EXTENSION))
+        .and_then(|name| name.strip_suffix('.'))
+        .is_some_and(is_urdf_file)
+}
+
 fn send_chunk_builder(
     tx: &Sender<LoadedData>,
     store_id: &StoreId,
@@ -38,23 +51,6 @@ fn send_chunk_builder(
     Ok(())
 }
 
-fn send_archetype(
-    tx: &Sender<LoadedData>,
-    store_id: &StoreId,
-    entity_path: EntityPath,
-    archetype: &impl AsComponents,
-) -> anyhow::Result<()> {
-    send_chunk_builder(
-        tx,
-        store_id,
-        ChunkBuilder::new(ChunkId::new(), entity_path).with_archetype(
-            RowId::new(),
-            TimePoint::default(),
-            archetype,
-        ),
-    )
-}
-
 /// A [`DataLoader`] for [URDF](https://en.wikipedia.org/wiki/URDF) (Unified Robot Description Format),
 /// common in ROS.
 pub struct UrdfDataLoader;
@@ -80,9 +76,19 @@ impl DataLoader for UrdfDataLoader {
         let robot = urdf_rs::read_file(&filepath)
             .with_context(|| format!("Path: {}", filepath.display()))?;
 
-        log_robot(robot, &filepath, &tx, &settings.store_id)
+        let urdf_tree = log_robot(robot, &filepath, &tx, &settings.store_id)
             .with_context(|| "Failed to load URDF file!")?;
 
+        let mut sidecar_path = filepath.clone().into_os_string();
+        sidecar_path.push(format!(".{JOINT_STATES_SIDECAR_EXTENSION}"));
+        let sidecar_path = PathBuf::from(sidecar_path);
+        if sidecar_path.is_file() {
+            let csv = std::fs::read_to_string(&sidecar_path)
+                .with_context(|| format!("Failed to read {}", sidecar_path.display()))?;
+            log_joint_states(&urdf_tree, &csv, &tx, &settings.store_id)
+                .with_context(|| format!("Failed to load {}", sidecar_path.display()))?;
+        }
+
         Ok(())
     }
 
@@ -244,6 +250,61 @@ impl UrdfTree {
     pub fn get_joint_child(&self, joint: &Joint) -> &Link {
         &self.links[&joint.child.link] // Safe because we checked that the joint's child link exists in `new()`
     }
+
+    /// The transform of the child link of `joint` relative to the joint frame, for the given joint position.
+    ///
+    /// `position` is an angle in radians for revolute and continuous joints, and a distance in
+    /// meters for prismatic joints.
+    /// Returns `None` for joints that cannot be moved by a single position (fixed, floating, planar, spherical).
+    pub fn joint_transform(joint: &Joint, position: f64) -> Option<Transform3D> {
+        let axis = glam::DVec3::from(joint.axis.xyz.0).normalize_or_zero();
+
+        match joint.joint_type {
+            urdf_rs::JointType::Revolute | urdf_rs::JointType::Continuous => {
+                let rotation = glam::DQuat::from_axis_angle(axis, position).as_quat();
+                Some(Transform3D::clear_fields().with_quaternion(rotation.to_array()))
+            }
+            urdf_rs::JointType::Prismatic => Some(Transform3D::from_translation(
+                (axis * position).as_vec3().to_array(),
+            )),
+            urdf_rs::JointType::Fixed
+            | urdf_rs::JointType::Floating
+            | urdf_rs::JointType::Planar
+            | urdf_rs::JointType::Spherical => None,
+        }
+    }
+
+    /// Computes the link transforms resulting from the given joint positions, indexed by joint name.
+    ///
+    /// Joints that mimic one of the given joints are moved accordingly.
+    /// Each [`Transform3D`] is relative to the frame of the parent joint and must be logged to the
+    /// returned link entity path, which is where the [`UrdfDataLoader`] logs the link itself.
+    pub fn joint_state_transforms<'a>(
+        &self,
+        joint_positions: impl IntoIterator<Item = (&'a str, f64)>,
+    ) -> Vec<(EntityPath, Transform3D)> {
+        let joint_positions: HashMap<&str, f64> = joint_positions.into_iter().collect();
+
+        self.joints
+            .iter()
+            .filter_map(|joint| {
+                let position = joint_positions
+                    .get(joint.name.as_str())
+                    .copied()
+                    .or_else(|| {
+                        let mimic = joint.mimic.as_ref()?;
+                        let position = joint_positions.get(mimic.joint.as_str())?;
+                        Some(
+                            position * mimic.multiplier.unwrap_or(1.0)
+                                + mimic.offset.unwrap_or(0.0),
+                        )
+                    })?;
+
+                let transform = Self::joint_transform(joint, position)?;
+                Some((self.get_link_path_by_name(&joint.child.link), transform))
+            })
+            .collect()
+    }
 }
 
 fn log_robot(
@@ -251,7 +312,7 @@ fn log_robot(
     filepath: &Path,
     tx: &Sender<LoadedData>,
     store_id: &StoreId,
-) -> anyhow::Result<()> {
+) -> anyhow::Result<UrdfTree> {
     let urdf_dir = filepath.parent().map(|path| path.to_path_buf());
 
     let urdf_tree = UrdfTree::new(robot, urdf_dir).with_context(|| "Failed to build URDF tree!")?;
@@ -264,6 +325,124 @@ fn log_robot(
         &urdf_tree.root.name,
     )?;
 
+    Ok(urdf_tree)
+}
+
+/// Joint positions over time, see [`parse_joint_states`].
+struct JointStates {
+    timeline: TimelineName,
+    rows: Vec<(TimeCell, Vec<(String, f64)>)>,
+}
+
+/// Parses a joint states CSV file.
+///
+/// The first column is the timeline, named after its header: integer values make up a sequence
+/// timeline, anything else is interpreted as seconds.
+/// Every other column holds the positions of the joint it is named after. Empty cells are skipped.
+fn parse_joint_states(csv: &str) -> anyhow::Result<JointStates> {
+    let mut lines = csv
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'));
+
+    let header = lines.next().context("Empty joint states file")?;
+    let mut columns = header.split(',').map(str::trim);
+    let timeline = columns.next().unwrap_or_default();
+    if timeline.is_empty() {
+        bail!("Missing timeline column");
+    }
+    let joint_names = columns.collect_vec();
+
+    let rows: Vec<Vec<&str>> = lines
+        .map(|line| line.split(',').map(str::trim).collect())
+        .collect();
+    let is_sequence = rows.iter().all(|row| row[0].parse::<i64>().is_ok());
+
+    let rows = rows
+        .iter()
+        .enumerate()
+        .map(|(row_index, row)| {
+            let time = if is_sequence {
+                TimeCell::from_sequence(row[0].parse::<i64>().unwrap_or_default())
+            } else {
+                let seconds: f64 = row[0]
+                    .parse()
+                    .with_context(|| format!("Invalid time {:?} on row #{row_index}", row[0]))?;
+                TimeCell::from_duration_nanos((seconds * 1e9).round() as i64)
+            };
+
+            let positions = joint_names
+                .iter()
+                .zip(&row[1..])
+                .filter(|(_, cell)| !cell.is_empty())
+                .map(|(joint_name, cell)| {
+                    let position = cell.parse().with_context(|| {
+                        format!(
+                            "Invalid position {cell:?} for joint {joint_name:?} on row #{row_index}"
+                        )
+                    })?;
+                    Ok(((*joint_name).to_owned(), position))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?;
+
+            Ok((time, positions))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(JointStates {
+        timeline: timeline.into(),
+        rows,
+    })
+}
+
+/// Logs the link [`Transform3D`]s resulting from the joint states found in `csv`.
+fn log_joint_states(
+    urdf_tree: &UrdfTree,
+    csv: &str,
+    tx: &Sender<LoadedData>,
+    store_id: &StoreId,
+) -> anyhow::Result<()> {
+    let JointStates { timeline, rows } = parse_joint_states(csv)?;
+
+    for (joint_name, _) in rows
+        .iter()
+        .flat_map(|(_, positions)| positions)
+        .unique_by(|(name, _)| name)
+    {
+        if urdf_tree.get_joint_by_name(joint_name).is_none() {
+            re_log::warn!(joint_name, "Ignoring joint states of unknown joint");
+        }
+    }
+
+    let mut transforms_per_link: std::collections::BTreeMap<EntityPath, Vec<_>> =
+        Default::default();
+
+    for (time, positions) in rows {
+        let timepoint = TimePoint::from([(timeline, time)]);
+        let transforms = urdf_tree.joint_state_transforms(
+            positions
+                .iter()
+                .map(|(name, position)| (name.as_str(), *position)),
+        );
+
+        for (link_path, transform) in transforms {
+            transforms_per_link
+                .entry(link_path)
+                .or_default()
+                .push((timepoint.clone(), transform));
+        }
+    }
+
+    for (link_path, transforms) in transforms_per_link {
+        let chunk = transforms.into_iter().fold(
+            ChunkBuilder::new(ChunkId::new(), link_path),
+            |chunk, (timepoint, transform)| {
+                chunk.with_archetype(RowId::new(), timepoint, &transform)
+            },
+        );
+        send_chunk_builder(tx, store_id, chunk)?;
+    }
+
     Ok(())
 }
 
@@ -367,7 +546,13 @@ fn send_transform(
     if is_identity {
         Ok(()) // avoid noise
     } else {
-        send_archetype(tx, store_id, entity_path, &transform_from_pose(origin))
+        send_archetype(
+            tx,
+            &UrdfDataLoader,
+            store_id,
+            entity_path,
+            &transform_from_pose(origin),
+        )
     }
 }
 
@@ -486,12 +671,15 @@ fn log_link(
 /// TODO(emilk): create a trait for this, so that one can use this URDF loader
 /// from e.g. a ROS-bag loader.
 #[cfg(target_arch = "wasm32")]
-fn load_ros_resource(_root_dir: Option<&PathBuf>, resource_path: &str) -> anyhow::Result<Vec<u8>> {
+pub(crate) fn load_ros_resource(
+    _root_dir: Option<&PathBuf>,
+    resource_path: &str,
+) -> anyhow::Result<Vec<u8>> {
     anyhow::bail!("Loading ROS resources is not supported in WebAssembly: {resource_path}");
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-fn load_ros_resource(
+pub(crate) fn load_ros_resource(
     // Where the .urdf file is located.
     root_dir: Option<&PathBuf>,
     resource_path: &str,
@@ -524,41 +712,35 @@ fn log_geometry(
     geometry: &Geometry,
     material: Option<&urdf_rs::Material>,
 ) -> anyhow::Result<()> {
+    let texture = material.and_then(|material| material.texture.as_ref());
+    let albedo_factor = material
+        .and_then(|material| material.color.as_ref())
+        .map(|color| {
+            let urdf_rs::Color {
+                rgba: Vec4([r, g, b, a]),
+            } = color;
+            // TODO(emilk): is this linear or sRGB?
+            re_types::datatypes::Rgba32::from_linear_unmultiplied_rgba_f32(
+                *r as f32, *g as f32, *b as f32, *a as f32,
+            )
+        });
+
+    if texture.is_some() && !is_obj_mesh(geometry) {
+        re_log::warn_once!("URDF material textures are only supported on OBJ meshes");
+    }
+
     match geometry {
         Geometry::Mesh { filename, scale } => {
             use re_types::components::MediaType;
 
             let mesh_bytes = load_ros_resource(urdf_tree.urdf_dir.as_ref(), filename)?;
-            let mut asset3d =
-                Asset3D::from_file_contents(mesh_bytes, MediaType::guess_from_path(filename));
-
-            if let Some(material) = material {
-                let urdf_rs::Material {
-                    name: _,
-                    color,
-                    texture,
-                } = material;
-                if let Some(color) = color {
-                    let urdf_rs::Color {
-                        rgba: Vec4([r, g, b, a]),
-                    } = color;
-                    asset3d = asset3d.with_albedo_factor(
-                        // TODO(emilk): is this linear or sRGB?
-                        re_types::datatypes::Rgba32::from_linear_unmultiplied_rgba_f32(
-                            *r as f32, *g as f32, *b as f32, *a as f32,
-                        ),
-                    );
-                };
-                if texture.is_some() {
-                    re_log::warn_once!("Material texture not supported"); // TODO(emilk): support textures
-                }
-            }
 
             if let Some(scale) = scale {
                 if scale != &urdf_rs::Vec3([1.0; 3]) {
                     let urdf_rs::Vec3([x, y, z]) = *scale;
                     send_archetype(
                         tx,
+                        &UrdfDataLoader,
                         store_id,
                         entity_path.clone(),
                         &Transform3D::update_fields().with_scale([x as f32, y as f32, z as f32]),
@@ -566,13 +748,32 @@ fn log_geometry(
                 }
             }
 
-            send_archetype(tx, store_id, entity_path, &asset3d)?;
+            if is_obj_mesh(geometry) {
+                log_obj_mesh(
+                    urdf_tree,
+                    tx,
+                    store_id,
+                    &entity_path,
+                    filename,
+                    &mesh_bytes,
+                    albedo_factor,
+                    texture,
+                )?;
+            } else {
+                let mut asset3d =
+                    Asset3D::from_file_contents(mesh_bytes, MediaType::guess_from_path(filename));
+                if let Some(albedo_factor) = albedo_factor {
+                    asset3d = asset3d.with_albedo_factor(albedo_factor);
+                }
+                send_archetype(tx, &UrdfDataLoader, store_id, entity_path, &asset3d)?;
+            }
         }
         Geometry::Box {
             size: Vec3([x, y, z]),
         } => {
             send_archetype(
                 tx,
+                &UrdfDataLoader,
                 store_id,
                 entity_path,
                 &re_types::archetypes::Boxes3D::from_sizes([Vec3D::new(*x as _, *y as _, *z as _)]),
@@ -582,6 +783,7 @@ fn log_geometry(
             // URDF and Rerun both use Z as the main axis
             send_archetype(
                 tx,
+                &UrdfDataLoader,
                 store_id,
                 entity_path,
                 &re_types::archetypes::Cylinders3D::from_lengths_and_radii(
@@ -594,6 +796,7 @@ fn log_geometry(
             // URDF and Rerun both use Z as the main axis
             send_archetype(
                 tx,
+                &UrdfDataLoader,
                 store_id,
                 entity_path,
                 &re_types::archetypes::Capsules3D::from_lengths_and_radii(
@@ -605,6 +808,7 @@ fn log_geometry(
         Geometry::Sphere { radius } => {
             send_archetype(
                 tx,
+                &UrdfDataLoader,
                 store_id,
                 entity_path,
                 &re_types::archetypes::Ellipsoids3D::from_radii([*radius as f32]),
@@ -614,6 +818,135 @@ fn log_geometry(
     Ok(())
 }
 
+fn is_obj_mesh(geometry: &Geometry) -> bool {
+    matches!(geometry, Geometry::Mesh { filename, .. } if filename.to_ascii_lowercase().ends_with(".obj"))
+}
+
+/// OBJ meshes are logged as [`Mesh3D`]s rather than [`Asset3D`]s, so that we can apply the
+/// materials of their `.mtl` files as well as the URDF material, including textures.
+///
+/// The URDF material takes precedence over the `.mtl` materials.
+#[expect(clippy::too_many_arguments)]
+fn log_obj_mesh(
+    urdf_tree: &UrdfTree,
+    tx: &Sender<LoadedData>,
+    store_id: &StoreId,
+    entity_path: &EntityPath,
+    filename: &str,
+    mesh_bytes: &[u8],
+    albedo_factor: Option<re_types::datatypes::Rgba32>,
+    texture: Option<&urdf_rs::Texture>,
+) -> anyhow::Result<()> {
+    let (models, obj_materials) = tobj::load_obj_buf(
+        &mut std::io::Cursor::new(mesh_bytes),
+        &tobj::LoadOptions {
+            single_index: true,
+            triangulate: true,
+            ..Default::default()
+        },
+        |mtl_path| {
+            let resource = sibling_resource(filename, mtl_path);
+            let mtl_bytes =
+                load_ros_resource(urdf_tree.urdf_dir.as_ref(), &resource).map_err(|err| {
+                    re_log::warn!(%err, "Failed to load OBJ material library");
+                    tobj::LoadError::OpenFileFailed
+                })?;
+            tobj::load_mtl_buf(&mut std::io::Cursor::new(mtl_bytes))
+        },
+    )
+    .with_context(|| format!("Failed to load OBJ mesh {filename:?}"))?;
+    let obj_materials = obj_materials.unwrap_or_default();
+
+    // Textures can be shared between models, only decode them once.
+    let mut textures: HashMap<String, Option<re_types::archetypes::Image>> = HashMap::new();
+    let mut load_texture = |resource: String| {
+        textures
+            .entry(resource)
+            .or_insert_with_key(|resource| {
+                load_ros_resource(urdf_tree.urdf_dir.as_ref(), resource)
+                    .and_then(|bytes| Ok(image::load_from_memory(&bytes)?))
+                    .and_then(|image| Ok(re_types::archetypes::Image::from_dynamic_image(image)?))
+                    .map_err(|err| re_log::warn!(resource, %err, "Failed to load texture"))
+                    .ok()
+            })
+            .clone()
+    };
+
+    let num_models = models.len();
+    for (i, model) in models.into_iter().enumerate() {
+        let tobj::Model { mesh, name } = model;
+
+        let entity_path = if num_models == 1 {
+            entity_path.clone()
+        } else if name.is_empty() {
+            entity_path / EntityPathPart::new(format!("mesh_{i}"))
+        } else {
+            entity_path / EntityPathPart::new(name)
+        };
+
+        let num_vertices = mesh.positions.len() / 3;
+        let mut mesh3d = Mesh3D::new(mesh.positions.chunks_exact(3).map(|p| [p[0], p[1], p[2]]))
+            .with_triangle_indices(mesh.indices.chunks_exact(3).map(|t| [t[0], t[1], t[2]]));
+
+        if mesh.normals.len() == mesh.positions.len() {
+            mesh3d = mesh3d
+                .with_vertex_normals(mesh.normals.chunks_exact(3).map(|n| [n[0], n[1], n[2]]));
+        }
+
+        let has_texcoords = mesh.texcoords.len() == num_vertices * 2;
+        if has_texcoords {
+            // OBJ puts the UV origin at the bottom-left, Rerun at the top-left.
+            mesh3d = mesh3d
+                .with_vertex_texcoords(mesh.texcoords.chunks_exact(2).map(|t| [t[0], 1.0 - t[1]]));
+        }
+
+        let obj_material = mesh.material_id.and_then(|id| obj_materials.get(id));
+
+        let albedo_factor = albedo_factor.or_else(|| {
+            let [r, g, b] = obj_material?.diffuse?;
+            let a = obj_material?.dissolve.unwrap_or(1.0);
+            Some(re_types::datatypes::Rgba32::from_linear_unmultiplied_rgba_f32(r, g, b, a))
+        });
+        if let Some(albedo_factor) = albedo_factor {
+            mesh3d = mesh3d.with_albedo_factor(albedo_factor);
+        }
+
+        // The URDF texture is relative to the URDF file, the OBJ one relative to the OBJ file.
+        let texture = texture.map(|texture| texture.filename.clone()).or_else(|| {
+            obj_material?
+                .diffuse_texture
+                .as_ref()
+                .map(|texture| sibling_resource(filename, Path::new(texture)))
+        });
+        if let Some(texture) = texture {
+            if !has_texcoords {
+                re_log::warn_once!(
+                    "Ignoring texture {texture:?} of OBJ mesh {filename:?}, which has no texture coordinates"
+                );
+            } else if let Some(image) = load_texture(texture) {
+                mesh3d = mesh3d.with_albedo_texture_image(image);
+            }
+        }
+
+        send_archetype(tx, &UrdfDataLoader, store_id, entity_path, &mesh3d)?;
+    }
+
+    Ok(())
+}
+
+/// Resolves `path`, relative to the ROS resource `resource_path` (e.g. an `.mtl` file next to an `.obj`).
+fn sibling_resource(resource_path: &str, path: &Path) -> String {
+    let path = path.to_string_lossy();
+    if path.starts_with('/') || path.contains("://") {
+        return path.into_owned();
+    }
+
+    match resource_path.rsplit_once('/') {
+        Some((dir, _)) => format!("{dir}/{path}"),
+        None => path.into_owned(),
+    }
+}
+
 fn quat_xyzw_from_roll_pitch_yaw(roll: f32, pitch: f32, yaw: f32) -> [f32; 4] {
     glam::Quat::from_euler(glam::EulerRot::ZYX, yaw, pitch, roll).to_array()
 }
@@ -693,3 +1026,164 @@ fn resolve_package_uri(uri: &str) -> anyhow::Result<PathBuf> {
         "Failed to resolve package URI: {uri}, tried `ROS_PACKAGE_PATH` and `AMENT_PREFIX_PATH`, but no matching package found"
     );
 }
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use re_chunk::Chunk;
+
+    use super::*;
+
+    const URDF: &str = r#"<?xml version="1.0"?>
+<robot name="robot">
+  <material name="checker">
+    <texture filename="checker.png"/>
+  </material>
+  <link name="base"/>
+  <link name="arm">
+    <visual>
+      <geometry><mesh filename="arm.obj"/></geometry>
+      <material name="checker"/>
+    </visual>
+  </link>
+  <link name="finger"/>
+  <joint name="shoulder" type="revolute">
+    <parent link="base"/>
+    <child link="arm"/>
+    <axis xyz="0 0 1"/>
+    <limit lower="-3.14" upper="3.14" effort="1" velocity="1"/>
+  </joint>
+  <joint name="gripper" type="prismatic">
+    <parent link="arm"/>
+    <child link="finger"/>
+    <axis xyz="1 0 0"/>
+    <limit lower="0" upper="1" effort="1" velocity="1"/>
+    <mimic joint="shoulder" multiplier="0.5"/>
+  </joint>
+  <link name="tool"/>
+  <joint name="tool_mount" type="fixed">
+    <parent link="finger"/>
+    <child link="tool"/>
+  </joint>
+</robot>
+"#;
+
+    const OBJ: &str = "mtllib arm.mtl
+v 0 0 0
+v 1 0 0
+v 0 1 0
+vt 0 0
+vt 1 0
+vt 0 1
+usemtl red
+f 1/1 2/2 3/3
+";
+
+    const MTL: &str = "newmtl red
+Kd 1 0 0
+";
+
+    const JOINT_STATES: &str = "time,shoulder
+0.0,0.0
+0.5,1.0
+";
+
+    fn load_robot(dir: &Path) -> anyhow::Result<Vec<Chunk>> {
+        std::fs::write(dir.join("robot.urdf"), URDF)?;
+        std::fs::write(dir.join("arm.obj"), OBJ)?;
+        std::fs::write(dir.join("arm.mtl"), MTL)?;
+        std::fs::write(dir.join("robot.urdf.joint_states.csv"), JOINT_STATES)?;
+        image::RgbImage::new(2, 2).save(dir.join("checker.png"))?;
+
+        let settings = crate::DataLoaderSettings::recommended(StoreId::random(
+            re_log_types::StoreKind::Recording,
+        ));
+        let (tx, rx) = std::sync::mpsc::channel();
+        UrdfDataLoader.load_from_path(&settings, dir.join("robot.urdf"), tx)?;
+
+        Ok(rx
+            .into_iter()
+            .filter_map(|data| match data {
+                LoadedData::Chunk(_, _, chunk) => Some(chunk),
+                LoadedData::ArrowMsg(..) | LoadedData::LogMsg(..) => None,
+            })
+            .collect())
+    }
+
+    #[test]
+    fn test_joint_transform() {
+        let urdf_tree = UrdfTree::new(urdf_rs::read_from_string(URDF).unwrap(), None).unwrap();
+
+        let shoulder = urdf_tree.get_joint_by_name("shoulder").unwrap();
+        let transform = UrdfTree::joint_transform(shoulder, std::f64::consts::FRAC_PI_2).unwrap();
+        let expected = Transform3D::clear_fields()
+            .with_quaternion(glam::Quat::from_rotation_z(std::f32::consts::FRAC_PI_2).to_array());
+        assert_eq!(transform.quaternion, expected.quaternion);
+
+        let gripper = urdf_tree.get_joint_by_name("gripper").unwrap();
+        let transform = UrdfTree::joint_transform(gripper, 0.25).unwrap();
+        assert_eq!(
+            transform.translation,
+            Transform3D::from_translation([0.25, 0.0, 0.0]).translation
+        );
+
+        let tool_mount = urdf_tree.get_joint_by_name("tool_mount").unwrap();
+        assert!(UrdfTree::joint_transform(tool_mount, 1.0).is_none());
+    }
+
+    #[test]
+    fn test_parse_joint_states() {
+        let states = parse_joint_states("frame,a,b\n0,1.0,\n1,,2.0\n").unwrap();
+        assert_eq!(states.timeline, TimelineName::new("frame"));
+        assert_eq!(
+            states.rows,
+            vec![
+                (TimeCell::from_sequence(0), vec![("a".to_owned(), 1.0)]),
+                (TimeCell::from_sequence(1), vec![("b".to_owned(), 2.0)]),
+            ]
+        );
+
+        let states = parse_joint_states(JOINT_STATES).unwrap();
+        assert_eq!(states.rows[1].0, TimeCell::from_duration_nanos(500_000_000));
+
+        assert!(parse_joint_states("time,a\n0.0,nope\n").is_err());
+    }
+
+    #[test]
+    fn test_textures_and_joint_states() {
+        let dir = tempfile::tempdir().unwrap();
+        let chunks = load_robot(dir.path()).unwrap();
+
+        let mesh = chunks
+            .iter()
+            .find(|chunk| {
+                chunk.entity_path() == &EntityPath::from("robot/base/shoulder/arm/visual_0")
+                    && chunk
+                        .components()
+                        .contains_key(&Mesh3D::descriptor_vertex_positions())
+            })
+            .expect("the OBJ mesh should be logged as a Mesh3D");
+        for descriptor in [
+            Mesh3D::descriptor_vertex_texcoords(),
+            Mesh3D::descriptor_albedo_factor(),
+            Mesh3D::descriptor_albedo_texture_buffer(),
+            Mesh3D::descriptor_albedo_texture_format(),
+        ] {
+            assert!(mesh.components().contains_key(&descriptor), "{descriptor}");
+        }
+
+        for link_path in [
+            "robot/base/shoulder/arm",
+            "robot/base/shoulder/arm/gripper/finger",
+        ] {
+            let time = TimelineName::new("time");
+            let transforms = chunks
+                .iter()
+                .find(|chunk| {
+                    chunk.entity_path() == &EntityPath::from(link_path)
+                        && chunk.timelines().contains_key(&time)
+                })
+                .expect("the link should be animated");
+            assert_eq!(transforms.timelines()[&time].times_raw(), &[0, 500_000_000]);
+        }
+    }
+}
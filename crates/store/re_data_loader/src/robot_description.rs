@@ -0,0 +1,47 @@
+//! Helpers shared by the loaders of robot description formats: URDF, SDF and MJCF.
+
+use std::sync::mpsc::Sender;
+
+use roxmltree::Node;
+
+use re_chunk::{ChunkBuilder, ChunkId, EntityPath, RowId, TimePoint};
+use re_log_types::StoreId;
+use re_types::AsComponents;
+
+use crate::{DataLoader, LoadedData};
+
+/// Sends a static chunk with a single archetype.
+pub(crate) fn send_archetype(
+    tx: &Sender<LoadedData>,
+    loader: &dyn DataLoader,
+    store_id: &StoreId,
+    entity_path: EntityPath,
+    archetype: &dyn AsComponents,
+) -> anyhow::Result<()> {
+    let chunk = ChunkBuilder::new(ChunkId::new(), entity_path)
+        .with_archetype(RowId::new(), TimePoint::default(), archetype)
+        .build()?;
+    tx.send(LoadedData::Chunk(loader.name(), store_id.clone(), chunk))?;
+    Ok(())
+}
+
+/// The child elements of `node` with the given tag name.
+pub(crate) fn children<'a, 'input>(
+    node: Node<'a, 'input>,
+    tag_name: &str,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children()
+        .filter(move |child| child.has_tag_name(tag_name))
+}
+
+/// Parses a whitespace-separated list of floats, e.g. `"0 0.5 1"`.
+pub(crate) fn parse_floats(text: &str) -> Option<Vec<f32>> {
+    text.split_whitespace()
+        .map(|value| value.parse().ok())
+        .collect()
+}
+
+/// Converts a color channel from `[0, 1]` to `[0, 255]`.
+pub(crate) fn u8(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
@@ -0,0 +1,755 @@
+//! Decoding of the common ROS 2 message types, as serialized in MCAP files.
+//!
+//! ROS 2 messages are serialized using the OMG [Common Data Representation (CDR)](https://www.omg.org/spec/DDS-XTypes/1.3/PDF),
+//! which is simple enough that we decode it by hand rather than pulling in a full DDS stack.
+//!
+//! Only the fields that end up being visualized are kept around.
+
+/// Errors that can occur when decoding a ROS 2 message.
+#[derive(thiserror::Error, Debug)]
+pub enum Ros2Error {
+    #[error("unexpected end of message: needed {needed} more byte(s) at offset {offset}")]
+    UnexpectedEof { offset: usize, needed: usize },
+
+    #[error("unsupported CDR encapsulation kind: {0:#06x}")]
+    UnsupportedEncapsulation(u16),
+
+    #[error("string is not valid UTF-8: {0}")]
+    InvalidUtf8(#[from] std::str::Utf8Error),
+}
+
+pub type Ros2Result<T> = Result<T, Ros2Error>;
+
+// ---
+
+/// A reader for CDR-encoded data, starting with the 4-byte encapsulation header.
+pub struct CdrReader<'a> {
+    /// The data after the encapsulation header, which is what alignment is relative to.
+    data: &'a [u8],
+    offset: usize,
+    little_endian: bool,
+}
+
+impl<'a> CdrReader<'a> {
+    pub fn new(data: &'a [u8]) -> Ros2Result<Self> {
+        let header = data.get(..4).ok_or_else(|| Ros2Error::UnexpectedEof {
+            offset: 0,
+            needed: 4usize.saturating_sub(data.len()),
+        })?;
+
+        // The first two bytes are the representation identifier, the last two are options.
+        let kind = u16::from_be_bytes([header[0], header[1]]);
+        let little_endian = match kind {
+            // CDR_BE, CDR_LE, PL_CDR_BE, PL_CDR_LE
+            0x0000..=0x0003 => kind & 1 == 1,
+            _ => return Err(Ros2Error::UnsupportedEncapsulation(kind)),
+        };
+
+        Ok(Self {
+            data: &data[4..],
+            offset: 0,
+            little_endian,
+        })
+    }
+
+    fn align(&mut self, alignment: usize) {
+        self.offset = self.offset.next_multiple_of(alignment);
+    }
+
+    fn take(&mut self, len: usize) -> Ros2Result<&'a [u8]> {
+        let bytes = self
+            .data
+            .get(self.offset..self.offset.saturating_add(len))
+            .ok_or_else(|| Ros2Error::UnexpectedEof {
+                offset: self.offset,
+                needed: (self.offset + len).saturating_sub(self.data.len()),
+            })?;
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Ros2Result<[u8; N]> {
+        self.align(N);
+        let mut bytes = [0; N];
+        bytes.copy_from_slice(self.take(N)?);
+        Ok(bytes)
+    }
+
+    pub fn u8(&mut self) -> Ros2Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub fn bool(&mut self) -> Ros2Result<bool> {
+        Ok(self.u8()? != 0)
+    }
+
+    pub fn u32(&mut self) -> Ros2Result<u32> {
+        let bytes = self.take_array()?;
+        Ok(if self.little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    pub fn i32(&mut self) -> Ros2Result<i32> {
+        let bytes = self.take_array()?;
+        Ok(if self.little_endian {
+            i32::from_le_bytes(bytes)
+        } else {
+            i32::from_be_bytes(bytes)
+        })
+    }
+
+    pub fn f64(&mut self) -> Ros2Result<f64> {
+        let bytes = self.take_array()?;
+        Ok(if self.little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    /// A string is its length (including the NUL terminator) followed by its bytes.
+    pub fn string(&mut self) -> Ros2Result<String> {
+        let len = self.u32()? as usize;
+        let bytes = self.take(len)?;
+        let bytes = bytes.strip_suffix(&[0]).unwrap_or(bytes);
+        Ok(std::str::from_utf8(bytes)?.to_owned())
+    }
+
+    /// The length prefix of a sequence.
+    pub fn sequence_len(&mut self) -> Ros2Result<usize> {
+        Ok(self.u32()? as usize)
+    }
+
+    pub fn bytes(&mut self) -> Ros2Result<&'a [u8]> {
+        let len = self.sequence_len()?;
+        self.take(len)
+    }
+
+    pub fn f64_array<const N: usize>(&mut self) -> Ros2Result<[f64; N]> {
+        let mut values = [0.0; N];
+        for value in &mut values {
+            *value = self.f64()?;
+        }
+        Ok(values)
+    }
+
+    pub fn f64_sequence(&mut self) -> Ros2Result<Vec<f64>> {
+        let len = self.sequence_len()?;
+        (0..len).map(|_| self.f64()).collect()
+    }
+}
+
+// --- builtin_interfaces & std_msgs ---
+
+/// `builtin_interfaces/msg/Time`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Time {
+    pub sec: i32,
+    pub nanosec: u32,
+}
+
+impl Time {
+    pub fn decode(reader: &mut CdrReader<'_>) -> Ros2Result<Self> {
+        Ok(Self {
+            sec: reader.i32()?,
+            nanosec: reader.u32()?,
+        })
+    }
+}
+
+/// `std_msgs/msg/Header`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Header {
+    pub stamp: Time,
+    pub frame_id: String,
+}
+
+impl Header {
+    pub fn decode(reader: &mut CdrReader<'_>) -> Ros2Result<Self> {
+        Ok(Self {
+            stamp: Time::decode(reader)?,
+            frame_id: reader.string()?,
+        })
+    }
+}
+
+// --- geometry_msgs ---
+
+/// `geometry_msgs/msg/Vector3`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vector3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Vector3 {
+    pub fn decode(reader: &mut CdrReader<'_>) -> Ros2Result<Self> {
+        Ok(Self {
+            x: reader.f64()?,
+            y: reader.f64()?,
+            z: reader.f64()?,
+        })
+    }
+
+    pub fn to_f32(self) -> [f32; 3] {
+        [self.x as f32, self.y as f32, self.z as f32]
+    }
+}
+
+/// `geometry_msgs/msg/Quaternion`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+
+impl Quaternion {
+    pub fn decode(reader: &mut CdrReader<'_>) -> Ros2Result<Self> {
+        Ok(Self {
+            x: reader.f64()?,
+            y: reader.f64()?,
+            z: reader.f64()?,
+            w: reader.f64()?,
+        })
+    }
+
+    pub fn to_xyzw_f32(self) -> [f32; 4] {
+        [self.x as f32, self.y as f32, self.z as f32, self.w as f32]
+    }
+}
+
+/// `geometry_msgs/msg/TransformStamped`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransformStamped {
+    pub header: Header,
+    pub child_frame_id: String,
+    pub translation: Vector3,
+    pub rotation: Quaternion,
+}
+
+impl TransformStamped {
+    pub fn decode(reader: &mut CdrReader<'_>) -> Ros2Result<Self> {
+        Ok(Self {
+            header: Header::decode(reader)?,
+            child_frame_id: reader.string()?,
+            translation: Vector3::decode(reader)?,
+            rotation: Quaternion::decode(reader)?,
+        })
+    }
+}
+
+// --- tf2_msgs ---
+
+/// `tf2_msgs/msg/TFMessage`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TfMessage {
+    pub transforms: Vec<TransformStamped>,
+}
+
+impl TfMessage {
+    pub fn decode(reader: &mut CdrReader<'_>) -> Ros2Result<Self> {
+        let len = reader.sequence_len()?;
+        Ok(Self {
+            transforms: (0..len)
+                .map(|_| TransformStamped::decode(reader))
+                .collect::<Ros2Result<_>>()?,
+        })
+    }
+}
+
+// --- sensor_msgs ---
+
+/// `sensor_msgs/msg/Image`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image<'a> {
+    pub header: Header,
+    pub height: u32,
+    pub width: u32,
+    pub encoding: String,
+    pub is_bigendian: bool,
+
+    /// Full row length in bytes.
+    pub step: u32,
+    pub data: &'a [u8],
+}
+
+impl<'a> Image<'a> {
+    pub fn decode(reader: &mut CdrReader<'a>) -> Ros2Result<Self> {
+        Ok(Self {
+            header: Header::decode(reader)?,
+            height: reader.u32()?,
+            width: reader.u32()?,
+            encoding: reader.string()?,
+            is_bigendian: reader.bool()?,
+            step: reader.u32()?,
+            data: reader.bytes()?,
+        })
+    }
+}
+
+/// `sensor_msgs/msg/CompressedImage`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompressedImage<'a> {
+    pub header: Header,
+
+    /// E.g. `jpeg`, `png`, or `rgb8; jpeg compressed bgr8`.
+    pub format: String,
+    pub data: &'a [u8],
+}
+
+impl<'a> CompressedImage<'a> {
+    pub fn decode(reader: &mut CdrReader<'a>) -> Ros2Result<Self> {
+        Ok(Self {
+            header: Header::decode(reader)?,
+            format: reader.string()?,
+            data: reader.bytes()?,
+        })
+    }
+}
+
+/// `sensor_msgs/msg/PointField`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PointField {
+    pub name: String,
+    pub offset: u32,
+    pub datatype: PointFieldDatatype,
+    pub count: u32,
+}
+
+impl PointField {
+    pub fn decode(reader: &mut CdrReader<'_>) -> Ros2Result<Self> {
+        Ok(Self {
+            name: reader.string()?,
+            offset: reader.u32()?,
+            datatype: PointFieldDatatype::from(reader.u8()?),
+            count: reader.u32()?,
+        })
+    }
+}
+
+/// The datatype of a [`PointField`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointFieldDatatype {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    F32,
+    F64,
+    Unknown(u8),
+}
+
+impl PointFieldDatatype {
+    /// Size of a single value, in bytes.
+    pub fn size(self) -> Option<usize> {
+        match self {
+            Self::I8 | Self::U8 => Some(1),
+            Self::I16 | Self::U16 => Some(2),
+            Self::I32 | Self::U32 | Self::F32 => Some(4),
+            Self::F64 => Some(8),
+            Self::Unknown(_) => None,
+        }
+    }
+}
+
+impl From<u8> for PointFieldDatatype {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::I8,
+            2 => Self::U8,
+            3 => Self::I16,
+            4 => Self::U16,
+            5 => Self::I32,
+            6 => Self::U32,
+            7 => Self::F32,
+            8 => Self::F64,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+/// `sensor_msgs/msg/PointCloud2`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PointCloud2<'a> {
+    pub header: Header,
+    pub height: u32,
+    pub width: u32,
+    pub fields: Vec<PointField>,
+    pub is_bigendian: bool,
+    pub point_step: u32,
+    pub row_step: u32,
+    pub data: &'a [u8],
+    pub is_dense: bool,
+}
+
+impl<'a> PointCloud2<'a> {
+    pub fn decode(reader: &mut CdrReader<'a>) -> Ros2Result<Self> {
+        let header = Header::decode(reader)?;
+        let height = reader.u32()?;
+        let width = reader.u32()?;
+        let num_fields = reader.sequence_len()?;
+        let fields = (0..num_fields)
+            .map(|_| PointField::decode(reader))
+            .collect::<Ros2Result<_>>()?;
+
+        Ok(Self {
+            header,
+            height,
+            width,
+            fields,
+            is_bigendian: reader.bool()?,
+            point_step: reader.u32()?,
+            row_step: reader.u32()?,
+            data: reader.bytes()?,
+            is_dense: reader.bool()?,
+        })
+    }
+
+    pub fn field(&self, name: &str) -> Option<&PointField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Iterates over the raw bytes of a field, for each point of the cloud.
+    ///
+    /// Returns `None` if the field has an unknown datatype, or if the layout of the cloud is invalid
+    /// (e.g. a zero `point_step`, or rows overlapping each other).
+    ///
+    /// The header is never trusted: the iteration stops as soon as the data runs out.
+    pub fn iter_field_bytes<'s>(
+        &'s self,
+        field: &'s PointField,
+    ) -> Option<impl Iterator<Item = &'a [u8]> + 's> {
+        let size = field.datatype.size()?;
+        let offset = field.offset as usize;
+        let point_step = self.point_step as usize;
+        let row_step = self.row_step as usize;
+        let width = self.width as usize;
+
+        if point_step == 0 || row_step < width.checked_mul(point_step)? {
+            return None;
+        }
+
+        // There cannot be more points than what the data can hold, whatever the header claims.
+        let num_points = width
+            .checked_mul(self.height as usize)?
+            .min(self.data.len() / point_step);
+
+        let data = self.data;
+        Some((0..num_points).map_while(move |index| {
+            let (row, col) = (index / width, index % width);
+            let start = row
+                .checked_mul(row_step)?
+                .checked_add(col.checked_mul(point_step)?)?
+                .checked_add(offset)?;
+            data.get(start..start.checked_add(size)?)
+        }))
+    }
+
+    /// Iterates over the values of a field as `f32`, for each point of the cloud.
+    ///
+    /// Returns `None` if the field has an unknown datatype.
+    pub fn iter_field_f32<'s>(
+        &'s self,
+        field: &'s PointField,
+    ) -> Option<impl Iterator<Item = f32> + 's> {
+        let is_bigendian = self.is_bigendian;
+        Some(
+            self.iter_field_bytes(field)?
+                .map(move |bytes| read_point_value(bytes, field.datatype, is_bigendian)),
+        )
+    }
+}
+
+fn read_point_value(bytes: &[u8], datatype: PointFieldDatatype, is_bigendian: bool) -> f32 {
+    macro_rules! read {
+        ($ty:ty) => {{
+            let bytes = bytes.try_into().unwrap_or_default();
+            if is_bigendian {
+                <$ty>::from_be_bytes(bytes)
+            } else {
+                <$ty>::from_le_bytes(bytes)
+            }
+        }};
+    }
+
+    match datatype {
+        PointFieldDatatype::I8 => bytes[0] as i8 as f32,
+        PointFieldDatatype::U8 => bytes[0] as f32,
+        PointFieldDatatype::I16 => read!(i16) as f32,
+        PointFieldDatatype::U16 => read!(u16) as f32,
+        PointFieldDatatype::I32 => read!(i32) as f32,
+        PointFieldDatatype::U32 => read!(u32) as f32,
+        PointFieldDatatype::F32 => read!(f32),
+        PointFieldDatatype::F64 => read!(f64) as f32,
+        PointFieldDatatype::Unknown(_) => f32::NAN,
+    }
+}
+
+/// `sensor_msgs/msg/Imu`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Imu {
+    pub header: Header,
+    pub orientation: Quaternion,
+    pub orientation_covariance: [f64; 9],
+    pub angular_velocity: Vector3,
+    pub angular_velocity_covariance: [f64; 9],
+    pub linear_acceleration: Vector3,
+    pub linear_acceleration_covariance: [f64; 9],
+}
+
+impl Imu {
+    pub fn decode(reader: &mut CdrReader<'_>) -> Ros2Result<Self> {
+        Ok(Self {
+            header: Header::decode(reader)?,
+            orientation: Quaternion::decode(reader)?,
+            orientation_covariance: reader.f64_array()?,
+            angular_velocity: Vector3::decode(reader)?,
+            angular_velocity_covariance: reader.f64_array()?,
+            linear_acceleration: Vector3::decode(reader)?,
+            linear_acceleration_covariance: reader.f64_array()?,
+        })
+    }
+}
+
+/// `sensor_msgs/msg/CameraInfo`
+///
+/// The trailing binning and region of interest fields are not decoded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CameraInfo {
+    pub header: Header,
+    pub height: u32,
+    pub width: u32,
+    pub distortion_model: String,
+    pub d: Vec<f64>,
+
+    /// Intrinsic camera matrix, row-major.
+    pub k: [f64; 9],
+
+    /// Rectification matrix, row-major.
+    pub r: [f64; 9],
+
+    /// Projection matrix, row-major.
+    pub p: [f64; 12],
+}
+
+impl CameraInfo {
+    pub fn decode(reader: &mut CdrReader<'_>) -> Ros2Result<Self> {
+        Ok(Self {
+            header: Header::decode(reader)?,
+            height: reader.u32()?,
+            width: reader.u32()?,
+            distortion_model: reader.string()?,
+            d: reader.f64_sequence()?,
+            k: reader.f64_array()?,
+            r: reader.f64_array()?,
+            p: reader.f64_array()?,
+        })
+    }
+}
+
+// ---
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// A minimal CDR writer, to produce test messages.
+    pub struct CdrWriter {
+        data: Vec<u8>,
+    }
+
+    impl Default for CdrWriter {
+        fn default() -> Self {
+            Self {
+                data: vec![0x00, 0x01, 0x00, 0x00], // CDR_LE
+            }
+        }
+    }
+
+    impl CdrWriter {
+        fn align(&mut self, alignment: usize) {
+            let len = (self.data.len() - 4).next_multiple_of(alignment) + 4;
+            self.data.resize(len, 0);
+        }
+
+        pub fn u8(&mut self, value: u8) -> &mut Self {
+            self.data.push(value);
+            self
+        }
+
+        pub fn u32(&mut self, value: u32) -> &mut Self {
+            self.align(4);
+            self.data.extend(value.to_le_bytes());
+            self
+        }
+
+        pub fn i32(&mut self, value: i32) -> &mut Self {
+            self.align(4);
+            self.data.extend(value.to_le_bytes());
+            self
+        }
+
+        pub fn f64(&mut self, value: f64) -> &mut Self {
+            self.align(8);
+            self.data.extend(value.to_le_bytes());
+            self
+        }
+
+        pub fn string(&mut self, value: &str) -> &mut Self {
+            self.u32(value.len() as u32 + 1);
+            self.data.extend(value.as_bytes());
+            self.data.push(0);
+            self
+        }
+
+        pub fn bytes(&mut self, value: &[u8]) -> &mut Self {
+            self.u32(value.len() as u32);
+            self.data.extend(value);
+            self
+        }
+
+        pub fn header(&mut self, sec: i32, frame_id: &str) -> &mut Self {
+            self.i32(sec).u32(0).string(frame_id)
+        }
+
+        pub fn transform(&mut self, parent: &str, child: &str, translation: [f64; 3]) -> &mut Self {
+            self.header(0, parent).string(child);
+            for value in translation {
+                self.f64(value);
+            }
+            self.f64(0.0).f64(0.0).f64(0.0).f64(1.0)
+        }
+
+        pub fn finish(&mut self) -> Vec<u8> {
+            std::mem::take(&mut self.data)
+        }
+    }
+
+    #[test]
+    fn test_decode_image() {
+        let data = CdrWriter::default()
+            .header(42, "camera")
+            .u32(2)
+            .u32(3)
+            .string("rgb8")
+            .u8(0)
+            .u32(9)
+            .bytes(&[0; 18])
+            .finish();
+
+        let image = Image::decode(&mut CdrReader::new(&data).unwrap()).unwrap();
+        assert_eq!(
+            image.header,
+            Header {
+                stamp: Time {
+                    sec: 42,
+                    nanosec: 0
+                },
+                frame_id: "camera".to_owned(),
+            }
+        );
+        assert_eq!((image.width, image.height, image.step), (3, 2, 9));
+        assert_eq!(image.encoding, "rgb8");
+        assert_eq!(image.data.len(), 18);
+    }
+
+    #[test]
+    fn test_decode_tf_message() {
+        let data = CdrWriter::default()
+            .u32(2)
+            .transform("world", "base_link", [1.0, 2.0, 3.0])
+            .transform("base_link", "camera", [0.0, 0.0, 1.0])
+            .finish();
+
+        let tf = TfMessage::decode(&mut CdrReader::new(&data).unwrap()).unwrap();
+        assert_eq!(tf.transforms.len(), 2);
+        assert_eq!(tf.transforms[0].header.frame_id, "world");
+        assert_eq!(tf.transforms[0].child_frame_id, "base_link");
+        assert_eq!(
+            tf.transforms[0].translation,
+            Vector3 {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0
+            }
+        );
+        assert_eq!(tf.transforms[1].child_frame_id, "camera");
+        assert_eq!(tf.transforms[1].rotation.w, 1.0);
+    }
+
+    #[test]
+    fn test_decode_point_cloud() {
+        let mut points = Vec::new();
+        for value in [1.0_f32, 2.0, 3.0, 4.0, 5.0, 6.0] {
+            points.extend(value.to_le_bytes());
+        }
+
+        let mut writer = CdrWriter::default();
+        writer.header(0, "lidar").u32(1).u32(2).u32(3);
+        for (name, offset) in [("x", 0), ("y", 4), ("z", 8)] {
+            writer.string(name).u32(offset).u8(7).u32(1);
+        }
+        let data = writer.u8(0).u32(12).u32(24).bytes(&points).u8(1).finish();
+
+        let cloud = PointCloud2::decode(&mut CdrReader::new(&data).unwrap()).unwrap();
+        assert_eq!(cloud.fields.len(), 3);
+        assert!(cloud.is_dense);
+
+        let z = cloud.field("z").unwrap();
+        assert_eq!(
+            cloud.iter_field_f32(z).unwrap().collect::<Vec<_>>(),
+            vec![3.0, 6.0]
+        );
+    }
+
+    #[test]
+    fn test_malformed_point_cloud() {
+        let points = [0_u8; 24];
+
+        let cloud = |height, width, point_step, row_step| {
+            let mut writer = CdrWriter::default();
+            writer.header(0, "lidar").u32(height).u32(width).u32(1);
+            writer.string("x").u32(0).u8(7).u32(1);
+            writer
+                .u8(0)
+                .u32(point_step)
+                .u32(row_step)
+                .bytes(&points)
+                .u8(1)
+                .finish()
+        };
+
+        let num_points = |data: &[u8]| {
+            let cloud = PointCloud2::decode(&mut CdrReader::new(data).unwrap()).unwrap();
+            let x = cloud.field("x").unwrap();
+            cloud.iter_field_bytes(x).map(|values| values.count())
+        };
+
+        // Zero steps would yield the same point forever.
+        assert_eq!(num_points(&cloud(u32::MAX, u32::MAX, 0, 0)), None);
+
+        // Rows cannot overlap.
+        assert_eq!(num_points(&cloud(2, 3, 4, 4)), None);
+
+        // Huge dimensions are capped by the actual data, and offsets must not overflow.
+        assert_eq!(num_points(&cloud(u32::MAX, 1, 4, u32::MAX)), Some(1));
+        assert_eq!(num_points(&cloud(u32::MAX, 2, 4, 8)), Some(6));
+    }
+
+    #[test]
+    fn test_truncated_message() {
+        let data = CdrWriter::default().header(0, "camera").finish();
+        assert!(matches!(
+            Image::decode(&mut CdrReader::new(&data).unwrap()),
+            Err(Ros2Error::UnexpectedEof { .. })
+        ));
+    }
+}
@@ -1,6 +1,6 @@
 use re_grpc_client::{ConnectionRegistryHandle, message_proxy};
 use re_log_types::LogMsg;
-use re_smart_channel::{Receiver, SmartChannelSource, SmartMessageSource};
+use re_smart_channel::{Receiver, Sender, SmartChannelSource, SmartMessageSource};
 use re_uri::RedapUri;
 
 use crate::FileContents;
@@ -8,6 +8,11 @@ use crate::FileContents;
 #[cfg(not(target_arch = "wasm32"))]
 use anyhow::Context as _;
 
+/// How many bytes worth of messages loaded from a file can be waiting to be ingested, before the
+/// [`re_data_loader::DataLoader`]s get paused.
+#[cfg(not(target_arch = "wasm32"))]
+const FILE_LOADING_BYTE_BUDGET: u64 = 256 * 1024 * 1024;
+
 /// Somewhere we can get Rerun data from.
 #[derive(Clone, Debug)]
 pub enum DataSource {
@@ -164,10 +169,7 @@ impl DataSource {
 
             #[cfg(not(target_arch = "wasm32"))]
             Self::FilePath(file_source, path) => {
-                let (tx, rx) = re_smart_channel::smart_channel(
-                    SmartMessageSource::File(path.clone()),
-                    SmartChannelSource::File(path.clone()),
-                );
+                let (tx, rx) = file_channel(&path);
 
                 // This `StoreId` will be communicated to all `DataLoader`s, which may or may not
                 // decide to use it depending on whether they want to share a common recording
@@ -193,10 +195,7 @@ impl DataSource {
             // When loading a file on Web, or when using drag-n-drop.
             Self::FileContents(file_source, file_contents) => {
                 let name = file_contents.name.clone();
-                let (tx, rx) = re_smart_channel::smart_channel(
-                    SmartMessageSource::File(name.clone().into()),
-                    SmartChannelSource::File(name.clone().into()),
-                );
+                let (tx, rx) = file_channel(std::path::Path::new(&name));
 
                 // This `StoreId` will be communicated to all `DataLoader`s, which may or may not
                 // decide to use it depending on whether they want to share a common recording
@@ -316,6 +315,30 @@ pub enum DataSourceCommand {
     },
 }
 
+/// Creates the channel that the [`re_data_loader::DataLoader`]s send the contents of `path` to.
+///
+/// On native, the channel has a byte budget so that large files get loaded only as fast as they are
+/// ingested, rather than buffered in memory in their entirety.
+fn file_channel(path: &std::path::Path) -> (Sender<LogMsg>, Receiver<LogMsg>) {
+    let sender_source = SmartMessageSource::File(path.to_owned());
+    let source = SmartChannelSource::File(path.to_owned());
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        re_smart_channel::smart_channel_with_byte_budget(
+            sender_source,
+            source,
+            FILE_LOADING_BYTE_BUDGET,
+            <LogMsg as re_byte_size::SizeBytes>::total_size_bytes,
+        )
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        re_smart_channel::smart_channel(sender_source, source)
+    }
+}
+
 // TODO(ab, andreas): This should be replaced by the use of `AsyncRuntimeHandle`. However, this
 // requires:
 // - `AsyncRuntimeHandle` to be moved lower in the crate hierarchy to be available here (unsure
@@ -17,7 +17,7 @@ use arrow::{
         SchemaRef as ArrowSchemaRef,
     },
 };
-use itertools::{Either, Itertools as _};
+use itertools::Itertools as _;
 use nohash_hasher::{IntMap, IntSet};
 
 use re_arrow_util::{ArrowArrayDowncastRef as _, into_arrow_ref};
@@ -27,7 +27,7 @@ use re_chunk::{
 };
 use re_chunk_store::{
     ChunkStore, ColumnDescriptor, ComponentColumnDescriptor, Index, IndexColumnDescriptor,
-    IndexValue, QueryExpression, SparseFillStrategy,
+    IndexValue, IsNotNullMode, QueryExpression, SparseFillStrategy,
 };
 use re_log_types::ResolvedTimeRange;
 use re_query::{QueryCache, StorageEngineLike};
@@ -302,24 +302,43 @@ impl<E: StorageEngineLike> QueryHandle<E> {
         } else {
             re_tracing::profile_scope!("index_values");
 
-            let mut view_chunks = view_chunks.iter();
-            let view_chunks = if let Some(view_pov_chunks_idx) = view_pov_chunks_idx {
-                Either::Left(view_chunks.nth(view_pov_chunks_idx).into_iter())
-            } else {
-                Either::Right(view_chunks)
-            };
-
-            let mut all_unique_index_values: BTreeSet<TimeInt> = view_chunks
-                .flat_map(|chunks| {
-                    chunks.iter().filter_map(|(_cursor, chunk)| {
+            let index_values = |chunks: &[(AtomicU64, Chunk)]| {
+                chunks
+                    .iter()
+                    .filter_map(|(_cursor, chunk)| {
                         chunk
                             .timelines()
                             .get(&filtered_index)
                             .map(|time_column| time_column.times())
                     })
-                })
-                .flatten()
-                .collect();
+                    .flatten()
+                    .collect::<BTreeSet<TimeInt>>()
+            };
+
+            // Point-of-view columns that aren't part of the view contents have no index values.
+            let pov_index_values = view_pov_chunks_idx.iter().map(|&idx| {
+                view_chunks
+                    .get(idx)
+                    .map(|chunks| index_values(chunks))
+                    .unwrap_or_default()
+            });
+
+            let mut all_unique_index_values: BTreeSet<TimeInt> =
+                if self.query.filtered_is_not_null.is_empty() {
+                    view_chunks
+                        .iter()
+                        .flat_map(|chunks| index_values(chunks))
+                        .collect()
+                } else {
+                    match self.query.filtered_is_not_null_mode {
+                        IsNotNullMode::Any => pov_index_values.flatten().collect(),
+                        IsNotNullMode::All => pov_index_values
+                            .reduce(|acc, index_values| {
+                                acc.intersection(&index_values).copied().collect()
+                            })
+                            .unwrap_or_default(),
+                    }
+                };
 
             if let Some(filtered_index_values) = self.query.filtered_index_values.as_ref() {
                 all_unique_index_values.retain(|time| filtered_index_values.contains(time));
@@ -473,8 +492,9 @@ impl<E: StorageEngineLike> QueryHandle<E> {
         cache: &QueryCache,
         query: &RangeQuery,
         view_contents: &[ColumnDescriptor],
-    ) -> (Option<usize>, Vec<Vec<(AtomicU64, Chunk)>>) {
-        let mut view_pov_chunks_idx = self.query.filtered_is_not_null.as_ref().map(|_| usize::MAX);
+    ) -> (Vec<usize>, Vec<Vec<(AtomicU64, Chunk)>>) {
+        // One entry per point-of-view column, `usize::MAX` if it isn't part of the view contents.
+        let mut view_pov_chunks_idx = vec![usize::MAX; self.query.filtered_is_not_null.len()];
 
         let view_chunks = view_contents
             .iter()
@@ -487,9 +507,14 @@ impl<E: StorageEngineLike> QueryHandle<E> {
                         .fetch_chunks(store, cache, query, &column.entity_path, [&column.into()])
                         .unwrap_or_default();
 
-                    if let Some(pov) = self.query.filtered_is_not_null.as_ref() {
+                    for (pov, pov_chunks_idx) in self
+                        .query
+                        .filtered_is_not_null
+                        .iter()
+                        .zip(&mut view_pov_chunks_idx)
+                    {
                         if column.matches(pov) {
-                            view_pov_chunks_idx = Some(idx);
+                            *pov_chunks_idx = idx;
                         }
                     }
 
@@ -1005,6 +1030,21 @@ impl<E: StorageEngineLike> QueryHandle<E> {
             }
         }
 
+        // The `RowId` of a row is the one of the most recent cell that was found for the current
+        // index value, before any kind of static- or sparse-filling happens.
+        let row_id_array = view_streaming_state
+            .iter()
+            .flatten()
+            .max_by_key(|streaming_state| streaming_state.row_id)
+            .map(|streaming_state| {
+                as_array_ref(
+                    streaming_state
+                        .chunk
+                        .row_ids_array()
+                        .slice(streaming_state.cursor as _, 1),
+                )
+            });
+
         let mut view_streaming_state = view_streaming_state
             .into_iter()
             .map(|streaming_state| streaming_state.map(StreamingJoinState::StreamingJoinState))
@@ -1087,6 +1127,66 @@ impl<E: StorageEngineLike> QueryHandle<E> {
                         .map(|unit| StreamingJoinState::Retrofilled(unit.clone()));
                 }
             }
+
+            SparseFillStrategy::LatestAtView => {
+                // Everything that yielded `null` for the current iteration.
+                let null_streaming_states = view_streaming_state
+                    .iter_mut()
+                    .enumerate()
+                    .filter(|(_view_idx, streaming_state)| streaming_state.is_none());
+
+                for (view_idx, streaming_state) in null_streaming_states {
+                    let Some(view_chunks) = state.view_chunks.get(view_idx) else {
+                        continue;
+                    };
+
+                    // NOTE: Contrary to `LatestAtGlobal`, there is no need to go back to the store
+                    // here: the view chunks already contain everything that is part of the view
+                    // (both in terms of contents and index range), and nothing more.
+                    // Just look for the latest row at or before the current index value.
+                    let mut latest: Option<(TimeInt, StreamingJoinStateEntry<'_>)> = None;
+                    for (_cursor, chunk) in view_chunks {
+                        let Some(time_column) = chunk.timelines().get(&state.filtered_index) else {
+                            continue;
+                        };
+
+                        // NOTE: The query cache has already sorted the chunk on the filtered index
+                        // (and row-id), see `fetch_chunks`.
+                        let times = time_column.times_raw();
+                        let num_candidates =
+                            times.partition_point(|time| *time <= cur_index_value.as_i64());
+                        let Some(cursor) = num_candidates.checked_sub(1) else {
+                            continue;
+                        };
+
+                        let (Some(index_value), Some(row_id)) = (
+                            times.get(cursor).copied().map(TimeInt::new_temporal),
+                            chunk.row_ids_slice().get(cursor).copied(),
+                        ) else {
+                            continue;
+                        };
+
+                        let is_latest =
+                            latest.as_ref().is_none_or(|(latest_index_value, entry)| {
+                                (index_value, row_id) > (*latest_index_value, entry.row_id)
+                            });
+
+                        if is_latest {
+                            latest = Some((
+                                index_value,
+                                StreamingJoinStateEntry {
+                                    chunk,
+                                    cursor: cursor as u64,
+                                    row_id,
+                                },
+                            ));
+                        }
+                    }
+
+                    *streaming_state = latest
+                        .map(|(_index_value, entry)| StreamingJoinState::StreamingJoinState(entry));
+                }
+            }
         }
 
         // We are stitching a bunch of unrelated cells together in order to create the final row
@@ -1221,17 +1321,8 @@ impl<E: StorageEngineLike> QueryHandle<E> {
             .selected_contents
             .iter()
             .map(|(view_idx, column)| match column {
-                ColumnDescriptor::RowId(_) => state
-                    .view_chunks
-                    .first()
-                    .and_then(|vec| vec.first()) // TODO(#9922): verify that using the row:ids from the first chunk always makes sense
-                    .map(|(row_idx, chunk)| {
-                        as_array_ref(
-                            chunk
-                                .row_ids_array()
-                                .slice(row_idx.load(Ordering::Acquire) as _, 1),
-                        )
-                    })
+                ColumnDescriptor::RowId(_) => row_id_array
+                    .clone()
                     .unwrap_or_else(|| arrow::array::new_null_array(&RowId::arrow_datatype(), 1)),
 
                 ColumnDescriptor::Time(descr) => max_value_per_index
@@ -1482,6 +1573,74 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn sparse_fill_strategy_latestatview() -> anyhow::Result<()> {
+        re_log::setup_logging();
+
+        let store = ChunkStoreHandle::new(create_nasty_store()?);
+        eprintln!("{store}");
+        let query_cache = QueryCache::new_handle(store.clone());
+        let query_engine = QueryEngine::new(store.clone(), query_cache.clone());
+
+        let filtered_index = Some(TimelineName::new("frame_nr"));
+
+        // Without any index range, the view contains everything: same results as global latest-at.
+        {
+            let query_global = QueryExpression {
+                filtered_index,
+                sparse_fill_strategy: SparseFillStrategy::LatestAtGlobal,
+                ..Default::default()
+            };
+            let query_view = QueryExpression {
+                sparse_fill_strategy: SparseFillStrategy::LatestAtView,
+                ..query_global.clone()
+            };
+
+            let query_handle_global = query_engine.query(query_global);
+            let dataframe_global = concat_batches(
+                query_handle_global.schema(),
+                &query_handle_global.batch_iter().collect_vec(),
+            )?;
+
+            let query_handle_view = query_engine.query(query_view);
+            let dataframe_view = concat_batches(
+                query_handle_view.schema(),
+                &query_handle_view.batch_iter().collect_vec(),
+            )?;
+
+            similar_asserts::assert_eq!(
+                format_record_batch(&dataframe_global).to_string(),
+                format_record_batch(&dataframe_view).to_string(),
+            );
+        }
+
+        // With an index range, nothing from before the start of the range must leak in.
+        {
+            let query = QueryExpression {
+                filtered_index,
+                filtered_index_range: Some(ResolvedTimeRange::new(60, 70)),
+                sparse_fill_strategy: SparseFillStrategy::LatestAtView,
+                ..Default::default()
+            };
+            eprintln!("{query:#?}:");
+
+            let query_handle = query_engine.query(query.clone());
+            assert_eq!(
+                query_engine.query(query.clone()).into_iter().count() as u64,
+                query_handle.num_rows()
+            );
+            let dataframe = concat_batches(
+                query_handle.schema(),
+                &query_handle.batch_iter().collect_vec(),
+            )?;
+            eprintln!("{}", format_record_batch(&dataframe.clone()));
+
+            assert_snapshot!(DisplayRB(dataframe));
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn filtered_index_range() -> anyhow::Result<()> {
         re_log::setup_logging();
@@ -1645,10 +1804,10 @@ mod tests {
 
             let query = QueryExpression {
                 filtered_index,
-                filtered_is_not_null: Some(ComponentColumnSelector {
+                filtered_is_not_null: vec![ComponentColumnSelector {
                     entity_path: "no/such/entity".into(),
                     component: component.to_string(),
-                }),
+                }],
                 ..Default::default()
             };
             eprintln!("{query:#?}:");
@@ -1671,10 +1830,10 @@ mod tests {
         {
             let query = QueryExpression {
                 filtered_index,
-                filtered_is_not_null: Some(ComponentColumnSelector {
+                filtered_is_not_null: vec![ComponentColumnSelector {
                     entity_path: entity_path.clone(),
                     component: "AFieldThatDoesntExist".to_owned(),
-                }),
+                }],
                 ..Default::default()
             };
             eprintln!("{query:#?}:");
@@ -1699,10 +1858,10 @@ mod tests {
 
             let query = QueryExpression {
                 filtered_index,
-                filtered_is_not_null: Some(ComponentColumnSelector {
+                filtered_is_not_null: vec![ComponentColumnSelector {
                     entity_path: entity_path.clone(),
                     component: component.to_string(),
-                }),
+                }],
                 ..Default::default()
             };
             eprintln!("{query:#?}:");
@@ -1727,10 +1886,10 @@ mod tests {
 
             let query = QueryExpression {
                 filtered_index,
-                filtered_is_not_null: Some(ComponentColumnSelector {
+                filtered_is_not_null: vec![ComponentColumnSelector {
                     entity_path: entity_path.clone(),
                     component: component.to_string(),
-                }),
+                }],
                 ..Default::default()
             };
             eprintln!("{query:#?}:");
@@ -1752,6 +1911,65 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn filtered_is_not_null_multiple_columns() -> anyhow::Result<()> {
+        re_log::setup_logging();
+
+        let store = ChunkStoreHandle::new(create_nasty_store()?);
+        eprintln!("{store}");
+        let query_cache = QueryCache::new_handle(store.clone());
+        let query_engine = QueryEngine::new(store.clone(), query_cache.clone());
+
+        let filtered_index = Some(TimelineName::new("frame_nr"));
+        let entity_path: EntityPath = "this/that".into();
+
+        let points = ComponentColumnSelector {
+            entity_path: entity_path.clone(),
+            component: MyPoints::descriptor_points().component.to_string(),
+        };
+        let colors = ComponentColumnSelector {
+            entity_path: entity_path.clone(),
+            component: MyPoints::descriptor_colors().component.to_string(),
+        };
+        let missing = ComponentColumnSelector {
+            entity_path: entity_path.clone(),
+            component: "AFieldThatDoesntExist".to_owned(),
+        };
+
+        for (filtered_is_not_null, filtered_is_not_null_mode) in [
+            // MyPoint or MyColor
+            (vec![points.clone(), colors.clone()], IsNotNullMode::Any),
+            // MyPoint and MyColor
+            (vec![points.clone(), colors.clone()], IsNotNullMode::All),
+            // MyPoint or non-existing component
+            (vec![points.clone(), missing.clone()], IsNotNullMode::Any),
+            // MyPoint and non-existing component
+            (vec![points.clone(), missing.clone()], IsNotNullMode::All),
+        ] {
+            let query = QueryExpression {
+                filtered_index,
+                filtered_is_not_null,
+                filtered_is_not_null_mode,
+                ..Default::default()
+            };
+            eprintln!("{query:#?}:");
+
+            let query_handle = query_engine.query(query.clone());
+            assert_eq!(
+                query_engine.query(query.clone()).into_iter().count() as u64,
+                query_handle.num_rows()
+            );
+            let dataframe = concat_batches(
+                query_handle.schema(),
+                &query_handle.batch_iter().collect_vec(),
+            )?;
+            eprintln!("{}", format_record_batch(&dataframe.clone()));
+
+            assert_snapshot!(DisplayRB(dataframe));
+        }
+
+        Ok(())
+    }
     #[test]
     fn view_contents() -> anyhow::Result<()> {
         re_log::setup_logging();
@@ -1992,6 +2210,80 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn selection_row_id() -> anyhow::Result<()> {
+        re_log::setup_logging();
+
+        let store = ChunkStore::new_handle(
+            re_log_types::StoreId::random(re_log_types::StoreKind::Recording),
+            ChunkStoreConfig::COMPACTION_DISABLED,
+        );
+
+        let frame1 = TimeInt::new_temporal(10);
+        let frame2 = TimeInt::new_temporal(20);
+
+        let row_id_a1 = RowId::new();
+        let row_id_b1 = RowId::new();
+        let row_id_a2 = RowId::new();
+
+        let chunk_a = Chunk::builder("a")
+            .with_component_batches(
+                row_id_a1,
+                [build_frame_nr(frame1)],
+                [(
+                    MyPoints::descriptor_points(),
+                    &MyPoint::from_iter(0..1) as _,
+                )],
+            )
+            .with_component_batches(
+                row_id_a2,
+                [build_frame_nr(frame2)],
+                [(
+                    MyPoints::descriptor_points(),
+                    &MyPoint::from_iter(1..2) as _,
+                )],
+            )
+            .build()?;
+        let chunk_b = Chunk::builder("b")
+            .with_component_batches(
+                row_id_b1,
+                [build_frame_nr(frame1)],
+                [(
+                    MyPoints::descriptor_colors(),
+                    &MyColor::from_iter(0..1) as _,
+                )],
+            )
+            .build()?;
+
+        store.write().insert_chunk(&Arc::new(chunk_a))?;
+        store.write().insert_chunk(&Arc::new(chunk_b))?;
+
+        let query_engine = QueryEngine::from_store(store);
+
+        let query = QueryExpression {
+            filtered_index: Some(TimelineName::new("frame_nr")),
+            selection: Some(vec![
+                ColumnSelector::RowId,
+                ColumnSelector::Time(TimeColumnSelector::from("frame_nr")),
+            ]),
+            ..Default::default()
+        };
+        eprintln!("{query:#?}:");
+
+        let query_handle = query_engine.query(query.clone());
+        let dataframe = concat_batches(
+            query_handle.schema(),
+            &query_handle.batch_iter().collect_vec(),
+        )?;
+        eprintln!("{}", format_record_batch(&dataframe.clone()));
+
+        // Every row gets the `RowId` of the most recent cell at that index value.
+        let row_ids = RowId::from_arrow(dataframe.column(0))?;
+        similar_asserts::assert_eq!(vec![row_id_b1, row_id_a2], row_ids);
+
+        Ok(())
+    }
+
     #[test]
     fn view_contents_and_selection() -> anyhow::Result<()> {
         re_log::setup_logging();
@@ -2184,10 +2476,10 @@ mod tests {
             let ComponentDescriptor { component, .. } = MyPoints::descriptor_points();
             let query = QueryExpression {
                 filtered_index,
-                filtered_is_not_null: Some(ComponentColumnSelector {
+                filtered_is_not_null: vec![ComponentColumnSelector {
                     entity_path: entity_path.clone(),
                     component: component.to_string(),
-                }),
+                }],
                 ..Default::default()
             };
             eprintln!("{query:#?}:");
@@ -2343,7 +2635,8 @@ mod tests {
             filtered_index_range: None,
             filtered_index_values: None,
             using_index_values: None,
-            filtered_is_not_null: None,
+            filtered_is_not_null: Vec::new(),
+            filtered_is_not_null_mode: IsNotNullMode::Any,
             sparse_fill_strategy: re_chunk_store::SparseFillStrategy::None,
             selection: None,
         };
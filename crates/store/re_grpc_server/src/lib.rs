@@ -1,10 +1,14 @@
 //! Server implementation of an in-memory Storage Node ("proxy").
 
+mod persistence;
 pub mod shutdown;
 
-use std::collections::VecDeque;
+pub use self::persistence::{DEFAULT_MAX_SEGMENT_BYTES, PersistenceOptions};
+
+use std::collections::{HashMap, VecDeque};
 use std::net::SocketAddr;
 use std::pin::Pin;
+use std::time::{Duration, SystemTime};
 
 use re_byte_size::SizeBytes;
 use re_log_encoding::codec::wire::decoder::Decode as _;
@@ -44,6 +48,57 @@ pub const DEFAULT_MEMORY_LIMIT: MemoryLimit = MemoryLimit::UNLIMITED;
 pub const MAX_DECODING_MESSAGE_SIZE: usize = u32::MAX as usize;
 pub const MAX_ENCODING_MESSAGE_SIZE: usize = MAX_DECODING_MESSAGE_SIZE;
 
+/// Options for the in-memory message proxy, mostly around retention of the message history.
+///
+/// The history is what the server replays to clients connecting late through `ReadMessages`.
+/// Store infos, blueprints and blueprint activation commands are always retained, these limits
+/// only apply to recording data and tables.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ServerOptions {
+    /// Upper limit on how much memory the message history may use in total.
+    ///
+    /// When this limit is reached, the oldest messages are dropped.
+    pub memory_limit: MemoryLimit,
+
+    /// Upper limit on how much memory the message history of any single recording may use.
+    ///
+    /// When this limit is reached, the oldest messages of that recording are dropped.
+    pub recording_memory_limit: MemoryLimit,
+
+    /// Messages that have been in the history for longer than this are dropped.
+    ///
+    /// Ages are measured using the wall clock, and the arrival time of every message is persisted
+    /// along with it (see [`Self::persistence`]), so that restored messages still expire on time.
+    ///
+    /// `None` means messages never expire.
+    pub max_age: Option<Duration>,
+
+    /// If set, the message history is also written to disk, and restored from there on startup.
+    ///
+    /// The retention policy applies to the history on disk as well.
+    pub persistence: Option<PersistenceOptions>,
+}
+
+impl Default for ServerOptions {
+    fn default() -> Self {
+        Self {
+            memory_limit: DEFAULT_MEMORY_LIMIT,
+            recording_memory_limit: MemoryLimit::UNLIMITED,
+            max_age: None,
+            persistence: None,
+        }
+    }
+}
+
+impl From<MemoryLimit> for ServerOptions {
+    fn from(memory_limit: MemoryLimit) -> Self {
+        Self {
+            memory_limit,
+            ..Default::default()
+        }
+    }
+}
+
 // Channel capacity is completely arbitrary, e just want something large enough
 // to handle bursts of messages. This is roughly 16 MiB of `Msg` (excluding their contents).
 const MESSAGE_QUEUE_CAPACITY: usize =
@@ -94,8 +149,8 @@ impl From<tonic::Status> for TonicStatusError {
 /// Currently, the only RPCs supported by the server are `WriteMessages` and `ReadMessages`.
 ///
 /// Clients send data to the server via `WriteMessages`. Any sent messages will be stored
-/// in the server's message queue. Messages are only removed if the server hits its configured
-/// memory limit.
+/// in the server's message queue. Messages are only removed according to the retention
+/// policy in [`ServerOptions`]: memory limits (both total and per-recording) and maximum age.
 ///
 /// Clients receive data from the server via `ReadMessages`. Upon establishing the stream,
 /// the server sends all messages stored in its message queue, and subscribes the client
@@ -103,10 +158,10 @@ impl From<tonic::Status> for TonicStatusError {
 /// to the open `ReadMessages` stream.
 pub async fn serve(
     addr: SocketAddr,
-    memory_limit: MemoryLimit,
+    options: ServerOptions,
     shutdown: shutdown::Shutdown,
 ) -> anyhow::Result<()> {
-    serve_impl(addr, MessageProxy::new(memory_limit), shutdown).await
+    serve_impl(addr, MessageProxy::new(options), shutdown).await
 }
 
 async fn serve_impl(
@@ -164,11 +219,11 @@ async fn serve_impl(
 /// See [`serve`] for more information about what a Rerun server is.
 pub async fn serve_from_channel(
     addr: SocketAddr,
-    memory_limit: MemoryLimit,
+    options: ServerOptions,
     shutdown: shutdown::Shutdown,
     channel_rx: re_smart_channel::Receiver<re_log_types::LogMsg>,
 ) {
-    let message_proxy = MessageProxy::new(memory_limit);
+    let message_proxy = MessageProxy::new(options);
     let event_tx = message_proxy.event_tx.clone();
 
     tokio::spawn(async move {
@@ -235,11 +290,11 @@ pub async fn serve_from_channel(
 /// See [`serve`] for more information about what a Rerun server is.
 pub fn spawn_from_rx_set(
     addr: SocketAddr,
-    memory_limit: MemoryLimit,
+    options: ServerOptions,
     shutdown: shutdown::Shutdown,
     rxs: re_smart_channel::ReceiveSet<re_log_types::LogMsg>,
 ) {
-    let message_proxy = MessageProxy::new(memory_limit);
+    let message_proxy = MessageProxy::new(options);
     let event_tx = message_proxy.event_tx.clone();
 
     tokio::spawn(async move {
@@ -293,7 +348,7 @@ pub fn spawn_from_rx_set(
 /// See [`serve`] for more information about what a Rerun server is.
 pub fn spawn_with_recv(
     addr: SocketAddr,
-    memory_limit: MemoryLimit,
+    options: ServerOptions,
     shutdown: shutdown::Shutdown,
 ) -> (
     re_smart_channel::Receiver<re_log_types::LogMsg>,
@@ -309,7 +364,7 @@ pub fn spawn_with_recv(
     );
     let (channel_table_tx, channel_table_rx) = crossbeam::channel::unbounded();
     let (message_proxy, mut broadcast_log_rx, mut broadcast_table_rx) =
-        MessageProxy::new_with_recv(memory_limit);
+        MessageProxy::new_with_recv(options);
     tokio::spawn(async move {
         if let Err(err) = serve_impl(addr, message_proxy, shutdown).await {
             re_log::error!("message proxy server crashed: {err}");
@@ -443,11 +498,47 @@ impl From<TableMsgProto> for Msg {
     }
 }
 
+/// A message in the history of the server, along with its retention bookkeeping.
+struct QueuedMsg {
+    /// Global order of arrival, used to interleave the per-recording queues.
+    seq: u64,
+
+    /// When the message was added to the history.
+    arrival: SystemTime,
+
+    size_bytes: u64,
+
+    msg: Msg,
+}
+
+/// The history of a single recording (or of everything that isn't part of a recording).
+#[derive(Default)]
+struct MessageQueue {
+    /// Messages stored in order of arrival.
+    messages: VecDeque<QueuedMsg>,
+
+    /// Total size of `messages` in bytes.
+    size_bytes: u64,
+}
+
+impl MessageQueue {
+    fn push_back(&mut self, msg: QueuedMsg) {
+        self.size_bytes += msg.size_bytes;
+        self.messages.push_back(msg);
+    }
+
+    fn pop_front(&mut self) -> Option<QueuedMsg> {
+        let msg = self.messages.pop_front()?;
+        self.size_bytes -= msg.size_bytes;
+        Some(msg)
+    }
+}
+
 /// Main event loop for the server, which runs in its own task.
 ///
 /// Handles message history, and broadcasts messages to clients.
 struct EventLoop {
-    server_memory_limit: MemoryLimit,
+    options: ServerOptions,
 
     /// New log messages are broadcast to all clients.
     broadcast_log_tx: broadcast::Sender<LogMsgProto>,
@@ -458,35 +549,50 @@ struct EventLoop {
     /// Channel for incoming events.
     event_rx: mpsc::Receiver<Event>,
 
-    /// Messages stored in order of arrival, and garbage collected if the server hits the memory limit.
-    ordered_message_queue: VecDeque<Msg>,
+    /// Messages stored in order of arrival, grouped by recording id, and garbage collected according
+    /// to the retention policy.
+    ///
+    /// Tables are not part of any recording and are stored under `None`.
+    ordered_message_queues: HashMap<Option<String>, MessageQueue>,
 
-    /// Total size of `ordered_message_queue` in bytes.
+    /// Total size of `ordered_message_queues` in bytes.
     ordered_message_bytes: u64,
 
+    /// Sequence number of the next message added to `ordered_message_queues`.
+    next_seq: u64,
+
     /// Messages potentially out of order with the rest of the message stream. These are never garbage collected.
     persistent_message_queue: VecDeque<LogMsgProto>,
+
+    /// Copy of the message history on disk, if enabled.
+    disk_backlog: Option<persistence::DiskBacklog>,
 }
 
 impl EventLoop {
     fn new(
-        server_memory_limit: MemoryLimit,
+        options: ServerOptions,
         event_rx: mpsc::Receiver<Event>,
         broadcast_log_tx: broadcast::Sender<LogMsgProto>,
         broadcast_table_tx: broadcast::Sender<TableMsgProto>,
     ) -> Self {
         Self {
-            server_memory_limit,
+            options,
             broadcast_log_tx,
             broadcast_table_tx,
             event_rx,
-            ordered_message_queue: Default::default(),
+            ordered_message_queues: Default::default(),
             ordered_message_bytes: 0,
+            next_seq: 0,
             persistent_message_queue: Default::default(),
+            disk_backlog: None,
         }
     }
 
     async fn run_in_place(mut self) {
+        if let Some(persistence) = self.options.persistence.clone() {
+            self.restore_from_disk(persistence).await;
+        }
+
         loop {
             let Some(event) = self.event_rx.recv().await else {
                 break;
@@ -494,20 +600,37 @@ impl EventLoop {
 
             match event {
                 Event::NewClient(channel) => self.handle_new_client(channel),
-                Event::Message(msg) => self.handle_msg(msg),
+                Event::Message(msg) => self.handle_msg(msg, SystemTime::now()),
                 Event::Table(table) => self.handle_table(table),
             }
         }
+
+        if let Some(disk_backlog) = self.disk_backlog.take() {
+            // Wait for everything to be on disk before reporting the server as stopped.
+            tokio::task::spawn_blocking(move || disk_backlog.close())
+                .await
+                .ok();
+        }
     }
 
     fn handle_new_client(
-        &self,
+        &mut self,
         channel: oneshot::Sender<(
             Vec<Msg>,
             broadcast::Receiver<LogMsgProto>,
             broadcast::Receiver<TableMsgProto>,
         )>,
     ) {
+        // Don't replay anything that has expired since the last message came in.
+        self.gc_expired_messages();
+
+        let mut ordered_messages = self
+            .ordered_message_queues
+            .values()
+            .flat_map(|queue| queue.messages.iter())
+            .collect::<Vec<_>>();
+        ordered_messages.sort_by_key(|queued| queued.seq);
+
         channel
             .send((
                 // static messages come first
@@ -515,7 +638,11 @@ impl EventLoop {
                     .iter()
                     .cloned()
                     .map(Msg::from)
-                    .chain(self.ordered_message_queue.iter().cloned())
+                    .chain(
+                        ordered_messages
+                            .into_iter()
+                            .map(|queued| queued.msg.clone()),
+                    )
                     .collect(),
                 self.broadcast_log_tx.subscribe(),
                 self.broadcast_table_tx.subscribe(),
@@ -523,7 +650,75 @@ impl EventLoop {
             .ok();
     }
 
-    fn handle_msg(&mut self, msg: LogMsgProto) {
+    /// Loads the message history from disk, and starts writing new messages to it.
+    async fn restore_from_disk(&mut self, persistence: PersistenceOptions) {
+        let history = tokio::task::spawn_blocking({
+            let dir = persistence.dir.clone();
+            move || persistence::load(&dir)
+        })
+        .await
+        .unwrap_or_else(|err| Err(std::io::Error::other(err)));
+
+        let history = match history {
+            Ok(history) => history,
+            Err(err) => {
+                re_log::error!(
+                    "Failed to load message history from {:?}, it won't be persisted: {err}",
+                    persistence.dir
+                );
+                return;
+            }
+        };
+
+        if !history.messages.is_empty() {
+            re_log::info!(
+                "Restoring {} message(s) from {:?}",
+                history.messages.len(),
+                persistence.dir
+            );
+        }
+
+        for (msg, arrival) in history.messages {
+            self.handle_msg(msg, arrival);
+        }
+        self.gc_expired_messages();
+
+        let mut ordered_messages = self
+            .ordered_message_queues
+            .values()
+            .flat_map(|queue| queue.messages.iter())
+            .filter_map(|queued| match &queued.msg {
+                Msg::LogMsg(msg) => Some((queued.seq, queued.arrival, msg.clone())),
+                Msg::Table(_) => None,
+            })
+            .collect::<Vec<_>>();
+        ordered_messages.sort_by_key(|(seq, _, _)| *seq);
+
+        let static_messages = self.persistent_message_queue.clone();
+        let dir = persistence.dir.clone();
+        let disk_backlog = tokio::task::spawn_blocking(move || {
+            persistence::DiskBacklog::new(
+                persistence,
+                history.next_index,
+                static_messages,
+                ordered_messages,
+                &history.files,
+            )
+        })
+        .await
+        .unwrap_or_else(|err| Err(std::io::Error::other(err)));
+
+        match disk_backlog {
+            Ok(disk_backlog) => self.disk_backlog = Some(disk_backlog),
+            Err(err) => {
+                re_log::error!(
+                    "Failed to write message history to {dir:?}, it won't be persisted: {err}"
+                );
+            }
+        }
+    }
+
+    fn handle_msg(&mut self, msg: LogMsgProto, arrival: SystemTime) {
         self.broadcast_log_tx.send(msg.clone()).ok();
 
         if self.is_history_disabled() {
@@ -531,6 +726,7 @@ impl EventLoop {
             return;
         }
 
+        self.gc_expired_messages();
         self.gc_if_using_too_much_ram();
 
         let Some(inner) = &msg.msg else {
@@ -547,6 +743,9 @@ impl EventLoop {
         match inner {
             // Store info, blueprint activation commands
             Msg::SetStoreInfo(..) | Msg::BlueprintActivationCommand(..) => {
+                if let Some(disk_backlog) = &self.disk_backlog {
+                    disk_backlog.append_static(msg.clone());
+                }
                 self.persistent_message_queue.push_back(msg);
             }
 
@@ -557,16 +756,23 @@ impl EventLoop {
                     .as_ref()
                     .is_some_and(|id| id.kind() == StoreKindProto::Blueprint) =>
             {
+                if let Some(disk_backlog) = &self.disk_backlog {
+                    disk_backlog.append_static(msg.clone());
+                }
                 self.persistent_message_queue.push_back(msg);
             }
 
             // Recording data
-            Msg::ArrowMsg(..) => {
-                let approx_size_bytes = msg.total_size_bytes();
-                self.ordered_message_bytes += approx_size_bytes;
-                self.ordered_message_queue.push_back(msg.into());
+            Msg::ArrowMsg(inner) => {
+                let recording_id = inner.store_id.as_ref().map(|id| id.id.clone());
+                if let Some(disk_backlog) = &self.disk_backlog {
+                    disk_backlog.append(self.next_seq, arrival, msg.clone());
+                }
+                self.push_ordered(recording_id, msg.into(), arrival);
             }
         }
+
+        self.gc_disk_backlog();
     }
 
     fn handle_table(&mut self, table: TableMsgProto) {
@@ -577,21 +783,134 @@ impl EventLoop {
             return;
         }
 
+        self.gc_expired_messages();
         self.gc_if_using_too_much_ram();
 
-        let approx_size_bytes = table.total_size_bytes();
-        self.ordered_message_bytes += approx_size_bytes;
-        self.ordered_message_queue.push_back(Msg::Table(table));
+        self.push_ordered(None, Msg::Table(table), SystemTime::now());
+
+        self.gc_disk_backlog();
+    }
+
+    fn push_ordered(&mut self, recording_id: Option<String>, msg: Msg, arrival: SystemTime) {
+        let size_bytes = msg.total_size_bytes();
+        let queued = QueuedMsg {
+            seq: self.next_seq,
+            arrival,
+            size_bytes,
+            msg,
+        };
+        self.next_seq += 1;
+
+        self.ordered_message_bytes += size_bytes;
+        self.ordered_message_queues
+            .entry(recording_id.clone())
+            .or_default()
+            .push_back(queued);
+
+        if let Some(recording_id) = recording_id {
+            self.gc_recording_if_using_too_much_ram(recording_id);
+        }
     }
 
     fn is_history_disabled(&self) -> bool {
-        self.server_memory_limit.max_bytes.is_some_and(|b| b == 0)
+        let ServerOptions {
+            memory_limit,
+            recording_memory_limit,
+            max_age,
+            persistence: _,
+        } = &self.options;
+
+        memory_limit.max_bytes.is_some_and(|b| b == 0)
+            || recording_memory_limit.max_bytes.is_some_and(|b| b == 0)
+            || max_age.is_some_and(|max_age| max_age.is_zero())
+    }
+
+    /// Deletes the parts of the on-disk history that have been dropped from memory.
+    fn gc_disk_backlog(&self) {
+        let Some(disk_backlog) = &self.disk_backlog else {
+            return;
+        };
+
+        let oldest_retained_seq = self
+            .ordered_message_queues
+            .values()
+            .filter_map(|queue| queue.messages.front().map(|msg| msg.seq))
+            .min()
+            .unwrap_or(self.next_seq);
+
+        disk_backlog.gc(oldest_retained_seq);
+    }
+
+    /// Removes the oldest message of the given queue, keeping the bookkeeping up to date.
+    fn pop_front(&mut self, recording_id: &Option<String>) -> Option<QueuedMsg> {
+        let queue = self.ordered_message_queues.get_mut(recording_id)?;
+        let msg = queue.pop_front();
+
+        if queue.messages.is_empty() {
+            self.ordered_message_queues.remove(recording_id);
+        }
+
+        if let Some(msg) = &msg {
+            self.ordered_message_bytes -= msg.size_bytes;
+        }
+
+        msg
+    }
+
+    fn gc_expired_messages(&mut self) {
+        re_tracing::profile_function!();
+
+        let Some(max_age) = self.options.max_age else {
+            // Messages never expire!
+            return;
+        };
+
+        let now = SystemTime::now();
+        let is_expired =
+            |queued: &QueuedMsg| now.duration_since(queued.arrival).unwrap_or_default() > max_age;
+
+        let expired_queues = self
+            .ordered_message_queues
+            .iter()
+            .filter(|(_, queue)| queue.messages.front().is_some_and(is_expired))
+            .map(|(recording_id, _)| recording_id.clone())
+            .collect::<Vec<_>>();
+
+        if expired_queues.is_empty() {
+            return;
+        }
+
+        re_log::info_once!(
+            "Maximum message age ({max_age:?}) exceeded. Dropping old log messages from the server. Clients connecting after this will not see the full history."
+        );
+
+        let mut bytes_dropped = 0;
+        let mut messages_dropped = 0;
+
+        for recording_id in expired_queues {
+            while self
+                .ordered_message_queues
+                .get(&recording_id)
+                .and_then(|queue| queue.messages.front())
+                .is_some_and(is_expired)
+            {
+                if let Some(msg) = self.pop_front(&recording_id) {
+                    bytes_dropped += msg.size_bytes;
+                    messages_dropped += 1;
+                }
+            }
+        }
+
+        re_log::trace!(
+            "Dropped {} bytes in {messages_dropped} expired message(s)",
+            re_format::format_bytes(bytes_dropped as _)
+        );
     }
 
     fn gc_if_using_too_much_ram(&mut self) {
         re_tracing::profile_function!();
 
-        let Some(max_bytes) = self.server_memory_limit.max_bytes else {
+        let Some(max_bytes) = self.options.memory_limit.max_bytes else {
             // Unlimited memory!
             return;
         };
@@ -615,9 +934,20 @@ impl EventLoop {
             let mut messages_dropped = 0;
 
             while bytes_dropped < bytes_to_free {
-                // only drop messages from temporal queue
-                if let Some(msg) = self.ordered_message_queue.pop_front() {
-                    bytes_dropped += msg.total_size_bytes();
+                // only drop messages from temporal queues, oldest first
+                let oldest_queue = self
+                    .ordered_message_queues
+                    .iter()
+                    .filter_map(|(recording_id, queue)| {
+                        queue.messages.front().map(|msg| (msg.seq, recording_id))
+                    })
+                    .min()
+                    .map(|(_, recording_id)| recording_id.clone());
+
+                if let Some(msg) =
+                    oldest_queue.and_then(|recording_id| self.pop_front(&recording_id))
+                {
+                    bytes_dropped += msg.size_bytes;
                     messages_dropped += 1;
                 } else {
                     break;
@@ -630,6 +960,49 @@ impl EventLoop {
             );
         }
     }
+
+    fn gc_recording_if_using_too_much_ram(&mut self, recording_id: String) {
+        re_tracing::profile_function!();
+
+        let Some(max_bytes) = self.options.recording_memory_limit.max_bytes else {
+            // Unlimited memory!
+            return;
+        };
+
+        let max_bytes = max_bytes as u64;
+        let recording_id = Some(recording_id);
+        let Some(queue) = self.ordered_message_queues.get(&recording_id) else {
+            return;
+        };
+        if max_bytes >= queue.size_bytes {
+            // We're not using too much memory.
+            return;
+        }
+
+        re_log::info_once!(
+            "Per-recording memory limit ({}) exceeded. Dropping old log messages from the server. Clients connecting after this will not see the full history.",
+            re_format::format_bytes(max_bytes as _)
+        );
+
+        let bytes_to_free = queue.size_bytes - max_bytes;
+
+        let mut bytes_dropped = 0;
+        let mut messages_dropped = 0;
+
+        while bytes_dropped < bytes_to_free {
+            if let Some(msg) = self.pop_front(&recording_id) {
+                bytes_dropped += msg.size_bytes;
+                messages_dropped += 1;
+            } else {
+                break;
+            }
+        }
+
+        re_log::trace!(
+            "Dropped {} bytes in {messages_dropped} message(s) of recording {recording_id:?}",
+            re_format::format_bytes(bytes_dropped as _)
+        );
+    }
 }
 
 impl SizeBytes for TableMsgProto {
@@ -645,12 +1018,12 @@ pub struct MessageProxy {
 }
 
 impl MessageProxy {
-    pub fn new(server_memory_limit: MemoryLimit) -> Self {
-        Self::new_with_recv(server_memory_limit).0
+    pub fn new(options: ServerOptions) -> Self {
+        Self::new_with_recv(options).0
     }
 
     fn new_with_recv(
-        server_memory_limit: MemoryLimit,
+        options: ServerOptions,
     ) -> (
         Self,
         broadcast::Receiver<LogMsgProto>,
@@ -661,14 +1034,9 @@ impl MessageProxy {
         let (broadcast_table_tx, broadcast_table_rx) = broadcast::channel(MESSAGE_QUEUE_CAPACITY);
 
         let task_handle = tokio::spawn(async move {
-            EventLoop::new(
-                server_memory_limit,
-                event_rx,
-                broadcast_log_tx,
-                broadcast_table_tx,
-            )
-            .run_in_place()
-            .await;
+            EventLoop::new(options, event_rx, broadcast_log_tx, broadcast_table_tx)
+                .run_in_place()
+                .await;
         });
 
         (
@@ -689,6 +1057,29 @@ impl MessageProxy {
         self.event_tx.send(Event::Table(table)).await.ok();
     }
 
+    /// The history that would be replayed to a new client, once all previous messages have been
+    /// handled.
+    #[cfg(test)]
+    async fn history(&self) -> Vec<Msg> {
+        let (sender, receiver) = oneshot::channel();
+        self.event_tx.send(Event::NewClient(sender)).await.ok();
+        receiver
+            .await
+            .map(|(history, _, _)| history)
+            .unwrap_or_default()
+    }
+
+    /// Stops the proxy, waiting for all messages to have been handled and persisted.
+    #[cfg(test)]
+    async fn shutdown(self) {
+        let Self {
+            _queue_task_handle: task_handle,
+            event_tx,
+        } = self;
+        drop(event_tx);
+        task_handle.await.ok();
+    }
+
     async fn new_client_message_stream(&self) -> ReadMessagesStream {
         let (sender, receiver) = oneshot::channel();
         if let Err(err) = self.event_tx.send(Event::NewClient(sender)).await {
@@ -992,6 +1383,10 @@ mod tests {
     }
 
     async fn setup_with_memory_limit(memory_limit: MemoryLimit) -> (Completion, SocketAddr) {
+        setup_with_options(memory_limit.into()).await
+    }
+
+    async fn setup_with_options(options: ServerOptions) -> (Completion, SocketAddr) {
         let completion = Completion::new();
 
         let tcp_listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
@@ -1002,7 +1397,7 @@ mod tests {
             async move {
                 tonic::transport::Server::builder()
                     .add_service(
-                        MessageProxyServiceServer::new(super::MessageProxy::new(memory_limit))
+                        MessageProxyServiceServer::new(super::MessageProxy::new(options))
                             .max_decoding_message_size(MAX_DECODING_MESSAGE_SIZE)
                             .max_encoding_message_size(MAX_ENCODING_MESSAGE_SIZE),
                     )
@@ -1044,6 +1439,43 @@ mod tests {
         messages
     }
 
+    /// Reads messages until the stream stays idle for a while.
+    async fn read_log_stream_until_idle(
+        log_stream: &mut tonic::Response<tonic::Streaming<ReadMessagesResponse>>,
+    ) -> Vec<LogMsg> {
+        let mut messages = vec![];
+        loop {
+            let timeout_stream = log_stream.get_mut().timeout(Duration::from_millis(100));
+            tokio::pin!(timeout_stream);
+            match timeout_stream.try_next().await {
+                Ok(Some(value)) => {
+                    messages.push(log_msg_from_proto(value.unwrap().log_msg.unwrap()).unwrap());
+                }
+
+                // Stream closed | Timed out
+                Ok(None) | Err(_) => break,
+            }
+        }
+        messages
+    }
+
+    async fn write_log_stream(
+        client: &mut MessageProxyServiceClient<Channel>,
+        messages: &[LogMsg],
+    ) {
+        client
+            .write_messages(tokio_stream::iter(
+                messages
+                    .iter()
+                    .map(|msg| log_msg_to_proto(msg.clone(), Compression::Off).unwrap())
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .map(|msg| WriteMessagesRequest { log_msg: Some(msg) }),
+            ))
+            .await
+            .unwrap();
+    }
+
     #[tokio::test]
     async fn pubsub_basic() {
         let (completion, addr) = setup().await;
@@ -1308,4 +1740,209 @@ mod tests {
 
         completion.finish();
     }
+
+    #[tokio::test]
+    async fn max_age_drops_messages() {
+        let (completion, addr) = setup_with_options(ServerOptions {
+            max_age: Some(Duration::from_millis(200)),
+            ..Default::default()
+        })
+        .await;
+        let mut client = make_client(addr).await;
+        let messages = fake_log_stream_recording(3);
+
+        write_log_stream(&mut client, &messages).await;
+
+        // Let the messages expire.
+        tokio::time::sleep(Duration::from_millis(400)).await;
+
+        let mut log_stream = client.read_messages(ReadMessagesRequest {}).await.unwrap();
+        let actual = read_log_stream_until_idle(&mut log_stream).await;
+
+        // Only the static message is left.
+        assert_eq!(actual, vec![messages[0].clone()]);
+
+        completion.finish();
+    }
+
+    #[tokio::test]
+    async fn recording_memory_limit_drops_messages_per_recording() {
+        let messages_a = fake_log_stream_recording(3);
+        let messages_b = fake_log_stream_recording(1);
+
+        // Enough room for two data messages per recording.
+        let max_msg_size_bytes = messages_a[1..]
+            .iter()
+            .map(|msg| {
+                log_msg_to_proto(msg.clone(), Compression::Off)
+                    .unwrap()
+                    .total_size_bytes()
+            })
+            .max()
+            .unwrap();
+
+        let (completion, addr) = setup_with_options(ServerOptions {
+            recording_memory_limit: MemoryLimit::from_bytes(2 * max_msg_size_bytes),
+            ..Default::default()
+        })
+        .await;
+        let mut client = make_client(addr).await;
+
+        write_log_stream(&mut client, &messages_a).await;
+        write_log_stream(&mut client, &messages_b).await;
+
+        let mut log_stream = client.read_messages(ReadMessagesRequest {}).await.unwrap();
+        let actual = read_log_stream_until_idle(&mut log_stream).await;
+
+        // Static messages first, then the last two messages of `a`, and everything from `b`.
+        assert_eq!(
+            actual,
+            vec![
+                messages_a[0].clone(),
+                messages_b[0].clone(),
+                messages_a[2].clone(),
+                messages_a[3].clone(),
+                messages_b[1].clone(),
+            ]
+        );
+
+        completion.finish();
+    }
+
+    async fn push_log_stream(proxy: &super::MessageProxy, messages: &[LogMsg]) {
+        for msg in messages {
+            proxy
+                .push_msg(log_msg_to_proto(msg.clone(), Compression::Off).unwrap())
+                .await;
+        }
+    }
+
+    async fn proxy_history(proxy: &super::MessageProxy) -> Vec<LogMsg> {
+        proxy
+            .history()
+            .await
+            .into_iter()
+            .filter_map(|msg| match msg {
+                Msg::LogMsg(msg) => Some(log_msg_from_proto(msg).unwrap()),
+                Msg::Table(_) => None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn persistence_restores_history() {
+        let dir = tempfile::tempdir().unwrap();
+        let options = ServerOptions {
+            persistence: Some(PersistenceOptions::new(dir.path())),
+            ..Default::default()
+        };
+        let messages = fake_log_stream_recording(3);
+
+        let proxy = super::MessageProxy::new(options.clone());
+        push_log_stream(&proxy, &messages).await;
+        proxy.shutdown().await;
+
+        // Start a new server from the same directory.
+        let proxy = super::MessageProxy::new(options);
+        assert_eq!(proxy_history(&proxy).await, messages);
+    }
+
+    #[tokio::test]
+    async fn persistence_drops_expired_segments() {
+        let dir = tempfile::tempdir().unwrap();
+        let messages = fake_log_stream_recording(4);
+
+        // Enough room for two data messages.
+        let max_msg_size_bytes = messages[1..]
+            .iter()
+            .map(|msg| {
+                log_msg_to_proto(msg.clone(), Compression::Off)
+                    .unwrap()
+                    .total_size_bytes()
+            })
+            .max()
+            .unwrap();
+
+        let options = ServerOptions {
+            recording_memory_limit: MemoryLimit::from_bytes(2 * max_msg_size_bytes),
+            persistence: Some(PersistenceOptions {
+                dir: dir.path().to_owned(),
+                // One segment per message.
+                max_segment_bytes: 1,
+            }),
+            ..Default::default()
+        };
+
+        let proxy = super::MessageProxy::new(options.clone());
+        push_log_stream(&proxy, &messages).await;
+        proxy.shutdown().await;
+
+        // The segments of the first two data messages are gone.
+        let mut files = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<_>>();
+        files.sort();
+        assert_eq!(
+            files,
+            vec![
+                "segment-0000000003.arrivals",
+                "segment-0000000003.rrd",
+                "segment-0000000004.arrivals",
+                "segment-0000000004.rrd",
+                "static-0000000000.rrd",
+            ]
+        );
+
+        let proxy = super::MessageProxy::new(options);
+        assert_eq!(
+            proxy_history(&proxy).await,
+            vec![
+                messages[0].clone(),
+                messages[3].clone(),
+                messages[4].clone()
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn persistence_keeps_message_ages_across_restarts() {
+        let dir = tempfile::tempdir().unwrap();
+        let messages = fake_log_stream_recording(2);
+        let to_proto = |msg: &LogMsg| log_msg_to_proto(msg.clone(), Compression::Off).unwrap();
+
+        // A history written two hours ago.
+        let two_hours_ago = SystemTime::now() - Duration::from_secs(2 * 60 * 60);
+        persistence::DiskBacklog::new(
+            PersistenceOptions::new(dir.path()),
+            0,
+            [to_proto(&messages[0])],
+            messages[1..]
+                .iter()
+                .enumerate()
+                .map(|(seq, msg)| (seq as u64, two_hours_ago, to_proto(msg))),
+            &[],
+        )
+        .unwrap()
+        .close();
+
+        // Every restart rewrites the history: that must not make it any younger.
+        for _ in 0..2 {
+            let proxy = super::MessageProxy::new(ServerOptions {
+                persistence: Some(PersistenceOptions::new(dir.path())),
+                ..Default::default()
+            });
+            assert_eq!(proxy_history(&proxy).await, messages);
+            proxy.shutdown().await;
+        }
+
+        let proxy = super::MessageProxy::new(ServerOptions {
+            max_age: Some(Duration::from_secs(60 * 60)),
+            persistence: Some(PersistenceOptions::new(dir.path())),
+            ..Default::default()
+        });
+
+        // Only the static message is left.
+        assert_eq!(proxy_history(&proxy).await, vec![messages[0].clone()]);
+    }
 }
@@ -2,7 +2,7 @@ use std::net::Ipv4Addr;
 use std::net::SocketAddr;
 use std::net::SocketAddrV4;
 
-use re_grpc_server::{DEFAULT_MEMORY_LIMIT, DEFAULT_SERVER_PORT, serve, shutdown};
+use re_grpc_server::{DEFAULT_SERVER_PORT, ServerOptions, serve, shutdown};
 
 #[tokio::main(flavor = "current_thread")]
 async fn main() -> anyhow::Result<()> {
@@ -13,7 +13,7 @@ async fn main() -> anyhow::Result<()> {
             Ipv4Addr::new(0, 0, 0, 0),
             DEFAULT_SERVER_PORT,
         )),
-        DEFAULT_MEMORY_LIMIT,
+        ServerOptions::default(),
         shutdown::never(),
     )
     .await?;
@@ -0,0 +1,510 @@
+//! Persistence of the message history of the server to disk, so that it survives restarts.
+//!
+//! The history is stored as a series of `.rrd` files in a single directory:
+//! * `static-<index>.rrd` files hold the messages that are never garbage collected (store infos,
+//!   blueprints and blueprint activation commands).
+//! * `segment-<index>.rrd` files hold recording data, in order of arrival. A new segment is
+//!   started whenever the current one grows past [`PersistenceOptions::max_segment_bytes`], and
+//!   segments are deleted once all of their messages have been dropped from the in-memory history.
+//! * `segment-<index>.arrivals` files hold the arrival time of each message of the segment of the
+//!   same name, as little-endian nanoseconds since the Unix epoch, so that the age of restored
+//!   messages is preserved across restarts.
+//!
+//! On startup, all of these files are read back in order and then rewritten as a single static
+//! file and a single segment, so that the directory doesn't grow across restarts.
+//!
+//! All file IO happens on a dedicated thread, see [`DiskBacklog`].
+//!
+//! Tables are not persisted.
+
+use std::collections::VecDeque;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{Receiver, Sender, TryRecvError};
+use std::time::{Duration, SystemTime};
+
+use re_byte_size::SizeBytes as _;
+use re_log_encoding::FileSink;
+use re_protos::log_msg::v1alpha1::LogMsg as LogMsgProto;
+
+/// Default value of [`PersistenceOptions::max_segment_bytes`].
+pub const DEFAULT_MAX_SEGMENT_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Options for persisting the message history of the server to disk.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PersistenceOptions {
+    /// Directory in which the message history is stored.
+    ///
+    /// It is created if it doesn't exist yet. Any history found in there is loaded on startup.
+    pub dir: PathBuf,
+
+    /// A new segment is started once the current one grows past this size.
+    pub max_segment_bytes: u64,
+}
+
+impl PersistenceOptions {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            max_segment_bytes: DEFAULT_MAX_SEGMENT_BYTES,
+        }
+    }
+}
+
+// ---
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum FileKind {
+    Static,
+    Segment,
+}
+
+impl FileKind {
+    fn prefix(self) -> &'static str {
+        match self {
+            Self::Static => "static",
+            Self::Segment => "segment",
+        }
+    }
+}
+
+fn file_path(dir: &Path, kind: FileKind, index: u64) -> PathBuf {
+    dir.join(format!("{}-{index:010}.rrd", kind.prefix()))
+}
+
+fn parse_file_name(name: &str) -> Option<(FileKind, u64)> {
+    let (prefix, index) = name.strip_suffix(".rrd")?.split_once('-')?;
+    let kind = match prefix {
+        "static" => FileKind::Static,
+        "segment" => FileKind::Segment,
+        _ => return None,
+    };
+    Some((kind, index.parse().ok()?))
+}
+
+/// The file holding the arrival times of the messages of a segment.
+fn arrivals_path(segment_path: &Path) -> PathBuf {
+    segment_path.with_extension("arrivals")
+}
+
+fn encode_arrival(arrival: SystemTime) -> [u8; 8] {
+    let nanos = arrival
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    u64::try_from(nanos).unwrap_or(u64::MAX).to_le_bytes()
+}
+
+/// Reads back the arrival times of the messages of a segment.
+///
+/// Missing or truncated files (e.g. because the server crashed) yield fewer arrival times than
+/// there are messages in the segment.
+fn read_arrivals(segment_path: &Path) -> Vec<SystemTime> {
+    let Ok(bytes) = std::fs::read(arrivals_path(segment_path)) else {
+        return Vec::new();
+    };
+
+    bytes
+        .chunks_exact(8)
+        .map(|nanos| {
+            let nanos = u64::from_le_bytes(nanos.try_into().unwrap_or_default());
+            SystemTime::UNIX_EPOCH + Duration::from_nanos(nanos)
+        })
+        .collect()
+}
+
+fn remove_file(path: &Path) {
+    if let Err(err) = std::fs::remove_file(path) {
+        re_log::warn!("Failed to remove {path:?}: {err}");
+    }
+}
+
+/// Removes a history file, along with its arrival times if any.
+fn remove_history_file(path: &Path) {
+    remove_file(path);
+
+    let arrivals_path = arrivals_path(path);
+    if let Err(err) = std::fs::remove_file(&arrivals_path) {
+        if err.kind() != std::io::ErrorKind::NotFound {
+            re_log::warn!("Failed to remove {arrivals_path:?}: {err}");
+        }
+    }
+}
+
+// ---
+
+/// The message history found on disk.
+pub(crate) struct PersistedHistory {
+    /// Every `.rrd` file the history was loaded from, to be deleted once it has been rewritten.
+    pub files: Vec<PathBuf>,
+
+    /// Static messages come first, then recording data in order of arrival.
+    ///
+    /// Each message comes with the time it arrived at.
+    /// If that wasn't recorded (e.g. static messages, or a crash before the arrival time could be
+    /// written), the last time its file was written to is used instead.
+    pub messages: Vec<(LogMsgProto, SystemTime)>,
+
+    /// Index of the next file to be written.
+    pub next_index: u64,
+}
+
+/// Reads back the message history previously written to `dir`.
+///
+/// Files that cannot be decoded are skipped, and truncated files (e.g. because the server crashed
+/// while writing to them) are read up to the first message that cannot be decoded.
+pub(crate) fn load(dir: &Path) -> std::io::Result<PersistedHistory> {
+    re_tracing::profile_function!();
+
+    std::fs::create_dir_all(dir)?;
+
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        if let Some((kind, index)) = entry.file_name().to_str().and_then(parse_file_name) {
+            files.push((kind, index, entry.path()));
+        }
+    }
+    files.sort();
+
+    let next_index = files
+        .iter()
+        .map(|(_, index, _)| index + 1)
+        .max()
+        .unwrap_or_default();
+
+    let mut messages = Vec::new();
+    for (kind, _, path) in &files {
+        let modified = std::fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .unwrap_or_else(|_err| SystemTime::now());
+        let arrivals = match kind {
+            FileKind::Static => Vec::new(),
+            FileKind::Segment => read_arrivals(path),
+        };
+
+        let decoder = match std::fs::File::open(path)
+            .map_err(re_log_encoding::decoder::DecodeError::Read)
+            .and_then(|file| re_log_encoding::decoder::Decoder::new(std::io::BufReader::new(file)))
+        {
+            Ok(decoder) => decoder,
+            Err(err) => {
+                re_log::warn!("Skipping unreadable message history file {path:?}: {err}");
+                continue;
+            }
+        };
+
+        for (index, msg) in decoder.into_raw_iter().enumerate() {
+            match msg {
+                Ok(msg) => {
+                    let arrival = arrivals.get(index).copied().unwrap_or(modified);
+                    messages.push((LogMsgProto { msg: Some(msg) }, arrival));
+                }
+                Err(err) => {
+                    re_log::warn!("Message history file {path:?} is truncated: {err}");
+                    break;
+                }
+            }
+        }
+    }
+
+    re_log::debug!(
+        "Loaded {} message(s) from {} file(s) in {dir:?}",
+        messages.len(),
+        files.len()
+    );
+
+    Ok(PersistedHistory {
+        files: files.into_iter().map(|(_, _, path)| path).collect(),
+        messages,
+        next_index,
+    })
+}
+
+// ---
+
+/// A segment that isn't written to anymore.
+struct ClosedSegment {
+    path: PathBuf,
+
+    /// Sequence number of the most recent message in this segment.
+    last_seq: u64,
+}
+
+/// The segment currently being written to.
+struct OpenSegment {
+    path: PathBuf,
+    sink: FileSink,
+    arrivals: std::io::BufWriter<std::fs::File>,
+    size_bytes: u64,
+
+    /// Sequence number of the most recent message in this segment, if any.
+    last_seq: Option<u64>,
+}
+
+impl OpenSegment {
+    fn new(path: PathBuf) -> std::io::Result<Self> {
+        Ok(Self {
+            sink: FileSink::new(&path).map_err(std::io::Error::other)?,
+            arrivals: std::io::BufWriter::new(std::fs::File::create(arrivals_path(&path))?),
+            path,
+            size_bytes: 0,
+            last_seq: None,
+        })
+    }
+
+    fn append(&mut self, seq: u64, arrival: SystemTime, msg: LogMsgProto) {
+        self.size_bytes += msg.total_size_bytes();
+        self.last_seq = Some(seq);
+        self.sink.send_proto(msg);
+
+        if let Err(err) = self.arrivals.write_all(&encode_arrival(arrival)) {
+            re_log::warn_once!("Failed to write arrival times to {:?}: {err}", self.path);
+        }
+    }
+
+    fn flush_arrivals(&mut self) {
+        if let Err(err) = self.arrivals.flush() {
+            re_log::warn_once!("Failed to write arrival times to {:?}: {err}", self.path);
+        }
+    }
+}
+
+enum Command {
+    AppendStatic(LogMsgProto),
+
+    Append {
+        seq: u64,
+        arrival: SystemTime,
+        msg: LogMsgProto,
+    },
+
+    Gc {
+        oldest_retained_seq: u64,
+    },
+}
+
+/// Writes the message history of the server to disk, as it comes in.
+///
+/// The actual IO happens on a dedicated thread, so that the server's event loop never blocks on
+/// the filesystem.
+pub(crate) struct DiskBacklog {
+    tx: Sender<Command>,
+    thread: std::thread::JoinHandle<()>,
+}
+
+impl DiskBacklog {
+    /// Starts a new set of files, initialized with the given history.
+    ///
+    /// Once that history has safely been written, the `previous_files` are deleted.
+    ///
+    /// This blocks until the initial history has been written.
+    pub fn new(
+        options: PersistenceOptions,
+        next_index: u64,
+        static_messages: impl IntoIterator<Item = LogMsgProto>,
+        ordered_messages: impl IntoIterator<Item = (u64, SystemTime, LogMsgProto)>,
+        previous_files: &[PathBuf],
+    ) -> std::io::Result<Self> {
+        let writer = DiskWriter::new(
+            options,
+            next_index,
+            static_messages,
+            ordered_messages,
+            previous_files,
+        )?;
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let thread = std::thread::Builder::new()
+            .name("grpc_server_disk_backlog".to_owned())
+            .spawn(move || writer.run(&rx))?;
+
+        Ok(Self { tx, thread })
+    }
+
+    /// Appends a message that is never garbage collected.
+    pub fn append_static(&self, msg: LogMsgProto) {
+        self.tx.send(Command::AppendStatic(msg)).ok();
+    }
+
+    /// Appends recording data, `seq` being its sequence number in the in-memory history.
+    pub fn append(&self, seq: u64, arrival: SystemTime, msg: LogMsgProto) {
+        self.tx.send(Command::Append { seq, arrival, msg }).ok();
+    }
+
+    /// Deletes the segments whose messages have all been dropped from the in-memory history.
+    pub fn gc(&self, oldest_retained_seq: u64) {
+        self.tx
+            .send(Command::Gc {
+                oldest_retained_seq,
+            })
+            .ok();
+    }
+
+    /// Blocks until everything that was sent so far has been written to disk.
+    pub fn close(self) {
+        let Self { tx, thread } = self;
+        drop(tx);
+        thread.join().ok();
+    }
+}
+
+/// The part of the [`DiskBacklog`] that lives on its dedicated thread.
+struct DiskWriter {
+    options: PersistenceOptions,
+
+    static_sink: FileSink,
+
+    /// Oldest first.
+    closed_segments: VecDeque<ClosedSegment>,
+
+    current_segment: OpenSegment,
+
+    /// Index of the next file to be written.
+    next_index: u64,
+}
+
+impl DiskWriter {
+    fn new(
+        options: PersistenceOptions,
+        mut next_index: u64,
+        static_messages: impl IntoIterator<Item = LogMsgProto>,
+        ordered_messages: impl IntoIterator<Item = (u64, SystemTime, LogMsgProto)>,
+        previous_files: &[PathBuf],
+    ) -> std::io::Result<Self> {
+        re_tracing::profile_function!();
+
+        let static_sink = FileSink::new(file_path(&options.dir, FileKind::Static, next_index))
+            .map_err(std::io::Error::other)?;
+        next_index += 1;
+        for msg in static_messages {
+            static_sink.send_proto(msg);
+        }
+
+        let mut current_segment =
+            OpenSegment::new(file_path(&options.dir, FileKind::Segment, next_index))?;
+        next_index += 1;
+        for (seq, arrival, msg) in ordered_messages {
+            current_segment.append(seq, arrival, msg);
+        }
+
+        static_sink.flush_blocking();
+        current_segment.sink.flush_blocking();
+        current_segment.arrivals.flush()?;
+
+        for path in previous_files {
+            remove_history_file(path);
+        }
+
+        Ok(Self {
+            options,
+            static_sink,
+            closed_segments: Default::default(),
+            current_segment,
+            next_index,
+        })
+    }
+
+    fn run(mut self, rx: &Receiver<Command>) {
+        loop {
+            let command = match rx.try_recv() {
+                Ok(command) => command,
+
+                Err(TryRecvError::Empty) => {
+                    // Nothing else to write for now: a good time to flush.
+                    self.current_segment.flush_arrivals();
+                    match rx.recv() {
+                        Ok(command) => command,
+                        Err(_) => break,
+                    }
+                }
+
+                Err(TryRecvError::Disconnected) => break,
+            };
+
+            match command {
+                Command::AppendStatic(msg) => self.static_sink.send_proto(msg),
+                Command::Append { seq, arrival, msg } => self.append(seq, arrival, msg),
+                Command::Gc {
+                    oldest_retained_seq,
+                } => self.gc(oldest_retained_seq),
+            }
+        }
+
+        self.static_sink.flush_blocking();
+        self.current_segment.sink.flush_blocking();
+        self.current_segment.flush_arrivals();
+    }
+
+    fn append(&mut self, seq: u64, arrival: SystemTime, msg: LogMsgProto) {
+        if self.current_segment.size_bytes >= self.options.max_segment_bytes {
+            self.start_new_segment();
+        }
+
+        self.current_segment.append(seq, arrival, msg);
+    }
+
+    fn gc(&mut self, oldest_retained_seq: u64) {
+        while self
+            .closed_segments
+            .front()
+            .is_some_and(|segment| segment.last_seq < oldest_retained_seq)
+        {
+            let Some(segment) = self.closed_segments.pop_front() else {
+                break;
+            };
+            re_log::trace!(
+                "Removing expired message history segment {:?}",
+                segment.path
+            );
+            remove_history_file(&segment.path);
+        }
+    }
+
+    fn start_new_segment(&mut self) {
+        re_tracing::profile_function!();
+
+        let path = file_path(&self.options.dir, FileKind::Segment, self.next_index);
+        let segment = match OpenSegment::new(path) {
+            Ok(segment) => segment,
+            Err(err) => {
+                // Keep on writing to the current segment, better than losing data.
+                re_log::error!("Failed to start new message history segment: {err}");
+                return;
+            }
+        };
+        self.next_index += 1;
+
+        let mut previous = std::mem::replace(&mut self.current_segment, segment);
+        previous.flush_arrivals();
+        if let Some(last_seq) = previous.last_seq {
+            self.closed_segments.push_back(ClosedSegment {
+                path: previous.path,
+                last_seq,
+            });
+        } else {
+            let OpenSegment { path, sink, .. } = previous;
+            drop(sink);
+            remove_history_file(&path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_names() {
+        let dir = Path::new("history");
+        for kind in [FileKind::Static, FileKind::Segment] {
+            let path = file_path(dir, kind, 42);
+            let name = path.file_name().unwrap().to_str().unwrap();
+            assert_eq!(parse_file_name(name), Some((kind, 42)));
+        }
+
+        assert_eq!(parse_file_name("static-0000000001.rrd.tmp"), None);
+        assert_eq!(parse_file_name("segment-abc.rrd"), None);
+        assert_eq!(parse_file_name("recording-0000000001.rrd"), None);
+    }
+}
@@ -77,6 +77,11 @@ pub(crate) fn encode_arrow(
             let _span = tracing::trace_span!("lz4::compress").entered();
             lz4_flex::block::compress(&uncompressed)
         }
+        crate::Compression::Zstd { level } => {
+            re_tracing::profile_scope!("zstd::compress");
+            let _span = tracing::trace_span!("zstd::compress").entered();
+            zstd_compress(&uncompressed, level)?
+        }
     };
 
     Ok(Payload {
@@ -106,6 +111,13 @@ pub(crate) fn decode_arrow(
                 lz4_flex::block::decompress_into(data, &mut uncompressed)?;
                 uncompressed.as_slice()
             }
+            crate::Compression::Zstd { .. } => {
+                re_tracing::profile_scope!("zstd-decompress");
+                let _span = tracing::trace_span!("zstd::decompress").entered();
+                uncompressed.resize(uncompressed_size, 0);
+                zstd_decompress_into(data, &mut uncompressed)?;
+                uncompressed.as_slice()
+            }
         };
 
         Ok(read_arrow_from_bytes(&mut &data[..])?)
@@ -127,9 +139,59 @@ pub(crate) fn decode_arrow(
                     lz4_flex::block::decompress_into(data, uncompressed)?;
                     uncompressed.as_slice()
                 }
+                crate::Compression::Zstd { .. } => {
+                    let _span = tracing::trace_span!("zstd::decompress").entered();
+                    uncompressed.resize(uncompressed_size, 0);
+                    zstd_decompress_into(data, uncompressed)?;
+                    uncompressed.as_slice()
+                }
             };
 
             Ok(read_arrow_from_bytes(&mut &data[..])?)
         })
     }
 }
+
+#[cfg(feature = "encoder")]
+fn zstd_compress(data: &[u8], level: i32) -> Result<Vec<u8>, crate::encoder::EncodeError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        zstd::bulk::compress(data, level).map_err(crate::encoder::EncodeError::Zstd)
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        _ = (data, level);
+        Err(super::CodecError::UnsupportedCompression("zstd").into())
+    }
+}
+
+#[cfg(feature = "decoder")]
+fn zstd_decompress_into(
+    data: &[u8],
+    uncompressed: &mut [u8],
+) -> Result<(), crate::decoder::DecodeError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let num_bytes = zstd::bulk::decompress_to_buffer(data, uncompressed)
+            .map_err(crate::decoder::DecodeError::Zstd)?;
+
+        if num_bytes != uncompressed.len() {
+            return Err(crate::decoder::DecodeError::Zstd(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!(
+                    "expected {} uncompressed bytes, got {num_bytes}",
+                    uncompressed.len()
+                ),
+            )));
+        }
+
+        Ok(())
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        _ = (data, uncompressed);
+        Err(super::CodecError::UnsupportedCompression("zstd").into())
+    }
+}
@@ -25,6 +25,10 @@ pub enum CodecError {
     #[error("Unexpected stream state")]
     UnexpectedStreamState,
 
+    #[allow(dead_code)] // only used on some platforms
+    #[error("Unsupported compression on this platform: {0}")]
+    UnsupportedCompression(&'static str),
+
     #[error("Unsupported encoding, expected Arrow IPC")]
     UnsupportedEncoding,
 
@@ -73,6 +73,9 @@ pub enum DecodeError {
     #[error("lz4 error: {0}")]
     Lz4(#[from] lz4_flex::block::DecompressError),
 
+    #[error("zstd error: {0}")]
+    Zstd(std::io::Error),
+
     #[error("Protobuf error: {0}")]
     Protobuf(#[from] re_protos::external::prost::DecodeError),
 
@@ -451,6 +454,10 @@ mod tests {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
             },
+            EncodingOptions {
+                compression: Compression::Zstd { level: 1 },
+                serializer: Serializer::Protobuf,
+            },
         ];
 
         for options in options {
@@ -478,6 +485,10 @@ mod tests {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
             },
+            EncodingOptions {
+                compression: Compression::Zstd { level: 1 },
+                serializer: Serializer::Protobuf,
+            },
         ];
 
         for options in options {
@@ -513,4 +524,29 @@ mod tests {
             similar_asserts::assert_eq!(decoded_messages, [messages.clone(), messages].concat());
         }
     }
+
+    #[test]
+    fn test_compression_header() {
+        let options = EncodingOptions::PROTOBUF_ZSTD;
+        assert_eq!(
+            EncodingOptions::from_bytes(options.to_bytes()).unwrap(),
+            options
+        );
+
+        // The level is not part of the header.
+        let options = EncodingOptions {
+            compression: Compression::Zstd { level: 19 },
+            serializer: Serializer::Protobuf,
+        };
+        assert_eq!(
+            EncodingOptions::from_bytes(options.to_bytes()).unwrap(),
+            EncodingOptions::PROTOBUF_ZSTD
+        );
+
+        // Unknown compression formats (e.g. from a newer version of Rerun) must fail cleanly.
+        assert!(matches!(
+            EncodingOptions::from_bytes([3, Serializer::Protobuf as u8, 0, 0]),
+            Err(crate::OptionsError::UnknownCompression(3))
+        ));
+    }
 }
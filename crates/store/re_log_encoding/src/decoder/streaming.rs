@@ -395,6 +395,10 @@ mod tests {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
             },
+            EncodingOptions {
+                compression: Compression::Zstd { level: 1 },
+                serializer: Serializer::Protobuf,
+            },
         ];
 
         for options in options {
@@ -438,6 +442,10 @@ mod tests {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
             },
+            EncodingOptions {
+                compression: Compression::Zstd { level: 1 },
+                serializer: Serializer::Protobuf,
+            },
         ];
 
         for options in options {
@@ -477,6 +485,10 @@ mod tests {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
             },
+            EncodingOptions {
+                compression: Compression::Zstd { level: 1 },
+                serializer: Serializer::Protobuf,
+            },
         ];
 
         for options in options {
@@ -21,6 +21,9 @@ pub enum EncodeError {
     #[error("lz4 error: {0}")]
     Lz4(#[from] lz4_flex::block::CompressError),
 
+    #[error("zstd error: {0}")]
+    Zstd(std::io::Error),
+
     #[error("Protobuf error: {0}")]
     Protobuf(#[from] re_protos::external::prost::EncodeError),
 
@@ -7,6 +7,7 @@ use std::{
 use parking_lot::Mutex;
 
 use re_log_types::LogMsg;
+use re_protos::log_msg::v1alpha1::LogMsg as LogMsgProto;
 
 /// Errors that can occur when creating a [`FileSink`].
 #[derive(thiserror::Error, Debug)]
@@ -26,6 +27,7 @@ pub enum FileSinkError {
 
 enum Command {
     Send(LogMsg),
+    SendProto(LogMsgProto),
     Flush(SyncSender<()>),
 }
 
@@ -61,8 +63,16 @@ impl FileSink {
     /// Start writing log messages to a file at the given path.
     pub fn new(path: impl Into<std::path::PathBuf>) -> Result<Self, FileSinkError> {
         // We always compress on disk
-        let encoding_options = crate::EncodingOptions::PROTOBUF_COMPRESSED;
+        Self::new_with_options(path, crate::EncodingOptions::PROTOBUF_COMPRESSED)
+    }
 
+    /// Start writing log messages to a file at the given path, using the given encoding options.
+    ///
+    /// Use e.g. [`crate::EncodingOptions::PROTOBUF_ZSTD`] to trade CPU time for smaller files.
+    pub fn new_with_options(
+        path: impl Into<std::path::PathBuf>,
+        encoding_options: crate::EncodingOptions,
+    ) -> Result<Self, FileSinkError> {
         let (tx, rx) = std::sync::mpsc::channel();
 
         let path = path.into();
@@ -122,6 +132,13 @@ impl FileSink {
     pub fn send(&self, log_msg: LogMsg) {
         self.tx.lock().send(Some(Command::Send(log_msg))).ok();
     }
+
+    /// Like [`Self::send`], but for messages that are already in their transport-level
+    /// (Protobuf) representation, which are written as-is without being decoded first.
+    #[inline]
+    pub fn send_proto(&self, log_msg: LogMsgProto) {
+        self.tx.lock().send(Some(Command::SendProto(log_msg))).ok();
+    }
 }
 
 /// Set `filepath` to `None` to stream to standard output.
@@ -147,6 +164,12 @@ fn spawn_and_stream<W: std::io::Write + Send + 'static>(
                                 return;
                             }
                         }
+                        Command::SendProto(log_msg) => {
+                            if let Err(err) = encoder.append_proto(log_msg) {
+                                re_log::error!("Failed to write log stream to {target}: {err}");
+                                return;
+                            }
+                        }
                         Command::Flush(oneshot) => {
                             re_log::trace!("Flushing…");
                             if let Err(err) = encoder.flush_blocking() {
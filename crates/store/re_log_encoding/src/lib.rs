@@ -20,6 +20,10 @@ pub mod stream_rrd_from_http;
 pub mod external {
     #[cfg(feature = "decoder")]
     pub use lz4_flex;
+
+    #[cfg(feature = "decoder")]
+    #[cfg(not(target_arch = "wasm32"))]
+    pub use zstd;
 }
 
 // ---------------------------------------------------------------------
@@ -40,12 +44,46 @@ const OLD_RRD_HEADERS: &[[u8; 4]] = &[*b"RRF0", *b"RRF1"];
 
 /// Compression format used.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-#[repr(u8)]
 pub enum Compression {
-    Off = 0,
+    Off,
 
     /// Very fast compression and decompression, but not very good compression ratio.
-    LZ4 = 1,
+    LZ4,
+
+    /// Much better compression ratio than [`Self::LZ4`], at the cost of slower compression.
+    ///
+    /// Decompression remains fast, which makes this a good fit for archived recordings.
+    ///
+    /// The `level` is only used when encoding and is not part of the encoded stream: decoded
+    /// data always reports [`Self::ZSTD_DEFAULT_LEVEL`].
+    ///
+    /// Not available on the web.
+    Zstd {
+        level: i32,
+    },
+}
+
+impl Compression {
+    /// The default Zstd compression level, see [`Self::Zstd`].
+    pub const ZSTD_DEFAULT_LEVEL: i32 = 3;
+
+    /// The range of Zstd compression levels we accept, see [`Self::Zstd`].
+    pub const ZSTD_LEVELS: std::ops::RangeInclusive<i32> = 1..=22;
+
+    /// [`Self::Zstd`] with [`Self::ZSTD_DEFAULT_LEVEL`].
+    pub const ZSTD: Self = Self::Zstd {
+        level: Self::ZSTD_DEFAULT_LEVEL,
+    };
+
+    /// The value of this compression in the file header.
+    #[inline]
+    pub const fn to_u8(self) -> u8 {
+        match self {
+            Self::Off => 0,
+            Self::LZ4 => 1,
+            Self::Zstd { .. } => 2,
+        }
+    }
 }
 
 /// How we serialize the data
@@ -70,6 +108,10 @@ impl EncodingOptions {
         compression: Compression::Off,
         serializer: Serializer::Protobuf,
     };
+    pub const PROTOBUF_ZSTD: Self = Self {
+        compression: Compression::ZSTD,
+        serializer: Serializer::Protobuf,
+    };
 
     pub fn from_bytes(bytes: [u8; 4]) -> Result<Self, OptionsError> {
         match bytes {
@@ -77,6 +119,7 @@ impl EncodingOptions {
                 let compression = match compression {
                     0 => Compression::Off,
                     1 => Compression::LZ4,
+                    2 => Compression::ZSTD,
                     _ => return Err(OptionsError::UnknownCompression(compression)),
                 };
                 let serializer = match serializer {
@@ -95,7 +138,7 @@ impl EncodingOptions {
 
     pub fn to_bytes(self) -> [u8; 4] {
         [
-            self.compression as u8,
+            self.compression.to_u8(),
             self.serializer as u8,
             0, // reserved
             0, // reserved
@@ -110,7 +153,9 @@ pub enum OptionsError {
     #[error("Reserved bytes not zero")]
     UnknownReservedBytes,
 
-    #[error("Unknown compression: {0}")]
+    #[error(
+        "Unknown compression: {0}. The data was likely written by a newer version of Rerun, consider updating."
+    )]
     UnknownCompression(u8),
 
     // TODO(jan): Remove this at some point, realistically 1-2 releases after 0.23
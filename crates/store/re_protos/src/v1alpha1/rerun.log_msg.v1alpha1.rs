@@ -257,6 +257,8 @@ pub enum Compression {
     None = 1,
     /// LZ4 block compression.
     Lz4 = 2,
+    /// Zstd compression.
+    Zstd = 3,
 }
 impl Compression {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -268,6 +270,7 @@ impl Compression {
             Self::Unspecified => "COMPRESSION_UNSPECIFIED",
             Self::None => "COMPRESSION_NONE",
             Self::Lz4 => "COMPRESSION_LZ4",
+            Self::Zstd => "COMPRESSION_ZSTD",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -276,6 +279,7 @@ impl Compression {
             "COMPRESSION_UNSPECIFIED" => Some(Self::Unspecified),
             "COMPRESSION_NONE" => Some(Self::None),
             "COMPRESSION_LZ4" => Some(Self::Lz4),
+            "COMPRESSION_ZSTD" => Some(Self::Zstd),
             _ => None,
         }
     }
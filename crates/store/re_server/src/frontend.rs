@@ -0,0 +1,1397 @@
+//! The [`FrontendService`] implementation, on top of a [`Store`].
+
+use std::pin::Pin;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, BooleanArray, RecordBatch, StringArray};
+use arrow::datatypes::{DataType, Field, Schema as ArrowSchema};
+use parking_lot::RwLock;
+use tokio_stream::{Stream, StreamExt as _};
+use tonic::{Request, Response, Status};
+
+use re_chunk::{Chunk, ChunkId};
+use re_log_encoding::codec::wire::encoder::Encode as _;
+use re_log_types::{EntityPath, EntryId, StoreId, TimelineName};
+use re_protos::catalog::v1alpha1::{
+    CreateDatasetEntryRequest, CreateDatasetEntryResponse, DeleteEntryRequest, DeleteEntryResponse,
+    EntryFilter, FindEntriesRequest, FindEntriesResponse, ReadDatasetEntryRequest,
+    ReadDatasetEntryResponse, ReadTableEntryRequest, ReadTableEntryResponse, RegisterTableRequest,
+    RegisterTableResponse, UpdateDatasetEntryRequest, UpdateDatasetEntryResponse,
+    ext::{LanceTable, ProviderDetails as _},
+};
+use re_protos::common::v1alpha1::{
+    DataframePart, ScanParameters,
+    ext::{IfDuplicateBehavior, IfMissingBehavior, ScanParameters as ScanParametersExt},
+};
+use re_protos::frontend::v1alpha1::{
+    CreateIndexRequest, DoMaintenanceRequest, GetChunksRequest, GetDatasetSchemaRequest,
+    GetPartitionTableSchemaRequest, GetTableSchemaRequest, GetTableSchemaResponse,
+    QueryDatasetRequest, ReIndexRequest, RegisterWithDatasetRequest, ScanPartitionTableRequest,
+    ScanTableRequest, ScanTableResponse, SearchDatasetRequest, VersionRequest, VersionResponse,
+    frontend_service_server::FrontendService,
+};
+use re_protos::manifest_registry::v1alpha1::{
+    CreateIndexResponse, DataSourceKind, DoMaintenanceResponse, GetChunksResponse,
+    GetDatasetSchemaResponse, GetPartitionTableSchemaResponse, QueryDatasetResponse,
+    ReIndexResponse, RegisterWithDatasetResponse, ScanPartitionTableResponse,
+    SearchDatasetResponse, WriteChunksRequest, WriteChunksResponse, ext::Query,
+};
+use re_protos::redap_tasks::v1alpha1::{
+    FetchTaskOutputRequest, FetchTaskOutputResponse, QueryTasksOnCompletionRequest,
+    QueryTasksOnCompletionResponse, QueryTasksRequest, QueryTasksResponse,
+};
+
+use crate::store::{Dataset, Partition, Store, StoreError, Table, Task, merged_dataset_schema};
+
+/// How many chunks are sent at most in a single [`GetChunksResponse`].
+const MAX_CHUNKS_PER_RESPONSE: usize = 64;
+
+type ResponseStream<T> = Pin<Box<dyn Stream<Item = Result<T, Status>> + Send>>;
+
+/// Serves the catalog API for a [`Store`].
+#[derive(Clone)]
+pub struct FrontendHandler {
+    store: Arc<RwLock<Store>>,
+}
+
+impl FrontendHandler {
+    pub fn new(store: Store) -> Self {
+        Self {
+            store: Arc::new(RwLock::new(store)),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl FrontendService for FrontendHandler {
+    async fn version(
+        &self,
+        _request: Request<VersionRequest>,
+    ) -> Result<Response<VersionResponse>, Status> {
+        Ok(Response::new(VersionResponse {
+            build_info: Some(re_build_info::build_info!().into()),
+        }))
+    }
+
+    // --- Catalog ---
+
+    async fn find_entries(
+        &self,
+        request: Request<FindEntriesRequest>,
+    ) -> Result<Response<FindEntriesResponse>, Status> {
+        let EntryFilter {
+            id,
+            name,
+            entry_kind,
+        } = request.into_inner().filter.unwrap_or_default();
+        let id: Option<EntryId> = id.map(TryInto::try_into).transpose()?;
+
+        let entries = self
+            .store
+            .read()
+            .entries()
+            .into_iter()
+            .filter(|entry| id.is_none_or(|id| entry.id == id))
+            .filter(|entry| name.as_ref().is_none_or(|name| &entry.name == name))
+            .filter(|entry| entry_kind.is_none_or(|kind| entry.kind as i32 == kind))
+            .map(Into::into)
+            .collect();
+
+        Ok(Response::new(FindEntriesResponse { entries }))
+    }
+
+    async fn delete_entry(
+        &self,
+        request: Request<DeleteEntryRequest>,
+    ) -> Result<Response<DeleteEntryResponse>, Status> {
+        let id: EntryId = request.into_inner().try_into()?;
+        self.store.write().delete_entry(id)?;
+
+        Ok(Response::new(DeleteEntryResponse {}))
+    }
+
+    async fn create_dataset_entry(
+        &self,
+        request: Request<CreateDatasetEntryRequest>,
+    ) -> Result<Response<CreateDatasetEntryResponse>, Status> {
+        let name: String = request.into_inner().try_into()?;
+
+        let dir = self.store.read().new_dataset_dir(&name)?;
+
+        // Don't block the runtime, nor hold on to the store, while touching the filesystem.
+        tokio::task::spawn_blocking({
+            let dir = dir.clone();
+            move || std::fs::create_dir_all(&dir)
+        })
+        .await
+        .map_err(internal)?
+        .map_err(|err| {
+            Status::internal(format!("couldn't create dataset directory {dir:?}: {err}"))
+        })?;
+
+        let dataset = Dataset::new(name, dir).map_err(internal)?;
+        let entry = dataset.as_entry();
+        self.store.write().insert_dataset(dataset)?;
+
+        Ok(Response::new(CreateDatasetEntryResponse {
+            dataset: Some(entry.into()),
+        }))
+    }
+
+    async fn read_dataset_entry(
+        &self,
+        request: Request<ReadDatasetEntryRequest>,
+    ) -> Result<Response<ReadDatasetEntryResponse>, Status> {
+        let id: EntryId = request.into_inner().try_into()?;
+
+        let store = self.store.read();
+        let dataset = store.dataset(id).ok_or_else(|| dataset_not_found(id))?;
+
+        Ok(Response::new(ReadDatasetEntryResponse {
+            dataset: Some(dataset.as_entry().into()),
+        }))
+    }
+
+    async fn update_dataset_entry(
+        &self,
+        request: Request<UpdateDatasetEntryRequest>,
+    ) -> Result<Response<UpdateDatasetEntryResponse>, Status> {
+        let request: re_protos::catalog::v1alpha1::ext::UpdateDatasetEntryRequest =
+            request.into_inner().try_into()?;
+
+        let mut store = self.store.write();
+        let dataset = store
+            .dataset_mut(request.id)
+            .ok_or_else(|| dataset_not_found(request.id))?;
+        dataset.dataset_details = request.dataset_details;
+        dataset.details.updated_at = jiff::Timestamp::now();
+
+        Ok(Response::new(UpdateDatasetEntryResponse {
+            dataset: Some(dataset.as_entry().into()),
+        }))
+    }
+
+    async fn read_table_entry(
+        &self,
+        request: Request<ReadTableEntryRequest>,
+    ) -> Result<Response<ReadTableEntryResponse>, Status> {
+        let id: EntryId = request.into_inner().try_into()?;
+
+        let store = self.store.read();
+        let table = store.table(id).ok_or_else(|| table_not_found(id))?;
+
+        Ok(Response::new(ReadTableEntryResponse {
+            table: Some(table.as_entry()?.into()),
+        }))
+    }
+
+    // --- Datasets ---
+
+    async fn register_with_dataset(
+        &self,
+        request: Request<RegisterWithDatasetRequest>,
+    ) -> Result<Response<RegisterWithDatasetResponse>, Status> {
+        let RegisterWithDatasetRequest {
+            dataset_id,
+            data_sources,
+            on_duplicate,
+        } = request.into_inner();
+
+        let dataset_id: EntryId = dataset_id
+            .ok_or_else(|| Status::invalid_argument("dataset_id is required"))?
+            .try_into()?;
+        let on_duplicate: IfDuplicateBehavior =
+            re_protos::common::v1alpha1::IfDuplicateBehavior::try_from(on_duplicate)
+                .map_err(|err| Status::invalid_argument(err.to_string()))?
+                .into();
+
+        let storage_urls = data_sources
+            .into_iter()
+            .map(|data_source| {
+                if data_source.typ != DataSourceKind::Rrd as i32 {
+                    return Err(Status::invalid_argument(
+                        "only RRD data sources are supported",
+                    ));
+                }
+
+                data_source
+                    .storage_url
+                    .ok_or_else(|| Status::invalid_argument("storage_url is required"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let root_dir = {
+            let store = self.store.read();
+            if store.dataset(dataset_id).is_none() {
+                return Err(dataset_not_found(dataset_id));
+            }
+            store.root_dir().to_owned()
+        };
+
+        // Decoding recordings is slow: do it without holding on to the store.
+        let loaded = tokio::task::spawn_blocking(move || {
+            storage_urls
+                .into_iter()
+                .map(|storage_url| {
+                    let path = local_path_from_url(&storage_url, &root_dir)?;
+                    let partitions = crate::store::load_rrd(&path).map_err(|err| {
+                        Status::invalid_argument(format!("couldn't load {storage_url}: {err:#}"))
+                    })?;
+                    Ok((storage_url, partitions))
+                })
+                .collect::<Result<Vec<_>, Status>>()
+        })
+        .await
+        .map_err(internal)??;
+
+        let mut partition_ids = Vec::new();
+        let mut partition_types = Vec::new();
+        let mut storage_urls = Vec::new();
+        let mut task_ids = Vec::new();
+
+        let mut store = self.store.write();
+        for (storage_url, partitions) in loaded {
+            let dataset = store
+                .dataset_mut(dataset_id)
+                .ok_or_else(|| dataset_not_found(dataset_id))?;
+
+            let registered = dataset.insert_partitions(partitions, on_duplicate)?;
+
+            let task = Task::new_register_rrd(format!(
+                "registered {} partition(s) from {storage_url}",
+                registered.len()
+            ));
+
+            for partition_id in registered {
+                partition_ids.push(partition_id);
+                partition_types.push("rrd".to_owned());
+                storage_urls.push(storage_url.clone());
+                task_ids.push(task.id.clone());
+            }
+
+            store.insert_task(task);
+        }
+        drop(store);
+
+        let batch = RegisterWithDatasetResponse::create_dataframe(
+            partition_ids,
+            partition_types,
+            storage_urls,
+            task_ids,
+        )
+        .map_err(internal)?;
+
+        Ok(Response::new(RegisterWithDatasetResponse {
+            data: Some(encode(&batch)?),
+        }))
+    }
+
+    async fn write_chunks(
+        &self,
+        _request: Request<tonic::Streaming<WriteChunksRequest>>,
+    ) -> Result<Response<WriteChunksResponse>, Status> {
+        Err(Status::unimplemented(
+            "write_chunks is not supported: register RRD files instead",
+        ))
+    }
+
+    async fn get_partition_table_schema(
+        &self,
+        request: Request<GetPartitionTableSchemaRequest>,
+    ) -> Result<Response<GetPartitionTableSchemaResponse>, Status> {
+        let id: EntryId = request.into_inner().try_into()?;
+
+        if self.store.read().dataset(id).is_none() {
+            return Err(dataset_not_found(id));
+        }
+
+        Ok(Response::new(GetPartitionTableSchemaResponse {
+            schema: Some(
+                (&ScanPartitionTableResponse::schema())
+                    .try_into()
+                    .map_err(internal)?,
+            ),
+        }))
+    }
+
+    type ScanPartitionTableStream = ResponseStream<ScanPartitionTableResponse>;
+
+    async fn scan_partition_table(
+        &self,
+        request: Request<ScanPartitionTableRequest>,
+    ) -> Result<Response<Self::ScanPartitionTableStream>, Status> {
+        let ScanPartitionTableRequest {
+            dataset_id,
+            scan_parameters,
+        } = request.into_inner();
+
+        let dataset_id: EntryId = dataset_id
+            .ok_or_else(|| Status::invalid_argument("dataset_id is required"))?
+            .try_into()?;
+
+        let batch = {
+            let store = self.store.read();
+            let dataset = store
+                .dataset(dataset_id)
+                .ok_or_else(|| dataset_not_found(dataset_id))?;
+
+            let partitions = dataset.partitions.iter();
+            ScanPartitionTableResponse::create_dataframe(
+                partitions.clone().map(|(id, _)| id.clone()).collect(),
+                partitions.clone().map(|_| "rrd".to_owned()).collect(),
+                partitions
+                    .clone()
+                    .map(|(_, partition)| partition.storage_url.to_string())
+                    .collect(),
+                partitions
+                    .clone()
+                    .map(|(_, partition)| partition.registration_time.as_nanosecond() as i64)
+                    .collect(),
+                partitions.clone().map(|_| None).collect(),
+                partitions.map(|_| None).collect(),
+            )
+            .map_err(internal)?
+        };
+
+        let batch = apply_scan_parameters(batch, scan_parameters)?;
+        let response = ScanPartitionTableResponse {
+            data: Some(encode(&batch)?),
+        };
+
+        Ok(Response::new(Box::pin(tokio_stream::once(Ok(response)))))
+    }
+
+    async fn get_dataset_schema(
+        &self,
+        request: Request<GetDatasetSchemaRequest>,
+    ) -> Result<Response<GetDatasetSchemaResponse>, Status> {
+        let id: EntryId = request.into_inner().try_into()?;
+
+        let schema = {
+            let store = self.store.read();
+            let dataset = store.dataset(id).ok_or_else(|| dataset_not_found(id))?;
+            merged_dataset_schema(dataset.partitions.values())
+        };
+
+        Ok(Response::new(GetDatasetSchemaResponse {
+            schema: Some((&schema).try_into().map_err(internal)?),
+        }))
+    }
+
+    async fn create_index(
+        &self,
+        _request: Request<CreateIndexRequest>,
+    ) -> Result<Response<CreateIndexResponse>, Status> {
+        Err(Status::unimplemented("indexes are not supported"))
+    }
+
+    async fn re_index(
+        &self,
+        _request: Request<ReIndexRequest>,
+    ) -> Result<Response<ReIndexResponse>, Status> {
+        Err(Status::unimplemented("indexes are not supported"))
+    }
+
+    type SearchDatasetStream = ResponseStream<SearchDatasetResponse>;
+
+    async fn search_dataset(
+        &self,
+        _request: Request<SearchDatasetRequest>,
+    ) -> Result<Response<Self::SearchDatasetStream>, Status> {
+        Err(Status::unimplemented("indexes are not supported"))
+    }
+
+    type QueryDatasetStream = ResponseStream<QueryDatasetResponse>;
+
+    async fn query_dataset(
+        &self,
+        request: Request<QueryDatasetRequest>,
+    ) -> Result<Response<Self::QueryDatasetStream>, Status> {
+        let request = request.into_inner();
+        let scan_parameters = request.scan_parameters.clone();
+        let chunk_query = ChunkQuery::try_from(request)?;
+
+        let chunks = chunk_query.run(&self.store.read())?;
+        let batch = chunk_info_dataframe(&chunks)?;
+        let batch = apply_scan_parameters(batch, scan_parameters)?;
+
+        let response = QueryDatasetResponse {
+            data: Some(encode(&batch)?),
+        };
+
+        Ok(Response::new(Box::pin(tokio_stream::once(Ok(response)))))
+    }
+
+    type GetChunksStream = ResponseStream<GetChunksResponse>;
+
+    async fn get_chunks(
+        &self,
+        request: Request<GetChunksRequest>,
+    ) -> Result<Response<Self::GetChunksStream>, Status> {
+        let chunk_query = ChunkQuery::try_from(request.into_inner())?;
+
+        let chunks = chunk_query.run(&self.store.read())?;
+        let batches = chunks
+            .chunks(MAX_CHUNKS_PER_RESPONSE)
+            .map(<[_]>::to_vec)
+            .collect::<Vec<_>>();
+
+        // Encoding is deferred until the client actually pulls on the stream.
+        let stream = tokio_stream::iter(batches).map(|chunks| {
+            let chunks = chunks
+                .into_iter()
+                .map(|(store_id, chunk)| {
+                    let arrow_msg = chunk.to_arrow_msg().map_err(internal)?;
+                    re_log_encoding::protobuf_conversions::arrow_msg_to_proto(
+                        &arrow_msg,
+                        store_id,
+                        re_log_encoding::Compression::Off,
+                    )
+                    .map_err(internal)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(GetChunksResponse { chunks })
+        });
+
+        Ok(Response::new(Box::pin(stream)))
+    }
+
+    // --- Tables ---
+
+    async fn register_table(
+        &self,
+        request: Request<RegisterTableRequest>,
+    ) -> Result<Response<RegisterTableResponse>, Status> {
+        let request: re_protos::catalog::v1alpha1::ext::RegisterTableRequest =
+            request.into_inner().try_into()?;
+
+        let table_url = LanceTable::try_from_any(&request.provider_details)?.table_url;
+        let root_dir = self.store.read().root_dir().to_owned();
+
+        let table = tokio::task::spawn_blocking(move || {
+            let path = local_path_from_url(table_url.as_str(), &root_dir)?;
+            if !crate::store::is_table_file(&path) {
+                return Err(Status::invalid_argument(format!(
+                    "only .arrow and .parquet files can be registered as tables, got {table_url}"
+                )));
+            }
+
+            Table::from_file(request.name, &path).map_err(internal)
+        })
+        .await
+        .map_err(internal)??;
+        let table_entry = table.as_entry()?;
+        self.store.write().insert_table(table)?;
+
+        Ok(Response::new(RegisterTableResponse {
+            table_entry: Some(table_entry.into()),
+        }))
+    }
+
+    async fn get_table_schema(
+        &self,
+        request: Request<GetTableSchemaRequest>,
+    ) -> Result<Response<GetTableSchemaResponse>, Status> {
+        let id: EntryId = request
+            .into_inner()
+            .table_id
+            .ok_or_else(|| Status::invalid_argument("table_id is required"))?
+            .try_into()?;
+
+        let store = self.store.read();
+        let table = store.table(id).ok_or_else(|| table_not_found(id))?;
+
+        Ok(Response::new(GetTableSchemaResponse {
+            schema: Some(table.schema.as_ref().try_into().map_err(internal)?),
+        }))
+    }
+
+    type ScanTableStream = ResponseStream<ScanTableResponse>;
+
+    async fn scan_table(
+        &self,
+        request: Request<ScanTableRequest>,
+    ) -> Result<Response<Self::ScanTableStream>, Status> {
+        let id: EntryId = request
+            .into_inner()
+            .table_id
+            .ok_or_else(|| Status::invalid_argument("table_id is required"))?
+            .try_into()?;
+
+        let batches = {
+            let store = self.store.read();
+            let table = store.table(id).ok_or_else(|| table_not_found(id))?;
+            table.batches.clone()
+        };
+
+        let stream = tokio_stream::iter(batches).map(|batch| {
+            Ok(ScanTableResponse {
+                dataframe_part: Some(encode(&batch)?),
+            })
+        });
+
+        Ok(Response::new(Box::pin(stream)))
+    }
+
+    // --- Tasks ---
+
+    async fn query_tasks(
+        &self,
+        request: Request<QueryTasksRequest>,
+    ) -> Result<Response<QueryTasksResponse>, Status> {
+        let batch = self.tasks_dataframe(&request.into_inner().ids)?;
+
+        Ok(Response::new(QueryTasksResponse {
+            data: Some(encode(&batch)?),
+        }))
+    }
+
+    async fn fetch_task_output(
+        &self,
+        _request: Request<FetchTaskOutputRequest>,
+    ) -> Result<Response<FetchTaskOutputResponse>, Status> {
+        Err(Status::unimplemented("tasks have no output"))
+    }
+
+    type QueryTasksOnCompletionStream = ResponseStream<QueryTasksOnCompletionResponse>;
+
+    async fn query_tasks_on_completion(
+        &self,
+        request: Request<QueryTasksOnCompletionRequest>,
+    ) -> Result<Response<Self::QueryTasksOnCompletionStream>, Status> {
+        // Tasks are always completed by the time they are created.
+        let batch = self.tasks_dataframe(&request.into_inner().ids)?;
+        let response = QueryTasksOnCompletionResponse {
+            data: Some(encode(&batch)?),
+        };
+
+        Ok(Response::new(Box::pin(tokio_stream::once(Ok(response)))))
+    }
+
+    async fn do_maintenance(
+        &self,
+        _request: Request<DoMaintenanceRequest>,
+    ) -> Result<Response<DoMaintenanceResponse>, Status> {
+        Err(Status::unimplemented("maintenance is not supported"))
+    }
+}
+
+impl FrontendHandler {
+    /// The status of the given tasks, following [`QueryTasksResponse::schema`].
+    fn tasks_dataframe(
+        &self,
+        ids: &[re_protos::common::v1alpha1::TaskId],
+    ) -> Result<RecordBatch, Status> {
+        use arrow::array::{TimestampNanosecondArray, UInt8Array, UInt64Array};
+
+        let store = self.store.read();
+        let tasks = ids
+            .iter()
+            .map(|id| {
+                store
+                    .task(&id.id)
+                    .ok_or_else(|| Status::not_found(format!("task {} not found", id.id)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let num_tasks = tasks.len();
+        let created_at = tasks
+            .iter()
+            .map(|task| Some(task.created_at.as_nanosecond() as i64))
+            .collect::<Vec<_>>();
+
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from_iter_values(
+                tasks.iter().map(|task| &task.id),
+            )),
+            Arc::new(StringArray::from_iter_values(
+                tasks.iter().map(|task| task.kind),
+            )),
+            Arc::new(StringArray::new_null(num_tasks)),
+            Arc::new(StringArray::from_iter_values(std::iter::repeat_n(
+                "success", num_tasks,
+            ))),
+            Arc::new(StringArray::from_iter_values(
+                tasks.iter().map(|task| &task.msg),
+            )),
+            Arc::new(UInt64Array::new_null(num_tasks)),
+            Arc::new(StringArray::new_null(num_tasks)),
+            Arc::new(TimestampNanosecondArray::new_null(num_tasks)),
+            Arc::new(UInt8Array::from(vec![1; num_tasks])),
+            Arc::new(TimestampNanosecondArray::from(created_at.clone())),
+            Arc::new(TimestampNanosecondArray::from(created_at)),
+        ];
+
+        RecordBatch::try_new(Arc::new(QueryTasksResponse::schema()), columns).map_err(internal)
+    }
+}
+
+// --- Chunk queries ---
+
+/// The chunk selection shared by `QueryDataset` and `GetChunks`.
+///
+/// Latest-at and range queries are evaluated at the chunk level: every chunk that could possibly
+/// contribute to the query results is returned, and it is up to the client to run the actual
+/// query on top of them.
+struct ChunkQuery {
+    dataset_id: EntryId,
+    partition_ids: Vec<String>,
+    chunk_ids: Vec<ChunkId>,
+
+    /// `None` means all entity paths.
+    entity_paths: Option<Vec<EntityPath>>,
+
+    fuzzy_descriptors: Vec<String>,
+    exclude_static_data: bool,
+    exclude_temporal_data: bool,
+    query: Option<Query>,
+}
+
+impl ChunkQuery {
+    /// Returns all matching chunks, along with the ID of the partition they belong to.
+    fn run(&self, store: &Store) -> Result<Vec<(StoreId, Arc<Chunk>)>, Status> {
+        re_tracing::profile_function!();
+
+        let dataset = store
+            .dataset(self.dataset_id)
+            .ok_or_else(|| dataset_not_found(self.dataset_id))?;
+
+        let partitions: Vec<&Partition> = if self.partition_ids.is_empty() {
+            dataset.partitions.values().collect()
+        } else {
+            self.partition_ids
+                .iter()
+                .map(|id| {
+                    dataset
+                        .partitions
+                        .get(id)
+                        .ok_or_else(|| Status::not_found(format!("partition {id:?} not found")))
+                })
+                .collect::<Result<_, _>>()?
+        };
+
+        Ok(partitions
+            .into_iter()
+            .flat_map(|partition| {
+                let store_id = partition.store_id();
+                partition
+                    .store
+                    .iter_chunks()
+                    .filter(|chunk| self.matches(chunk))
+                    .map(move |chunk| (store_id.clone(), chunk.clone()))
+                    .collect::<Vec<_>>()
+            })
+            .collect())
+    }
+
+    fn matches(&self, chunk: &Chunk) -> bool {
+        if !self.chunk_ids.is_empty() && !self.chunk_ids.contains(&chunk.id()) {
+            return false;
+        }
+
+        if let Some(entity_paths) = &self.entity_paths {
+            if !entity_paths.contains(chunk.entity_path()) {
+                return false;
+            }
+        }
+
+        if !self.fuzzy_descriptors.is_empty() {
+            let matches_any_descriptor = chunk.component_descriptors().any(|descr| {
+                let descr = descr.to_string();
+                self.fuzzy_descriptors
+                    .iter()
+                    .any(|needle| descr.contains(needle.as_str()))
+            });
+            if !matches_any_descriptor {
+                return false;
+            }
+        }
+
+        if chunk.is_static() {
+            // Static data is relevant to any query.
+            return !self.exclude_static_data;
+        }
+
+        if self.exclude_temporal_data {
+            return false;
+        }
+
+        let Some(query) = &self.query else {
+            return true;
+        };
+
+        let time_range = |index: &str| {
+            chunk
+                .timelines()
+                .get(&TimelineName::new(index))
+                .map(|time_column| time_column.time_range())
+        };
+
+        let matches_latest_at = query.latest_at.as_ref().is_some_and(|latest_at| {
+            latest_at.index.as_deref().is_some_and(|index| {
+                time_range(index).is_some_and(|range| range.min() <= latest_at.at)
+            })
+        });
+
+        let matches_range = query.range.as_ref().is_some_and(|range| {
+            time_range(&range.index).is_some_and(|chunk_range| {
+                chunk_range.intersects(range.index_range)
+                    // A range query also needs the latest state right before its start.
+                    || chunk_range.min() < range.index_range.min()
+            })
+        });
+
+        let is_unconstrained = query.latest_at.is_none() && query.range.is_none();
+
+        is_unconstrained || matches_latest_at || matches_range
+    }
+}
+
+impl TryFrom<QueryDatasetRequest> for ChunkQuery {
+    type Error = Status;
+
+    fn try_from(value: QueryDatasetRequest) -> Result<Self, Self::Error> {
+        // Same selection, the scan parameters are applied to the results separately.
+        GetChunksRequest {
+            dataset_id: value.dataset_id,
+            partition_ids: value.partition_ids,
+            chunk_ids: value.chunk_ids,
+            entity_paths: value.entity_paths,
+            select_all_entity_paths: value.select_all_entity_paths,
+            fuzzy_descriptors: value.fuzzy_descriptors,
+            exclude_static_data: value.exclude_static_data,
+            exclude_temporal_data: value.exclude_temporal_data,
+            query: value.query,
+        }
+        .try_into()
+    }
+}
+
+impl TryFrom<GetChunksRequest> for ChunkQuery {
+    type Error = Status;
+
+    fn try_from(value: GetChunksRequest) -> Result<Self, Self::Error> {
+        let GetChunksRequest {
+            dataset_id,
+            partition_ids,
+            chunk_ids,
+            entity_paths,
+            select_all_entity_paths,
+            fuzzy_descriptors,
+            exclude_static_data,
+            exclude_temporal_data,
+            query,
+        } = value;
+
+        if select_all_entity_paths && !entity_paths.is_empty() {
+            return Err(Status::invalid_argument(
+                "entity_paths must be empty when select_all_entity_paths is set",
+            ));
+        }
+
+        let entity_paths = (!select_all_entity_paths)
+            .then(|| {
+                entity_paths
+                    .into_iter()
+                    .map(|path| {
+                        path.try_into().map_err(|err| {
+                            Status::invalid_argument(format!("invalid entity path: {err}"))
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+
+        Ok(Self {
+            dataset_id: dataset_id
+                .ok_or_else(|| Status::invalid_argument("dataset_id is required"))?
+                .try_into()?,
+            partition_ids: partition_ids
+                .into_iter()
+                .map(|id| {
+                    id.id
+                        .ok_or_else(|| Status::invalid_argument("partition ID is required"))
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            chunk_ids: chunk_ids
+                .into_iter()
+                .map(|tuid| {
+                    let tuid: re_tuid::Tuid = tuid.try_into()?;
+                    Ok::<_, Status>(ChunkId::from_tuid(tuid))
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            entity_paths,
+            fuzzy_descriptors,
+            exclude_static_data,
+            exclude_temporal_data,
+            query: query.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+/// One row per chunk, identifying it and the partition it belongs to.
+fn chunk_info_dataframe(chunks: &[(StoreId, Arc<Chunk>)]) -> Result<RecordBatch, Status> {
+    use re_types_core::Loggable as _;
+
+    let chunk_ids = chunks
+        .iter()
+        .map(|(_, chunk)| chunk.id())
+        .collect::<Vec<_>>();
+    let chunk_ids = ChunkId::to_arrow(&chunk_ids).map_err(internal)?;
+
+    let schema = ArrowSchema::new_with_metadata(
+        vec![
+            Field::new("chunk_partition_id", DataType::Utf8, false),
+            Field::new("chunk_id", chunk_ids.data_type().clone(), false),
+            Field::new("chunk_entity_path", DataType::Utf8, false),
+            Field::new("chunk_is_static", DataType::Boolean, false),
+        ],
+        Default::default(),
+    );
+
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(StringArray::from_iter_values(
+            chunks.iter().map(|(store_id, _)| store_id.id.as_str()),
+        )),
+        chunk_ids,
+        Arc::new(StringArray::from_iter_values(
+            chunks
+                .iter()
+                .map(|(_, chunk)| chunk.entity_path().to_string()),
+        )),
+        Arc::new(
+            chunks
+                .iter()
+                .map(|(_, chunk)| Some(chunk.is_static()))
+                .collect::<BooleanArray>(),
+        ),
+    ];
+
+    RecordBatch::try_new(Arc::new(schema), columns).map_err(internal)
+}
+
+// --- Helpers ---
+
+/// Applies the column projection and limits of the given scan parameters.
+///
+/// Filtering and ordering are not supported.
+fn apply_scan_parameters(
+    batch: RecordBatch,
+    scan_parameters: Option<ScanParameters>,
+) -> Result<RecordBatch, Status> {
+    let Some(scan_parameters) = scan_parameters else {
+        return Ok(batch);
+    };
+    let scan_parameters: ScanParametersExt = scan_parameters.try_into()?;
+
+    if scan_parameters.filter.is_some() || !scan_parameters.order_by.is_empty() {
+        return Err(Status::unimplemented(
+            "filtering and ordering scans is not supported",
+        ));
+    }
+
+    let batch = if scan_parameters.columns.is_empty() {
+        batch
+    } else {
+        let schema = batch.schema();
+        let mut indices = Vec::with_capacity(scan_parameters.columns.len());
+        for column in &scan_parameters.columns {
+            match schema.index_of(column) {
+                Ok(index) => indices.push(index),
+                Err(_) if scan_parameters.on_missing_columns == IfMissingBehavior::Skip => {}
+                Err(_) => {
+                    return Err(Status::invalid_argument(format!(
+                        "unknown column {column:?}"
+                    )));
+                }
+            }
+        }
+        batch.project(&indices).map_err(internal)?
+    };
+
+    let offset = scan_parameters
+        .limit_offset
+        .map_or(0, |offset| (offset.max(0) as usize).min(batch.num_rows()));
+    let len = scan_parameters
+        .limit_len
+        .map_or(batch.num_rows() - offset, |len| {
+            (len.max(0) as usize).min(batch.num_rows() - offset)
+        });
+
+    Ok(batch.slice(offset, len))
+}
+
+/// The local path a `file://` URL points to, which must be within `root_dir`.
+///
+/// This does blocking IO.
+fn local_path_from_url(
+    url: &str,
+    root_dir: &std::path::Path,
+) -> Result<std::path::PathBuf, Status> {
+    let path = url::Url::parse(url)
+        .ok()
+        .filter(|parsed| parsed.scheme() == "file")
+        .and_then(|parsed| parsed.to_file_path().ok())
+        .ok_or_else(|| {
+            Status::invalid_argument(format!(
+                "only local file:// URLs are supported, got {url:?}"
+            ))
+        })?;
+
+    crate::store::resolve_path_within(root_dir, &path).map_err(|err| match err {
+        StoreError::Other(err) => Status::invalid_argument(format!("{err:#}")),
+        err => err.into(),
+    })
+}
+
+fn encode(batch: &RecordBatch) -> Result<DataframePart, Status> {
+    batch
+        .encode()
+        .map_err(|err| Status::internal(format!("failed to encode dataframe: {err}")))
+}
+
+fn internal(err: impl std::fmt::Display) -> Status {
+    Status::internal(err.to_string())
+}
+
+fn dataset_not_found(id: EntryId) -> Status {
+    Status::not_found(format!("dataset {id} not found"))
+}
+
+fn table_not_found(id: EntryId) -> Status {
+    Status::not_found(format!("table {id} not found"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use arrow::array::{Int64Array, RecordBatchOptions};
+    use re_chunk::{RowId, TimePoint};
+    use re_log_encoding::codec::wire::decoder::Decode as _;
+    use re_log_types::example_components::{MyPoint, MyPoints};
+    use re_log_types::{
+        ApplicationId, LogMsg, SetStoreInfo, StoreInfo, StoreKind, StoreSource, TimeInt,
+        build_frame_nr,
+    };
+    use re_protos::catalog::v1alpha1::EntryKind;
+    use re_protos::common::v1alpha1::TaskId;
+    use re_protos::manifest_registry::v1alpha1::DataSource;
+
+    use super::*;
+
+    fn write_recording(path: &Path, recording_id: &str) {
+        let store_id = StoreId::from_string(StoreKind::Recording, recording_id.to_owned());
+
+        let static_chunk = Chunk::builder(EntityPath::from("static"))
+            .with_archetype(
+                RowId::new(),
+                TimePoint::default(),
+                &MyPoints::new(MyPoint::from_iter(0..1)),
+            )
+            .build()
+            .unwrap();
+        let temporal_chunk = Chunk::builder(EntityPath::from("points"))
+            .with_archetype(
+                RowId::new(),
+                [build_frame_nr(TimeInt::new_temporal(1))],
+                &MyPoints::new(MyPoint::from_iter(0..3)),
+            )
+            .build()
+            .unwrap();
+
+        let messages = std::iter::once(LogMsg::SetStoreInfo(SetStoreInfo {
+            row_id: *RowId::new(),
+            info: StoreInfo {
+                application_id: ApplicationId("test".to_owned()),
+                store_id: store_id.clone(),
+                cloned_from: None,
+                store_source: StoreSource::Unknown,
+                store_version: None,
+            },
+        }))
+        .chain(
+            [static_chunk, temporal_chunk]
+                .into_iter()
+                .map(|chunk| LogMsg::ArrowMsg(store_id.clone(), chunk.to_arrow_msg().unwrap())),
+        );
+
+        let bytes = re_log_encoding::encoder::encode_as_bytes_local(messages.map(Ok)).unwrap();
+        std::fs::write(path, bytes).unwrap();
+    }
+
+    fn write_table(path: &Path) {
+        let schema = Arc::new(ArrowSchema::new_with_metadata(
+            vec![Field::new("value", DataType::Int64, false)],
+            Default::default(),
+        ));
+        let batch = RecordBatch::try_new_with_options(
+            schema.clone(),
+            vec![Arc::new(Int64Array::from(vec![1, 2, 3]))],
+            &RecordBatchOptions::default(),
+        )
+        .unwrap();
+
+        let file = std::fs::File::create(path).unwrap();
+        let mut writer = arrow::ipc::writer::FileWriter::try_new(file, &schema).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+    }
+
+    /// A root directory with a `my_dataset` dataset made of one recording, and a `my_table` table.
+    fn setup() -> (tempfile::TempDir, FrontendHandler) {
+        let root_dir = tempfile::tempdir().unwrap();
+
+        std::fs::create_dir(root_dir.path().join("my_dataset")).unwrap();
+        write_recording(&root_dir.path().join("my_dataset/a.rrd"), "recording_a");
+        write_table(&root_dir.path().join("my_table.arrow"));
+        std::fs::write(root_dir.path().join("README.md"), "ignored").unwrap();
+
+        let store = Store::load_directory(root_dir.path()).unwrap();
+        (root_dir, FrontendHandler::new(store))
+    }
+
+    async fn find_entry(handler: &FrontendHandler, name: &str) -> EntryId {
+        let entries = handler
+            .find_entries(Request::new(FindEntriesRequest {
+                filter: Some(EntryFilter::new().with_name(name)),
+            }))
+            .await
+            .unwrap()
+            .into_inner()
+            .entries;
+        assert_eq!(entries.len(), 1);
+
+        entries[0].id.unwrap().try_into().unwrap()
+    }
+
+    async fn get_chunks(handler: &FrontendHandler, request: GetChunksRequest) -> Vec<Chunk> {
+        let mut stream = handler
+            .get_chunks(Request::new(request))
+            .await
+            .unwrap()
+            .into_inner();
+
+        let mut chunks = Vec::new();
+        while let Some(response) = stream.next().await {
+            for arrow_msg in response.unwrap().chunks {
+                assert_eq!(arrow_msg.store_id.as_ref().unwrap().id, "recording_a");
+                let arrow_msg =
+                    re_log_encoding::protobuf_conversions::arrow_msg_from_proto(&arrow_msg)
+                        .unwrap();
+                chunks.push(Chunk::from_arrow_msg(&arrow_msg).unwrap());
+            }
+        }
+        chunks
+    }
+
+    #[tokio::test]
+    async fn test_find_entries() {
+        let (_root_dir, handler) = setup();
+
+        let entries = handler
+            .find_entries(Request::new(FindEntriesRequest { filter: None }))
+            .await
+            .unwrap()
+            .into_inner()
+            .entries;
+        let entries = entries
+            .into_iter()
+            .map(|entry| (entry.name.unwrap(), entry.entry_kind))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            entries,
+            vec![
+                ("my_dataset".to_owned(), EntryKind::Dataset as i32),
+                ("my_table".to_owned(), EntryKind::Table as i32),
+            ]
+        );
+
+        let tables = handler
+            .find_entries(Request::new(FindEntriesRequest {
+                filter: Some(EntryFilter::new().with_entry_kind(EntryKind::Table)),
+            }))
+            .await
+            .unwrap()
+            .into_inner()
+            .entries;
+        assert_eq!(tables.len(), 1);
+        assert_eq!(tables[0].name.as_deref(), Some("my_table"));
+    }
+
+    #[tokio::test]
+    async fn test_scan_partition_table() {
+        let (_root_dir, handler) = setup();
+        let dataset_id = find_entry(&handler, "my_dataset").await;
+
+        let mut stream = handler
+            .scan_partition_table(Request::new(ScanPartitionTableRequest {
+                dataset_id: Some(dataset_id.into()),
+                scan_parameters: None,
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        let batch = stream.next().await.unwrap().unwrap().data.unwrap();
+        let batch = batch.decode().unwrap();
+
+        assert_eq!(
+            batch.schema().as_ref(),
+            &ScanPartitionTableResponse::schema()
+        );
+        let partition_ids = batch
+            .column_by_name(ScanPartitionTableResponse::PARTITION_ID)
+            .unwrap();
+        assert_eq!(
+            partition_ids.as_ref(),
+            &StringArray::from(vec!["recording_a"]) as &dyn arrow::array::Array
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_chunks() {
+        let (_root_dir, handler) = setup();
+        let dataset_id = find_entry(&handler, "my_dataset").await;
+
+        let request = GetChunksRequest {
+            dataset_id: Some(dataset_id.into()),
+            select_all_entity_paths: true,
+            ..Default::default()
+        };
+
+        assert_eq!(get_chunks(&handler, request.clone()).await.len(), 2);
+
+        let static_chunks = get_chunks(
+            &handler,
+            GetChunksRequest {
+                exclude_temporal_data: true,
+                ..request.clone()
+            },
+        )
+        .await;
+        assert_eq!(static_chunks.len(), 1);
+        assert!(static_chunks[0].is_static());
+
+        let temporal_chunks = get_chunks(
+            &handler,
+            GetChunksRequest {
+                select_all_entity_paths: false,
+                entity_paths: vec![EntityPath::from("points").into()],
+                ..request.clone()
+            },
+        )
+        .await;
+        assert_eq!(temporal_chunks.len(), 1);
+        assert_eq!(
+            temporal_chunks[0].entity_path(),
+            &EntityPath::from("points")
+        );
+
+        // No entity paths selected at all.
+        let no_chunks = get_chunks(
+            &handler,
+            GetChunksRequest {
+                select_all_entity_paths: false,
+                ..request.clone()
+            },
+        )
+        .await;
+        assert!(no_chunks.is_empty());
+
+        let invalid = handler
+            .get_chunks(Request::new(GetChunksRequest {
+                entity_paths: vec![EntityPath::from("points").into()],
+                ..request
+            }))
+            .await;
+        assert_eq!(invalid.err().unwrap().code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn test_query_dataset() {
+        let (_root_dir, handler) = setup();
+        let dataset_id = find_entry(&handler, "my_dataset").await;
+
+        let mut stream = handler
+            .query_dataset(Request::new(QueryDatasetRequest {
+                dataset_id: Some(dataset_id.into()),
+                select_all_entity_paths: true,
+                scan_parameters: Some(ScanParameters {
+                    columns: vec!["chunk_partition_id".to_owned(), "chunk_id".to_owned()],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        let batch = stream.next().await.unwrap().unwrap().data.unwrap();
+        let batch = batch.decode().unwrap();
+
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(
+            batch
+                .schema()
+                .fields()
+                .iter()
+                .map(|field| field.name().as_str())
+                .collect::<Vec<_>>(),
+            vec!["chunk_partition_id", "chunk_id"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_scan_table() {
+        let (_root_dir, handler) = setup();
+        let table_id = find_entry(&handler, "my_table").await;
+
+        let schema = handler
+            .get_table_schema(Request::new(GetTableSchemaRequest {
+                table_id: Some(table_id.into()),
+            }))
+            .await
+            .unwrap()
+            .into_inner()
+            .schema
+            .unwrap();
+        let schema = ArrowSchema::try_from(&schema).unwrap();
+        assert_eq!(schema.field(0).name(), "value");
+
+        let mut stream = handler
+            .scan_table(Request::new(ScanTableRequest {
+                table_id: Some(table_id.into()),
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        let batch = stream
+            .next()
+            .await
+            .unwrap()
+            .unwrap()
+            .dataframe_part
+            .unwrap()
+            .decode()
+            .unwrap();
+        assert_eq!(batch.num_rows(), 3);
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_create_dataset_and_register() {
+        let (root_dir, handler) = setup();
+
+        let dataset = handler
+            .create_dataset_entry(Request::new(CreateDatasetEntryRequest {
+                name: Some("new_dataset".to_owned()),
+            }))
+            .await
+            .unwrap()
+            .into_inner()
+            .dataset
+            .unwrap();
+        let dataset_id: EntryId = dataset.details.unwrap().id.unwrap().try_into().unwrap();
+        assert!(root_dir.path().join("new_dataset").is_dir());
+
+        let duplicate = handler
+            .create_dataset_entry(Request::new(CreateDatasetEntryRequest {
+                name: Some("my_table".to_owned()),
+            }))
+            .await;
+        assert_eq!(duplicate.err().unwrap().code(), tonic::Code::AlreadyExists);
+
+        let rrd_path = root_dir.path().join("b.rrd");
+        write_recording(&rrd_path, "recording_b");
+        let register = |on_duplicate: re_protos::common::v1alpha1::IfDuplicateBehavior| {
+            handler.register_with_dataset(Request::new(RegisterWithDatasetRequest {
+                dataset_id: Some(dataset_id.into()),
+                data_sources: vec![DataSource {
+                    storage_url: Some(url::Url::from_file_path(&rrd_path).unwrap().to_string()),
+                    typ: DataSourceKind::Rrd as i32,
+                }],
+                on_duplicate: on_duplicate as i32,
+            }))
+        };
+
+        let batch = register(re_protos::common::v1alpha1::IfDuplicateBehavior::Error)
+            .await
+            .unwrap()
+            .into_inner()
+            .data
+            .unwrap()
+            .decode()
+            .unwrap();
+        assert_eq!(batch.num_rows(), 1);
+        let task_id = batch
+            .column_by_name(RegisterWithDatasetResponse::TASK_ID)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap()
+            .value(0)
+            .to_owned();
+
+        let tasks = handler
+            .query_tasks(Request::new(QueryTasksRequest {
+                ids: vec![TaskId { id: task_id }],
+            }))
+            .await
+            .unwrap()
+            .into_inner()
+            .data
+            .unwrap()
+            .decode()
+            .unwrap();
+        let exec_status = tasks
+            .column_by_name(QueryTasksResponse::EXEC_STATUS)
+            .unwrap();
+        assert_eq!(
+            exec_status.as_ref(),
+            &StringArray::from(vec!["success"]) as &dyn arrow::array::Array
+        );
+
+        let duplicate = register(re_protos::common::v1alpha1::IfDuplicateBehavior::Error).await;
+        assert_eq!(duplicate.err().unwrap().code(), tonic::Code::AlreadyExists);
+        assert!(
+            register(re_protos::common::v1alpha1::IfDuplicateBehavior::Skip)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_register_outside_root_dir() {
+        let (root_dir, handler) = setup();
+        let dataset_id = find_entry(&handler, "my_dataset").await;
+
+        let other_dir = tempfile::tempdir().unwrap();
+        let outside = other_dir.path().join("outside.rrd");
+        write_recording(&outside, "recording_outside");
+
+        // `..` and symlinks are resolved before checking.
+        let escaping = root_dir
+            .path()
+            .join("my_dataset/../..")
+            .join(other_dir.path().strip_prefix("/").unwrap())
+            .join("outside.rrd");
+
+        for path in [outside, escaping] {
+            let result = handler
+                .register_with_dataset(Request::new(RegisterWithDatasetRequest {
+                    dataset_id: Some(dataset_id.into()),
+                    data_sources: vec![DataSource {
+                        storage_url: Some(url::Url::from_file_path(&path).unwrap().to_string()),
+                        typ: DataSourceKind::Rrd as i32,
+                    }],
+                    on_duplicate: re_protos::common::v1alpha1::IfDuplicateBehavior::Error as i32,
+                }))
+                .await;
+            assert_eq!(
+                result.err().unwrap().code(),
+                tonic::Code::PermissionDenied,
+                "{path:?}"
+            );
+        }
+    }
+}
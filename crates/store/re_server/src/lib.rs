@@ -0,0 +1,144 @@
+//! Local, file-backed implementation of the Rerun Data Platform catalog API.
+//!
+//! The server exposes the `FrontendService` over a root directory:
+//! * every sub-directory is a dataset, whose partitions are the recordings in its `.rrd` files,
+//! * every `.arrow` (Arrow IPC file format) or `.parquet` file is a table.
+//!
+//! The directory is only read at startup. Entries created or registered through the API live in
+//! memory for as long as the server runs, and existing files are never modified.
+//!
+//! Indexing, searching, `WriteChunks` and maintenance operations are not supported.
+//!
+//! Only files within the root directory can be registered, and web clients are only allowed from
+//! pages served by this machine or from the official web viewer (see [`is_allowed_origin`]).
+
+mod frontend;
+mod store;
+
+pub use self::frontend::FrontendHandler;
+pub use self::store::{ARROW_EXTENSION, PARQUET_EXTENSION, RRD_EXTENSION, Store};
+
+use std::net::SocketAddr;
+
+use tokio::net::TcpListener;
+use tonic::codegen::http::HeaderValue;
+use tonic::transport::Server;
+use tonic::transport::server::TcpIncoming;
+use tower_http::cors::{AllowOrigin, Any, CorsLayer};
+
+pub use re_grpc_server::shutdown;
+
+/// Default port of the catalog server, the same as the one assumed by `rerun+http://…/catalog` URLs.
+pub const DEFAULT_SERVER_PORT: u16 = re_uri::DEFAULT_REDAP_PORT;
+
+pub const MAX_DECODING_MESSAGE_SIZE: usize = re_grpc_server::MAX_DECODING_MESSAGE_SIZE;
+pub const MAX_ENCODING_MESSAGE_SIZE: usize = re_grpc_server::MAX_ENCODING_MESSAGE_SIZE;
+
+/// Start a catalog server serving the contents of `root_dir`, listening on `addr`.
+///
+/// See the [crate-level documentation](crate) for how the directory is interpreted.
+///
+/// The returned future must be polled for the server to make progress.
+pub async fn serve(
+    addr: SocketAddr,
+    root_dir: impl Into<std::path::PathBuf>,
+    shutdown: shutdown::Shutdown,
+) -> anyhow::Result<()> {
+    let root_dir = root_dir.into();
+    let store = tokio::task::spawn_blocking(move || Store::load_directory(root_dir)).await??;
+
+    serve_impl(addr, FrontendHandler::new(store), shutdown).await
+}
+
+async fn serve_impl(
+    addr: SocketAddr,
+    handler: FrontendHandler,
+    shutdown: shutdown::Shutdown,
+) -> anyhow::Result<()> {
+    let tcp_listener = TcpListener::bind(addr).await?;
+    let incoming = TcpIncoming::from(tcp_listener).with_nodelay(Some(true));
+
+    let connect_addr = if addr.ip().is_loopback() || addr.ip().is_unspecified() {
+        format!("rerun+http://127.0.0.1:{}/catalog", addr.port())
+    } else {
+        format!("rerun+http://{addr}/catalog")
+    };
+    re_log::info!(
+        "Listening for gRPC connections on {addr}. Connect by running `rerun {connect_addr}`"
+    );
+
+    let cors = CorsLayer::new()
+        .allow_origin(AllowOrigin::predicate(|origin, _| {
+            is_allowed_origin(origin)
+        }))
+        .allow_methods(Any)
+        .allow_headers(Any)
+        .expose_headers(Any);
+    let grpc_web = tonic_web::GrpcWebLayer::new();
+
+    let routes = {
+        let mut routes_builder = tonic::service::Routes::builder();
+        routes_builder.add_service(
+            re_protos::frontend::v1alpha1::frontend_service_server::FrontendServiceServer::new(
+                handler,
+            )
+            .max_decoding_message_size(MAX_DECODING_MESSAGE_SIZE)
+            .max_encoding_message_size(MAX_ENCODING_MESSAGE_SIZE),
+        );
+        routes_builder.routes()
+    };
+
+    Server::builder()
+        .accept_http1(true) // Support `grpc-web` clients
+        .layer(cors) // Allow CORS requests from web clients
+        .layer(grpc_web) // Support `grpc-web` clients
+        .add_routes(routes)
+        .serve_with_incoming_shutdown(incoming, shutdown.wait())
+        .await?;
+
+    Ok(())
+}
+
+/// Is a web page served from `origin` allowed to make requests to the server?
+///
+/// The server gives access to local files, so arbitrary web pages must not be able to reach it:
+/// only loopback origins and the official web viewer are allowed.
+pub fn is_allowed_origin(origin: &HeaderValue) -> bool {
+    let Some(origin) = origin
+        .to_str()
+        .ok()
+        .and_then(|origin| url::Url::parse(origin).ok())
+    else {
+        return false;
+    };
+
+    match origin.host() {
+        Some(url::Host::Domain(domain)) => {
+            domain == "localhost" || (origin.scheme() == "https" && domain == "app.rerun.io")
+        }
+        Some(url::Host::Ipv4(ip)) => ip.is_loopback(),
+        Some(url::Host::Ipv6(ip)) => ip.is_loopback(),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allowed_origins() {
+        let is_allowed = |origin| is_allowed_origin(&HeaderValue::from_static(origin));
+
+        assert!(is_allowed("http://localhost:9090"));
+        assert!(is_allowed("http://127.0.0.1:9090"));
+        assert!(is_allowed("http://[::1]"));
+        assert!(is_allowed("https://app.rerun.io"));
+
+        assert!(!is_allowed("http://app.rerun.io"));
+        assert!(!is_allowed("https://example.com"));
+        assert!(!is_allowed("https://localhost.example.com"));
+        assert!(!is_allowed("http://192.168.1.10:9090"));
+        assert!(!is_allowed("null"));
+    }
+}
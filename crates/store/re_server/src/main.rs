@@ -0,0 +1,30 @@
+use std::net::Ipv4Addr;
+use std::net::SocketAddr;
+use std::net::SocketAddrV4;
+
+use anyhow::Context as _;
+
+use re_server::{DEFAULT_SERVER_PORT, serve, shutdown};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let mut args = std::env::args_os().skip(1);
+    let Some(root_dir) = args.next() else {
+        anyhow::bail!("Usage: re_server <DIRECTORY> [BIND_ADDRESS]");
+    };
+
+    // The server gives access to local files: only listen on the loopback interface by default.
+    let addr = match args.next() {
+        Some(addr) => addr
+            .to_string_lossy()
+            .parse()
+            .with_context(|| format!("invalid bind address: {addr:?}"))?,
+        None => SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, DEFAULT_SERVER_PORT)),
+    };
+
+    serve(addr, root_dir, shutdown::never()).await?;
+
+    Ok(())
+}
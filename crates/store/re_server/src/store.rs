@@ -0,0 +1,527 @@
+//! The in-memory model of the catalog: datasets, their partitions, tables and registration tasks.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::Context as _;
+use arrow::array::RecordBatch;
+use arrow::datatypes::{Schema as ArrowSchema, SchemaRef as ArrowSchemaRef};
+
+use re_chunk_store::{ChunkStore, ChunkStoreConfig};
+use re_log_types::{EntryId, StoreId, StoreKind};
+use re_protos::catalog::v1alpha1::EntryKind;
+use re_protos::catalog::v1alpha1::ext::{DatasetDetails, DatasetEntry, EntryDetails, TableEntry};
+use re_protos::common::v1alpha1::ext::{DatasetHandle, IfDuplicateBehavior};
+
+/// File extension of the recordings making up the partitions of a dataset.
+pub const RRD_EXTENSION: &str = "rrd";
+
+/// File extension of Arrow IPC files, which are served as tables.
+pub const ARROW_EXTENSION: &str = "arrow";
+
+/// File extension of Parquet files, which are served as tables.
+pub const PARQUET_EXTENSION: &str = "parquet";
+
+// ---
+
+/// All the entries served by a server, as loaded from its root directory.
+///
+/// Only the initial contents come from disk: entries created, registered or deleted through the
+/// API only ever live in memory, and the files on disk are never modified (with the exception of
+/// dataset creation, which creates an empty directory, see [`Store::new_dataset_dir`]).
+///
+/// Files registered through the API must live within the root directory.
+pub struct Store {
+    root_dir: PathBuf,
+    datasets: HashMap<EntryId, Dataset>,
+    tables: HashMap<EntryId, Table>,
+    tasks: HashMap<String, Task>,
+}
+
+impl Store {
+    /// Loads all datasets and tables found in `root_dir`.
+    ///
+    /// * Every sub-directory is a dataset named after it, and the recordings of every `.rrd` file
+    ///   it contains become its partitions.
+    /// * Every `.arrow` (IPC file format) or `.parquet` file is a table named after its file stem.
+    ///
+    /// Anything else is ignored.
+    pub fn load_directory(root_dir: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        let root_dir = root_dir.into();
+        let root_dir = std::fs::canonicalize(&root_dir)
+            .with_context(|| format!("couldn't read directory {root_dir:?}"))?;
+
+        re_tracing::profile_function!(root_dir.display().to_string());
+
+        let mut store = Self {
+            root_dir: root_dir.clone(),
+            datasets: HashMap::default(),
+            tables: HashMap::default(),
+            tasks: HashMap::default(),
+        };
+
+        let mut paths = std::fs::read_dir(&root_dir)
+            .with_context(|| format!("couldn't read directory {root_dir:?}"))?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()
+            .with_context(|| format!("couldn't read directory {root_dir:?}"))?;
+        paths.sort();
+
+        for path in paths {
+            let Some(name) = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+            else {
+                continue;
+            };
+
+            if path.is_dir() {
+                let mut dataset = Dataset::new(name, path.clone())?;
+                dataset.load_partitions_from_directory()?;
+
+                re_log::debug!(
+                    ?path,
+                    num_partitions = dataset.partitions.len(),
+                    "Loaded dataset"
+                );
+                store.insert_dataset(dataset)?;
+            } else if is_table_file(&path) {
+                let table = Table::from_file(name, &path)?;
+
+                re_log::debug!(?path, num_rows = table.num_rows(), "Loaded table");
+                store.insert_table(table)?;
+            }
+        }
+
+        Ok(store)
+    }
+
+    /// The canonical path of the root directory.
+    pub fn root_dir(&self) -> &Path {
+        &self.root_dir
+    }
+
+    /// All entries, sorted by name.
+    pub fn entries(&self) -> Vec<EntryDetails> {
+        let mut entries = self
+            .datasets
+            .values()
+            .map(|dataset| dataset.details.clone())
+            .chain(self.tables.values().map(|table| table.details.clone()))
+            .collect::<Vec<_>>();
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        entries
+    }
+
+    pub fn dataset(&self, id: EntryId) -> Option<&Dataset> {
+        self.datasets.get(&id)
+    }
+
+    pub fn dataset_mut(&mut self, id: EntryId) -> Option<&mut Dataset> {
+        self.datasets.get_mut(&id)
+    }
+
+    pub fn table(&self, id: EntryId) -> Option<&Table> {
+        self.tables.get(&id)
+    }
+
+    /// Validates the name of a new dataset, and returns the directory in the root directory that
+    /// should back it.
+    ///
+    /// Creating the directory is left to the caller, so that no blocking IO happens while the store
+    /// is locked. The dataset can then be added with [`Self::insert_dataset`].
+    pub fn new_dataset_dir(&self, name: &str) -> Result<PathBuf, StoreError> {
+        if name.is_empty() || name.contains(['/', '\\']) || name == "." || name == ".." {
+            return Err(StoreError::InvalidName(name.to_owned()));
+        }
+
+        self.check_name_is_available(name)?;
+
+        Ok(self.root_dir.join(name))
+    }
+
+    pub fn insert_dataset(&mut self, dataset: Dataset) -> Result<(), StoreError> {
+        self.check_name_is_available(&dataset.details.name)?;
+        self.datasets.insert(dataset.id(), dataset);
+        Ok(())
+    }
+
+    pub fn insert_table(&mut self, table: Table) -> Result<(), StoreError> {
+        self.check_name_is_available(&table.details.name)?;
+        self.tables.insert(table.id(), table);
+        Ok(())
+    }
+
+    /// Removes an entry from the catalog.
+    ///
+    /// The underlying files are left untouched.
+    pub fn delete_entry(&mut self, id: EntryId) -> Result<(), StoreError> {
+        if self.datasets.remove(&id).is_some() || self.tables.remove(&id).is_some() {
+            Ok(())
+        } else {
+            Err(StoreError::EntryNotFound(id))
+        }
+    }
+
+    pub fn insert_task(&mut self, task: Task) {
+        self.tasks.insert(task.id.clone(), task);
+    }
+
+    pub fn task(&self, id: &str) -> Option<&Task> {
+        self.tasks.get(id)
+    }
+
+    fn check_name_is_available(&self, name: &str) -> Result<(), StoreError> {
+        let is_taken = self
+            .datasets
+            .values()
+            .map(|dataset| &dataset.details.name)
+            .chain(self.tables.values().map(|table| &table.details.name))
+            .any(|existing| existing == name);
+
+        if is_taken {
+            Err(StoreError::DuplicateName(name.to_owned()))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum StoreError {
+    #[error("entry {0} not found")]
+    EntryNotFound(EntryId),
+
+    #[error("an entry named {0:?} already exists")]
+    DuplicateName(String),
+
+    #[error("invalid entry name {0:?}")]
+    InvalidName(String),
+
+    #[error("partition {0:?} already exists")]
+    DuplicatePartition(String),
+
+    #[error("{0:?} is outside of the server's root directory")]
+    OutsideRootDir(PathBuf),
+
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+impl From<StoreError> for tonic::Status {
+    fn from(err: StoreError) -> Self {
+        match err {
+            StoreError::EntryNotFound(_) => Self::not_found(err.to_string()),
+            StoreError::DuplicateName(_) | StoreError::DuplicatePartition(_) => {
+                Self::already_exists(err.to_string())
+            }
+            StoreError::InvalidName(_) => Self::invalid_argument(err.to_string()),
+            StoreError::OutsideRootDir(_) => Self::permission_denied(err.to_string()),
+            StoreError::Other(err) => Self::internal(format!("{err:#}")),
+        }
+    }
+}
+
+// --- Datasets ---
+
+/// A dataset: a named collection of partitions, each of which is a single recording.
+pub struct Dataset {
+    pub details: EntryDetails,
+    pub dataset_details: DatasetDetails,
+
+    /// The directory the dataset was loaded from.
+    pub dir: PathBuf,
+    pub url: url::Url,
+
+    /// Partitions, keyed by partition ID (i.e. recording ID).
+    pub partitions: BTreeMap<String, Partition>,
+}
+
+impl Dataset {
+    pub fn new(name: String, dir: PathBuf) -> anyhow::Result<Self> {
+        let now = jiff::Timestamp::now();
+
+        Ok(Self {
+            details: EntryDetails {
+                id: EntryId::new(),
+                name,
+                kind: EntryKind::Dataset,
+                created_at: now,
+                updated_at: now,
+            },
+            dataset_details: DatasetDetails::default(),
+            url: file_url(&dir)?,
+            dir,
+            partitions: BTreeMap::default(),
+        })
+    }
+
+    #[inline]
+    pub fn id(&self) -> EntryId {
+        self.details.id
+    }
+
+    pub fn as_entry(&self) -> DatasetEntry {
+        DatasetEntry {
+            details: self.details.clone(),
+            dataset_details: self.dataset_details.clone(),
+            handle: DatasetHandle {
+                id: Some(self.id()),
+                store_kind: StoreKind::Recording,
+                url: self.url.clone(),
+            },
+        }
+    }
+
+    /// Loads all the `.rrd` files in [`Self::dir`] as partitions.
+    fn load_partitions_from_directory(&mut self) -> anyhow::Result<()> {
+        let mut paths = std::fs::read_dir(&self.dir)
+            .with_context(|| format!("couldn't read dataset directory {:?}", self.dir))?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()
+            .with_context(|| format!("couldn't read dataset directory {:?}", self.dir))?;
+        paths.sort();
+
+        for path in paths {
+            if has_extension(&path, RRD_EXTENSION) {
+                self.insert_partitions(load_rrd(&path)?, IfDuplicateBehavior::Error)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds the given partitions to the dataset.
+    ///
+    /// Returns the IDs of the partitions that are now part of the dataset, including the ones that
+    /// were skipped because they already existed.
+    /// Nothing is inserted if any of them is a duplicate and `on_duplicate` is
+    /// [`IfDuplicateBehavior::Error`].
+    pub fn insert_partitions(
+        &mut self,
+        partitions: Vec<(String, Partition)>,
+        on_duplicate: IfDuplicateBehavior,
+    ) -> Result<Vec<String>, StoreError> {
+        if on_duplicate == IfDuplicateBehavior::Error {
+            if let Some((partition_id, _)) = partitions
+                .iter()
+                .find(|(partition_id, _)| self.partitions.contains_key(partition_id))
+            {
+                return Err(StoreError::DuplicatePartition(partition_id.clone()));
+            }
+        }
+
+        let mut partition_ids = Vec::with_capacity(partitions.len());
+        for (partition_id, partition) in partitions {
+            let is_duplicate = self.partitions.contains_key(&partition_id);
+            if !is_duplicate || on_duplicate == IfDuplicateBehavior::Overwrite {
+                self.details.updated_at = partition.registration_time;
+                self.partitions.insert(partition_id.clone(), partition);
+            }
+            partition_ids.push(partition_id);
+        }
+
+        Ok(partition_ids)
+    }
+}
+
+/// A single recording within a dataset.
+pub struct Partition {
+    pub store: Arc<ChunkStore>,
+
+    /// Where the recording was loaded from.
+    pub storage_url: url::Url,
+
+    pub registration_time: jiff::Timestamp,
+}
+
+impl Partition {
+    #[inline]
+    pub fn store_id(&self) -> StoreId {
+        self.store.id()
+    }
+}
+
+/// Loads every recording found in the RRD file at `path` as a partition, keyed by recording ID.
+///
+/// Blueprints are ignored.
+pub fn load_rrd(path: &Path) -> anyhow::Result<Vec<(String, Partition)>> {
+    re_tracing::profile_function!(path.display().to_string());
+
+    let stores = ChunkStore::from_rrd_filepath(&ChunkStoreConfig::ALL_DISABLED, path)?;
+    let storage_url = file_url(path)?;
+    let registration_time = jiff::Timestamp::now();
+
+    Ok(stores
+        .into_iter()
+        .filter(|(store_id, _)| store_id.kind == StoreKind::Recording)
+        .map(|(store_id, store)| {
+            let partition = Partition {
+                store: Arc::new(store),
+                storage_url: storage_url.clone(),
+                registration_time,
+            };
+            (store_id.id.to_string(), partition)
+        })
+        .collect())
+}
+
+// --- Tables ---
+
+/// A table, loaded in its entirety from an Arrow IPC or Parquet file.
+pub struct Table {
+    pub details: EntryDetails,
+
+    /// The file the table was loaded from.
+    pub path: PathBuf,
+    pub url: url::Url,
+
+    pub schema: ArrowSchemaRef,
+    pub batches: Vec<RecordBatch>,
+}
+
+impl Table {
+    pub fn from_file(name: String, path: &Path) -> anyhow::Result<Self> {
+        re_tracing::profile_function!(path.display().to_string());
+
+        let file =
+            std::fs::File::open(path).with_context(|| format!("couldn't open table {path:?}"))?;
+
+        let (schema, batches) = if has_extension(path, PARQUET_EXTENSION) {
+            let reader =
+                parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder::try_new(file)?
+                    .build()?;
+            let schema = arrow::array::RecordBatchReader::schema(&reader);
+            (schema, reader.collect::<Result<Vec<_>, _>>()?)
+        } else {
+            let reader = arrow::ipc::reader::FileReader::try_new(file, None)?;
+            let schema = reader.schema();
+            (schema, reader.collect::<Result<Vec<_>, _>>()?)
+        };
+
+        let now = jiff::Timestamp::now();
+
+        Ok(Self {
+            details: EntryDetails {
+                id: EntryId::new(),
+                name,
+                kind: EntryKind::Table,
+                created_at: now,
+                updated_at: now,
+            },
+            path: path.to_owned(),
+            url: file_url(path)?,
+            schema,
+            batches,
+        })
+    }
+
+    #[inline]
+    pub fn id(&self) -> EntryId {
+        self.details.id
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.batches.iter().map(|batch| batch.num_rows()).sum()
+    }
+
+    pub fn as_entry(&self) -> Result<TableEntry, re_protos::TypeConversionError> {
+        use re_protos::catalog::v1alpha1::ext::{LanceTable, ProviderDetails as _};
+
+        // The protocol has no dedicated provider for plain files; a `LanceTable` pointing at the
+        // file is the closest match, and is all clients need to tell it apart from system tables.
+        let provider_details = LanceTable {
+            table_url: self.url.clone(),
+        }
+        .try_as_any()?;
+
+        Ok(TableEntry {
+            details: self.details.clone(),
+            provider_details,
+        })
+    }
+}
+
+/// Whether the file at `path` can be served as a [`Table`].
+/// Resolves a path coming from a client, making sure it points within `root_dir`.
+///
+/// `root_dir` must already be canonical, see [`Store::root_dir`].
+/// This does blocking IO.
+pub fn resolve_path_within(root_dir: &Path, path: &Path) -> Result<PathBuf, StoreError> {
+    // Resolves symlinks and `..`, so that they cannot be used to escape the root directory.
+    let canonical =
+        std::fs::canonicalize(path).with_context(|| format!("couldn't read {path:?}"))?;
+
+    if canonical.starts_with(root_dir) {
+        Ok(canonical)
+    } else {
+        Err(StoreError::OutsideRootDir(path.to_owned()))
+    }
+}
+
+pub fn is_table_file(path: &Path) -> bool {
+    path.is_file()
+        && (has_extension(path, ARROW_EXTENSION) || has_extension(path, PARQUET_EXTENSION))
+}
+
+// --- Tasks ---
+
+/// A registration task.
+///
+/// Registrations happen synchronously, so tasks are always already completed by the time
+/// clients learn about them.
+pub struct Task {
+    pub id: String,
+    pub kind: &'static str,
+
+    /// Human-readable description of what the task did.
+    pub msg: String,
+
+    pub created_at: jiff::Timestamp,
+}
+
+impl Task {
+    pub const KIND_REGISTER_RRD: &'static str = "register_rrd";
+
+    pub fn new_register_rrd(msg: String) -> Self {
+        Self {
+            id: re_tuid::Tuid::new().to_string(),
+            kind: Self::KIND_REGISTER_RRD,
+            msg,
+            created_at: jiff::Timestamp::now(),
+        }
+    }
+}
+
+// ---
+
+/// The union of the schemas of all the given partitions.
+pub fn merged_dataset_schema<'a>(partitions: impl Iterator<Item = &'a Partition>) -> ArrowSchema {
+    let mut fields: Vec<arrow::datatypes::FieldRef> = Vec::new();
+    for partition in partitions {
+        for field in partition.store.schema().arrow_fields() {
+            if !fields
+                .iter()
+                .any(|existing| existing.name() == field.name())
+            {
+                fields.push(field.into());
+            }
+        }
+    }
+
+    ArrowSchema::new_with_metadata(fields, Default::default())
+}
+
+fn has_extension(path: &Path, extension: &str) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case(extension))
+}
+
+/// The `file://` URL of the given path.
+fn file_url(path: &Path) -> anyhow::Result<url::Url> {
+    let path =
+        std::path::absolute(path).with_context(|| format!("couldn't make {path:?} absolute"))?;
+
+    url::Url::from_file_path(&path).map_err(|()| anyhow::anyhow!("invalid file path {path:?}"))
+}
@@ -30,11 +30,22 @@ impl ChunkColumnDescriptors {
         }
     }
 
+    /// Returns the `row_id` column, then all indices and then all components.
+    ///
+    /// See also [`Self::indices_and_components`].
+    pub fn row_id_indices_and_components(&self) -> Vec<ColumnDescriptor> {
+        std::iter::once(ColumnDescriptor::RowId(self.row_id.clone()))
+            .chain(self.indices_and_components())
+            .collect()
+    }
+
     /// Returns all indices and then all components;
     /// skipping the `row_id` column.
     ///
-    /// See also [`Self::get_index_or_component`].
-    // TODO(#9922): stop ignoring row_id
+    /// The `row_id` of a dataframe row is not stored in any single index or component column, so
+    /// it is resolved separately by the query engine.
+    ///
+    /// See also [`Self::get_index_or_component`] and [`Self::row_id_indices_and_components`].
     pub fn indices_and_components(&self) -> Vec<ColumnDescriptor> {
         itertools::chain!(
             self.indices.iter().cloned().map(ColumnDescriptor::Time),
@@ -52,7 +63,6 @@ impl ChunkColumnDescriptors {
     /// the first component column).
     ///
     /// See also [`Self::indices_and_components`].
-    // TODO(#9922): stop ignoring row_id
     pub fn get_index_or_component(&self, index_ignoring_row_id: usize) -> Option<ColumnDescriptor> {
         if index_ignoring_row_id < self.indices.len() {
             Some(ColumnDescriptor::Time(
@@ -71,6 +71,11 @@ pub struct TextLog {
 
     /// Optional color to use for the log line in the Rerun Viewer.
     pub color: Option<SerializedComponentBatch>,
+
+    /// Optional structured key-value fields attached to the message.
+    ///
+    /// These are shown as extra columns in the Rerun Viewer.
+    pub fields: Option<SerializedComponentBatch>,
 }
 
 impl TextLog {
@@ -109,6 +114,18 @@ impl TextLog {
             component_type: Some("rerun.components.Color".into()),
         }
     }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::fields`].
+    ///
+    /// The corresponding component is [`crate::components::TextLogField`].
+    #[inline]
+    pub fn descriptor_fields() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.archetypes.TextLog".into()),
+            component: "TextLog:fields".into(),
+            component_type: Some("rerun.components.TextLogField".into()),
+        }
+    }
 }
 
 static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
@@ -117,21 +134,22 @@ static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]>
 static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
     once_cell::sync::Lazy::new(|| [TextLog::descriptor_level()]);
 
-static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
-    once_cell::sync::Lazy::new(|| [TextLog::descriptor_color()]);
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
+    once_cell::sync::Lazy::new(|| [TextLog::descriptor_color(), TextLog::descriptor_fields()]);
 
-static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 3usize]> =
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 4usize]> =
     once_cell::sync::Lazy::new(|| {
         [
             TextLog::descriptor_text(),
             TextLog::descriptor_level(),
             TextLog::descriptor_color(),
+            TextLog::descriptor_fields(),
         ]
     });
 
 impl TextLog {
-    /// The total number of components in the archetype: 1 required, 1 recommended, 1 optional
-    pub const NUM_COMPONENTS: usize = 3usize;
+    /// The total number of components in the archetype: 1 required, 1 recommended, 2 optional
+    pub const NUM_COMPONENTS: usize = 4usize;
 }
 
 impl ::re_types_core::Archetype for TextLog {
@@ -181,7 +199,15 @@ impl ::re_types_core::Archetype for TextLog {
         let color = arrays_by_descr
             .get(&Self::descriptor_color())
             .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_color()));
-        Ok(Self { text, level, color })
+        let fields = arrays_by_descr
+            .get(&Self::descriptor_fields())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_fields()));
+        Ok(Self {
+            text,
+            level,
+            color,
+            fields,
+        })
     }
 }
 
@@ -189,10 +215,15 @@ impl ::re_types_core::AsComponents for TextLog {
     #[inline]
     fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
         use ::re_types_core::Archetype as _;
-        [self.text.clone(), self.level.clone(), self.color.clone()]
-            .into_iter()
-            .flatten()
-            .collect()
+        [
+            self.text.clone(),
+            self.level.clone(),
+            self.color.clone(),
+            self.fields.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
     }
 }
 
@@ -206,6 +237,7 @@ impl TextLog {
             text: try_serialize_field(Self::descriptor_text(), [text]),
             level: None,
             color: None,
+            fields: None,
         }
     }
 
@@ -232,6 +264,10 @@ impl TextLog {
                 crate::components::Color::arrow_empty(),
                 Self::descriptor_color(),
             )),
+            fields: Some(SerializedComponentBatch::new(
+                crate::components::TextLogField::arrow_empty(),
+                Self::descriptor_fields(),
+            )),
         }
     }
 
@@ -263,6 +299,9 @@ impl TextLog {
             self.color
                 .map(|color| color.partitioned(_lengths.clone()))
                 .transpose()?,
+            self.fields
+                .map(|fields| fields.partitioned(_lengths.clone()))
+                .transpose()?,
         ];
         Ok(columns.into_iter().flatten())
     }
@@ -278,7 +317,13 @@ impl TextLog {
         let len_text = self.text.as_ref().map(|b| b.array.len());
         let len_level = self.level.as_ref().map(|b| b.array.len());
         let len_color = self.color.as_ref().map(|b| b.array.len());
-        let len = None.or(len_text).or(len_level).or(len_color).unwrap_or(0);
+        let len_fields = self.fields.as_ref().map(|b| b.array.len());
+        let len = None
+            .or(len_text)
+            .or(len_level)
+            .or(len_color)
+            .or(len_fields)
+            .unwrap_or(0);
         self.columns(std::iter::repeat(1).take(len))
     }
 
@@ -343,11 +388,26 @@ impl TextLog {
         self.color = try_serialize_field(Self::descriptor_color(), color);
         self
     }
+
+    /// Optional structured key-value fields attached to the message.
+    ///
+    /// These are shown as extra columns in the Rerun Viewer.
+    #[inline]
+    pub fn with_fields(
+        mut self,
+        fields: impl IntoIterator<Item = impl Into<crate::components::TextLogField>>,
+    ) -> Self {
+        self.fields = try_serialize_field(Self::descriptor_fields(), fields);
+        self
+    }
 }
 
 impl ::re_byte_size::SizeBytes for TextLog {
     #[inline]
     fn heap_size_bytes(&self) -> u64 {
-        self.text.heap_size_bytes() + self.level.heap_size_bytes() + self.color.heap_size_bytes()
+        self.text.heap_size_bytes()
+            + self.level.heap_size_bytes()
+            + self.color.heap_size_bytes()
+            + self.fields.heap_size_bytes()
     }
 }
@@ -34,12 +34,24 @@ pub struct DataframeQuery {
     /// Note: will be unset as soon as `timeline` is changed.
     pub filter_by_range: Option<SerializedComponentBatch>,
 
-    /// If provided, only show rows which contains a logged event for the specified component.
+    /// If provided, only show rows which contains a logged event for the specified components.
+    ///
+    /// See `filter_is_not_null_mode` for how multiple components are combined.
     pub filter_is_not_null: Option<SerializedComponentBatch>,
 
+    /// Whether rows need a logged event for any or all of the components of `filter_is_not_null`.
+    ///
+    /// If unset, rows with a logged event for any of the components are shown.
+    pub filter_is_not_null_mode: Option<SerializedComponentBatch>,
+
     /// Should empty cells be filled with latest-at queries?
     pub apply_latest_at: Option<SerializedComponentBatch>,
 
+    /// Which data should empty cells be filled from, if `apply_latest_at` is enabled?
+    ///
+    /// If unset, the latest value in the entire recording is used.
+    pub latest_at_scope: Option<SerializedComponentBatch>,
+
     /// Selected columns. If unset, all columns are selected.
     pub select: Option<SerializedComponentBatch>,
 }
@@ -81,6 +93,18 @@ impl DataframeQuery {
         }
     }
 
+    /// Returns the [`ComponentDescriptor`] for [`Self::filter_is_not_null_mode`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::FilterIsNotNullMode`].
+    #[inline]
+    pub fn descriptor_filter_is_not_null_mode() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.DataframeQuery".into()),
+            component: "DataframeQuery:filter_is_not_null_mode".into(),
+            component_type: Some("rerun.blueprint.components.FilterIsNotNullMode".into()),
+        }
+    }
+
     /// Returns the [`ComponentDescriptor`] for [`Self::apply_latest_at`].
     ///
     /// The corresponding component is [`crate::blueprint::components::ApplyLatestAt`].
@@ -93,6 +117,18 @@ impl DataframeQuery {
         }
     }
 
+    /// Returns the [`ComponentDescriptor`] for [`Self::latest_at_scope`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::LatestAtScope`].
+    #[inline]
+    pub fn descriptor_latest_at_scope() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.DataframeQuery".into()),
+            component: "DataframeQuery:latest_at_scope".into(),
+            component_type: Some("rerun.blueprint.components.LatestAtScope".into()),
+        }
+    }
+
     /// Returns the [`ComponentDescriptor`] for [`Self::select`].
     ///
     /// The corresponding component is [`crate::blueprint::components::SelectedColumns`].
@@ -112,31 +148,35 @@ static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]>
 static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
     once_cell::sync::Lazy::new(|| []);
 
-static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 5usize]> =
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 7usize]> =
     once_cell::sync::Lazy::new(|| {
         [
             DataframeQuery::descriptor_timeline(),
             DataframeQuery::descriptor_filter_by_range(),
             DataframeQuery::descriptor_filter_is_not_null(),
+            DataframeQuery::descriptor_filter_is_not_null_mode(),
             DataframeQuery::descriptor_apply_latest_at(),
+            DataframeQuery::descriptor_latest_at_scope(),
             DataframeQuery::descriptor_select(),
         ]
     });
 
-static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 5usize]> =
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 7usize]> =
     once_cell::sync::Lazy::new(|| {
         [
             DataframeQuery::descriptor_timeline(),
             DataframeQuery::descriptor_filter_by_range(),
             DataframeQuery::descriptor_filter_is_not_null(),
+            DataframeQuery::descriptor_filter_is_not_null_mode(),
             DataframeQuery::descriptor_apply_latest_at(),
+            DataframeQuery::descriptor_latest_at_scope(),
             DataframeQuery::descriptor_select(),
         ]
     });
 
 impl DataframeQuery {
-    /// The total number of components in the archetype: 0 required, 0 recommended, 5 optional
-    pub const NUM_COMPONENTS: usize = 5usize;
+    /// The total number of components in the archetype: 0 required, 0 recommended, 7 optional
+    pub const NUM_COMPONENTS: usize = 7usize;
 }
 
 impl ::re_types_core::Archetype for DataframeQuery {
@@ -190,11 +230,24 @@ impl ::re_types_core::Archetype for DataframeQuery {
             .map(|array| {
                 SerializedComponentBatch::new(array.clone(), Self::descriptor_filter_is_not_null())
             });
+        let filter_is_not_null_mode = arrays_by_descr
+            .get(&Self::descriptor_filter_is_not_null_mode())
+            .map(|array| {
+                SerializedComponentBatch::new(
+                    array.clone(),
+                    Self::descriptor_filter_is_not_null_mode(),
+                )
+            });
         let apply_latest_at = arrays_by_descr
             .get(&Self::descriptor_apply_latest_at())
             .map(|array| {
                 SerializedComponentBatch::new(array.clone(), Self::descriptor_apply_latest_at())
             });
+        let latest_at_scope = arrays_by_descr
+            .get(&Self::descriptor_latest_at_scope())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_latest_at_scope())
+            });
         let select = arrays_by_descr
             .get(&Self::descriptor_select())
             .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_select()));
@@ -202,7 +255,9 @@ impl ::re_types_core::Archetype for DataframeQuery {
             timeline,
             filter_by_range,
             filter_is_not_null,
+            filter_is_not_null_mode,
             apply_latest_at,
+            latest_at_scope,
             select,
         })
     }
@@ -216,7 +271,9 @@ impl ::re_types_core::AsComponents for DataframeQuery {
             self.timeline.clone(),
             self.filter_by_range.clone(),
             self.filter_is_not_null.clone(),
+            self.filter_is_not_null_mode.clone(),
             self.apply_latest_at.clone(),
+            self.latest_at_scope.clone(),
             self.select.clone(),
         ]
         .into_iter()
@@ -235,7 +292,9 @@ impl DataframeQuery {
             timeline: None,
             filter_by_range: None,
             filter_is_not_null: None,
+            filter_is_not_null_mode: None,
             apply_latest_at: None,
+            latest_at_scope: None,
             select: None,
         }
     }
@@ -263,10 +322,18 @@ impl DataframeQuery {
                 crate::blueprint::components::FilterIsNotNull::arrow_empty(),
                 Self::descriptor_filter_is_not_null(),
             )),
+            filter_is_not_null_mode: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::FilterIsNotNullMode::arrow_empty(),
+                Self::descriptor_filter_is_not_null_mode(),
+            )),
             apply_latest_at: Some(SerializedComponentBatch::new(
                 crate::blueprint::components::ApplyLatestAt::arrow_empty(),
                 Self::descriptor_apply_latest_at(),
             )),
+            latest_at_scope: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::LatestAtScope::arrow_empty(),
+                Self::descriptor_latest_at_scope(),
+            )),
             select: Some(SerializedComponentBatch::new(
                 crate::blueprint::components::SelectedColumns::arrow_empty(),
                 Self::descriptor_select(),
@@ -299,14 +366,33 @@ impl DataframeQuery {
         self
     }
 
-    /// If provided, only show rows which contains a logged event for the specified component.
+    /// If provided, only show rows which contains a logged event for the specified components.
+    ///
+    /// See `filter_is_not_null_mode` for how multiple components are combined.
     #[inline]
     pub fn with_filter_is_not_null(
         mut self,
-        filter_is_not_null: impl Into<crate::blueprint::components::FilterIsNotNull>,
+        filter_is_not_null: impl IntoIterator<
+            Item = impl Into<crate::blueprint::components::FilterIsNotNull>,
+        >,
     ) -> Self {
         self.filter_is_not_null =
-            try_serialize_field(Self::descriptor_filter_is_not_null(), [filter_is_not_null]);
+            try_serialize_field(Self::descriptor_filter_is_not_null(), filter_is_not_null);
+        self
+    }
+
+    /// Whether rows need a logged event for any or all of the components of `filter_is_not_null`.
+    ///
+    /// If unset, rows with a logged event for any of the components are shown.
+    #[inline]
+    pub fn with_filter_is_not_null_mode(
+        mut self,
+        filter_is_not_null_mode: impl Into<crate::blueprint::components::FilterIsNotNullMode>,
+    ) -> Self {
+        self.filter_is_not_null_mode = try_serialize_field(
+            Self::descriptor_filter_is_not_null_mode(),
+            [filter_is_not_null_mode],
+        );
         self
     }
 
@@ -321,6 +407,19 @@ impl DataframeQuery {
         self
     }
 
+    /// Which data should empty cells be filled from, if `apply_latest_at` is enabled?
+    ///
+    /// If unset, the latest value in the entire recording is used.
+    #[inline]
+    pub fn with_latest_at_scope(
+        mut self,
+        latest_at_scope: impl Into<crate::blueprint::components::LatestAtScope>,
+    ) -> Self {
+        self.latest_at_scope =
+            try_serialize_field(Self::descriptor_latest_at_scope(), [latest_at_scope]);
+        self
+    }
+
     /// Selected columns. If unset, all columns are selected.
     #[inline]
     pub fn with_select(
@@ -338,7 +437,9 @@ impl ::re_byte_size::SizeBytes for DataframeQuery {
         self.timeline.heap_size_bytes()
             + self.filter_by_range.heap_size_bytes()
             + self.filter_is_not_null.heap_size_bytes()
+            + self.filter_is_not_null_mode.heap_size_bytes()
             + self.apply_latest_at.heap_size_bytes()
+            + self.latest_at_scope.heap_size_bytes()
             + self.select.heap_size_bytes()
     }
 }
@@ -0,0 +1,162 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/derived_series.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Series of a time series view that are computed from other series, rather than logged.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct DerivedSeries {
+    /// The expressions of the derived series, one series per expression.
+    pub expressions: Option<SerializedComponentBatch>,
+}
+
+impl DerivedSeries {
+    /// Returns the [`ComponentDescriptor`] for [`Self::expressions`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::SeriesExpression`].
+    #[inline]
+    pub fn descriptor_expressions() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.DerivedSeries".into()),
+            component: "DerivedSeries:expressions".into(),
+            component_type: Some("rerun.blueprint.components.SeriesExpression".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [DerivedSeries::descriptor_expressions()]);
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 1usize]> =
+    once_cell::sync::Lazy::new(|| [DerivedSeries::descriptor_expressions()]);
+
+impl DerivedSeries {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 1 optional
+    pub const NUM_COMPONENTS: usize = 1usize;
+}
+
+impl ::re_types_core::Archetype for DerivedSeries {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.DerivedSeries".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Derived series"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let expressions = arrays_by_descr
+            .get(&Self::descriptor_expressions())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_expressions())
+            });
+        Ok(Self { expressions })
+    }
+}
+
+impl ::re_types_core::AsComponents for DerivedSeries {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        std::iter::once(self.expressions.clone())
+            .flatten()
+            .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for DerivedSeries {}
+
+impl DerivedSeries {
+    /// Create a new `DerivedSeries`.
+    #[inline]
+    pub fn new() -> Self {
+        Self { expressions: None }
+    }
+
+    /// Update only some specific fields of a `DerivedSeries`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `DerivedSeries`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            expressions: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::SeriesExpression::arrow_empty(),
+                Self::descriptor_expressions(),
+            )),
+        }
+    }
+
+    /// The expressions of the derived series, one series per expression.
+    #[inline]
+    pub fn with_expressions(
+        mut self,
+        expressions: impl IntoIterator<Item = impl Into<crate::blueprint::components::SeriesExpression>>,
+    ) -> Self {
+        self.expressions = try_serialize_field(Self::descriptor_expressions(), expressions);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for DerivedSeries {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.expressions.heap_size_bytes()
+    }
+}
@@ -3,6 +3,7 @@
 mod background;
 mod container_blueprint;
 mod dataframe_query;
+mod derived_series;
 mod entity_behavior;
 mod eye_controls3d;
 mod force_center;
@@ -20,6 +21,8 @@ mod scalar_axis;
 mod tensor_scalar_mapping;
 mod tensor_slice_selection;
 mod tensor_view_fit;
+mod text_log_columns;
+mod text_log_filter;
 mod time_axis;
 mod view_blueprint;
 mod view_contents;
@@ -31,6 +34,7 @@ mod visualizer_overrides;
 pub use self::background::Background;
 pub use self::container_blueprint::ContainerBlueprint;
 pub use self::dataframe_query::DataframeQuery;
+pub use self::derived_series::DerivedSeries;
 pub use self::entity_behavior::EntityBehavior;
 pub use self::eye_controls3d::EyeControls3D;
 pub use self::force_center::ForceCenter;
@@ -48,6 +52,8 @@ pub use self::scalar_axis::ScalarAxis;
 pub use self::tensor_scalar_mapping::TensorScalarMapping;
 pub use self::tensor_slice_selection::TensorSliceSelection;
 pub use self::tensor_view_fit::TensorViewFit;
+pub use self::text_log_columns::TextLogColumns;
+pub use self::text_log_filter::TextLogFilter;
 pub use self::time_axis::TimeAxis;
 pub use self::view_blueprint::ViewBlueprint;
 pub use self::view_contents::ViewContents;
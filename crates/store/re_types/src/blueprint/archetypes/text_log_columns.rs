@@ -0,0 +1,214 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/text_log_columns.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Configuration for the columns of a text log view.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct TextLogColumns {
+    /// The order in which columns are shown.
+    ///
+    /// Columns that aren't listed are shown after the listed ones, in their default order.
+    pub order: Option<SerializedComponentBatch>,
+
+    /// Columns that aren't shown.
+    ///
+    /// By default, all columns are shown.
+    pub hidden: Option<SerializedComponentBatch>,
+}
+
+impl TextLogColumns {
+    /// Returns the [`ComponentDescriptor`] for [`Self::order`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::TextLogColumn`].
+    #[inline]
+    pub fn descriptor_order() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.TextLogColumns".into()),
+            component: "TextLogColumns:order".into(),
+            component_type: Some("rerun.blueprint.components.TextLogColumn".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::hidden`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::TextLogColumn`].
+    #[inline]
+    pub fn descriptor_hidden() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.TextLogColumns".into()),
+            component: "TextLogColumns:hidden".into(),
+            component_type: Some("rerun.blueprint.components.TextLogColumn".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            TextLogColumns::descriptor_order(),
+            TextLogColumns::descriptor_hidden(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 2usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            TextLogColumns::descriptor_order(),
+            TextLogColumns::descriptor_hidden(),
+        ]
+    });
+
+impl TextLogColumns {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 2 optional
+    pub const NUM_COMPONENTS: usize = 2usize;
+}
+
+impl ::re_types_core::Archetype for TextLogColumns {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.TextLogColumns".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Text log columns"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let order = arrays_by_descr
+            .get(&Self::descriptor_order())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_order()));
+        let hidden = arrays_by_descr
+            .get(&Self::descriptor_hidden())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_hidden()));
+        Ok(Self { order, hidden })
+    }
+}
+
+impl ::re_types_core::AsComponents for TextLogColumns {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [self.order.clone(), self.hidden.clone()]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for TextLogColumns {}
+
+impl TextLogColumns {
+    /// Create a new `TextLogColumns`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            order: None,
+            hidden: None,
+        }
+    }
+
+    /// Update only some specific fields of a `TextLogColumns`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `TextLogColumns`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            order: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::TextLogColumn::arrow_empty(),
+                Self::descriptor_order(),
+            )),
+            hidden: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::TextLogColumn::arrow_empty(),
+                Self::descriptor_hidden(),
+            )),
+        }
+    }
+
+    /// The order in which columns are shown.
+    ///
+    /// Columns that aren't listed are shown after the listed ones, in their default order.
+    #[inline]
+    pub fn with_order(
+        mut self,
+        order: impl IntoIterator<Item = impl Into<crate::blueprint::components::TextLogColumn>>,
+    ) -> Self {
+        self.order = try_serialize_field(Self::descriptor_order(), order);
+        self
+    }
+
+    /// Columns that aren't shown.
+    ///
+    /// By default, all columns are shown.
+    #[inline]
+    pub fn with_hidden(
+        mut self,
+        hidden: impl IntoIterator<Item = impl Into<crate::blueprint::components::TextLogColumn>>,
+    ) -> Self {
+        self.hidden = try_serialize_field(Self::descriptor_hidden(), hidden);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for TextLogColumns {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.order.heap_size_bytes() + self.hidden.heap_size_bytes()
+    }
+}
@@ -0,0 +1,259 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/text_log_filter.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Archetype**: Configuration for filtering the entries of a text log view.
+///
+/// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+#[derive(Clone, Debug, Default)]
+pub struct TextLogFilter {
+    /// Only entries whose body matches this filter are shown.
+    ///
+    /// If unset or empty, entries aren't filtered by their body.
+    pub body: Option<SerializedComponentBatch>,
+
+    /// Only entries whose entity path matches this filter are shown.
+    ///
+    /// If unset or empty, entries aren't filtered by their entity path.
+    pub entity_path: Option<SerializedComponentBatch>,
+
+    /// How the filters are matched.
+    ///
+    /// Defaults to case-insensitive substring matching.
+    pub mode: Option<SerializedComponentBatch>,
+}
+
+impl TextLogFilter {
+    /// Returns the [`ComponentDescriptor`] for [`Self::body`].
+    ///
+    /// The corresponding component is [`crate::components::Text`].
+    #[inline]
+    pub fn descriptor_body() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.TextLogFilter".into()),
+            component: "TextLogFilter:body".into(),
+            component_type: Some("rerun.components.Text".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::entity_path`].
+    ///
+    /// The corresponding component is [`crate::components::Text`].
+    #[inline]
+    pub fn descriptor_entity_path() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.TextLogFilter".into()),
+            component: "TextLogFilter:entity_path".into(),
+            component_type: Some("rerun.components.Text".into()),
+        }
+    }
+
+    /// Returns the [`ComponentDescriptor`] for [`Self::mode`].
+    ///
+    /// The corresponding component is [`crate::blueprint::components::TextFilterMode`].
+    #[inline]
+    pub fn descriptor_mode() -> ComponentDescriptor {
+        ComponentDescriptor {
+            archetype: Some("rerun.blueprint.archetypes.TextLogFilter".into()),
+            component: "TextLogFilter:mode".into(),
+            component_type: Some("rerun.blueprint.components.TextFilterMode".into()),
+        }
+    }
+}
+
+static REQUIRED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static RECOMMENDED_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 0usize]> =
+    once_cell::sync::Lazy::new(|| []);
+
+static OPTIONAL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 3usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            TextLogFilter::descriptor_body(),
+            TextLogFilter::descriptor_entity_path(),
+            TextLogFilter::descriptor_mode(),
+        ]
+    });
+
+static ALL_COMPONENTS: once_cell::sync::Lazy<[ComponentDescriptor; 3usize]> =
+    once_cell::sync::Lazy::new(|| {
+        [
+            TextLogFilter::descriptor_body(),
+            TextLogFilter::descriptor_entity_path(),
+            TextLogFilter::descriptor_mode(),
+        ]
+    });
+
+impl TextLogFilter {
+    /// The total number of components in the archetype: 0 required, 0 recommended, 3 optional
+    pub const NUM_COMPONENTS: usize = 3usize;
+}
+
+impl ::re_types_core::Archetype for TextLogFilter {
+    #[inline]
+    fn name() -> ::re_types_core::ArchetypeName {
+        "rerun.blueprint.archetypes.TextLogFilter".into()
+    }
+
+    #[inline]
+    fn display_name() -> &'static str {
+        "Text log filter"
+    }
+
+    #[inline]
+    fn required_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        REQUIRED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn recommended_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        RECOMMENDED_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn optional_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        OPTIONAL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn all_components() -> ::std::borrow::Cow<'static, [ComponentDescriptor]> {
+        ALL_COMPONENTS.as_slice().into()
+    }
+
+    #[inline]
+    fn from_arrow_components(
+        arrow_data: impl IntoIterator<Item = (ComponentDescriptor, arrow::array::ArrayRef)>,
+    ) -> DeserializationResult<Self> {
+        re_tracing::profile_function!();
+        use ::re_types_core::{Loggable as _, ResultExt as _};
+        let arrays_by_descr: ::nohash_hasher::IntMap<_, _> = arrow_data.into_iter().collect();
+        let body = arrays_by_descr
+            .get(&Self::descriptor_body())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_body()));
+        let entity_path = arrays_by_descr
+            .get(&Self::descriptor_entity_path())
+            .map(|array| {
+                SerializedComponentBatch::new(array.clone(), Self::descriptor_entity_path())
+            });
+        let mode = arrays_by_descr
+            .get(&Self::descriptor_mode())
+            .map(|array| SerializedComponentBatch::new(array.clone(), Self::descriptor_mode()));
+        Ok(Self {
+            body,
+            entity_path,
+            mode,
+        })
+    }
+}
+
+impl ::re_types_core::AsComponents for TextLogFilter {
+    #[inline]
+    fn as_serialized_batches(&self) -> Vec<SerializedComponentBatch> {
+        use ::re_types_core::Archetype as _;
+        [
+            self.body.clone(),
+            self.entity_path.clone(),
+            self.mode.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl ::re_types_core::ArchetypeReflectionMarker for TextLogFilter {}
+
+impl TextLogFilter {
+    /// Create a new `TextLogFilter`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            body: None,
+            entity_path: None,
+            mode: None,
+        }
+    }
+
+    /// Update only some specific fields of a `TextLogFilter`.
+    #[inline]
+    pub fn update_fields() -> Self {
+        Self::default()
+    }
+
+    /// Clear all the fields of a `TextLogFilter`.
+    #[inline]
+    pub fn clear_fields() -> Self {
+        use ::re_types_core::Loggable as _;
+        Self {
+            body: Some(SerializedComponentBatch::new(
+                crate::components::Text::arrow_empty(),
+                Self::descriptor_body(),
+            )),
+            entity_path: Some(SerializedComponentBatch::new(
+                crate::components::Text::arrow_empty(),
+                Self::descriptor_entity_path(),
+            )),
+            mode: Some(SerializedComponentBatch::new(
+                crate::blueprint::components::TextFilterMode::arrow_empty(),
+                Self::descriptor_mode(),
+            )),
+        }
+    }
+
+    /// Only entries whose body matches this filter are shown.
+    ///
+    /// If unset or empty, entries aren't filtered by their body.
+    #[inline]
+    pub fn with_body(mut self, body: impl Into<crate::components::Text>) -> Self {
+        self.body = try_serialize_field(Self::descriptor_body(), [body]);
+        self
+    }
+
+    /// Only entries whose entity path matches this filter are shown.
+    ///
+    /// If unset or empty, entries aren't filtered by their entity path.
+    #[inline]
+    pub fn with_entity_path(mut self, entity_path: impl Into<crate::components::Text>) -> Self {
+        self.entity_path = try_serialize_field(Self::descriptor_entity_path(), [entity_path]);
+        self
+    }
+
+    /// How the filters are matched.
+    ///
+    /// Defaults to case-insensitive substring matching.
+    #[inline]
+    pub fn with_mode(
+        mut self,
+        mode: impl Into<crate::blueprint::components::TextFilterMode>,
+    ) -> Self {
+        self.mode = try_serialize_field(Self::descriptor_mode(), [mode]);
+        self
+    }
+}
+
+impl ::re_byte_size::SizeBytes for TextLogFilter {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.body.heap_size_bytes()
+            + self.entity_path.heap_size_bytes()
+            + self.mode.heap_size_bytes()
+    }
+}
@@ -19,6 +19,7 @@ mod filter_by_range;
 mod filter_by_range_ext;
 mod filter_is_not_null;
 mod filter_is_not_null_ext;
+mod filter_is_not_null_mode;
 mod force_distance;
 mod force_iterations;
 mod force_strength;
@@ -26,6 +27,7 @@ mod grid_columns;
 mod grid_spacing;
 mod grid_spacing_ext;
 mod included_content;
+mod latest_at_scope;
 mod link_axis;
 mod lock_range_during_zoom;
 mod map_provider;
@@ -37,8 +39,11 @@ mod query_expression;
 mod root_container;
 mod row_share;
 mod selected_columns;
+mod series_expression;
 mod tensor_dimension_index_slider;
 mod tensor_dimension_index_slider_ext;
+mod text_filter_mode;
+mod text_log_column;
 mod timeline_name;
 mod timeline_name_ext;
 mod view_class;
@@ -68,12 +73,14 @@ pub use self::enabled::Enabled;
 pub use self::eye3d_kind::Eye3DKind;
 pub use self::filter_by_range::FilterByRange;
 pub use self::filter_is_not_null::FilterIsNotNull;
+pub use self::filter_is_not_null_mode::FilterIsNotNullMode;
 pub use self::force_distance::ForceDistance;
 pub use self::force_iterations::ForceIterations;
 pub use self::force_strength::ForceStrength;
 pub use self::grid_columns::GridColumns;
 pub use self::grid_spacing::GridSpacing;
 pub use self::included_content::IncludedContent;
+pub use self::latest_at_scope::LatestAtScope;
 pub use self::link_axis::LinkAxis;
 pub use self::lock_range_during_zoom::LockRangeDuringZoom;
 pub use self::map_provider::MapProvider;
@@ -83,7 +90,10 @@ pub use self::query_expression::QueryExpression;
 pub use self::root_container::RootContainer;
 pub use self::row_share::RowShare;
 pub use self::selected_columns::SelectedColumns;
+pub use self::series_expression::SeriesExpression;
 pub use self::tensor_dimension_index_slider::TensorDimensionIndexSlider;
+pub use self::text_filter_mode::TextFilterMode;
+pub use self::text_log_column::TextLogColumn;
 pub use self::timeline_name::TimelineName;
 pub use self::view_class::ViewClass;
 pub use self::view_fit::ViewFit;
@@ -23,7 +23,13 @@ use ::re_types_core::{DeserializationError, DeserializationResult};
 ///
 /// ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
 #[derive(Clone, Debug)]
-pub struct TextLogView {}
+pub struct TextLogView {
+    /// Filters which entries are shown, by their body or entity path.
+    pub filter: crate::blueprint::archetypes::TextLogFilter,
+
+    /// Which columns are shown, and in which order.
+    pub columns: crate::blueprint::archetypes::TextLogColumns,
+}
 
 impl ::re_types_core::View for TextLogView {
     #[inline]
@@ -35,11 +41,12 @@ impl ::re_types_core::View for TextLogView {
 impl ::re_byte_size::SizeBytes for TextLogView {
     #[inline]
     fn heap_size_bytes(&self) -> u64 {
-        0
+        self.filter.heap_size_bytes() + self.columns.heap_size_bytes()
     }
 
     #[inline]
     fn is_pod() -> bool {
-        true
+        <crate::blueprint::archetypes::TextLogFilter>::is_pod()
+            && <crate::blueprint::archetypes::TextLogColumns>::is_pod()
     }
 }
@@ -33,6 +33,9 @@ pub struct TimeSeriesView {
     /// Configures the legend of the plot.
     pub plot_legend: crate::blueprint::archetypes::PlotLegend,
 
+    /// Series computed from the other series of the view.
+    pub derived_series: crate::blueprint::archetypes::DerivedSeries,
+
     /// Configures which range on each timeline is shown by this view (unless specified differently per entity).
     ///
     /// If not specified, the default is to show the entire timeline.
@@ -53,6 +56,7 @@ impl ::re_byte_size::SizeBytes for TimeSeriesView {
         self.axis_x.heap_size_bytes()
             + self.axis_y.heap_size_bytes()
             + self.plot_legend.heap_size_bytes()
+            + self.derived_series.heap_size_bytes()
             + self.time_ranges.heap_size_bytes()
     }
 
@@ -61,6 +65,7 @@ impl ::re_byte_size::SizeBytes for TimeSeriesView {
         <crate::blueprint::archetypes::TimeAxis>::is_pod()
             && <crate::blueprint::archetypes::ScalarAxis>::is_pod()
             && <crate::blueprint::archetypes::PlotLegend>::is_pod()
+            && <crate::blueprint::archetypes::DerivedSeries>::is_pod()
             && <crate::blueprint::archetypes::VisibleTimeRanges>::is_pod()
     }
 }
@@ -48,6 +48,16 @@ pub enum AggregationPolicy {
 
     /// Find both the minimum and maximum values in the range, then use the average of those.
     MinMaxAverage = 6,
+
+    /// Use the median of all values in the range.
+    ///
+    /// Unlike `Average`, this is not skewed by outliers.
+    Median = 7,
+
+    /// Shade the band between the minimum and maximum values in the range, with a line through the average of all values.
+    ///
+    /// This shows both the trend and the spread of long, noisy signals.
+    Envelope = 8,
 }
 
 impl ::re_types_core::Component for AggregationPolicy {
@@ -128,6 +138,8 @@ impl ::re_types_core::Loggable for AggregationPolicy {
                 Some(4) => Ok(Some(Self::Min)),
                 Some(5) => Ok(Some(Self::MinMax)),
                 Some(6) => Ok(Some(Self::MinMaxAverage)),
+                Some(7) => Ok(Some(Self::Median)),
+                Some(8) => Ok(Some(Self::Envelope)),
                 None => Ok(None),
                 Some(invalid) => Err(DeserializationError::missing_union_arm(
                     Self::arrow_datatype(),
@@ -149,6 +161,8 @@ impl std::fmt::Display for AggregationPolicy {
             Self::Min => write!(f, "Min"),
             Self::MinMax => write!(f, "MinMax"),
             Self::MinMaxAverage => write!(f, "MinMaxAverage"),
+            Self::Median => write!(f, "Median"),
+            Self::Envelope => write!(f, "Envelope"),
         }
     }
 }
@@ -163,6 +177,8 @@ impl ::re_types_core::reflection::Enum for AggregationPolicy {
             Self::Min,
             Self::MinMax,
             Self::MinMaxAverage,
+            Self::Median,
+            Self::Envelope,
         ]
     }
 
@@ -179,6 +195,12 @@ impl ::re_types_core::reflection::Enum for AggregationPolicy {
             Self::MinMaxAverage => {
                 "Find both the minimum and maximum values in the range, then use the average of those."
             }
+            Self::Median => {
+                "Use the median of all values in the range.\n\nUnlike `Average`, this is not skewed by outliers."
+            }
+            Self::Envelope => {
+                "Shade the band between the minimum and maximum values in the range, with a line through the average of all values.\n\nThis shows both the trend and the spread of long, noisy signals."
+            }
         }
     }
 }
@@ -11,6 +11,26 @@ impl MediaType {
     /// <https://www.iana.org/assignments/media-types/text/markdown>
     pub const MARKDOWN: &'static str = "text/markdown";
 
+    /// [JSON](https://en.wikipedia.org/wiki/JSON): `application/json`.
+    ///
+    /// <https://www.iana.org/assignments/media-types/application/json>
+    pub const JSON: &'static str = "application/json";
+
+    /// [YAML](https://en.wikipedia.org/wiki/YAML): `application/yaml`.
+    ///
+    /// <https://www.iana.org/assignments/media-types/application/yaml>
+    pub const YAML: &'static str = "application/yaml";
+
+    /// [TOML](https://en.wikipedia.org/wiki/TOML): `application/toml`.
+    ///
+    /// <https://www.iana.org/assignments/media-types/application/toml>
+    pub const TOML: &'static str = "application/toml";
+
+    /// [CSV](https://en.wikipedia.org/wiki/Comma-separated_values): `text/csv`.
+    ///
+    /// <https://www.iana.org/assignments/media-types/text/csv>
+    pub const CSV: &'static str = "text/csv";
+
     // -------------------------------------------------------
     // Images:
 
@@ -53,6 +73,24 @@ impl MediaType {
     ///
     /// <https://www.iana.org/assignments/media-types/video/mp4>
     pub const MP4: &'static str = "video/mp4";
+
+    /// [Matroska video](https://en.wikipedia.org/wiki/Matroska): `video/x-matroska`.
+    pub const MATROSKA: &'static str = "video/x-matroska";
+
+    /// [WebM video](https://en.wikipedia.org/wiki/WebM): `video/webm`.
+    ///
+    /// <https://www.iana.org/assignments/media-types/video/webm>
+    pub const WEBM: &'static str = "video/webm";
+
+    /// Raw [H.264](https://en.wikipedia.org/wiki/Advanced_Video_Coding) bitstream in the Annex B format: `video/h264`.
+    ///
+    /// <https://www.iana.org/assignments/media-types/video/H264>
+    pub const H264: &'static str = "video/h264";
+
+    /// Raw [H.265](https://en.wikipedia.org/wiki/High_Efficiency_Video_Coding) bitstream in the Annex B format: `video/h265`.
+    ///
+    /// <https://www.iana.org/assignments/media-types/video/H265>
+    pub const H265: &'static str = "video/h265";
 }
 
 impl MediaType {
@@ -68,6 +106,30 @@ impl MediaType {
         Self(Self::MARKDOWN.into())
     }
 
+    /// `application/json`
+    #[inline]
+    pub fn json() -> Self {
+        Self(Self::JSON.into())
+    }
+
+    /// `application/yaml`
+    #[inline]
+    pub fn yaml() -> Self {
+        Self(Self::YAML.into())
+    }
+
+    /// `application/toml`
+    #[inline]
+    pub fn toml() -> Self {
+        Self(Self::TOML.into())
+    }
+
+    /// `text/csv`
+    #[inline]
+    pub fn csv() -> Self {
+        Self(Self::CSV.into())
+    }
+
     // -------------------------------------------------------
     // Images:
 
@@ -118,6 +180,30 @@ impl MediaType {
     pub fn mp4() -> Self {
         Self(Self::MP4.into())
     }
+
+    /// `video/x-matroska`
+    #[inline]
+    pub fn matroska() -> Self {
+        Self(Self::MATROSKA.into())
+    }
+
+    /// `video/webm`
+    #[inline]
+    pub fn webm() -> Self {
+        Self(Self::WEBM.into())
+    }
+
+    /// `video/h264`
+    #[inline]
+    pub fn h264() -> Self {
+        Self(Self::H264.into())
+    }
+
+    /// `video/h265`
+    #[inline]
+    pub fn h265() -> Self {
+        Self(Self::H265.into())
+    }
 }
 
 impl MediaType {
@@ -146,6 +232,20 @@ impl MediaType {
             Some("stl") => {
                 return Some(Self::stl());
             }
+            // `mime_guess2` uses the legacy `text/x-yaml` and `text/x-toml` media types.
+            Some("yaml" | "yml") => {
+                return Some(Self::yaml());
+            }
+            Some("toml") => {
+                return Some(Self::toml());
+            }
+            // Raw video bitstreams, which `mime_guess2` only partially knows about.
+            Some("264") => {
+                return Some(Self::h264());
+            }
+            Some("h265" | "hevc" | "265") => {
+                return Some(Self::h265());
+            }
             _ => {}
         }
 
@@ -174,6 +274,31 @@ impl MediaType {
             // https://en.wikipedia.org/wiki/STL_(file_format)#Binary
         }
 
+        // Raw H.264 & H.265 bitstreams start with a start code, usually followed by
+        // a parameter set or an access unit delimiter.
+        fn annexb_nal_header(buf: &[u8]) -> Option<&[u8]> {
+            if buf.starts_with(&[0, 0, 0, 1]) {
+                buf.get(4..)
+            } else if buf.starts_with(&[0, 0, 1]) {
+                buf.get(3..)
+            } else {
+                None
+            }
+        }
+
+        fn h264_matcher(buf: &[u8]) -> bool {
+            // SPS (with any `nal_ref_idc`) or access unit delimiter.
+            annexb_nal_header(buf)
+                .and_then(|nal| nal.first())
+                .is_some_and(|header| matches!(header, 0x67 | 0x27 | 0x47 | 0x09))
+        }
+
+        fn h265_matcher(buf: &[u8]) -> bool {
+            // VPS or access unit delimiter.
+            annexb_nal_header(buf)
+                .is_some_and(|nal| nal.starts_with(&[0x40, 0x01]) || nal.starts_with(&[0x46, 0x01]))
+        }
+
         // NOTE:
         // - gltf is simply json, so no magic byte
         //   (also most gltf files contain file:// links, so not much point in sending that to
@@ -183,6 +308,8 @@ impl MediaType {
         let mut inferer = infer::Infer::new();
         inferer.add(Self::GLB, "glb", glb_matcher);
         inferer.add(Self::STL, "stl", stl_matcher);
+        inferer.add(Self::H264, "h264", h264_matcher);
+        inferer.add(Self::H265, "h265", h265_matcher);
 
         inferer
             .get(data)
@@ -210,10 +337,17 @@ impl MediaType {
     pub fn file_extension(&self) -> Option<&'static str> {
         match self.as_str() {
             // Special-case some where there are multiple extensions:
+            Self::CSV => Some("csv"),
+            Self::H264 => Some("h264"),
+            Self::H265 => Some("h265"),
             Self::JPEG => Some("jpg"),
+            Self::JSON => Some("json"),
+            Self::MATROSKA => Some("mkv"),
             Self::MARKDOWN => Some("md"),
             Self::STL => Some("stl"),
             Self::TEXT => Some("txt"),
+            Self::TOML => Some("toml"),
+            Self::YAML => Some("yaml"),
 
             _ => {
                 let alternatives = mime_guess2::get_mime_extensions_str(&self.0)?;
@@ -224,6 +358,12 @@ impl MediaType {
         }
     }
 
+    /// Returns `true` if this is a text media type, i.e. `text/*` or a known text-based format.
+    pub fn is_text(&self) -> bool {
+        self.as_str().starts_with("text/")
+            || [Self::JSON, Self::YAML, Self::TOML].contains(&self.as_str())
+    }
+
     /// Returns `true` if this is an image media type.
     pub fn is_image(&self) -> bool {
         self.as_str().starts_with("image/")
@@ -253,12 +393,50 @@ impl Default for MediaType {
 
 #[test]
 fn test_media_type_extension() {
+    assert_eq!(MediaType::csv().file_extension(), Some("csv"));
     assert_eq!(MediaType::glb().file_extension(), Some("glb"));
     assert_eq!(MediaType::gltf().file_extension(), Some("gltf"));
+    assert_eq!(MediaType::h264().file_extension(), Some("h264"));
+    assert_eq!(MediaType::h265().file_extension(), Some("h265"));
     assert_eq!(MediaType::jpeg().file_extension(), Some("jpg"));
+    assert_eq!(MediaType::json().file_extension(), Some("json"));
     assert_eq!(MediaType::mp4().file_extension(), Some("mp4"));
     assert_eq!(MediaType::markdown().file_extension(), Some("md"));
+    assert_eq!(MediaType::matroska().file_extension(), Some("mkv"));
     assert_eq!(MediaType::plain_text().file_extension(), Some("txt"));
     assert_eq!(MediaType::png().file_extension(), Some("png"));
     assert_eq!(MediaType::stl().file_extension(), Some("stl"));
+    assert_eq!(MediaType::toml().file_extension(), Some("toml"));
+    assert_eq!(MediaType::webm().file_extension(), Some("webm"));
+    assert_eq!(MediaType::yaml().file_extension(), Some("yaml"));
+}
+
+#[test]
+fn test_guess_video_media_type() {
+    assert_eq!(
+        MediaType::guess_from_path("a.mkv"),
+        Some(MediaType::matroska())
+    );
+    assert_eq!(
+        MediaType::guess_from_path("a.webm"),
+        Some(MediaType::webm())
+    );
+    assert_eq!(
+        MediaType::guess_from_path("a.h264"),
+        Some(MediaType::h264())
+    );
+    assert_eq!(MediaType::guess_from_path("a.264"), Some(MediaType::h264()));
+    assert_eq!(
+        MediaType::guess_from_path("a.hevc"),
+        Some(MediaType::h265())
+    );
+
+    assert_eq!(
+        MediaType::guess_from_data(&[0, 0, 0, 1, 0x67, 0x42]),
+        Some(MediaType::h264())
+    );
+    assert_eq!(
+        MediaType::guess_from_data(&[0, 0, 0, 1, 0x40, 0x01, 0x0C]),
+        Some(MediaType::h265())
+    );
 }
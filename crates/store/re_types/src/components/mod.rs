@@ -110,6 +110,8 @@ mod texcoord2d;
 mod texcoord2d_ext;
 mod text;
 mod text_ext;
+mod text_log_field;
+mod text_log_field_ext;
 mod text_log_level;
 mod text_log_level_ext;
 mod timestamp;
@@ -197,6 +199,7 @@ pub use self::tensor_height_dimension::TensorHeightDimension;
 pub use self::tensor_width_dimension::TensorWidthDimension;
 pub use self::texcoord2d::Texcoord2D;
 pub use self::text::Text;
+pub use self::text_log_field::TextLogField;
 pub use self::text_log_level::TextLogLevel;
 pub use self::timestamp::Timestamp;
 pub use self::transform_mat3x3::TransformMat3x3;
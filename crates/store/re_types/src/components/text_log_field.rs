@@ -0,0 +1,108 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/rust/api.rs
+// Based on "crates/store/re_types/definitions/rerun/components/text_log_field.fbs".
+
+#![allow(unused_braces)]
+#![allow(unused_imports)]
+#![allow(unused_parens)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::cloned_instead_of_copied)]
+#![allow(clippy::map_flatten)]
+#![allow(clippy::needless_question_mark)]
+#![allow(clippy::new_without_default)]
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_lines)]
+
+use ::re_types_core::try_serialize_field;
+use ::re_types_core::SerializationResult;
+use ::re_types_core::{ComponentBatch as _, SerializedComponentBatch};
+use ::re_types_core::{ComponentDescriptor, ComponentType};
+use ::re_types_core::{DeserializationError, DeserializationResult};
+
+/// **Component**: A structured key-value field of a text log entry.
+///
+/// The first string is the key, the second one is the value.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct TextLogField(pub crate::datatypes::Utf8Pair);
+
+impl ::re_types_core::Component for TextLogField {
+    #[inline]
+    fn name() -> ComponentType {
+        "rerun.components.TextLogField".into()
+    }
+}
+
+::re_types_core::macros::impl_into_cow!(TextLogField);
+
+impl ::re_types_core::Loggable for TextLogField {
+    #[inline]
+    fn arrow_datatype() -> arrow::datatypes::DataType {
+        crate::datatypes::Utf8Pair::arrow_datatype()
+    }
+
+    fn to_arrow_opt<'a>(
+        data: impl IntoIterator<Item = Option<impl Into<::std::borrow::Cow<'a, Self>>>>,
+    ) -> SerializationResult<arrow::array::ArrayRef>
+    where
+        Self: Clone + 'a,
+    {
+        crate::datatypes::Utf8Pair::to_arrow_opt(data.into_iter().map(|datum| {
+            datum.map(|datum| match datum.into() {
+                ::std::borrow::Cow::Borrowed(datum) => ::std::borrow::Cow::Borrowed(&datum.0),
+                ::std::borrow::Cow::Owned(datum) => ::std::borrow::Cow::Owned(datum.0),
+            })
+        }))
+    }
+
+    fn from_arrow_opt(
+        arrow_data: &dyn arrow::array::Array,
+    ) -> DeserializationResult<Vec<Option<Self>>>
+    where
+        Self: Sized,
+    {
+        crate::datatypes::Utf8Pair::from_arrow_opt(arrow_data)
+            .map(|v| v.into_iter().map(|v| v.map(Self)).collect())
+    }
+}
+
+impl<T: Into<crate::datatypes::Utf8Pair>> From<T> for TextLogField {
+    fn from(v: T) -> Self {
+        Self(v.into())
+    }
+}
+
+impl std::borrow::Borrow<crate::datatypes::Utf8Pair> for TextLogField {
+    #[inline]
+    fn borrow(&self) -> &crate::datatypes::Utf8Pair {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for TextLogField {
+    type Target = crate::datatypes::Utf8Pair;
+
+    #[inline]
+    fn deref(&self) -> &crate::datatypes::Utf8Pair {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for TextLogField {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut crate::datatypes::Utf8Pair {
+        &mut self.0
+    }
+}
+
+impl ::re_byte_size::SizeBytes for TextLogField {
+    #[inline]
+    fn heap_size_bytes(&self) -> u64 {
+        self.0.heap_size_bytes()
+    }
+
+    #[inline]
+    fn is_pod() -> bool {
+        <crate::datatypes::Utf8Pair>::is_pod()
+    }
+}
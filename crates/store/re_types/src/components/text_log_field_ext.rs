@@ -0,0 +1,26 @@
+use crate::datatypes::{Utf8, Utf8Pair};
+
+use super::TextLogField;
+
+impl TextLogField {
+    /// Creates a new field from a key and a value.
+    #[inline]
+    pub fn new(key: impl Into<Utf8>, value: impl Into<Utf8>) -> Self {
+        Self(Utf8Pair {
+            first: key.into(),
+            second: value.into(),
+        })
+    }
+
+    /// The key of the field.
+    #[inline]
+    pub fn key(&self) -> &str {
+        self.0.first.as_str()
+    }
+
+    /// The value of the field.
+    #[inline]
+    pub fn value(&self) -> &str {
+        self.0.second.as_str()
+    }
+}
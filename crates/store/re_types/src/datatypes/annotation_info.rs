@@ -212,16 +212,9 @@ impl ::re_types_core::Loggable for AnnotationInfo {
                         .with_context("rerun.datatypes.AnnotationInfo#id")?
                         .into_iter()
                 };
-                let label = {
-                    if !arrays_by_name.contains_key("label") {
-                        return Err(DeserializationError::missing_struct_field(
-                            Self::arrow_datatype(),
-                            "label",
-                        ))
-                        .with_context("rerun.datatypes.AnnotationInfo");
-                    }
+                let label = if arrays_by_name.contains_key("label") {
                     let arrow_data = &**arrays_by_name["label"];
-                    {
+                    ::itertools::Either::Left({
                         let arrow_data = arrow_data
                             .as_any()
                             .downcast_ref::<StringArray>()
@@ -264,28 +257,31 @@ impl ::re_types_core::Loggable for AnnotationInfo {
                             .collect::<DeserializationResult<Vec<Option<_>>>>()
                             .with_context("rerun.datatypes.AnnotationInfo#label")?
                             .into_iter()
-                    }
+                    })
+                } else {
+                    ::itertools::Either::Right(
+                        ::std::iter::repeat_with(|| None).take(arrow_data.len()),
+                    )
                 };
-                let color = {
-                    if !arrays_by_name.contains_key("color") {
-                        return Err(DeserializationError::missing_struct_field(
-                            Self::arrow_datatype(),
-                            "color",
-                        ))
-                        .with_context("rerun.datatypes.AnnotationInfo");
-                    }
+                let color = if arrays_by_name.contains_key("color") {
                     let arrow_data = &**arrays_by_name["color"];
-                    arrow_data
-                        .as_any()
-                        .downcast_ref::<UInt32Array>()
-                        .ok_or_else(|| {
-                            let expected = DataType::UInt32;
-                            let actual = arrow_data.data_type().clone();
-                            DeserializationError::datatype_mismatch(expected, actual)
-                        })
-                        .with_context("rerun.datatypes.AnnotationInfo#color")?
-                        .into_iter()
-                        .map(|res_or_opt| res_or_opt.map(crate::datatypes::Rgba32))
+                    ::itertools::Either::Left(
+                        arrow_data
+                            .as_any()
+                            .downcast_ref::<UInt32Array>()
+                            .ok_or_else(|| {
+                                let expected = DataType::UInt32;
+                                let actual = arrow_data.data_type().clone();
+                                DeserializationError::datatype_mismatch(expected, actual)
+                            })
+                            .with_context("rerun.datatypes.AnnotationInfo#color")?
+                            .into_iter()
+                            .map(|res_or_opt| res_or_opt.map(crate::datatypes::Rgba32)),
+                    )
+                } else {
+                    ::itertools::Either::Right(
+                        ::std::iter::repeat_with(|| None).take(arrow_data.len()),
+                    )
                 };
                 ZipValidity::new_with_validity(
                     ::itertools::izip!(id, label, color),
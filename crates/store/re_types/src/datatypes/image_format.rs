@@ -295,44 +295,41 @@ impl ::re_types_core::Loggable for ImageFormat {
                         .with_context("rerun.datatypes.ImageFormat#height")?
                         .into_iter()
                 };
-                let pixel_format = {
-                    if !arrays_by_name.contains_key("pixel_format") {
-                        return Err(DeserializationError::missing_struct_field(
-                            Self::arrow_datatype(),
-                            "pixel_format",
-                        ))
-                        .with_context("rerun.datatypes.ImageFormat");
-                    }
+                let pixel_format = if arrays_by_name.contains_key("pixel_format") {
                     let arrow_data = &**arrays_by_name["pixel_format"];
-                    crate::datatypes::PixelFormat::from_arrow_opt(arrow_data)
-                        .with_context("rerun.datatypes.ImageFormat#pixel_format")?
-                        .into_iter()
+                    ::itertools::Either::Left(
+                        crate::datatypes::PixelFormat::from_arrow_opt(arrow_data)
+                            .with_context("rerun.datatypes.ImageFormat#pixel_format")?
+                            .into_iter(),
+                    )
+                } else {
+                    ::itertools::Either::Right(
+                        ::std::iter::repeat_with(|| None).take(arrow_data.len()),
+                    )
                 };
-                let color_model = {
-                    if !arrays_by_name.contains_key("color_model") {
-                        return Err(DeserializationError::missing_struct_field(
-                            Self::arrow_datatype(),
-                            "color_model",
-                        ))
-                        .with_context("rerun.datatypes.ImageFormat");
-                    }
+                let color_model = if arrays_by_name.contains_key("color_model") {
                     let arrow_data = &**arrays_by_name["color_model"];
-                    crate::datatypes::ColorModel::from_arrow_opt(arrow_data)
-                        .with_context("rerun.datatypes.ImageFormat#color_model")?
-                        .into_iter()
+                    ::itertools::Either::Left(
+                        crate::datatypes::ColorModel::from_arrow_opt(arrow_data)
+                            .with_context("rerun.datatypes.ImageFormat#color_model")?
+                            .into_iter(),
+                    )
+                } else {
+                    ::itertools::Either::Right(
+                        ::std::iter::repeat_with(|| None).take(arrow_data.len()),
+                    )
                 };
-                let channel_datatype = {
-                    if !arrays_by_name.contains_key("channel_datatype") {
-                        return Err(DeserializationError::missing_struct_field(
-                            Self::arrow_datatype(),
-                            "channel_datatype",
-                        ))
-                        .with_context("rerun.datatypes.ImageFormat");
-                    }
+                let channel_datatype = if arrays_by_name.contains_key("channel_datatype") {
                     let arrow_data = &**arrays_by_name["channel_datatype"];
-                    crate::datatypes::ChannelDatatype::from_arrow_opt(arrow_data)
-                        .with_context("rerun.datatypes.ImageFormat#channel_datatype")?
-                        .into_iter()
+                    ::itertools::Either::Left(
+                        crate::datatypes::ChannelDatatype::from_arrow_opt(arrow_data)
+                            .with_context("rerun.datatypes.ImageFormat#channel_datatype")?
+                            .into_iter(),
+                    )
+                } else {
+                    ::itertools::Either::Right(
+                        ::std::iter::repeat_with(|| None).take(arrow_data.len()),
+                    )
                 };
                 ZipValidity::new_with_validity(
                     ::itertools::izip!(width, height, pixel_format, color_model, channel_datatype),
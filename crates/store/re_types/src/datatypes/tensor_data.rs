@@ -332,16 +332,9 @@ impl ::re_types_core::Loggable for TensorData {
                         .into_iter()
                     }
                 };
-                let names = {
-                    if !arrays_by_name.contains_key("names") {
-                        return Err(DeserializationError::missing_struct_field(
-                            Self::arrow_datatype(),
-                            "names",
-                        ))
-                        .with_context("rerun.datatypes.TensorData");
-                    }
+                let names = if arrays_by_name.contains_key("names") {
                     let arrow_data = &**arrays_by_name["names"];
-                    {
+                    ::itertools::Either::Left({
                         let arrow_data = arrow_data
                             .as_any()
                             .downcast_ref::<arrow::array::ListArray>()
@@ -442,7 +435,11 @@ impl ::re_types_core::Loggable for TensorData {
                                 .collect::<DeserializationResult<Vec<Option<_>>>>()?
                         }
                         .into_iter()
-                    }
+                    })
+                } else {
+                    ::itertools::Either::Right(
+                        ::std::iter::repeat_with(|| None).take(arrow_data.len()),
+                    )
                 };
                 let buffer = {
                     if !arrays_by_name.contains_key("buffer") {
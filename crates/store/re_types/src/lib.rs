@@ -294,6 +294,7 @@ pub mod external {
 // TODO(jleibs): Should all of this go into `tensor_data_ext`? Don't have a good way to export
 // additional helpers yet.
 pub mod image;
+pub mod ply;
 pub mod tensor_data;
 pub mod view_coordinates;
 
@@ -0,0 +1,537 @@
+//! Support for loading `.ply` files as native archetypes.
+//!
+//! See [`PlyContents`].
+
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+
+use linked_hash_map::LinkedHashMap;
+use ply_rs::ply::{DefaultElement, Ply, Property};
+
+use crate::archetypes::{Mesh3D, Points2D, Points3D};
+use crate::components::{Color, Position2D, Position3D, Radius, Text, Vector3D};
+
+// NOTE: Empirical evidence points to these being de-facto standard…
+const ELEMENT_VERTEX: &str = "vertex";
+const ELEMENT_FACE: &str = "face";
+
+const PROP_X: &str = "x";
+const PROP_Y: &str = "y";
+const PROP_Z: &str = "z";
+const PROP_NX: &str = "nx";
+const PROP_NY: &str = "ny";
+const PROP_NZ: &str = "nz";
+const PROP_RED: &str = "red";
+const PROP_GREEN: &str = "green";
+const PROP_BLUE: &str = "blue";
+const PROP_ALPHA: &str = "alpha";
+const PROP_RADIUS: &str = "radius";
+const PROP_LABEL: &str = "label";
+const PROP_VERTEX_INDICES: &str = "vertex_indices";
+const PROP_VERTEX_INDEX: &str = "vertex_index";
+
+/// The contents of a `.ply` file, as the archetype that represents it best.
+///
+/// ASCII, binary little-endian and binary big-endian files are all supported.
+///
+/// ## Supported elements & properties
+///
+/// - `vertex`:
+///   - (Required) Positions of the vertices: `"x"`, `"y"` & `"z"`.
+///     If there is no `"z"` property, the file is considered to be 2D.
+///   - (Optional) Colors of the vertices: `"red"`, `"green"`, `"blue"` & `"alpha"`.
+///   - (Optional) Normals of the vertices: `"nx"`, `"ny"` & `"nz"` (meshes only).
+///   - (Optional) Radii of the vertices: `"radius"` (point clouds only).
+///   - (Optional) Labels of the vertices: `"label"` (point clouds only).
+/// - `face`:
+///   - (Required) Indices of the vertices of the face: `"vertex_indices"` or `"vertex_index"`.
+///     Polygons are triangulated as fans.
+///
+/// Files with faces are loaded as a [`Mesh3D`] (2D vertices lying in the `z=0` plane), while
+/// files without faces are loaded as either [`Points2D`] or [`Points3D`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum PlyContents {
+    /// A 2D point cloud: vertices without `"z"` coordinates, and no faces.
+    Points2D(Points2D),
+
+    /// A 3D point cloud: vertices, and no faces.
+    Points3D(Points3D),
+
+    /// A triangle mesh: vertices and faces.
+    Mesh3D(Mesh3D),
+}
+
+impl PlyContents {
+    /// Loads the contents of a `.ply` file from disk.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_file_path(filepath: &std::path::Path) -> anyhow::Result<Self> {
+        Ok(PlyData::from_file_path(filepath)?.into_contents())
+    }
+
+    /// Loads the contents of a `.ply` file from memory.
+    pub fn from_file_contents(contents: &[u8]) -> anyhow::Result<Self> {
+        Ok(PlyData::from_file_contents(contents)?.into_contents())
+    }
+}
+
+// ---
+
+/// Everything we know how to make sense of in a `.ply` file.
+#[derive(Default)]
+pub(crate) struct PlyData {
+    is_2d: bool,
+
+    positions: Vec<[f32; 3]>,
+    colors: Vec<Option<Color>>,
+    normals: Vec<Option<Vector3D>>,
+    radii: Vec<Option<Radius>>,
+    labels: Vec<Option<Text>>,
+
+    triangles: Vec<[u32; 3]>,
+}
+
+impl PlyData {
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn from_file_path(filepath: &std::path::Path) -> anyhow::Result<Self> {
+        re_tracing::profile_function!(filepath.to_string_lossy());
+        use anyhow::Context as _;
+
+        let file = std::fs::File::open(filepath)
+            .with_context(|| format!("Failed to open file {filepath:?}"))?;
+        let mut file = std::io::BufReader::new(file);
+
+        Ok(Self::from_ply(read_ply(&mut file)?))
+    }
+
+    pub(crate) fn from_file_contents(contents: &[u8]) -> anyhow::Result<Self> {
+        re_tracing::profile_function!();
+        let mut contents = std::io::Cursor::new(contents);
+        Ok(Self::from_ply(read_ply(&mut contents)?))
+    }
+
+    fn from_ply(ply: Ply<DefaultElement>) -> Self {
+        re_tracing::profile_function!();
+
+        let is_2d = ply
+            .header
+            .elements
+            .get(ELEMENT_VERTEX)
+            .is_some_and(|vertex| !vertex.properties.contains_key(PROP_Z));
+
+        let mut this = Self {
+            is_2d,
+            ..Default::default()
+        };
+
+        let mut ignored_props = BTreeSet::new();
+        let mut skipped_vertices = false;
+
+        for (key, all_props) in ply.payload {
+            match key.as_str() {
+                ELEMENT_VERTEX => {
+                    for props in all_props {
+                        skipped_vertices |= !this.push_vertex(props, &mut ignored_props);
+                    }
+                }
+
+                ELEMENT_FACE => {
+                    for props in all_props {
+                        this.push_face(props, &mut ignored_props);
+                    }
+                }
+
+                _ => re_log::warn!("Ignoring {key:?} in .ply file"),
+            }
+        }
+
+        if !ignored_props.is_empty() {
+            re_log::warn!("Ignored properties of .ply file: {ignored_props:?}");
+        }
+
+        if !this.triangles.is_empty() {
+            if skipped_vertices {
+                // Faces refer to vertices by index: they cannot be trusted anymore.
+                re_log::warn!("Ignoring faces of .ply file because some vertices were invalid");
+                this.triangles.clear();
+            } else {
+                let num_vertices = this.positions.len();
+                let num_triangles = this.triangles.len();
+                this.triangles.retain(|triangle| {
+                    triangle
+                        .iter()
+                        .all(|&index| (index as usize) < num_vertices)
+                });
+
+                let num_invalid = num_triangles - this.triangles.len();
+                if num_invalid > 0 {
+                    re_log::warn!(
+                        "Ignored {num_invalid} faces of .ply file with out-of-bounds vertex indices"
+                    );
+                }
+            }
+        }
+
+        this
+    }
+
+    /// Returns `false` if the vertex had to be skipped.
+    fn push_vertex(
+        &mut self,
+        mut props: LinkedHashMap<String, Property>,
+        ignored_props: &mut BTreeSet<String>,
+    ) -> bool {
+        let z = if self.is_2d {
+            Some(0.0)
+        } else {
+            props.get(PROP_Z).and_then(f32)
+        };
+
+        let (Some(x), Some(y), Some(z)) = (
+            props.get(PROP_X).and_then(f32),
+            props.get(PROP_Y).and_then(f32),
+            z,
+        ) else {
+            // All vertices must have positions.
+            ignored_props.extend(props.into_iter().map(|(key, _value)| key));
+            return false;
+        };
+
+        // We remove properties as they are read so we can warn about the ones we don't recognize.
+        props.remove(PROP_X);
+        props.remove(PROP_Y);
+        props.remove(PROP_Z);
+
+        self.positions.push([x, y, z]);
+
+        let color = if let (Some(r), Some(g), Some(b)) = (
+            props.get(PROP_RED).and_then(u8),
+            props.get(PROP_GREEN).and_then(u8),
+            props.get(PROP_BLUE).and_then(u8),
+        ) {
+            let a = props.get(PROP_ALPHA).and_then(u8).unwrap_or(255);
+
+            props.remove(PROP_RED);
+            props.remove(PROP_GREEN);
+            props.remove(PROP_BLUE);
+            props.remove(PROP_ALPHA);
+
+            Some(Color::new((r, g, b, a)))
+        } else {
+            None
+        };
+        self.colors.push(color);
+
+        let normal = if let (Some(nx), Some(ny), Some(nz)) = (
+            props.get(PROP_NX).and_then(f32),
+            props.get(PROP_NY).and_then(f32),
+            props.get(PROP_NZ).and_then(f32),
+        ) {
+            props.remove(PROP_NX);
+            props.remove(PROP_NY);
+            props.remove(PROP_NZ);
+
+            Some(Vector3D::from([nx, ny, nz]))
+        } else {
+            None
+        };
+        self.normals.push(normal);
+
+        let radius = props.get(PROP_RADIUS).and_then(f32).map(Radius::from);
+        if radius.is_some() {
+            props.remove(PROP_RADIUS);
+        }
+        self.radii.push(radius);
+
+        let label = props
+            .get(PROP_LABEL)
+            .and_then(string)
+            .map(|label| Text(label.to_string().into()));
+        if label.is_some() {
+            props.remove(PROP_LABEL);
+        }
+        self.labels.push(label);
+
+        ignored_props.extend(props.into_iter().map(|(key, _value)| key));
+
+        true
+    }
+
+    fn push_face(
+        &mut self,
+        mut props: LinkedHashMap<String, Property>,
+        ignored_props: &mut BTreeSet<String>,
+    ) {
+        let indices = [PROP_VERTEX_INDICES, PROP_VERTEX_INDEX]
+            .into_iter()
+            .find_map(|key| Some((key, indices(props.get(key)?)?)));
+
+        if let Some((key, indices)) = indices {
+            props.remove(key);
+
+            // Triangulate (convex) polygons as fans. Degenerate faces yield no triangles.
+            if let Some((&first, rest)) = indices.split_first() {
+                self.triangles
+                    .extend(rest.windows(2).map(|window| [first, window[0], window[1]]));
+            }
+        }
+
+        ignored_props.extend(props.into_iter().map(|(key, _value)| key));
+    }
+
+    pub(crate) fn into_points3d(self) -> Points3D {
+        re_tracing::profile_function!();
+
+        let Self {
+            is_2d: _,
+            positions,
+            colors,
+            normals,
+            radii,
+            labels,
+            triangles,
+        } = self;
+
+        warn_ignored_mesh_data(&normals, &triangles);
+
+        let (colors, radii, labels) = point_attributes(positions.len(), colors, radii, labels);
+
+        let mut arch = Points3D::new(positions.into_iter().map(Position3D::from));
+        if let Some(colors) = colors {
+            arch = arch.with_colors(colors);
+        }
+        if let Some(radii) = radii {
+            arch = arch.with_radii(radii);
+        }
+        if let Some(labels) = labels {
+            arch = arch.with_labels(labels);
+        }
+
+        arch
+    }
+
+    fn into_points2d(self) -> Points2D {
+        re_tracing::profile_function!();
+
+        let Self {
+            is_2d: _,
+            positions,
+            colors,
+            normals,
+            radii,
+            labels,
+            triangles,
+        } = self;
+
+        warn_ignored_mesh_data(&normals, &triangles);
+
+        let (colors, radii, labels) = point_attributes(positions.len(), colors, radii, labels);
+
+        let mut arch = Points2D::new(
+            positions
+                .into_iter()
+                .map(|[x, y, _z]| Position2D::new(x, y)),
+        );
+        if let Some(colors) = colors {
+            arch = arch.with_colors(colors);
+        }
+        if let Some(radii) = radii {
+            arch = arch.with_radii(radii);
+        }
+        if let Some(labels) = labels {
+            arch = arch.with_labels(labels);
+        }
+
+        arch
+    }
+
+    fn into_mesh3d(self) -> Mesh3D {
+        re_tracing::profile_function!();
+
+        let Self {
+            is_2d: _,
+            positions,
+            colors,
+            normals,
+            radii,
+            labels,
+            triangles,
+        } = self;
+
+        if radii.iter().any(Option::is_some) || labels.iter().any(Option::is_some) {
+            re_log::warn!("Ignoring radii and labels of .ply mesh");
+        }
+
+        let num_vertices = positions.len();
+
+        let mut arch = Mesh3D::new(positions.into_iter().map(Position3D::from))
+            .with_triangle_indices(triangles);
+
+        if colors.iter().any(Option::is_some) {
+            // If some colors have been specified but not others, default the unspecified ones to white.
+            let colors = colors
+                .into_iter()
+                .take(num_vertices)
+                .map(|opt| opt.unwrap_or(Color::from_rgb(255, 255, 255)));
+            arch = arch.with_vertex_colors(colors);
+        }
+        if normals.iter().any(Option::is_some) {
+            // If some normals have been specified but not others, default the unspecified ones to zero.
+            let normals = normals
+                .into_iter()
+                .take(num_vertices)
+                .map(Option::unwrap_or_default);
+            arch = arch.with_vertex_normals(normals);
+        }
+
+        arch
+    }
+
+    fn into_contents(self) -> PlyContents {
+        if !self.triangles.is_empty() {
+            PlyContents::Mesh3D(self.into_mesh3d())
+        } else if self.is_2d {
+            PlyContents::Points2D(self.into_points2d())
+        } else {
+            PlyContents::Points3D(self.into_points3d())
+        }
+    }
+}
+
+fn read_ply(source: &mut impl std::io::Read) -> anyhow::Result<Ply<DefaultElement>> {
+    re_tracing::profile_function!();
+    let parser = ply_rs::parser::Parser::<DefaultElement>::new();
+    Ok(parser.read_ply(source)?)
+}
+
+fn warn_ignored_mesh_data(normals: &[Option<Vector3D>], triangles: &[[u32; 3]]) {
+    if normals.iter().any(Option::is_some) || !triangles.is_empty() {
+        re_log::warn!("Ignoring normals and faces of .ply point cloud");
+    }
+}
+
+/// Fills in the blanks of the optional per-point attributes, if any of them was specified at all.
+#[allow(clippy::type_complexity)]
+fn point_attributes(
+    num_points: usize,
+    colors: Vec<Option<Color>>,
+    radii: Vec<Option<Radius>>,
+    labels: Vec<Option<Text>>,
+) -> (Option<Vec<Color>>, Option<Vec<Radius>>, Option<Vec<Text>>) {
+    re_tracing::profile_function!();
+
+    // If some colors have been specified but not others, default the unspecified ones to white.
+    let colors = colors.iter().any(Option::is_some).then(|| {
+        colors
+            .into_iter()
+            .take(num_points)
+            .map(|opt| opt.unwrap_or(Color::from_rgb(255, 255, 255)))
+            .collect()
+    });
+
+    // If some radii have been specified but not others, default the unspecified ones to 1.0.
+    let radii = radii.iter().any(Option::is_some).then(|| {
+        radii
+            .into_iter()
+            .take(num_points)
+            .map(|opt| opt.unwrap_or(Radius::from(1.0)))
+            .collect()
+    });
+
+    // If some labels have been specified but not others, default the unspecified ones to "undef".
+    let labels = labels.iter().any(Option::is_some).then(|| {
+        labels
+            .into_iter()
+            .take(num_points)
+            .map(|opt| opt.unwrap_or(Text("undef".into())))
+            .collect()
+    });
+
+    (colors, radii, labels)
+}
+
+// ---
+
+fn f32(prop: &Property) -> Option<f32> {
+    match *prop {
+        Property::Short(v) => Some(v as f32),
+        Property::UShort(v) => Some(v as f32),
+        Property::Int(v) => Some(v as f32),
+        Property::UInt(v) => Some(v as f32),
+        Property::Float(v) => Some(v),
+        Property::Double(v) => Some(v as f32),
+        Property::Char(_)
+        | Property::UChar(_)
+        | Property::ListChar(_)
+        | Property::ListUChar(_)
+        | Property::ListShort(_)
+        | Property::ListUShort(_)
+        | Property::ListInt(_)
+        | Property::ListUInt(_)
+        | Property::ListFloat(_)
+        | Property::ListDouble(_) => None,
+    }
+}
+
+fn u8(prop: &Property) -> Option<u8> {
+    match *prop {
+        Property::Short(v) => Some(v as u8),
+        Property::UShort(v) => Some(v as u8),
+        Property::Int(v) => Some(v as u8),
+        Property::UInt(v) => Some(v as u8),
+        Property::Float(v) => Some((v * 255.0) as u8),
+        Property::Double(v) => Some((v * 255.0) as u8),
+        Property::Char(v) => Some(v as u8),
+        Property::UChar(v) => Some(v),
+        Property::ListChar(_)
+        | Property::ListUChar(_)
+        | Property::ListShort(_)
+        | Property::ListUShort(_)
+        | Property::ListInt(_)
+        | Property::ListUInt(_)
+        | Property::ListFloat(_)
+        | Property::ListDouble(_) => None,
+    }
+}
+
+fn string(prop: &Property) -> Option<Cow<'_, str>> {
+    match prop {
+        Property::ListUChar(chars) => Some(String::from_utf8_lossy(chars)),
+        Property::ListChar(_)
+        | Property::ListShort(_)
+        | Property::ListUShort(_)
+        | Property::ListInt(_)
+        | Property::ListUInt(_)
+        | Property::ListFloat(_)
+        | Property::ListDouble(_)
+        | Property::Char(_)
+        | Property::UChar(_)
+        | Property::Short(_)
+        | Property::UShort(_)
+        | Property::Int(_)
+        | Property::UInt(_)
+        | Property::Float(_)
+        | Property::Double(_) => None,
+    }
+}
+
+/// Negative indices are mapped out of bounds, and will be discarded later on.
+fn indices(prop: &Property) -> Option<Vec<u32>> {
+    match prop {
+        Property::ListChar(indices) => Some(indices.iter().map(|&i| i as u32).collect()),
+        Property::ListUChar(indices) => Some(indices.iter().map(|&i| i as u32).collect()),
+        Property::ListShort(indices) => Some(indices.iter().map(|&i| i as u32).collect()),
+        Property::ListUShort(indices) => Some(indices.iter().map(|&i| i as u32).collect()),
+        Property::ListInt(indices) => Some(indices.iter().map(|&i| i as u32).collect()),
+        Property::ListUInt(indices) => Some(indices.clone()),
+        Property::ListFloat(_)
+        | Property::ListDouble(_)
+        | Property::Char(_)
+        | Property::UChar(_)
+        | Property::Short(_)
+        | Property::UShort(_)
+        | Property::Int(_)
+        | Property::UInt(_)
+        | Property::Float(_)
+        | Property::Double(_) => None,
+    }
+}
@@ -166,6 +166,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: FilterIsNotNull::verify_arrow_array,
             },
         ),
+        (
+            <FilterIsNotNullMode as Component>::name(),
+            ComponentReflection {
+                docstring_md: "How the columns of a dataframe's filter is not null feature are combined.",
+                deprecation_summary: None,
+                custom_placeholder: Some(FilterIsNotNullMode::default().to_arrow()?),
+                datatype: FilterIsNotNullMode::arrow_datatype(),
+                verify_arrow_array: FilterIsNotNullMode::verify_arrow_array,
+            },
+        ),
         (
             <ForceDistance as Component>::name(),
             ComponentReflection {
@@ -226,6 +236,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: IncludedContent::verify_arrow_array,
             },
         ),
+        (
+            <LatestAtScope as Component>::name(),
+            ComponentReflection {
+                docstring_md: "Which data empty cells of a dataframe may be filled from, when latest-at filling is enabled.",
+                deprecation_summary: None,
+                custom_placeholder: Some(LatestAtScope::default().to_arrow()?),
+                datatype: LatestAtScope::arrow_datatype(),
+                verify_arrow_array: LatestAtScope::verify_arrow_array,
+            },
+        ),
         (
             <LinkAxis as Component>::name(),
             ComponentReflection {
@@ -316,6 +336,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: SelectedColumns::verify_arrow_array,
             },
         ),
+        (
+            <SeriesExpression as Component>::name(),
+            ComponentReflection {
+                docstring_md: "A series computed from other series of a time series view.\n\nWritten as `<label> = <expression>`, e.g. `speed = d/dt(/robot/position)`.\nThe label is optional and defaults to the expression itself.\n\nExpressions reference series by entity path, optionally with an instance index (`/robot/joints[2]`),\nand support numbers, `+`, `-`, `*`, `/`, parentheses, `d/dt(x)` and `rolling_mean(x, n)`.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(SeriesExpression::default().to_arrow()?),
+                datatype: SeriesExpression::arrow_datatype(),
+                verify_arrow_array: SeriesExpression::verify_arrow_array,
+            },
+        ),
         (
             <TensorDimensionIndexSlider as Component>::name(),
             ComponentReflection {
@@ -328,6 +358,26 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: TensorDimensionIndexSlider::verify_arrow_array,
             },
         ),
+        (
+            <TextFilterMode as Component>::name(),
+            ComponentReflection {
+                docstring_md: "How a text filter is matched against text.",
+                deprecation_summary: None,
+                custom_placeholder: Some(TextFilterMode::default().to_arrow()?),
+                datatype: TextFilterMode::arrow_datatype(),
+                verify_arrow_array: TextFilterMode::verify_arrow_array,
+            },
+        ),
+        (
+            <TextLogColumn as Component>::name(),
+            ComponentReflection {
+                docstring_md: "A column of a text log view.\n\nOne of:\n* `\"entity_path\"`: the entity path of the entry.\n* `\"level\"`: the log level of the entry.\n* `\"body\"`: the body of the entry.\n* `\"timeline:<name>\"`: the time of the entry on the timeline `<name>`.\n* `\"field:<key>\"`: the value of the structured field `<key>` of the entry.\n\n⚠\u{fe0f} **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**",
+                deprecation_summary: None,
+                custom_placeholder: Some(TextLogColumn::default().to_arrow()?),
+                datatype: TextLogColumn::arrow_datatype(),
+                verify_arrow_array: TextLogColumn::verify_arrow_array,
+            },
+        ),
         (
             <TimelineName as Component>::name(),
             ComponentReflection {
@@ -1052,6 +1102,16 @@ fn generate_component_reflection() -> Result<ComponentReflectionMap, Serializati
                 verify_arrow_array: Text::verify_arrow_array,
             },
         ),
+        (
+            <TextLogField as Component>::name(),
+            ComponentReflection {
+                docstring_md: "A structured key-value field of a text log entry.\n\nThe first string is the key, the second one is the value.",
+                deprecation_summary: None,
+                custom_placeholder: Some(TextLogField::default().to_arrow()?),
+                datatype: TextLogField::arrow_datatype(),
+                verify_arrow_array: TextLogField::verify_arrow_array,
+            },
+        ),
         (
             <TextLogLevel as Component>::name(),
             ComponentReflection {
@@ -2248,6 +2308,10 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                     display_name : "Color", component_type : "rerun.components.Color"
                     .into(), docstring_md :
                     "Optional color to use for the log line in the Rerun Viewer.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "fields",
+                    display_name : "Fields", component_type :
+                    "rerun.components.TextLogField".into(), docstring_md :
+                    "Optional structured key-value fields attached to the message.\n\nThese are shown as extra columns in the Rerun Viewer.",
                     is_required : false, },
                 ],
             },
@@ -2430,12 +2494,22 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                     "filter_is_not_null", display_name : "Filter is not null",
                     component_type : "rerun.blueprint.components.FilterIsNotNull".into(),
                     docstring_md :
-                    "If provided, only show rows which contains a logged event for the specified component.",
+                    "If provided, only show rows which contains a logged event for the specified components.\n\nSee `filter_is_not_null_mode` for how multiple components are combined.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "filter_is_not_null_mode", display_name : "Filter is not null mode",
+                    component_type : "rerun.blueprint.components.FilterIsNotNullMode"
+                    .into(), docstring_md :
+                    "Whether rows need a logged event for any or all of the components of `filter_is_not_null`.\n\nIf unset, rows with a logged event for any of the components are shown.",
                     is_required : false, }, ArchetypeFieldReflection { name :
                     "apply_latest_at", display_name : "Apply latest at", component_type :
                     "rerun.blueprint.components.ApplyLatestAt".into(), docstring_md :
                     "Should empty cells be filled with latest-at queries?", is_required :
-                    false, }, ArchetypeFieldReflection { name : "select", display_name :
+                    false, }, ArchetypeFieldReflection { name : "latest_at_scope",
+                    display_name : "Latest at scope", component_type :
+                    "rerun.blueprint.components.LatestAtScope".into(), docstring_md :
+                    "Which data should empty cells be filled from, if `apply_latest_at` is enabled?\n\nIf unset, the latest value in the entire recording is used.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "select",
+                    display_name :
                     "Select", component_type :
                     "rerun.blueprint.components.SelectedColumns".into(), docstring_md :
                     "Selected columns. If unset, all columns are selected.", is_required
@@ -2443,6 +2517,22 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.DerivedSeries"),
+            ArchetypeReflection {
+                display_name: "Derived series",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "expressions", display_name :
+                    "Expressions", component_type :
+                    "rerun.blueprint.components.SeriesExpression".into(), docstring_md :
+                    "The expressions of the derived series, one series per expression.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.EntityBehavior"),
             ArchetypeReflection {
@@ -2797,6 +2887,49 @@ fn generate_archetype_reflection() -> ArchetypeReflectionMap {
                 ],
             },
         ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.TextLogColumns"),
+            ArchetypeReflection {
+                display_name: "Text log columns",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "order", display_name : "Order",
+                    component_type : "rerun.blueprint.components.TextLogColumn".into(),
+                    docstring_md :
+                    "The order in which columns are shown.\n\nColumns that aren't listed are shown after the listed ones, in their default order.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "hidden",
+                    display_name : "Hidden", component_type :
+                    "rerun.blueprint.components.TextLogColumn".into(), docstring_md :
+                    "Columns that aren't shown.\n\nBy default, all columns are shown.",
+                    is_required : false, },
+                ],
+            },
+        ),
+        (
+            ArchetypeName::new("rerun.blueprint.archetypes.TextLogFilter"),
+            ArchetypeReflection {
+                display_name: "Text log filter",
+                deprecation_summary: None,
+                scope: Some("blueprint"),
+                view_types: &[],
+                fields: vec![
+                    ArchetypeFieldReflection { name : "body", display_name : "Body",
+                    component_type : "rerun.components.Text".into(), docstring_md :
+                    "Only entries whose body matches this filter are shown.\n\nIf unset or empty, entries aren't filtered by their body.",
+                    is_required : false, }, ArchetypeFieldReflection { name :
+                    "entity_path", display_name : "Entity path", component_type :
+                    "rerun.components.Text".into(), docstring_md :
+                    "Only entries whose entity path matches this filter are shown.\n\nIf unset or empty, entries aren't filtered by their entity path.",
+                    is_required : false, }, ArchetypeFieldReflection { name : "mode",
+                    display_name : "Mode", component_type :
+                    "rerun.blueprint.components.TextFilterMode".into(), docstring_md :
+                    "How the filters are matched.\n\nDefaults to case-insensitive substring matching.",
+                    is_required : false, },
+                ],
+            },
+        ),
         (
             ArchetypeName::new("rerun.blueprint.archetypes.TimeAxis"),
             ArchetypeReflection {
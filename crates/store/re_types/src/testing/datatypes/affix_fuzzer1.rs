@@ -516,25 +516,27 @@ impl ::re_types_core::Loggable for AffixFuzzer1 {
                     .map(|field| field.name().as_str())
                     .zip(arrow_data_arrays)
                     .collect();
-                let single_float_optional = {
-                    if !arrays_by_name.contains_key("single_float_optional") {
-                        return Err(DeserializationError::missing_struct_field(
-                            Self::arrow_datatype(),
-                            "single_float_optional",
-                        ))
-                        .with_context("rerun.testing.datatypes.AffixFuzzer1");
-                    }
+                let single_float_optional = if arrays_by_name.contains_key("single_float_optional")
+                {
                     let arrow_data = &**arrays_by_name["single_float_optional"];
-                    arrow_data
-                        .as_any()
-                        .downcast_ref::<Float32Array>()
-                        .ok_or_else(|| {
-                            let expected = DataType::Float32;
-                            let actual = arrow_data.data_type().clone();
-                            DeserializationError::datatype_mismatch(expected, actual)
-                        })
-                        .with_context("rerun.testing.datatypes.AffixFuzzer1#single_float_optional")?
-                        .into_iter()
+                    ::itertools::Either::Left(
+                        arrow_data
+                            .as_any()
+                            .downcast_ref::<Float32Array>()
+                            .ok_or_else(|| {
+                                let expected = DataType::Float32;
+                                let actual = arrow_data.data_type().clone();
+                                DeserializationError::datatype_mismatch(expected, actual)
+                            })
+                            .with_context(
+                                "rerun.testing.datatypes.AffixFuzzer1#single_float_optional",
+                            )?
+                            .into_iter(),
+                    )
+                } else {
+                    ::itertools::Either::Right(
+                        ::std::iter::repeat_with(|| None).take(arrow_data.len()),
+                    )
                 };
                 let single_string_required = {
                     if !arrays_by_name.contains_key("single_string_required") {
@@ -590,70 +592,61 @@ impl ::re_types_core::Loggable for AffixFuzzer1 {
                             .into_iter()
                     }
                 };
-                let single_string_optional = {
-                    if !arrays_by_name.contains_key("single_string_optional") {
-                        return Err(DeserializationError::missing_struct_field(
-                            Self::arrow_datatype(),
-                            "single_string_optional",
-                        ))
-                        .with_context("rerun.testing.datatypes.AffixFuzzer1");
-                    }
-                    let arrow_data = &**arrays_by_name["single_string_optional"];
-                    {
-                        let arrow_data = arrow_data
-                            .as_any()
-                            .downcast_ref::<StringArray>()
-                            .ok_or_else(|| {
-                                let expected = DataType::Utf8;
-                                let actual = arrow_data.data_type().clone();
-                                DeserializationError::datatype_mismatch(expected, actual)
-                            })
-                            .with_context(
-                                "rerun.testing.datatypes.AffixFuzzer1#single_string_optional",
-                            )?;
-                        let arrow_data_buf = arrow_data.values();
-                        let offsets = arrow_data.offsets();
-                        ZipValidity::new_with_validity(offsets.windows(2), arrow_data.nulls())
-                            .map(|elem| {
-                                elem.map(|window| {
-                                    let start = window[0] as usize;
-                                    let end = window[1] as usize;
-                                    let len = end - start;
-                                    if arrow_data_buf.len() < end {
-                                        return Err(DeserializationError::offset_slice_oob(
-                                            (start, end),
-                                            arrow_data_buf.len(),
-                                        ));
-                                    }
+                let single_string_optional =
+                    if arrays_by_name.contains_key("single_string_optional") {
+                        let arrow_data = &**arrays_by_name["single_string_optional"];
+                        ::itertools::Either::Left({
+                            let arrow_data = arrow_data
+                                .as_any()
+                                .downcast_ref::<StringArray>()
+                                .ok_or_else(|| {
+                                    let expected = DataType::Utf8;
+                                    let actual = arrow_data.data_type().clone();
+                                    DeserializationError::datatype_mismatch(expected, actual)
+                                })
+                                .with_context(
+                                    "rerun.testing.datatypes.AffixFuzzer1#single_string_optional",
+                                )?;
+                            let arrow_data_buf = arrow_data.values();
+                            let offsets = arrow_data.offsets();
+                            ZipValidity::new_with_validity(offsets.windows(2), arrow_data.nulls())
+                                .map(|elem| {
+                                    elem.map(|window| {
+                                        let start = window[0] as usize;
+                                        let end = window[1] as usize;
+                                        let len = end - start;
+                                        if arrow_data_buf.len() < end {
+                                            return Err(DeserializationError::offset_slice_oob(
+                                                (start, end),
+                                                arrow_data_buf.len(),
+                                            ));
+                                        }
 
-                                    #[allow(unsafe_code, clippy::undocumented_unsafe_blocks)]
-                                    let data = arrow_data_buf.slice_with_length(start, len);
-                                    Ok(data)
+                                        #[allow(unsafe_code, clippy::undocumented_unsafe_blocks)]
+                                        let data = arrow_data_buf.slice_with_length(start, len);
+                                        Ok(data)
+                                    })
+                                    .transpose()
                                 })
-                                .transpose()
-                            })
-                            .map(|res_or_opt| {
-                                res_or_opt.map(|res_or_opt| {
-                                    res_or_opt.map(|v| ::re_types_core::ArrowString::from(v))
+                                .map(|res_or_opt| {
+                                    res_or_opt.map(|res_or_opt| {
+                                        res_or_opt.map(|v| ::re_types_core::ArrowString::from(v))
+                                    })
                                 })
-                            })
-                            .collect::<DeserializationResult<Vec<Option<_>>>>()
-                            .with_context(
-                                "rerun.testing.datatypes.AffixFuzzer1#single_string_optional",
-                            )?
-                            .into_iter()
-                    }
-                };
-                let many_floats_optional = {
-                    if !arrays_by_name.contains_key("many_floats_optional") {
-                        return Err(DeserializationError::missing_struct_field(
-                            Self::arrow_datatype(),
-                            "many_floats_optional",
-                        ))
-                        .with_context("rerun.testing.datatypes.AffixFuzzer1");
-                    }
+                                .collect::<DeserializationResult<Vec<Option<_>>>>()
+                                .with_context(
+                                    "rerun.testing.datatypes.AffixFuzzer1#single_string_optional",
+                                )?
+                                .into_iter()
+                        })
+                    } else {
+                        ::itertools::Either::Right(
+                            ::std::iter::repeat_with(|| None).take(arrow_data.len()),
+                        )
+                    };
+                let many_floats_optional = if arrays_by_name.contains_key("many_floats_optional") {
                     let arrow_data = &**arrays_by_name["many_floats_optional"];
-                    {
+                    ::itertools::Either::Left({
                         let arrow_data = arrow_data
                             .as_any()
                             .downcast_ref::<arrow::array::ListArray>()
@@ -710,7 +703,11 @@ impl ::re_types_core::Loggable for AffixFuzzer1 {
                                 .collect::<DeserializationResult<Vec<Option<_>>>>()?
                         }
                         .into_iter()
-                    }
+                    })
+                } else {
+                    ::itertools::Either::Right(
+                        ::std::iter::repeat_with(|| None).take(arrow_data.len()),
+                    )
                 };
                 let many_strings_required = {
                     if !arrays_by_name.contains_key("many_strings_required") {
@@ -833,16 +830,10 @@ impl ::re_types_core::Loggable for AffixFuzzer1 {
                             .into_iter()
                     }
                 };
-                let many_strings_optional = {
-                    if !arrays_by_name.contains_key("many_strings_optional") {
-                        return Err(DeserializationError::missing_struct_field(
-                            Self::arrow_datatype(),
-                            "many_strings_optional",
-                        ))
-                        .with_context("rerun.testing.datatypes.AffixFuzzer1");
-                    }
+                let many_strings_optional = if arrays_by_name.contains_key("many_strings_optional")
+                {
                     let arrow_data = &**arrays_by_name["many_strings_optional"];
-                    {
+                    ::itertools::Either::Left({
                         let arrow_data = arrow_data
                             .as_any()
                             .downcast_ref::<arrow::array::ListArray>()
@@ -952,7 +943,11 @@ impl ::re_types_core::Loggable for AffixFuzzer1 {
                                 .collect::<DeserializationResult<Vec<Option<_>>>>()?
                         }
                             .into_iter()
-                    }
+                    })
+                } else {
+                    ::itertools::Either::Right(
+                        ::std::iter::repeat_with(|| None).take(arrow_data.len()),
+                    )
                 };
                 let flattened_scalar = {
                     if !arrays_by_name.contains_key("flattened_scalar") {
@@ -989,25 +984,24 @@ impl ::re_types_core::Loggable for AffixFuzzer1 {
                         )?
                         .into_iter()
                 };
-                let from_parent = {
-                    if !arrays_by_name.contains_key("from_parent") {
-                        return Err(DeserializationError::missing_struct_field(
-                            Self::arrow_datatype(),
-                            "from_parent",
-                        ))
-                        .with_context("rerun.testing.datatypes.AffixFuzzer1");
-                    }
+                let from_parent = if arrays_by_name.contains_key("from_parent") {
                     let arrow_data = &**arrays_by_name["from_parent"];
-                    arrow_data
-                        .as_any()
-                        .downcast_ref::<BooleanArray>()
-                        .ok_or_else(|| {
-                            let expected = DataType::Boolean;
-                            let actual = arrow_data.data_type().clone();
-                            DeserializationError::datatype_mismatch(expected, actual)
-                        })
-                        .with_context("rerun.testing.datatypes.AffixFuzzer1#from_parent")?
-                        .into_iter()
+                    ::itertools::Either::Left(
+                        arrow_data
+                            .as_any()
+                            .downcast_ref::<BooleanArray>()
+                            .ok_or_else(|| {
+                                let expected = DataType::Boolean;
+                                let actual = arrow_data.data_type().clone();
+                                DeserializationError::datatype_mismatch(expected, actual)
+                            })
+                            .with_context("rerun.testing.datatypes.AffixFuzzer1#from_parent")?
+                            .into_iter(),
+                    )
+                } else {
+                    ::itertools::Either::Right(
+                        ::std::iter::repeat_with(|| None).take(arrow_data.len()),
+                    )
                 };
                 ZipValidity::new_with_validity(
                         ::itertools::izip!(
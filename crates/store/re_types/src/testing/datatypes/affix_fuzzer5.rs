@@ -123,18 +123,20 @@ impl ::re_types_core::Loggable for AffixFuzzer5 {
                     .map(|field| field.name().as_str())
                     .zip(arrow_data_arrays)
                     .collect();
-                let single_optional_union = {
-                    if !arrays_by_name.contains_key("single_optional_union") {
-                        return Err(DeserializationError::missing_struct_field(
-                            Self::arrow_datatype(),
-                            "single_optional_union",
-                        ))
-                        .with_context("rerun.testing.datatypes.AffixFuzzer5");
-                    }
+                let single_optional_union = if arrays_by_name.contains_key("single_optional_union")
+                {
                     let arrow_data = &**arrays_by_name["single_optional_union"];
-                    crate::testing::datatypes::AffixFuzzer4::from_arrow_opt(arrow_data)
-                        .with_context("rerun.testing.datatypes.AffixFuzzer5#single_optional_union")?
-                        .into_iter()
+                    ::itertools::Either::Left(
+                        crate::testing::datatypes::AffixFuzzer4::from_arrow_opt(arrow_data)
+                            .with_context(
+                                "rerun.testing.datatypes.AffixFuzzer5#single_optional_union",
+                            )?
+                            .into_iter(),
+                    )
+                } else {
+                    ::itertools::Either::Right(
+                        ::std::iter::repeat_with(|| None).take(arrow_data.len()),
+                    )
                 };
                 ZipValidity::new_with_validity(
                     ::itertools::izip!(single_optional_union),
@@ -169,18 +169,17 @@ impl ::re_types_core::Loggable for MultiEnum {
                         .with_context("rerun.testing.datatypes.MultiEnum#value1")?
                         .into_iter()
                 };
-                let value2 = {
-                    if !arrays_by_name.contains_key("value2") {
-                        return Err(DeserializationError::missing_struct_field(
-                            Self::arrow_datatype(),
-                            "value2",
-                        ))
-                        .with_context("rerun.testing.datatypes.MultiEnum");
-                    }
+                let value2 = if arrays_by_name.contains_key("value2") {
                     let arrow_data = &**arrays_by_name["value2"];
-                    crate::testing::datatypes::ValuedEnum::from_arrow_opt(arrow_data)
-                        .with_context("rerun.testing.datatypes.MultiEnum#value2")?
-                        .into_iter()
+                    ::itertools::Either::Left(
+                        crate::testing::datatypes::ValuedEnum::from_arrow_opt(arrow_data)
+                            .with_context("rerun.testing.datatypes.MultiEnum#value2")?
+                            .into_iter(),
+                    )
+                } else {
+                    ::itertools::Either::Right(
+                        ::std::iter::repeat_with(|| None).take(arrow_data.len()),
+                    )
                 };
                 ZipValidity::new_with_validity(
                     ::itertools::izip!(value1, value2),
@@ -13,6 +13,7 @@ mod line_strips2d;
 mod line_strips3d;
 mod mesh3d;
 mod pinhole;
+mod ply;
 mod points2d;
 mod points3d;
 mod segmentation_image;
@@ -27,4 +28,5 @@ mod view_coordinates;
 mod fuzzy;
 #[cfg(feature = "mint")]
 mod mint_conversions;
+mod selected_columns;
 mod validity;
@@ -0,0 +1,211 @@
+use re_types::{
+    archetypes::{Mesh3D, Points2D, Points3D},
+    components::{Color, Position2D, Position3D, Radius, Vector3D},
+    ply::PlyContents,
+};
+
+/// A quad, i.e. two triangles once triangulated, with per-vertex colors and normals.
+const MESH_HEADER: &str = "ply
+format <format> 1.0
+element vertex 4
+property float x
+property float y
+property float z
+property uchar red
+property uchar green
+property uchar blue
+property float nx
+property float ny
+property float nz
+element face 1
+property list uchar int vertex_indices
+end_header
+";
+
+const MESH_VERTICES: [([f32; 3], [u8; 3], [f32; 3]); 4] = [
+    ([0.0, 0.0, 0.0], [255, 0, 0], [0.0, 0.0, 1.0]),
+    ([1.0, 0.0, 0.0], [0, 255, 0], [0.0, 0.0, 1.0]),
+    ([1.0, 1.0, 0.0], [0, 0, 255], [0.0, 0.0, 1.0]),
+    ([0.0, 1.0, 0.0], [255, 255, 255], [0.0, 0.0, 1.0]),
+];
+
+const MESH_FACE: [i32; 4] = [0, 1, 2, 3];
+
+fn expected_mesh() -> Mesh3D {
+    Mesh3D::new(MESH_VERTICES.iter().map(|(p, _, _)| Position3D::from(*p)))
+        .with_triangle_indices([[0, 1, 2], [0, 2, 3]])
+        .with_vertex_colors(
+            MESH_VERTICES
+                .iter()
+                .map(|(_, [r, g, b], _)| Color::from_rgb(*r, *g, *b)),
+        )
+        .with_vertex_normals(MESH_VERTICES.iter().map(|(_, _, n)| Vector3D::from(*n)))
+}
+
+fn mesh_binary(big_endian: bool) -> Vec<u8> {
+    let format = if big_endian {
+        "binary_big_endian"
+    } else {
+        "binary_little_endian"
+    };
+
+    let f32_bytes = |v: f32| {
+        if big_endian {
+            v.to_be_bytes()
+        } else {
+            v.to_le_bytes()
+        }
+    };
+    let i32_bytes = |v: i32| {
+        if big_endian {
+            v.to_be_bytes()
+        } else {
+            v.to_le_bytes()
+        }
+    };
+
+    let mut bytes = MESH_HEADER.replace("<format>", format).into_bytes();
+    for (position, color, normal) in MESH_VERTICES {
+        bytes.extend(position.into_iter().flat_map(f32_bytes));
+        bytes.extend(color);
+        bytes.extend(normal.into_iter().flat_map(f32_bytes));
+    }
+    bytes.push(MESH_FACE.len() as u8);
+    bytes.extend(MESH_FACE.into_iter().flat_map(i32_bytes));
+
+    bytes
+}
+
+#[test]
+fn mesh_ascii() {
+    let mut ply = MESH_HEADER.replace("<format>", "ascii");
+    for ([x, y, z], [r, g, b], [nx, ny, nz]) in MESH_VERTICES {
+        ply += &format!("{x} {y} {z} {r} {g} {b} {nx} {ny} {nz}\n");
+    }
+    ply += "4 0 1 2 3\n";
+
+    let contents = PlyContents::from_file_contents(ply.as_bytes()).unwrap();
+    similar_asserts::assert_eq!(PlyContents::Mesh3D(expected_mesh()), contents);
+}
+
+#[test]
+fn mesh_binary_little_endian() {
+    let contents = PlyContents::from_file_contents(&mesh_binary(false)).unwrap();
+    similar_asserts::assert_eq!(PlyContents::Mesh3D(expected_mesh()), contents);
+}
+
+#[test]
+fn mesh_binary_big_endian() {
+    let contents = PlyContents::from_file_contents(&mesh_binary(true)).unwrap();
+    similar_asserts::assert_eq!(PlyContents::Mesh3D(expected_mesh()), contents);
+}
+
+#[test]
+fn mesh_out_of_bounds_faces() {
+    let ply = "ply
+format ascii 1.0
+element vertex 3
+property float x
+property float y
+property float z
+element face 2
+property list uchar uint vertex_index
+end_header
+0 0 0
+1 0 0
+0 1 0
+3 0 1 2
+3 0 1 3
+";
+
+    let contents = PlyContents::from_file_contents(ply.as_bytes()).unwrap();
+
+    let expected = Mesh3D::new([[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]])
+        .with_triangle_indices([[0, 1, 2]]);
+    similar_asserts::assert_eq!(PlyContents::Mesh3D(expected), contents);
+}
+
+#[test]
+fn points2d() {
+    let ply = "ply
+format ascii 1.0
+element vertex 3
+property float x
+property float y
+property uchar red
+property uchar green
+property uchar blue
+property float radius
+end_header
+0 0 255 0 0 0.5
+1 2 0 255 0 1.5
+3 4 0 0 255 2.5
+";
+
+    let contents = PlyContents::from_file_contents(ply.as_bytes()).unwrap();
+
+    let expected = Points2D::new([
+        Position2D::new(0.0, 0.0),
+        (1.0, 2.0).into(),
+        (3.0, 4.0).into(),
+    ])
+    .with_colors([
+        Color::from_rgb(255, 0, 0),
+        Color::from_rgb(0, 255, 0),
+        Color::from_rgb(0, 0, 255),
+    ])
+    .with_radii([Radius::from(0.5), Radius::from(1.5), Radius::from(2.5)]);
+    similar_asserts::assert_eq!(PlyContents::Points2D(expected), contents);
+}
+
+#[test]
+fn points2d_mesh() {
+    let ply = "ply
+format ascii 1.0
+element vertex 3
+property double x
+property double y
+element face 1
+property list uchar int vertex_indices
+end_header
+0 0
+1 0
+0 1
+3 0 1 2
+";
+
+    let contents = PlyContents::from_file_contents(ply.as_bytes()).unwrap();
+
+    let expected = Mesh3D::new([[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]])
+        .with_triangle_indices([[0, 1, 2]]);
+    similar_asserts::assert_eq!(PlyContents::Mesh3D(expected), contents);
+}
+
+#[test]
+fn points3d() {
+    let ply = "ply
+format ascii 1.0
+element vertex 2
+property float x
+property float y
+property float z
+property uchar red
+property uchar green
+property uchar blue
+end_header
+1 2 3 255 0 0
+4 5 6 0 255 0
+";
+
+    let contents = PlyContents::from_file_contents(ply.as_bytes()).unwrap();
+
+    let expected = Points3D::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]])
+        .with_colors([Color::from_rgb(255, 0, 0), Color::from_rgb(0, 255, 0)]);
+    similar_asserts::assert_eq!(PlyContents::Points3D(expected.clone()), contents);
+
+    // `Points3D` keeps on loading 3D point clouds as it always did.
+    similar_asserts::assert_eq!(
+        expected,
+        Points3D::from_file_contents(ply.as_bytes()).unwrap()
+    );
+}
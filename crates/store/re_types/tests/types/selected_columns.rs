@@ -0,0 +1,37 @@
+use arrow::array::{Array as _, StructArray};
+use re_types::{blueprint::datatypes::SelectedColumns, datatypes::Utf8, Loggable as _};
+
+/// Blueprints written before `row_id` was introduced must still deserialize.
+#[test]
+fn selected_columns_without_row_id() {
+    let selected_columns = SelectedColumns {
+        row_id: Some(true.into()),
+        time_columns: vec![Utf8::from("frame_nr"), Utf8::from("log_time")],
+        component_columns: vec![],
+    };
+
+    let serialized = SelectedColumns::to_arrow([&selected_columns]).unwrap();
+    let serialized = serialized
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap()
+        .clone();
+
+    let (fields, columns, nulls) = serialized.into_parts();
+    let (legacy_fields, legacy_columns): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .cloned()
+        .zip(columns)
+        .filter(|(field, _)| field.name() != "row_id")
+        .unzip();
+    let legacy = StructArray::new(legacy_fields.into(), legacy_columns, nulls);
+
+    let deserialized = SelectedColumns::from_arrow(&legacy).unwrap();
+    similar_asserts::assert_eq!(
+        vec![SelectedColumns {
+            row_id: None,
+            ..selected_columns
+        }],
+        deserialized
+    );
+}
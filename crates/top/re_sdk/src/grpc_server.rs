@@ -47,7 +47,7 @@ impl GrpcServerSink {
 
                 rt.block_on(re_grpc_server::serve_from_channel(
                     grpc_server_addr,
-                    server_memory_limit,
+                    server_memory_limit.into(),
                     shutdown,
                     channel_rx,
                 ));
@@ -1447,6 +1447,7 @@ impl RecordingStream {
                 })
                 .unwrap_or_default()
             }),
+            video_frame_rate: None,
         };
 
         if prefer_current_recording {
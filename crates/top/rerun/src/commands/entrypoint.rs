@@ -126,6 +126,44 @@ Default is `0B`, or `25%` if any of the `--serve-*` flags are set."
     )]
     server_memory_limit: Option<String>,
 
+    #[clap(
+        long,
+        default_value = None,
+        long_help = r"An upper limit on the age of the log messages buffered by the gRPC server.
+Messages older than this are dropped, and won't be seen by late-arriving viewers.
+Example: `10min` or `1h 30min`.
+Default is to keep messages until the memory limits are reached."
+    )]
+    server_max_age: Option<String>,
+
+    #[clap(
+        long,
+        default_value = None,
+        long_help = r"An upper limit on how much memory the gRPC server should use for any single recording.
+When this limit is reached, Rerun will drop the oldest data of that recording.
+This applies on top of `--server-memory-limit`.
+Example: `2GB` or `10%` (of system total).
+Default is no per-recording limit."
+    )]
+    server_recording_memory_limit: Option<String>,
+
+    #[clap(
+        long,
+        default_value = None,
+        long_help = r"A directory in which the gRPC server should persist the log messages it buffers.
+The buffered messages are restored from there when the server restarts, so that they are not lost
+if it crashes. The same limits apply to the messages on disk as to those in memory."
+    )]
+    server_persist_dir: Option<std::path::PathBuf>,
+
+    #[clap(
+        long,
+        default_value = "64MB",
+        long_help = r"The size of the segment files written to `--server-persist-dir`.
+Segments are deleted once all of their messages have been dropped by the server."
+    )]
+    server_persist_segment_size: String,
+
     #[clap(
         long,
         default_value_t = true,
@@ -765,7 +803,7 @@ fn run_impl(
     #[cfg(feature = "server")]
     let server_addr = std::net::SocketAddr::new(args.bind, args.port);
     #[cfg(feature = "server")]
-    let server_memory_limit = {
+    let server_options = {
         re_log::debug!("Parsing memory limit for gRPC server");
         let value = match &args.server_memory_limit {
             Some(v) => v.as_str(),
@@ -779,8 +817,47 @@ fn run_impl(
             }
         };
         re_log::debug!("Server memory limit: {value}");
-        re_memory::MemoryLimit::parse(value)
-            .map_err(|err| anyhow::format_err!("Bad --server-memory-limit: {err}"))?
+        let memory_limit = re_memory::MemoryLimit::parse(value)
+            .map_err(|err| anyhow::format_err!("Bad --server-memory-limit: {err}"))?;
+
+        let recording_memory_limit = match &args.server_recording_memory_limit {
+            Some(value) => re_memory::MemoryLimit::parse(value)
+                .map_err(|err| anyhow::format_err!("Bad --server-recording-memory-limit: {err}"))?,
+            None => re_memory::MemoryLimit::UNLIMITED,
+        };
+
+        let max_age = args
+            .server_max_age
+            .as_deref()
+            .map(parse_server_max_age)
+            .transpose()
+            .map_err(|err| anyhow::format_err!("Bad --server-max-age: {err}"))?;
+
+        let persistence = match &args.server_persist_dir {
+            Some(dir) => {
+                let max_segment_bytes = re_format::parse_bytes(&args.server_persist_segment_size)
+                    .and_then(|bytes| u64::try_from(bytes).ok())
+                    .ok_or_else(|| {
+                        anyhow::format_err!(
+                            "Bad --server-persist-segment-size: expected a size, e.g. `64MB`, got {:?}",
+                            args.server_persist_segment_size
+                        )
+                    })?;
+
+                Some(re_grpc_server::PersistenceOptions {
+                    dir: dir.clone(),
+                    max_segment_bytes,
+                })
+            }
+            None => None,
+        };
+
+        re_grpc_server::ServerOptions {
+            memory_limit,
+            recording_memory_limit,
+            max_age,
+            persistence,
+        }
     };
 
     #[allow(unused_variables)]
@@ -892,7 +969,7 @@ fn run_impl(
                     crossbeam::channel::Receiver<TableMsg>,
                 ) = re_grpc_server::spawn_with_recv(
                     server_addr,
-                    server_memory_limit,
+                    server_options.clone(),
                     re_grpc_server::shutdown::never(),
                 );
                 rxs_logs.push(log_server);
@@ -936,7 +1013,7 @@ fn run_impl(
             // All `rxs` are consumed by the server.
             re_grpc_server::spawn_from_rx_set(
                 server_addr,
-                server_memory_limit,
+                server_options,
                 shutdown,
                 ReceiveSet::new(rxs_log),
             );
@@ -979,7 +1056,7 @@ fn run_impl(
             // All `rxs` are consumed by the server.
             re_grpc_server::spawn_from_rx_set(
                 server_addr,
-                server_memory_limit,
+                server_options,
                 re_grpc_server::shutdown::never(),
                 ReceiveSet::new(rxs_log),
             );
@@ -1229,6 +1306,17 @@ fn parse_size(size: &str) -> anyhow::Result<[f32; 2]> {
         .ok_or_else(|| anyhow::anyhow!("Invalid size {:?}, expected e.g. 800x600", size))
 }
 
+#[cfg(feature = "server")]
+fn parse_server_max_age(max_age: &str) -> anyhow::Result<std::time::Duration> {
+    let max_age = max_age
+        .parse::<re_log_types::Duration>()
+        .map_err(|err| anyhow::anyhow!("Invalid duration {max_age:?}: {err}"))?;
+
+    u64::try_from(max_age.as_nanos())
+        .map(std::time::Duration::from_nanos)
+        .map_err(|_err| anyhow::anyhow!("Expected a positive duration, got {max_age}"))
+}
+
 // --- io ---
 
 // TODO(cmc): dedicated module for io utils, especially stdio streaming in and out.
@@ -0,0 +1,504 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use anyhow::Context as _;
+use arrow::{
+    array::{RecordBatch as ArrowRecordBatch, RecordBatchOptions, new_null_array},
+    datatypes::{Field as ArrowField, Schema as ArrowSchema},
+};
+
+use re_chunk::Chunk;
+use re_chunk_store::{ChunkStoreHandle, QueryExpression};
+use re_dataframe::QueryEngine;
+use re_log_types::{EntityPath, TimelineName};
+use re_sorbet::SorbetSchema;
+
+use super::query::{BatchWriter, OutputFormat, load_recording};
+
+// ---
+
+/// The file format used for the exported files.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum ExportFormat {
+    /// Apache Parquet.
+    #[default]
+    Parquet,
+
+    /// Arrow IPC file format.
+    Arrow,
+}
+
+impl ExportFormat {
+    fn file_extension(self) -> &'static str {
+        match self {
+            Self::Parquet => "parquet",
+            Self::Arrow => "arrow",
+        }
+    }
+}
+
+impl From<ExportFormat> for OutputFormat {
+    fn from(value: ExportFormat) -> Self {
+        match value {
+            ExportFormat::Parquet => Self::Parquet,
+            ExportFormat::Arrow => Self::Arrow,
+        }
+    }
+}
+
+/// How the recording is split into files.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum SplitBy {
+    /// One file per entity, containing the raw chunks of that entity.
+    ///
+    /// Each row is a row of the original data. Index columns are null for static data.
+    #[default]
+    Entity,
+
+    /// One file per timeline, containing a flattened dataframe of the whole recording indexed by
+    /// that timeline.
+    Timeline,
+}
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ExportCommand {
+    /// Paths to read from. Reads from standard input if none are specified.
+    path_to_input_rrds: Vec<String>,
+
+    /// Directory to write the exported files to. Created if it doesn't exist.
+    #[arg(short = 'o', long = "output", value_name = "DIR")]
+    path_to_output_dir: PathBuf,
+
+    /// The format of the exported files.
+    #[clap(long = "format", value_enum, default_value_t = ExportFormat::Parquet)]
+    format: ExportFormat,
+
+    /// How the recording is split into files.
+    #[clap(long = "split-by", value_enum, default_value_t = SplitBy::Entity)]
+    split_by: SplitBy,
+
+    /// The recording to export, if the input contains more than one.
+    #[clap(long = "recording-id")]
+    recording_id: Option<String>,
+
+    /// If set, will try to proceed even in the face of IO and/or decoding errors in the input data.
+    #[clap(long = "continue-on-error", default_value_t = false)]
+    continue_on_error: bool,
+}
+
+impl ExportCommand {
+    pub fn run(&self) -> anyhow::Result<()> {
+        let Self {
+            path_to_input_rrds,
+            path_to_output_dir,
+            format,
+            split_by,
+            recording_id,
+            continue_on_error,
+        } = self;
+
+        let now = std::time::Instant::now();
+        re_log::info!(srcs = ?path_to_input_rrds, ?format, ?split_by, "export started");
+
+        let store = load_recording(
+            path_to_input_rrds,
+            recording_id.as_deref(),
+            *continue_on_error,
+        )?;
+
+        std::fs::create_dir_all(path_to_output_dir)
+            .with_context(|| format!("{path_to_output_dir:?}"))?;
+
+        let num_files = match split_by {
+            SplitBy::Entity => export_per_entity(&store, path_to_output_dir, *format)?,
+            SplitBy::Timeline => export_per_timeline(store, path_to_output_dir, *format)?,
+        };
+
+        re_log::info!(
+            dst = ?path_to_output_dir,
+            num_files = %re_format::format_uint(num_files),
+            time = ?now.elapsed(),
+            "export finished"
+        );
+
+        Ok(())
+    }
+}
+
+// ---
+
+/// Writes one file per entity, with all the chunks of that entity unified under a single schema.
+///
+/// Returns the number of files written.
+fn export_per_entity(
+    store: &ChunkStoreHandle,
+    path_to_output_dir: &Path,
+    format: ExportFormat,
+) -> anyhow::Result<usize> {
+    let mut chunks_per_entity: BTreeMap<EntityPath, Vec<Arc<Chunk>>> = BTreeMap::new();
+    for chunk in store.read().iter_chunks() {
+        chunks_per_entity
+            .entry(chunk.entity_path().clone())
+            .or_default()
+            .push(chunk.clone());
+    }
+
+    let mut used_paths = HashSet::new();
+    for (entity_path, chunks) in &chunks_per_entity {
+        let path = path_to_output_dir.join(entity_file_path(entity_path, format));
+        anyhow::ensure!(
+            used_paths.insert(path.clone()),
+            "several entities map to the same file: {path:?}"
+        );
+
+        let batches = chunks
+            .iter()
+            .map(|chunk| chunk.to_chunk_batch())
+            .collect::<Result<Vec<_>, _>>()
+            .with_context(|| format!("couldn't convert chunks of {entity_path}"))?;
+
+        let schema = entity_schema(entity_path, &batches)?;
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).with_context(|| format!("{parent:?}"))?;
+        }
+
+        let mut writer = BatchWriter::new(format.into(), Some(&path), &schema)?;
+        for batch in &batches {
+            writer.write(&conform_to_schema(batch, &schema)?)?;
+        }
+        writer.finish()?;
+
+        re_log::debug!(entity = %entity_path, ?path, num_chunks = chunks.len(), "exported entity");
+    }
+
+    Ok(chunks_per_entity.len())
+}
+
+/// Writes one file per timeline, each containing a dataframe of the entire recording indexed by
+/// that timeline.
+///
+/// Returns the number of files written.
+fn export_per_timeline(
+    store: ChunkStoreHandle,
+    path_to_output_dir: &Path,
+    format: ExportFormat,
+) -> anyhow::Result<usize> {
+    let timelines: Vec<TimelineName> = store.read().timelines().keys().copied().collect();
+    let engine = QueryEngine::from_store(store);
+
+    let mut used_paths = HashSet::new();
+    for timeline in &timelines {
+        let path = path_to_output_dir.join(format!(
+            "{}.{}",
+            sanitize_file_name(timeline.as_str()),
+            format.file_extension()
+        ));
+        anyhow::ensure!(
+            used_paths.insert(path.clone()),
+            "several timelines map to the same file: {path:?}"
+        );
+
+        let query_handle = engine.query(QueryExpression {
+            filtered_index: Some(*timeline),
+            ..Default::default()
+        });
+
+        let mut writer = BatchWriter::new(format.into(), Some(&path), query_handle.schema())?;
+        for batch in query_handle.batch_iter() {
+            writer.write(&batch)?;
+        }
+        writer.finish()?;
+
+        re_log::debug!(%timeline, ?path, "exported timeline");
+    }
+
+    Ok(timelines.len())
+}
+
+// ---
+
+/// The path of the file for a given entity, relative to the output directory.
+///
+/// The hierarchy of the entity paths is mirrored on disk, e.g. `/world/points` ends up in
+/// `world/points.parquet`.
+fn entity_file_path(entity_path: &EntityPath, format: ExportFormat) -> PathBuf {
+    let mut parts: Vec<String> = entity_path
+        .iter()
+        .map(|part| sanitize_file_name(part.unescaped_str()))
+        .collect();
+
+    let file_stem = parts.pop().unwrap_or_else(|| "_root".to_owned());
+
+    let mut path: PathBuf = parts.into_iter().collect();
+    path.push(format!("{file_stem}.{}", format.file_extension()));
+    path
+}
+
+/// Replaces "dangerous" characters by a safe one.
+///
+/// Leading dots are replaced as well, so that no part can refer to a parent directory or end up
+/// as a hidden file.
+fn sanitize_file_name(name: &str) -> String {
+    let name = name.replace(
+        |c: char| !(c.is_alphanumeric() || matches!(c, ' ' | '-' | '_' | '.')),
+        "-",
+    );
+
+    let num_leading_dots = name.len() - name.trim_start_matches('.').len();
+    format!(
+        "{}{}",
+        "-".repeat(num_leading_dots),
+        &name[num_leading_dots..]
+    )
+}
+
+/// The union of the schemas of all the chunks of an entity.
+///
+/// Column metadata is kept as is, while chunk-specific batch metadata (chunk ID, heap size,
+/// timestamps…) is dropped.
+fn entity_schema(
+    entity_path: &EntityPath,
+    batches: &[re_sorbet::ChunkBatch],
+) -> anyhow::Result<ArrowSchema> {
+    let mut row_id_field = None;
+    let mut index_fields: BTreeMap<String, ArrowField> = BTreeMap::new();
+    let mut component_fields: BTreeMap<String, ArrowField> = BTreeMap::new();
+
+    for batch in batches {
+        let descrs = &batch.chunk_schema().columns.columns;
+        for (descr, field) in itertools::izip!(descrs, batch.schema_ref().fields()) {
+            let fields = match descr {
+                re_sorbet::ColumnDescriptor::RowId(_) => {
+                    row_id_field.get_or_insert_with(|| field.as_ref().clone());
+                    continue;
+                }
+                re_sorbet::ColumnDescriptor::Time(_) => &mut index_fields,
+                re_sorbet::ColumnDescriptor::Component(_) => &mut component_fields,
+            };
+
+            let existing = fields
+                .entry(field.name().clone())
+                .or_insert_with(|| field.as_ref().clone().with_nullable(true));
+            anyhow::ensure!(
+                existing.data_type() == field.data_type(),
+                "column {:?} of {entity_path} has conflicting datatypes: {} vs. {}",
+                field.name(),
+                existing.data_type(),
+                field.data_type(),
+            );
+        }
+    }
+
+    let metadata = std::iter::once(SorbetSchema::entity_path_metadata(entity_path))
+        .chain(batches.first().and_then(|batch| {
+            batch
+                .schema_ref()
+                .metadata()
+                .get_key_value("sorbet:version")
+                .map(|(key, value)| (key.clone(), value.clone()))
+        }))
+        .collect();
+
+    Ok(ArrowSchema::new_with_metadata(
+        itertools::chain!(
+            row_id_field,
+            index_fields.into_values(),
+            component_fields.into_values()
+        )
+        .collect::<Vec<_>>(),
+        metadata,
+    ))
+}
+
+/// Reorders the columns of a chunk to match the given schema, filling the missing ones with nulls.
+fn conform_to_schema(
+    batch: &ArrowRecordBatch,
+    schema: &ArrowSchema,
+) -> anyhow::Result<ArrowRecordBatch> {
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            batch
+                .column_by_name(field.name())
+                .cloned()
+                .unwrap_or_else(|| new_null_array(field.data_type(), batch.num_rows()))
+        })
+        .collect();
+
+    Ok(ArrowRecordBatch::try_new_with_options(
+        Arc::new(schema.clone()),
+        columns,
+        &RecordBatchOptions::default().with_row_count(Some(batch.num_rows())),
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use re_chunk::{RowId, TimePoint, Timeline};
+    use re_types::archetypes::{Points3D, Scalars};
+
+    use super::*;
+
+    #[test]
+    fn file_names() {
+        assert_eq!(sanitize_file_name("points"), "points");
+        assert_eq!(sanitize_file_name("my points-2_b.v1"), "my points-2_b.v1");
+        assert_eq!(sanitize_file_name("a/b\\c:d*e?"), "a-b-c-d-e-");
+        assert_eq!(sanitize_file_name(".."), "--");
+        assert_eq!(sanitize_file_name(".hidden.txt"), "-hidden.txt");
+        assert_eq!(sanitize_file_name("wörld"), "wörld");
+        assert_eq!(sanitize_file_name("点群 🚀"), "点群 -");
+    }
+
+    #[test]
+    fn entity_file_paths() {
+        let path = |entity_path: &str| {
+            entity_file_path(
+                &EntityPath::parse_forgiving(entity_path),
+                ExportFormat::Parquet,
+            )
+        };
+
+        assert_eq!(path("/"), PathBuf::from("_root.parquet"));
+        assert_eq!(path("/points"), PathBuf::from("points.parquet"));
+        assert_eq!(
+            path("/world/points"),
+            PathBuf::from("world").join("points.parquet")
+        );
+        assert_eq!(
+            entity_file_path(&EntityPath::from("/world/points"), ExportFormat::Arrow),
+            PathBuf::from("world").join("points.arrow")
+        );
+
+        // Nothing can escape the output directory.
+        assert_eq!(
+            path("/../world/.."),
+            PathBuf::from("--").join("world").join("--.parquet")
+        );
+
+        // Non-ASCII entity paths are preserved.
+        assert_eq!(
+            path("/世界/wörld"),
+            PathBuf::from("世界").join("wörld.parquet")
+        );
+
+        // Escaped slashes stay within a single file name…
+        assert_ne!(path("/a/b"), path(r"/a\/b"));
+        assert_ne!(path("/a/b"), path("/a_b"));
+
+        // …but sanitization can still make distinct entities collide, which the export rejects.
+        assert_eq!(path("/a/b?"), path("/a/b*"));
+        assert_eq!(path(r"/a\/b"), path("/a-b"));
+    }
+
+    #[test]
+    fn entity_schema_unifies_chunks() -> anyhow::Result<()> {
+        let entity_path = EntityPath::from("/world/points");
+        let frame = Timeline::new_sequence("frame");
+        let log_time = Timeline::new_duration("log_time");
+
+        let points = Chunk::builder(entity_path.clone())
+            .with_archetype(
+                RowId::new(),
+                TimePoint::default().with(frame, 1),
+                &Points3D::new([[1.0, 2.0, 3.0]]),
+            )
+            .build()?;
+        let scalars = Chunk::builder(entity_path.clone())
+            .with_archetype(
+                RowId::new(),
+                TimePoint::default().with(log_time, 2),
+                &Scalars::new([4.0]),
+            )
+            .build()?;
+        let static_points = Chunk::builder(entity_path.clone())
+            .with_archetype(
+                RowId::new(),
+                TimePoint::default(),
+                &Points3D::new([[5.0, 6.0, 7.0]]),
+            )
+            .build()?;
+
+        let batches = [&points, &scalars, &static_points]
+            .into_iter()
+            .map(|chunk| chunk.to_chunk_batch())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let schema = entity_schema(&entity_path, &batches)?;
+
+        // Row ID first, then the sorted indices, then the sorted components.
+        let field_names = schema
+            .fields()
+            .iter()
+            .map(|field| field.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(field_names.len(), 5);
+        assert!(field_names[0].contains("row_id"), "{field_names:?}");
+        assert_eq!(field_names[1], "frame");
+        assert_eq!(field_names[2], "log_time");
+        assert!(field_names[3].contains("positions"), "{field_names:?}");
+        assert!(field_names[4].contains("scalars"), "{field_names:?}");
+
+        // Indices and components may be missing from some chunks.
+        assert!(!schema.field(0).is_nullable());
+        assert!(schema.fields()[1..].iter().all(|field| field.is_nullable()));
+
+        // Chunk-specific metadata is dropped.
+        assert_eq!(
+            schema.metadata().get("rerun:entity_path"),
+            Some(&entity_path.to_string())
+        );
+        assert!(
+            schema
+                .metadata()
+                .keys()
+                .all(|key| key == "rerun:entity_path" || key == "sorbet:version")
+        );
+
+        for batch in &batches {
+            let conformed = conform_to_schema(batch, &schema)?;
+            assert_eq!(conformed.schema().as_ref(), &schema);
+            assert_eq!(conformed.num_rows(), 1);
+
+            // Columns the chunk doesn't have are null, the others are kept as-is.
+            for (field, column) in itertools::izip!(schema.fields(), conformed.columns()) {
+                match batch.column_by_name(field.name()) {
+                    Some(original) => assert_eq!(original, column),
+                    None => assert_eq!(column.null_count(), 1),
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn entity_schema_conflicting_datatypes() -> anyhow::Result<()> {
+        let entity_path = EntityPath::from("/conflict");
+
+        let sequence = Chunk::builder(entity_path.clone())
+            .with_archetype(
+                RowId::new(),
+                TimePoint::default().with(Timeline::new_sequence("t"), 1),
+                &Scalars::new([1.0]),
+            )
+            .build()?;
+        let duration = Chunk::builder(entity_path.clone())
+            .with_archetype(
+                RowId::new(),
+                TimePoint::default().with(Timeline::new_duration("t"), 1),
+                &Scalars::new([2.0]),
+            )
+            .build()?;
+
+        let batches = [sequence.to_chunk_batch()?, duration.to_chunk_batch()?];
+        assert!(entity_schema(&entity_path, &batches).is_err());
+
+        Ok(())
+    }
+}
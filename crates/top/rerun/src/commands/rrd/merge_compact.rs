@@ -5,13 +5,62 @@ use itertools::Either;
 
 use re_chunk_store::{ChunkStore, ChunkStoreConfig, ChunkStoreError};
 use re_entity_db::EntityDb;
-use re_log_types::StoreId;
+use re_log_encoding::Compression;
+use re_log_types::{StoreId, TimelineName};
 use re_sdk::StoreKind;
 
 use crate::commands::read_rrd_streams_from_file_or_stdin;
 
 // ---
 
+/// Output compression options shared by [`MergeCommand`] and [`CompactCommand`].
+#[derive(Debug, Clone, clap::Args)]
+struct CompressionArgs {
+    /// Compresses the output with Zstd rather than LZ4, optionally at the given level.
+    ///
+    /// This yields much smaller files at the cost of slower encoding, which is a good fit for archival.
+    /// Older versions of Rerun will refuse to load the resulting files.
+    ///
+    /// Example: `--zstd` or `--zstd=19`.
+    #[clap(
+        long = "zstd",
+        value_name = "LEVEL",
+        num_args = 0..=1,
+        require_equals = true,
+        value_parser = parse_zstd_level,
+    )]
+    #[expect(clippy::option_option)]
+    // `Some(None)` is clap's way of saying "flag set, but no value".
+    zstd_level: Option<Option<i32>>,
+}
+
+impl CompressionArgs {
+    fn encoding_options(&self) -> re_log_encoding::EncodingOptions {
+        let mut encoding_options = re_log_encoding::EncodingOptions::PROTOBUF_COMPRESSED;
+        if let Some(level) = self.zstd_level {
+            encoding_options.compression = re_log_encoding::Compression::Zstd {
+                level: level.unwrap_or(Compression::ZSTD_DEFAULT_LEVEL),
+            };
+        }
+        encoding_options
+    }
+}
+
+fn parse_zstd_level(level: &str) -> Result<i32, String> {
+    let level = level.parse::<i32>().map_err(|err| err.to_string())?;
+    if Compression::ZSTD_LEVELS.contains(&level) {
+        Ok(level)
+    } else {
+        Err(format!(
+            "expected a level in {}..={}",
+            Compression::ZSTD_LEVELS.start(),
+            Compression::ZSTD_LEVELS.end()
+        ))
+    }
+}
+
+// ---
+
 #[derive(Debug, Clone, clap::Parser)]
 pub struct MergeCommand {
     /// Paths to read from. Reads from standard input if none are specified.
@@ -24,6 +73,9 @@ pub struct MergeCommand {
     /// If set, will try to proceed even in the face of IO and/or decoding errors in the input data.
     #[clap(long = "continue-on-error", default_value_t = false)]
     continue_on_error: bool,
+
+    #[clap(flatten)]
+    compression: CompressionArgs,
 }
 
 impl MergeCommand {
@@ -32,6 +84,7 @@ impl MergeCommand {
             path_to_input_rrds,
             path_to_output_rrd,
             continue_on_error,
+            compression,
         } = self;
 
         if path_to_output_rrd.is_none() {
@@ -52,6 +105,7 @@ impl MergeCommand {
             num_passes,
             *continue_on_error,
             &store_config,
+            compression.encoding_options(),
             path_to_input_rrds,
             path_to_output_rrd.as_ref(),
         )
@@ -89,6 +143,21 @@ pub struct CompactCommand {
     #[arg(long = "max-rows-if-unsorted")]
     max_rows_if_unsorted: Option<u64>,
 
+    /// What is the threshold, in terms of time span covered on a given timeline, after which a Chunk
+    /// cannot be compacted any further?
+    ///
+    /// Expects `timeline=span`, where `span` is either an integer in the native unit of the timeline
+    /// (e.g. `frame_nr=100`), or a duration for temporal timelines (e.g. `log_time=10s`).
+    /// Can be specified multiple times, once per timeline.
+    ///
+    /// Overrides `RERUN_CHUNK_MAX_TIME_SPAN` for the specified timelines.
+    #[arg(
+        long = "max-time-span",
+        value_name = "TIMELINE=SPAN",
+        value_parser = ChunkStoreConfig::parse_chunk_max_time_span,
+    )]
+    max_time_spans: Vec<(TimelineName, u64)>,
+
     /// Configures the number of extra compaction passes to run on the data.
     ///
     /// Compaction in Rerun is an iterative, convergent process: every single pass will improve the
@@ -110,6 +179,9 @@ pub struct CompactCommand {
     /// If set, will try to proceed even in the face of IO and/or decoding errors in the input data.
     #[clap(long = "continue-on-error", default_value_t = false)]
     continue_on_error: bool,
+
+    #[clap(flatten)]
+    compression: CompressionArgs,
 }
 
 impl CompactCommand {
@@ -120,8 +192,10 @@ impl CompactCommand {
             max_bytes,
             max_rows,
             max_rows_if_unsorted,
+            max_time_spans,
             num_extra_passes,
             continue_on_error,
+            compression,
         } = self;
 
         if path_to_output_rrd.is_none() {
@@ -145,11 +219,15 @@ impl CompactCommand {
         if let Some(max_rows_if_unsorted) = max_rows_if_unsorted {
             store_config.chunk_max_rows_if_unsorted = *max_rows_if_unsorted;
         }
+        store_config
+            .chunk_max_time_span_per_timeline
+            .extend(max_time_spans.iter().copied());
 
         merge_and_compact(
             *num_extra_passes,
             *continue_on_error,
             &store_config,
+            compression.encoding_options(),
             path_to_input_rrds,
             path_to_output_rrd.as_ref(),
         )
@@ -160,6 +238,7 @@ fn merge_and_compact(
     num_passes: u32,
     continue_on_error: bool,
     store_config: &ChunkStoreConfig,
+    encoding_options: re_log_encoding::EncodingOptions,
     path_to_input_rrds: &[String],
     path_to_output_rrd: Option<&String>,
 ) -> anyhow::Result<()> {
@@ -175,6 +254,8 @@ fn merge_and_compact(
         max_rows = %re_format::format_uint(store_config.chunk_max_rows),
         max_rows_if_unsorted = %re_format::format_uint(store_config.chunk_max_rows_if_unsorted),
         max_bytes = %re_format::format_bytes(store_config.chunk_max_bytes as _),
+        max_time_spans = ?store_config.chunk_max_time_span_per_timeline,
+        compression = ?encoding_options.compression,
         srcs = ?path_to_input_rrds,
         "merge/compaction started"
     );
@@ -299,8 +380,7 @@ fn merge_and_compact(
             num_chunks_after += matches!(msg, Ok(re_log_types::LogMsg::ArrowMsg(_, _))) as u64;
         });
 
-    // TODO(cmc): encoding options should match the original.
-    let encoding_options = re_log_encoding::EncodingOptions::PROTOBUF_COMPRESSED;
+    // TODO(cmc): encoding options should match the original by default.
     let version = entity_dbs
         .values()
         .next()
@@ -1,18 +1,22 @@
 mod compare;
+mod export;
 mod filter;
 mod merge_compact;
 mod migrate;
 mod print;
+mod query;
 mod route;
 mod stats;
 mod verify;
 
 use self::{
     compare::CompareCommand,
+    export::ExportCommand,
     filter::FilterCommand,
     merge_compact::{CompactCommand, MergeCommand},
     migrate::MigrateCommand,
     print::PrintCommand,
+    query::QueryCommand,
     route::RouteCommand,
     stats::StatsCommand,
     verify::VerifyCommand,
@@ -33,7 +37,8 @@ pub enum RrdCommands {
     /// Uses the usual environment variables to control the compaction thresholds:
     /// `RERUN_CHUNK_MAX_ROWS`,
     /// `RERUN_CHUNK_MAX_ROWS_IF_UNSORTED`,
-    /// `RERUN_CHUNK_MAX_BYTES`.
+    /// `RERUN_CHUNK_MAX_BYTES`,
+    /// `RERUN_CHUNK_MAX_TIME_SPAN`.
     ///
     /// Unless explicit flags are passed, in which case they will override environment values.
     ///
@@ -44,6 +49,10 @@ pub enum RrdCommands {
     /// * `RERUN_CHUNK_MAX_ROWS=4096 RERUN_CHUNK_MAX_BYTES=1048576 rerun rrd compact /my/recordings/*.rrd -o output.rrd`
     ///
     /// * `rerun rrd compact --max-rows 4096 --max-bytes=1048576 /my/recordings/*.rrd > output.rrd`
+    ///
+    /// * `rerun rrd compact --max-time-span log_time=10s --max-time-span frame_nr=100 /my/recordings/*.rrd > output.rrd`
+    ///
+    /// * `rerun rrd compact --zstd=19 /my/recordings/*.rrd -o archive.rrd`
     Compact(CompactCommand),
 
     /// Compares the data between 2 .rrd files, returning a successful shell exit code if they
@@ -52,6 +61,21 @@ pub enum RrdCommands {
     /// This ignores the `log_time` timeline.
     Compare(CompareCommand),
 
+    /// Exports the contents of one or more .rrd files/streams as Parquet or Arrow IPC files, for
+    /// use in analysis tools.
+    ///
+    /// Reads from standard input if no paths are specified.
+    ///
+    /// Writes either one file per entity, or one flattened dataframe per timeline. Rerun's column
+    /// metadata (entity path, archetype, component…) is preserved in the Arrow field metadata.
+    ///
+    /// Examples:
+    ///
+    /// * `rerun rrd export my.rrd -o my_recording/`
+    ///
+    /// * `rerun rrd export --split-by timeline --format arrow my.rrd -o my_recording/`
+    Export(ExportCommand),
+
     /// Filters out data from .rrd/.rbl files/streams, and writes the result to standard output.
     ///
     /// Reads from standard input if no paths are specified.
@@ -83,6 +107,18 @@ pub enum RrdCommands {
     /// Example: `rerun rrd print /my/recordings/*.rrd`
     Print(PrintCommand),
 
+    /// Runs a dataframe query against the contents of one or more .rrd files/streams, and writes
+    /// the results as Arrow IPC, Parquet or CSV.
+    ///
+    /// Reads from standard input if no paths are specified.
+    ///
+    /// Examples:
+    ///
+    /// * `rerun rrd query --index frame_nr --with /world/points:[Points3D:positions, Points3D:radii] my.rrd -o points.parquet`
+    ///
+    /// * `rerun rrd query --index log_time --range 2025-01-01T00:00:00Z.. --fill latest-at-global my.rrd --format csv`
+    Query(QueryCommand),
+
     /// Manipulates the metadata of log message streams without decoding the payloads.
     ///
     /// This can be used to combine multiple .rrd files into a single recording.
@@ -113,10 +149,12 @@ impl RrdCommands {
                     .with_context(|| format!("current directory {:?}", std::env::current_dir()))
             }
             Self::Compact(cmd) => cmd.run(),
+            Self::Export(cmd) => cmd.run(),
             Self::Filter(cmd) => cmd.run(),
             Self::Merge(cmd) => cmd.run(),
             Self::Migrate(cmd) => cmd.run(),
             Self::Print(cmd) => cmd.run(),
+            Self::Query(cmd) => cmd.run(),
             Self::Route(cmd) => cmd.run(),
             Self::Stats(cmd) => cmd.run(),
             Self::Verify(cmd) => cmd.run(),
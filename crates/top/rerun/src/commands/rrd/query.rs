@@ -0,0 +1,676 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::IsTerminal as _,
+    path::Path,
+    sync::Arc,
+};
+
+use anyhow::Context as _;
+use arrow::{
+    array::{ArrayRef as ArrowArrayRef, RecordBatch as ArrowRecordBatch, StringBuilder},
+    datatypes::{DataType as ArrowDataType, Field as ArrowField, Schema as ArrowSchema},
+    util::display::{ArrayFormatter, FormatOptions},
+};
+use itertools::Itertools as _;
+
+use re_chunk::{Chunk, ComponentIdentifier};
+use re_chunk_store::{
+    ChunkStore, ChunkStoreConfig, ChunkStoreHandle, IsNotNullMode, QueryExpression,
+    SparseFillStrategy, ViewContentsSelector,
+};
+use re_dataframe::QueryEngine;
+use re_log_types::{
+    EntityPath, ResolvedTimeRange, StoreId, StoreKind, TimeInt, TimeType, TimelineName,
+};
+use re_sorbet::{ColumnSelector, ComponentColumnSelector};
+
+use crate::commands::read_rrd_streams_from_file_or_stdin;
+
+// ---
+
+/// The file format used to write the results of a query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(super) enum OutputFormat {
+    /// Arrow IPC file format.
+    Arrow,
+
+    /// Apache Parquet.
+    Parquet,
+
+    /// Comma-separated values. Nested values are written using their textual representation.
+    Csv,
+}
+
+impl OutputFormat {
+    fn from_path(path: &str) -> Option<Self> {
+        let extension = Path::new(path).extension()?.to_str()?.to_lowercase();
+
+        match extension.as_str() {
+            "arrow" | "ipc" | "feather" => Some(Self::Arrow),
+            "parquet" => Some(Self::Parquet),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+}
+
+/// How null values should be filled in the results of a query.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum SparseFill {
+    /// No sparse filling. Nulls stay nulls.
+    #[default]
+    None,
+
+    /// Fill null values with the latest known value in the entire recording.
+    LatestAtGlobal,
+
+    /// Fill null values with the latest known value within the view contents and index range.
+    LatestAtView,
+}
+
+impl From<SparseFill> for SparseFillStrategy {
+    fn from(value: SparseFill) -> Self {
+        match value {
+            SparseFill::None => Self::None,
+            SparseFill::LatestAtGlobal => Self::LatestAtGlobal,
+            SparseFill::LatestAtView => Self::LatestAtView,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum FilterMode {
+    /// Keep rows where any of the columns is not null.
+    #[default]
+    Any,
+
+    /// Keep rows where all of the columns are not null.
+    All,
+}
+
+impl From<FilterMode> for IsNotNullMode {
+    fn from(value: FilterMode) -> Self {
+        match value {
+            FilterMode::Any => Self::Any,
+            FilterMode::All => Self::All,
+        }
+    }
+}
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct QueryCommand {
+    /// Paths to read from. Reads from standard input if none are specified.
+    path_to_input_rrds: Vec<String>,
+
+    /// Path to write to. Writes to standard output if unspecified.
+    #[arg(short = 'o', long = "output", value_name = "dst.(arrow|parquet|csv)")]
+    path_to_output: Option<String>,
+
+    /// The format of the output.
+    ///
+    /// Inferred from the extension of the output path if unspecified, defaults to Arrow otherwise.
+    #[clap(long = "format", value_enum)]
+    format: Option<OutputFormat>,
+
+    /// The recording to query, if the input contains more than one.
+    #[clap(long = "recording-id")]
+    recording_id: Option<String>,
+
+    /// Restricts the view contents to an entity, and optionally to some of its components.
+    ///
+    /// Either `/entity/path` for all components of that entity, or
+    /// `/entity/path:[Archetype:component, …]` for specific components.
+    ///
+    /// Can be specified multiple times, once per entity. Everything is included if unspecified.
+    #[clap(
+        long = "with",
+        value_name = "ENTITY[:[COMPONENTS]]",
+        value_parser = ViewContentsSelector::parse_entry,
+    )]
+    view_contents: Vec<(EntityPath, Option<BTreeSet<ComponentIdentifier>>)>,
+
+    /// The timeline to use as the index of the results.
+    ///
+    /// Only static data is returned if unspecified.
+    #[clap(long = "index", value_name = "TIMELINE")]
+    index: Option<String>,
+
+    /// Only return rows whose index value lies within this inclusive range.
+    ///
+    /// Either bound can be omitted (e.g. `100..`).
+    /// Values are integers for sequence timelines, durations (e.g. `1.5s`) for duration
+    /// timelines and RFC 3339 timestamps for timestamp timelines.
+    /// Raw integers are interpreted as nanoseconds on duration timelines, and rejected on timestamp
+    /// timelines.
+    #[clap(long = "range", value_name = "START..END", requires = "index")]
+    range: Option<String>,
+
+    /// Only return rows where this component column is not null.
+    ///
+    /// Can be specified multiple times, see `--filter-is-not-null-mode`.
+    #[clap(
+        long = "filter-is-not-null",
+        value_name = "ENTITY:COMPONENT",
+        requires = "index"
+    )]
+    filtered_is_not_null: Vec<ComponentColumnSelector>,
+
+    /// Whether rows need _any_ or _all_ of the `--filter-is-not-null` columns to be non-null.
+    #[clap(long = "filter-is-not-null-mode", value_enum, default_value_t = FilterMode::Any)]
+    filtered_is_not_null_mode: FilterMode,
+
+    /// How null values should be filled in.
+    #[clap(long = "fill", value_enum, default_value_t = SparseFill::None)]
+    sparse_fill: SparseFill,
+
+    /// The columns to return, in order.
+    ///
+    /// Either `row_id`, the name of a timeline, or `/entity/path:Archetype:component`.
+    ///
+    /// Can be specified multiple times. Every column in the view contents is returned if unspecified.
+    #[clap(long = "select", value_name = "COLUMN")]
+    selection: Vec<String>,
+
+    /// If set, will try to proceed even in the face of IO and/or decoding errors in the input data.
+    #[clap(long = "continue-on-error", default_value_t = false)]
+    continue_on_error: bool,
+}
+
+impl QueryCommand {
+    pub fn run(&self) -> anyhow::Result<()> {
+        let Self {
+            path_to_input_rrds,
+            path_to_output,
+            format,
+            recording_id,
+            view_contents,
+            index,
+            range,
+            filtered_is_not_null,
+            filtered_is_not_null_mode,
+            sparse_fill,
+            selection,
+            continue_on_error,
+        } = self;
+
+        let format = format
+            .or_else(|| path_to_output.as_deref().and_then(OutputFormat::from_path))
+            .unwrap_or(OutputFormat::Arrow);
+
+        if path_to_output.is_none() && format != OutputFormat::Csv {
+            anyhow::ensure!(
+                !std::io::stdout().is_terminal(),
+                "you must redirect the output to a file and/or stream"
+            );
+        }
+
+        let now = std::time::Instant::now();
+        re_log::info!(srcs = ?path_to_input_rrds, ?format, "query started");
+
+        let store = load_recording(
+            path_to_input_rrds,
+            recording_id.as_deref(),
+            *continue_on_error,
+        )?;
+
+        let filtered_index = index.as_deref().map(TimelineName::new);
+        let filtered_index_range = match (filtered_index.as_ref(), range.as_deref()) {
+            (Some(timeline), Some(range)) => {
+                let Some(time_type) = store
+                    .read()
+                    .timelines()
+                    .get(timeline)
+                    .map(|timeline| timeline.typ())
+                else {
+                    anyhow::bail!("unknown timeline: {timeline:?}");
+                };
+
+                Some(parse_index_range(range, time_type).with_context(|| format!("{range:?}"))?)
+            }
+            _ => None,
+        };
+
+        let query = QueryExpression {
+            view_contents: parse_view_contents(view_contents)?,
+            filtered_index,
+            filtered_index_range,
+            filtered_is_not_null: filtered_is_not_null.clone(),
+            filtered_is_not_null_mode: (*filtered_is_not_null_mode).into(),
+            sparse_fill_strategy: (*sparse_fill).into(),
+            selection: (!selection.is_empty())
+                .then(|| selection.iter().map(|s| parse_column_selector(s)).collect()),
+            ..Default::default()
+        };
+        re_log::debug!(?query, "running query");
+
+        let engine = QueryEngine::from_store(store);
+        let query_handle = engine.query(query);
+
+        let mut writer = BatchWriter::new(
+            format,
+            path_to_output.as_deref().map(Path::new),
+            query_handle.schema(),
+        )?;
+        let mut num_rows = 0;
+        for batch in query_handle.batch_iter() {
+            num_rows += batch.num_rows();
+            writer.write(&batch)?;
+        }
+        writer.finish()?;
+
+        re_log::info!(
+            num_rows = %re_format::format_uint(num_rows),
+            time = ?now.elapsed(),
+            "query finished"
+        );
+
+        Ok(())
+    }
+}
+
+// ---
+
+/// Loads the recording to be queried into a new [`ChunkStore`].
+///
+/// Blueprints are ignored. If the input contains several recordings, `recording_id` must be
+/// specified.
+pub(super) fn load_recording(
+    path_to_input_rrds: &[String],
+    recording_id: Option<&str>,
+    continue_on_error: bool,
+) -> anyhow::Result<ChunkStoreHandle> {
+    let (rx, _rx_size_bytes) = read_rrd_streams_from_file_or_stdin(path_to_input_rrds);
+
+    let mut stores: BTreeMap<StoreId, ChunkStore> = BTreeMap::new();
+    for (_source, res) in rx {
+        let mut is_success = true;
+
+        match res {
+            Ok(re_log_types::LogMsg::SetStoreInfo(info)) => {
+                let store_id = info.info.store_id.clone();
+                if store_id.kind == StoreKind::Recording {
+                    stores
+                        .entry(store_id.clone())
+                        .or_insert_with(|| ChunkStore::new(store_id, ChunkStoreConfig::DEFAULT))
+                        .set_store_info(info.info);
+                }
+            }
+
+            Ok(re_log_types::LogMsg::ArrowMsg(store_id, msg)) => {
+                if store_id.kind == StoreKind::Recording {
+                    let store = stores.entry(store_id.clone()).or_insert_with(|| {
+                        ChunkStore::new(store_id.clone(), ChunkStoreConfig::DEFAULT)
+                    });
+
+                    match Chunk::from_arrow_msg(&msg) {
+                        Ok(chunk) => {
+                            if let Err(err) = store.insert_chunk(&Arc::new(chunk)) {
+                                re_log::error!(%err, "couldn't index corrupt chunk");
+                                is_success = false;
+                            }
+                        }
+                        Err(err) => {
+                            re_log::error!(%err, "couldn't decode chunk");
+                            is_success = false;
+                        }
+                    }
+                }
+            }
+
+            Ok(re_log_types::LogMsg::BlueprintActivationCommand(_)) => {}
+
+            Err(err) => {
+                re_log::error!(err = re_error::format(err));
+                is_success = false;
+            }
+        }
+
+        if !continue_on_error && !is_success {
+            anyhow::bail!(
+                "one or more IO and/or decoding failures in the input stream (check logs)"
+            )
+        }
+    }
+
+    let store = if let Some(recording_id) = recording_id {
+        stores
+            .into_iter()
+            .find(|(store_id, _store)| store_id.id.as_str() == recording_id)
+            .map(|(_store_id, store)| store)
+            .with_context(|| format!("recording not found: {recording_id:?}"))?
+    } else {
+        match stores.len() {
+            0 => anyhow::bail!("no recording found in the input"),
+            1 => stores.into_values().next().expect("checked above"),
+            _ => anyhow::bail!(
+                "the input contains several recordings, use `--recording-id` to pick one of: {}",
+                stores
+                    .keys()
+                    .map(|store_id| store_id.id.as_str())
+                    .join(", ")
+            ),
+        }
+    };
+
+    Ok(ChunkStoreHandle::new(store))
+}
+
+/// Parses a `START..END` index range, where either bound can be omitted.
+fn parse_index_range(range: &str, time_type: TimeType) -> anyhow::Result<ResolvedTimeRange> {
+    let Some((start, end)) = range.split_once("..") else {
+        anyhow::bail!("expected `START..END`");
+    };
+
+    let start = match start.trim() {
+        "" => TimeInt::MIN,
+        start => parse_index_value(start, time_type)?,
+    };
+    let end = match end.trim() {
+        "" => TimeInt::MAX,
+        end => parse_index_value(end, time_type)?,
+    };
+
+    anyhow::ensure!(
+        start <= end,
+        "start of range cannot be greater than its end"
+    );
+
+    Ok(ResolvedTimeRange::new(start, end))
+}
+
+fn parse_index_value(value: &str, time_type: TimeType) -> anyhow::Result<TimeInt> {
+    let value = match time_type {
+        TimeType::Sequence => value
+            .parse::<i64>()
+            .with_context(|| format!("expected an integer, got {value:?}"))?,
+
+        TimeType::DurationNs => {
+            if let Ok(nanos) = value.parse::<i64>() {
+                nanos
+            } else {
+                value
+                    .parse::<re_log_types::Duration>()
+                    .with_context(|| format!("expected a duration, got {value:?}"))?
+                    .as_nanos()
+            }
+        }
+
+        TimeType::TimestampNs => {
+            anyhow::ensure!(
+                value.parse::<i64>().is_err(),
+                "expected an RFC 3339 timestamp (e.g. `2025-01-01T00:00:00Z`), got raw integer {value:?}"
+            );
+
+            value
+                .parse::<re_log_types::Timestamp>()
+                .with_context(|| format!("expected a timestamp, got {value:?}"))?
+                .nanos_since_epoch()
+        }
+    };
+
+    Ok(TimeInt::new_temporal(value))
+}
+
+/// Component columns are specified as `/entity/path:Archetype:component`, `row_id` is the row id
+/// column, anything else is a timeline name.
+fn parse_column_selector(column: &str) -> ColumnSelector {
+    if column == "row_id" {
+        return ColumnSelector::RowId;
+    }
+
+    column.parse::<ComponentColumnSelector>().map_or_else(
+        |_err| ColumnSelector::Time(re_sorbet::TimeColumnSelector::from(column)),
+        ColumnSelector::Component,
+    )
+}
+
+// ---
+
+/// Writes record batches in any of the supported [`OutputFormat`]s.
+pub(super) enum BatchWriter {
+    Arrow(arrow::ipc::writer::FileWriter<Box<dyn std::io::Write + Send>>),
+    Parquet(parquet::arrow::ArrowWriter<Box<dyn std::io::Write + Send>>),
+    Csv(Box<arrow::csv::Writer<Box<dyn std::io::Write + Send>>>),
+}
+
+impl BatchWriter {
+    pub(super) fn new(
+        format: OutputFormat,
+        path_to_output: Option<&Path>,
+        schema: &ArrowSchema,
+    ) -> anyhow::Result<Self> {
+        let out: Box<dyn std::io::Write + Send> = if let Some(path) = path_to_output {
+            Box::new(std::io::BufWriter::new(
+                std::fs::File::create(path).with_context(|| format!("{path:?}"))?,
+            ))
+        } else {
+            Box::new(std::io::BufWriter::new(std::io::stdout()))
+        };
+
+        Ok(match format {
+            OutputFormat::Arrow => Self::Arrow(
+                arrow::ipc::writer::FileWriter::try_new(out, schema)
+                    .context("couldn't init Arrow writer")?,
+            ),
+
+            OutputFormat::Parquet => Self::Parquet(
+                parquet::arrow::ArrowWriter::try_new(out, Arc::new(schema.clone()), None)
+                    .context("couldn't init Parquet writer")?,
+            ),
+
+            OutputFormat::Csv => Self::Csv(Box::new(arrow::csv::Writer::new(out))),
+        })
+    }
+
+    pub(super) fn write(&mut self, batch: &ArrowRecordBatch) -> anyhow::Result<()> {
+        match self {
+            Self::Arrow(writer) => writer.write(batch).context("couldn't write Arrow batch"),
+            Self::Parquet(writer) => writer.write(batch).context("couldn't write Parquet batch"),
+            Self::Csv(writer) => writer
+                .write(&stringify_nested_columns(batch)?)
+                .context("couldn't write CSV batch"),
+        }
+    }
+
+    pub(super) fn finish(self) -> anyhow::Result<()> {
+        match self {
+            Self::Arrow(mut writer) => {
+                writer.finish().context("couldn't finish Arrow file")?;
+                writer.into_inner()?.flush()?;
+            }
+            Self::Parquet(writer) => {
+                writer.close().context("couldn't finish Parquet file")?;
+            }
+            Self::Csv(writer) => {
+                writer.into_inner().flush()?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Builds the view contents from the `--with` flags.
+///
+/// Each entity may only be specified once, rather than silently keeping only the last occurrence.
+fn parse_view_contents(
+    view_contents: &[(EntityPath, Option<BTreeSet<ComponentIdentifier>>)],
+) -> anyhow::Result<Option<ViewContentsSelector>> {
+    if view_contents.is_empty() {
+        return Ok(None);
+    }
+
+    let mut selector = BTreeMap::new();
+    for (entity_path, components) in view_contents {
+        anyhow::ensure!(
+            selector
+                .insert(entity_path.clone(), components.clone())
+                .is_none(),
+            "--with {entity_path} is specified more than once, list all of its components in a single flag instead"
+        );
+    }
+
+    Ok(Some(ViewContentsSelector(selector)))
+}
+
+/// CSV cannot represent nested data: replace every non-primitive column with its textual representation.
+fn stringify_nested_columns(batch: &ArrowRecordBatch) -> anyhow::Result<ArrowRecordBatch> {
+    let format_options = FormatOptions::default().with_null("");
+
+    let (fields, columns): (Vec<_>, Vec<_>) =
+        itertools::izip!(batch.schema().fields(), batch.columns())
+            .map(|(field, column)| {
+                if !field.data_type().is_nested() {
+                    return Ok((field.clone(), column.clone()));
+                }
+
+                let formatter = ArrayFormatter::try_new(column.as_ref(), &format_options)?;
+                let mut builder = StringBuilder::with_capacity(column.len(), 0);
+                for row in 0..column.len() {
+                    if column.is_null(row) {
+                        builder.append_null();
+                    } else {
+                        builder.append_value(formatter.value(row).to_string());
+                    }
+                }
+
+                let field = ArrowField::new(field.name(), ArrowDataType::Utf8, true)
+                    .with_metadata(field.metadata().clone());
+                let column: ArrowArrayRef = Arc::new(builder.finish());
+
+                Ok((Arc::new(field), column))
+            })
+            .collect::<Result<Vec<_>, arrow::error::ArrowError>>()?
+            .into_iter()
+            .unzip();
+
+    Ok(ArrowRecordBatch::try_new(
+        Arc::new(ArrowSchema::new_with_metadata(
+            fields,
+            batch.schema().metadata().clone(),
+        )),
+        columns,
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_range_sequence() {
+        let range = |s| parse_index_range(s, TimeType::Sequence);
+
+        assert_eq!(
+            range("10..20").unwrap(),
+            ResolvedTimeRange::new(TimeInt::new_temporal(10), TimeInt::new_temporal(20))
+        );
+        assert_eq!(
+            range(" 10 .. ").unwrap(),
+            ResolvedTimeRange::new(TimeInt::new_temporal(10), TimeInt::MAX)
+        );
+        assert_eq!(
+            range("..-5").unwrap(),
+            ResolvedTimeRange::new(TimeInt::MIN, TimeInt::new_temporal(-5))
+        );
+        assert_eq!(range("..").unwrap(), ResolvedTimeRange::EVERYTHING);
+
+        assert!(range("10").is_err());
+        assert!(range("20..10").is_err());
+        assert!(range("1s..2s").is_err());
+    }
+
+    #[test]
+    fn index_range_duration() {
+        let range = |s| parse_index_range(s, TimeType::DurationNs);
+
+        assert_eq!(
+            range("1.5s..2s").unwrap(),
+            ResolvedTimeRange::new(
+                TimeInt::new_temporal(1_500_000_000),
+                TimeInt::new_temporal(2_000_000_000)
+            )
+        );
+
+        // Raw integers are nanoseconds.
+        assert_eq!(
+            range("100..").unwrap(),
+            ResolvedTimeRange::new(TimeInt::new_temporal(100), TimeInt::MAX)
+        );
+
+        assert!(range("2025-01-01T00:00:00Z..").is_err());
+    }
+
+    #[test]
+    fn index_range_timestamp() {
+        let range = |s| parse_index_range(s, TimeType::TimestampNs);
+
+        assert_eq!(
+            range("1970-01-01T00:00:01Z..1970-01-01T00:00:02Z").unwrap(),
+            ResolvedTimeRange::new(
+                TimeInt::new_temporal(1_000_000_000),
+                TimeInt::new_temporal(2_000_000_000)
+            )
+        );
+
+        // Raw integers are ambiguous on timestamp timelines (seconds? nanoseconds?).
+        assert!(range("100..").is_err());
+        assert!(range("..1000000000").is_err());
+    }
+
+    #[test]
+    fn column_selector() {
+        assert_eq!(parse_column_selector("row_id"), ColumnSelector::RowId);
+        assert_eq!(
+            parse_column_selector("frame"),
+            ColumnSelector::Time(re_sorbet::TimeColumnSelector::from("frame"))
+        );
+        assert!(matches!(
+            parse_column_selector("/points:Points3D:positions"),
+            ColumnSelector::Component(ComponentColumnSelector { entity_path, component })
+                if entity_path == EntityPath::from("/points") && component == "Points3D:positions"
+        ));
+    }
+
+    #[test]
+    fn view_contents() {
+        let parse = |args: &[&str]| {
+            let entries = args
+                .iter()
+                .map(|arg| ViewContentsSelector::parse_entry(arg).unwrap())
+                .collect::<Vec<_>>();
+            parse_view_contents(&entries)
+        };
+
+        assert_eq!(parse(&[]).unwrap(), None);
+
+        let view_contents = parse(&["/points:[Points3D:positions]", "/camera"])
+            .unwrap()
+            .unwrap()
+            .into_inner();
+        assert_eq!(view_contents.len(), 2);
+        assert_eq!(view_contents[&EntityPath::from("/camera")], None);
+        assert_eq!(
+            view_contents[&EntityPath::from("/points")],
+            Some(std::iter::once(ComponentIdentifier::from("Points3D:positions")).collect())
+        );
+
+        assert!(parse(&["/points", "/points:[Points3D:positions]"]).is_err());
+        assert!(parse(&["/points:[Points3D:colors]", "points:[Points3D:positions]"]).is_err());
+    }
+
+    #[test]
+    fn output_format_from_path() {
+        assert_eq!(
+            OutputFormat::from_path("out.parquet"),
+            Some(OutputFormat::Parquet)
+        );
+        assert_eq!(OutputFormat::from_path("out.CSV"), Some(OutputFormat::Csv));
+        assert_eq!(
+            OutputFormat::from_path("dir/out.feather"),
+            Some(OutputFormat::Arrow)
+        );
+        assert_eq!(OutputFormat::from_path("out.txt"), None);
+        assert_eq!(OutputFormat::from_path("out"), None);
+    }
+}
@@ -63,6 +63,8 @@ impl StatsCommand {
                         re_protos::log_msg::v1alpha1::Compression::None as _;
                     const COMPRESSION_LZ4: i32 =
                         re_protos::log_msg::v1alpha1::Compression::Lz4 as _;
+                    const COMPRESSION_ZSTD: i32 =
+                        re_protos::log_msg::v1alpha1::Compression::Zstd as _;
 
                     match msg.compression {
                         COMPRESSION_NONE => {}
@@ -77,6 +79,16 @@ impl StatsCommand {
                             msg.compression = COMPRESSION_NONE;
                         }
 
+                        COMPRESSION_ZSTD => {
+                            uncompressed.resize(msg.uncompressed_size as _, 0);
+                            re_log_encoding::external::zstd::bulk::decompress_to_buffer(
+                                &msg.payload,
+                                &mut uncompressed,
+                            )?;
+                            msg.payload = uncompressed.into();
+                            msg.compression = COMPRESSION_NONE;
+                        }
+
                         huh => anyhow::bail!("unknown Compression: {huh}"),
                     };
 
@@ -1,7 +1,10 @@
 //! Integrates the Rerun SDK with the [`log`] crate.
 
 use log::Log as _;
-use re_types::{archetypes::TextLog, components::TextLogLevel};
+use re_types::{
+    archetypes::TextLog,
+    components::{TextLogField, TextLogLevel},
+};
 
 use crate::RecordingStream;
 
@@ -9,6 +12,8 @@ use crate::RecordingStream;
 
 /// Implements a [`log::Log`] that forwards all events to the Rerun SDK.
 ///
+/// Structured key-values attached to an event are forwarded as [`TextLogField`]s.
+///
 /// ```
 /// let rec = rerun::RecordingStreamBuilder::new("rerun_example_app").buffered()?;
 ///
@@ -18,6 +23,7 @@ use crate::RecordingStream;
 ///     .init()?;
 ///
 /// log::info!("This INFO log got added through the standard logging interface");
+/// log::info!(user = "alice", attempt = 2; "This one carries structured fields");
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 #[derive(Debug)]
@@ -110,9 +116,15 @@ impl log::Log for Logger {
 
         let body = format!("{}", record.args());
 
-        self.rec
-            .log(ent_path, &TextLog::new(body).with_level(level))
-            .ok(); // ignore error
+        let mut text_log = TextLog::new(body).with_level(level);
+
+        let mut fields = FieldCollector::default();
+        record.key_values().visit(&mut fields).ok(); // our visitor never fails
+        if !fields.0.is_empty() {
+            text_log = text_log.with_fields(fields.0);
+        }
+
+        self.rec.log(ent_path, &text_log).ok(); // ignore error
     }
 
     #[inline]
@@ -123,6 +135,22 @@ impl log::Log for Logger {
 
 // ---
 
+/// Collects the key-values of a [`log::Record`].
+#[derive(Default)]
+struct FieldCollector(Vec<TextLogField>);
+
+impl<'kvs> log::kv::VisitSource<'kvs> for FieldCollector {
+    fn visit_pair(
+        &mut self,
+        key: log::kv::Key<'kvs>,
+        value: log::kv::Value<'kvs>,
+    ) -> Result<(), log::kv::Error> {
+        self.0
+            .push(TextLogField::new(key.as_str(), value.to_string()));
+        Ok(())
+    }
+}
+
 fn log_level_to_rerun_level(lvl: log::Level) -> TextLogLevel {
     match lvl {
         log::Level::Error => TextLogLevel::ERROR,
@@ -0,0 +1,389 @@
+//! Integrates the Rerun SDK with the [`tracing`] crate.
+
+use std::time::Instant;
+
+use re_types::{
+    archetypes::{Scalars, TextLog},
+    components::{TextLogField, TextLogLevel},
+};
+use tracing::{
+    Event, Subscriber,
+    field::{Field, Visit},
+    span,
+};
+use tracing_subscriber::{filter::Targets, layer::Context, registry::LookupSpan};
+
+use crate::RecordingStream;
+
+// ---
+
+/// Implements a [`tracing_subscriber::Layer`] that forwards events and spans to the Rerun SDK.
+///
+/// Events are logged as [`TextLog`]s at the entity path derived from their target, e.g.
+/// `my_crate::net` becomes `my_crate/net`.
+/// Their fields, as well as the fields of all their enclosing spans, are forwarded as
+/// [`TextLogField`]s.
+///
+/// Every time a span is exited, the time it spent entered is logged as a [`Scalars`] (in seconds)
+/// at `<target>/<span name>`.
+///
+/// Events and span durations are filtered with [`re_log::default_log_filter`] unless configured
+/// otherwise with [`TracingLayer::with_filter`].
+///
+/// ```
+/// use tracing_subscriber::layer::SubscriberExt as _;
+/// use tracing_subscriber::util::SubscriberInitExt as _;
+///
+/// let rec = rerun::RecordingStreamBuilder::new("rerun_example_app").buffered()?;
+///
+/// tracing_subscriber::registry()
+///     .with(
+///         rerun::TracingLayer::new(rec.clone())
+///             .with_path_prefix("logs")
+///             .with_filter("info,my_crate::net=debug"),
+///     )
+///     .init();
+///
+/// let _span = tracing::info_span!("request", user = "alice").entered();
+/// tracing::info!(attempt = 2, "This INFO event got added through the tracing interface");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct TracingLayer {
+    rec: RecordingStream,
+    filter: Targets,
+    path_prefix: Option<String>,
+    span_durations: bool,
+}
+
+impl Drop for TracingLayer {
+    fn drop(&mut self) {
+        self.rec.flush_blocking();
+    }
+}
+
+impl TracingLayer {
+    /// Returns a new [`TracingLayer`] that forwards all events and spans to the specified [`RecordingStream`].
+    pub fn new(rec: RecordingStream) -> Self {
+        Self {
+            rec,
+            filter: parse_filter(&re_log::default_log_filter()),
+            path_prefix: None,
+            span_durations: true,
+        }
+    }
+
+    /// Configures the [`TracingLayer`] to filter events and span durations.
+    ///
+    /// This uses the familiar [env_logger syntax], e.g. `info,my_crate::net=debug`.
+    ///
+    /// If you don't call this, the [`TracingLayer`] uses [`re_log::default_log_filter`], i.e. it
+    /// parses the `RUST_LOG` environment variable.
+    ///
+    /// [env_logger syntax]: https://docs.rs/env_logger/latest/env_logger/index.html#enabling-logging
+    #[inline]
+    pub fn with_filter(mut self, filter: impl AsRef<str>) -> Self {
+        self.filter = parse_filter(filter.as_ref());
+        self
+    }
+
+    /// Configures the [`TracingLayer`] to prefix the specified `path_prefix` to all entity paths.
+    #[inline]
+    pub fn with_path_prefix(mut self, path_prefix: impl Into<String>) -> Self {
+        self.path_prefix = Some(path_prefix.into());
+        self
+    }
+
+    /// Configures whether the [`TracingLayer`] logs the duration of spans.
+    ///
+    /// Enabled by default.
+    #[inline]
+    pub fn with_span_durations(mut self, span_durations: bool) -> Self {
+        self.span_durations = span_durations;
+        self
+    }
+
+    fn entity_path(&self, target: &str) -> String {
+        let target = target.replace("::", "/");
+        if let Some(path_prefix) = self.path_prefix.as_ref() {
+            format!("{path_prefix}/{target}")
+        } else {
+            target
+        }
+    }
+}
+
+impl<S> tracing_subscriber::Layer<S> for TracingLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+
+        let mut fields = FieldCollector::default();
+        attrs.record(&mut fields);
+        span.extensions_mut().insert(SpanFields(fields.fields));
+    }
+
+    fn on_record(&self, id: &span::Id, values: &span::Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+
+        let mut fields = FieldCollector::default();
+        values.record(&mut fields);
+
+        let mut extensions = span.extensions_mut();
+        if let Some(SpanFields(span_fields)) = extensions.get_mut::<SpanFields>() {
+            span_fields.extend(fields.fields);
+        } else {
+            extensions.insert(SpanFields(fields.fields));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        if !self
+            .filter
+            .would_enable(metadata.target(), metadata.level())
+        {
+            return;
+        }
+
+        let mut fields = FieldCollector::default();
+        event.record(&mut fields);
+
+        // Outermost spans first, so that the event's own fields come last.
+        let mut all_fields = Vec::new();
+        if let Some(scope) = ctx.event_scope(event) {
+            for span in scope.from_root() {
+                if let Some(SpanFields(span_fields)) = span.extensions().get::<SpanFields>() {
+                    all_fields.extend(span_fields.iter().cloned());
+                }
+            }
+        }
+        all_fields.extend(fields.fields);
+
+        let level = tracing_level_to_rerun_level(*metadata.level());
+
+        let mut text_log = TextLog::new(fields.message.unwrap_or_default()).with_level(level);
+        if !all_fields.is_empty() {
+            text_log = text_log.with_fields(all_fields);
+        }
+
+        self.rec
+            .log(self.entity_path(metadata.target()), &text_log)
+            .ok(); // ignore error
+    }
+
+    fn on_enter(&self, id: &span::Id, ctx: Context<'_, S>) {
+        if !self.span_durations {
+            return;
+        }
+
+        if let Some(span) = ctx.span(id) {
+            let metadata = span.metadata();
+            if self
+                .filter
+                .would_enable(metadata.target(), metadata.level())
+            {
+                span.extensions_mut().replace(SpanEntered(Instant::now()));
+            }
+        }
+    }
+
+    fn on_exit(&self, id: &span::Id, ctx: Context<'_, S>) {
+        if !self.span_durations {
+            return;
+        }
+
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let Some(SpanEntered(entered)) = span.extensions_mut().remove::<SpanEntered>() else {
+            return;
+        };
+
+        let ent_path = format!(
+            "{}/{}",
+            self.entity_path(span.metadata().target()),
+            span.name()
+        );
+
+        self.rec
+            .log(ent_path, &Scalars::single(entered.elapsed().as_secs_f64()))
+            .ok(); // ignore error
+    }
+}
+
+// ---
+
+/// Parses an [env_logger]-style filter, ignoring invalid directives.
+///
+/// [env_logger]: https://docs.rs/env_logger/latest/env_logger/index.html#enabling-logging
+fn parse_filter(filter: &str) -> Targets {
+    filter
+        .split(',')
+        .map(str::trim)
+        .filter(|directive| !directive.is_empty())
+        .filter_map(|directive| match directive.parse::<Targets>() {
+            Ok(targets) => Some(targets),
+            Err(err) => {
+                re_log::warn!("Ignoring invalid tracing filter directive {directive:?}: {err}");
+                None
+            }
+        })
+        .fold(Targets::new(), |filter, targets| {
+            let default = targets.default_level();
+            let filter = filter.with_targets(targets);
+            if let Some(default) = default {
+                filter.with_default(default)
+            } else {
+                filter
+            }
+        })
+}
+
+/// The fields recorded on a span, stored in its extensions.
+struct SpanFields(Vec<TextLogField>);
+
+/// When a span was last entered, stored in its extensions.
+struct SpanEntered(Instant);
+
+/// Collects the fields of a [`tracing`] event or span.
+///
+/// The `message` field of events becomes the body of the [`TextLog`].
+#[derive(Default)]
+struct FieldCollector {
+    message: Option<String>,
+    fields: Vec<TextLogField>,
+}
+
+impl FieldCollector {
+    fn push(&mut self, field: &Field, value: String) {
+        if field.name() == "message" {
+            self.message = Some(value);
+        } else {
+            self.fields.push(TextLogField::new(field.name(), value));
+        }
+    }
+}
+
+impl Visit for FieldCollector {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.push(field, value.to_owned());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.push(field, format!("{value:?}"));
+    }
+}
+
+fn tracing_level_to_rerun_level(lvl: tracing::Level) -> TextLogLevel {
+    match lvl {
+        tracing::Level::ERROR => TextLogLevel::ERROR,
+        tracing::Level::WARN => TextLogLevel::WARN,
+        tracing::Level::INFO => TextLogLevel::INFO,
+        tracing::Level::DEBUG => TextLogLevel::DEBUG,
+        tracing::Level::TRACE => TextLogLevel::TRACE,
+    }
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use re_chunk::Chunk;
+    use re_log_types::{EntityPath, LogMsg};
+    use re_types::components::{Scalar, Text};
+    use tracing_subscriber::layer::SubscriberExt as _;
+
+    use super::*;
+
+    fn logged_chunks(storage: &crate::sink::MemorySinkStorage) -> Vec<Chunk> {
+        storage
+            .take()
+            .iter()
+            .filter_map(|msg| match msg {
+                LogMsg::ArrowMsg(_, msg) => Some(Chunk::from_arrow_msg(msg).unwrap()),
+                _ => None,
+            })
+            .filter(|chunk| !chunk.entity_path().is_reserved())
+            .collect()
+    }
+
+    #[test]
+    fn test_tracing_layer() {
+        let (rec, storage) = crate::RecordingStreamBuilder::new("rerun_example_tracing_layer")
+            .memory()
+            .unwrap();
+
+        let subscriber = tracing_subscriber::registry().with(
+            TracingLayer::new(rec.clone())
+                .with_path_prefix("logs")
+                .with_filter("info,my_crate::noisy=warn"),
+        );
+        tracing::subscriber::with_default(subscriber, || {
+            let _span =
+                tracing::info_span!(target: "my_crate::net", "request", user = "alice").entered();
+            tracing::info!(target: "my_crate::net", attempt = 2, "Connected");
+            tracing::debug!(target: "my_crate::net", "Below the default level");
+            tracing::info!(target: "my_crate::noisy", "Below the target level");
+
+            let _span = tracing::debug_span!(target: "my_crate::net", "filtered").entered();
+        });
+        rec.flush_blocking();
+
+        let chunks = logged_chunks(&storage);
+        let entity_paths = chunks
+            .iter()
+            .map(|chunk| chunk.entity_path().clone())
+            .collect::<std::collections::BTreeSet<_>>();
+        similar_asserts::assert_eq!(
+            std::collections::BTreeSet::from([
+                EntityPath::from("logs/my_crate/net"),
+                EntityPath::from("logs/my_crate/net/request"),
+            ]),
+            entity_paths
+        );
+
+        let events = chunks
+            .iter()
+            .filter(|chunk| chunk.entity_path() == &EntityPath::from("logs/my_crate/net"))
+            .flat_map(|chunk| {
+                itertools::izip!(
+                    chunk.iter_component::<Text>(&TextLog::descriptor_text()),
+                    chunk.iter_component::<TextLogLevel>(&TextLog::descriptor_level()),
+                    chunk.iter_component::<TextLogField>(&TextLog::descriptor_fields()),
+                )
+                .map(|(text, level, fields)| (text.to_vec(), level.to_vec(), fields.to_vec()))
+                .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        similar_asserts::assert_eq!(
+            vec![(
+                vec![Text::from("Connected")],
+                vec![TextLogLevel::from(TextLogLevel::INFO)],
+                vec![
+                    TextLogField::new("user", "alice"),
+                    TextLogField::new("attempt", "2"),
+                ],
+            )],
+            events
+        );
+
+        let durations = chunks
+            .iter()
+            .filter(|chunk| chunk.entity_path() == &EntityPath::from("logs/my_crate/net/request"))
+            .flat_map(|chunk| {
+                chunk
+                    .iter_component::<Scalar>(&Scalars::descriptor_scalars())
+                    .map(|scalars| scalars.to_vec())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(durations.len(), 1, "one duration per span exit");
+        assert!(durations[0].iter().all(|duration| 0.0 <= duration.0.0));
+    }
+}
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use parking_lot::{Condvar, Mutex};
+
+/// Limits how many bytes worth of messages can be waiting in a channel.
+///
+/// See [`crate::smart_channel_with_byte_budget`].
+pub(crate) struct ByteBudget {
+    max_bytes: u64,
+    queued_bytes: Mutex<u64>,
+    released: Condvar,
+}
+
+impl ByteBudget {
+    #[cfg_attr(target_arch = "wasm32", expect(dead_code))] // Cannot block on web
+    pub(crate) fn new(max_bytes: u64) -> Arc<Self> {
+        Arc::new(Self {
+            max_bytes,
+            queued_bytes: Mutex::new(0),
+            released: Condvar::new(),
+        })
+    }
+
+    /// Blocks until `size_bytes` fit within the budget.
+    ///
+    /// A single message that is larger than the whole budget is let through as soon as nothing
+    /// else is queued, so that it cannot block the channel forever.
+    pub(crate) fn acquire(self: &Arc<Self>, size_bytes: u64) -> ByteBudgetGuard {
+        let mut queued_bytes = self.queued_bytes.lock();
+        while 0 < *queued_bytes && self.max_bytes < queued_bytes.saturating_add(size_bytes) {
+            self.released.wait(&mut queued_bytes);
+        }
+        *queued_bytes = queued_bytes.saturating_add(size_bytes);
+
+        ByteBudgetGuard {
+            budget: Arc::clone(self),
+            size_bytes,
+        }
+    }
+}
+
+/// A [`ByteBudget`], along with how to measure the size of the messages charged against it.
+pub(crate) type MeasuredByteBudget<T> = (Arc<ByteBudget>, fn(&T) -> u64);
+
+/// Gives its bytes back to the [`ByteBudget`] once the message it is attached to is dropped.
+pub(crate) struct ByteBudgetGuard {
+    budget: Arc<ByteBudget>,
+    size_bytes: u64,
+}
+
+impl Drop for ByteBudgetGuard {
+    fn drop(&mut self) {
+        let mut queued_bytes = self.budget.queued_bytes.lock();
+        *queued_bytes = queued_bytes.saturating_sub(self.size_bytes);
+        self.budget.released.notify_all();
+    }
+}
+
+impl std::fmt::Debug for ByteBudgetGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ByteBudgetGuard")
+            .field("size_bytes", &self.size_bytes)
+            .finish_non_exhaustive()
+    }
+}
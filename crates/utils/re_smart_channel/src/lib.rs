@@ -7,6 +7,7 @@ use web_time::Instant;
 
 pub use crossbeam::channel::{RecvError, RecvTimeoutError, SendError, TryRecvError};
 
+mod byte_budget;
 mod receive_set;
 mod receiver;
 mod sender;
@@ -252,6 +253,30 @@ pub fn smart_channel<T: Send>(
     smart_channel_with_stats(sender_source, Arc::new(source), stats)
 }
 
+/// Like [`smart_channel`], but [`Sender::send`] blocks while the messages that haven't been
+/// received yet add up to more than `max_bytes`, as measured by `size_bytes`.
+///
+/// A message gives its bytes back to the budget once it is dropped by the receiving end.
+#[cfg(not(target_arch = "wasm32"))] // Cannot block on web
+pub fn smart_channel_with_byte_budget<T: Send>(
+    sender_source: SmartMessageSource,
+    source: SmartChannelSource,
+    max_bytes: u64,
+    size_bytes: fn(&T) -> u64,
+) -> (Sender<T>, Receiver<T>) {
+    let (tx, rx) = crossbeam::channel::unbounded();
+    let stats = Arc::new(SharedStats::default());
+    let byte_budget = byte_budget::ByteBudget::new(max_bytes);
+    let sender = Sender::new(
+        tx,
+        Arc::new(sender_source),
+        stats.clone(),
+        Some((byte_budget, size_bytes)),
+    );
+    let receiver = Receiver::new(rx, stats, Arc::new(source));
+    (sender, receiver)
+}
+
 /// Create a new channel using the same stats as some other.
 ///
 /// This is a very leaky abstraction, and it would be nice to refactor some day
@@ -262,7 +287,7 @@ pub(crate) fn smart_channel_with_stats<T: Send>(
 ) -> (Sender<T>, Receiver<T>) {
     let (tx, rx) = crossbeam::channel::unbounded();
     let sender_source = Arc::new(sender_source);
-    let sender = Sender::new(tx, sender_source, stats.clone());
+    let sender = Sender::new(tx, sender_source, stats.clone(), None);
     let receiver = Receiver::new(rx, stats, source);
     (sender, receiver)
 }
@@ -306,11 +331,20 @@ impl<T: Send + PartialEq> PartialEq for SmartMessagePayload<T> {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug)]
 pub struct SmartMessage<T: Send> {
     pub time: Instant,
     pub source: Arc<SmartMessageSource>,
     pub payload: SmartMessagePayload<T>,
+
+    /// Held onto until the message is dropped, see [`smart_channel_with_byte_budget`].
+    _byte_budget: Option<byte_budget::ByteBudgetGuard>,
+}
+
+impl<T: Send + PartialEq> PartialEq for SmartMessage<T> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.time == rhs.time && self.source == rhs.source && self.payload == rhs.payload
+    }
 }
 
 impl<T: Send> SmartMessage<T> {
@@ -376,3 +410,38 @@ fn test_smart_channel_connected() {
     assert_eq!(rx.try_recv(), Err(TryRecvError::Disconnected));
     assert!(!rx.is_connected());
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn test_smart_channel_byte_budget() {
+    let (tx, rx) = smart_channel_with_byte_budget(
+        SmartMessageSource::Sdk,
+        SmartChannelSource::Sdk,
+        10,
+        |msg: &Vec<u8>| msg.len() as u64,
+    ); // whatever source
+
+    tx.send(vec![1; 6]).unwrap();
+    tx.send(vec![2; 4]).unwrap(); // exactly fills the budget
+
+    // Blocks until the first message has been received.
+    let sender = std::thread::Builder::new()
+        .name("byte_budget_sender".to_owned())
+        .spawn(move || {
+            tx.send(vec![3; 5]).unwrap();
+            tx
+        })
+        .unwrap();
+    assert_eq!(rx.len(), 2);
+
+    assert_eq!(rx.recv().map(|msg| msg.into_data()), Ok(Some(vec![1; 6])));
+    let tx = sender.join().unwrap();
+    assert_eq!(rx.len(), 2);
+
+    assert_eq!(rx.recv().map(|msg| msg.into_data()), Ok(Some(vec![2; 4])));
+    assert_eq!(rx.recv().map(|msg| msg.into_data()), Ok(Some(vec![3; 5])));
+
+    // A message larger than the whole budget still gets through once nothing else is queued.
+    tx.send(vec![4; 100]).unwrap();
+    assert_eq!(rx.recv().map(|msg| msg.into_data()), Ok(Some(vec![4; 100])));
+}
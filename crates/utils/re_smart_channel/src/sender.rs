@@ -2,13 +2,19 @@ use std::sync::{Arc, atomic::Ordering::Relaxed};
 
 use web_time::Instant;
 
-use crate::{SendError, SharedStats, SmartMessage, SmartMessagePayload, SmartMessageSource};
+use crate::{
+    SendError, SharedStats, SmartMessage, SmartMessagePayload, SmartMessageSource,
+    byte_budget::{ByteBudgetGuard, MeasuredByteBudget},
+};
 
 #[derive(Clone)]
 pub struct Sender<T: Send> {
     tx: crossbeam::channel::Sender<SmartMessage<T>>,
     source: Arc<SmartMessageSource>,
     stats: Arc<SharedStats>,
+
+    /// See [`crate::smart_channel_with_byte_budget`].
+    byte_budget: Option<MeasuredByteBudget<T>>,
 }
 
 impl<T: Send> Sender<T> {
@@ -16,8 +22,14 @@ impl<T: Send> Sender<T> {
         tx: crossbeam::channel::Sender<SmartMessage<T>>,
         source: Arc<SmartMessageSource>,
         stats: Arc<SharedStats>,
+        byte_budget: Option<MeasuredByteBudget<T>>,
     ) -> Self {
-        Self { tx, source, stats }
+        Self {
+            tx,
+            source,
+            stats,
+            byte_budget,
+        }
     }
 
     /// Clones the sender with an updated source.
@@ -26,14 +38,25 @@ impl<T: Send> Sender<T> {
             tx: self.tx.clone(),
             source: Arc::new(source),
             stats: Arc::clone(&self.stats),
+            byte_budget: self.byte_budget.clone(),
         }
     }
 
+    /// Sends a message down the channel.
+    ///
+    /// If the channel was created with [`crate::smart_channel_with_byte_budget`], this blocks
+    /// until the message fits within the budget.
     pub fn send(&self, msg: T) -> Result<(), SendError<T>> {
-        self.send_at(
+        let byte_budget = self
+            .byte_budget
+            .as_ref()
+            .map(|(byte_budget, size_bytes)| byte_budget.acquire(size_bytes(&msg)));
+
+        self.send_impl(
             Instant::now(),
             Arc::clone(&self.source),
             SmartMessagePayload::Msg(msg),
+            byte_budget,
         )
         .map_err(|SendError(msg)| match msg {
             SmartMessagePayload::Msg(msg) => SendError(msg),
@@ -42,11 +65,23 @@ impl<T: Send> Sender<T> {
     }
 
     /// Forwards a message as-is.
+    ///
+    /// This never blocks, regardless of the byte budget of the channel.
     pub fn send_at(
         &self,
         time: Instant,
         source: Arc<SmartMessageSource>,
         payload: SmartMessagePayload<T>,
+    ) -> Result<(), SendError<SmartMessagePayload<T>>> {
+        self.send_impl(time, source, payload, None)
+    }
+
+    fn send_impl(
+        &self,
+        time: Instant,
+        source: Arc<SmartMessageSource>,
+        payload: SmartMessagePayload<T>,
+        byte_budget: Option<ByteBudgetGuard>,
     ) -> Result<(), SendError<SmartMessagePayload<T>>> {
         // NOTE: We should never be sending a message with an unknown source.
         debug_assert!(!matches!(*source, SmartMessageSource::Unknown));
@@ -56,6 +91,7 @@ impl<T: Send> Sender<T> {
                 time,
                 source,
                 payload,
+                _byte_budget: byte_budget,
             })
             .map_err(|SendError(msg)| SendError(msg.payload))
     }
@@ -76,6 +112,7 @@ impl<T: Send> Sender<T> {
                         tx.send(()).ok();
                     }),
                 },
+                _byte_budget: None,
             })
             .map_err(|_ignored| SendError(()))?;
 
@@ -101,6 +138,7 @@ impl<T: Send> Sender<T> {
             time: Instant::now(),
             source: Arc::clone(&self.source),
             payload: SmartMessagePayload::Quit(err),
+            _byte_budget: None,
         })
     }
 
@@ -0,0 +1,369 @@
+//! Demuxing of raw H.264 & H.265 bitstreams in the Annex B format, i.e. `.h264`/`.h265` files.
+//!
+//! These elementary streams are a sequence of NAL units separated by start codes, without any
+//! container around them. Since they carry no timestamps, frames are assumed to be in presentation
+//! order (no B-frame reordering) and are spaced according to a fixed frame rate.
+
+use std::collections::BTreeMap;
+
+use h264_reader::nal::{self, Nal as _};
+use re_span::Span;
+
+use super::{SampleMetadata, VideoDataDescription, VideoLoadError};
+
+use crate::{
+    StableIndexDeque, Time, Timescale, VideoCodec, VideoEncodingDetails,
+    h264::encoding_details_from_h264_sps, h265::encoding_details_from_h265_sps,
+};
+
+/// Frame rate used if neither the caller nor the bitstream specifies one.
+pub const DEFAULT_FRAME_RATE: f64 = 30.0;
+
+/// The customary MPEG timescale, which represents all common frame rates exactly.
+const TIMESCALE: u64 = 90_000;
+
+/// A NAL unit within the bitstream.
+struct NalUnit {
+    /// Absolute offset of the start code preceding the NAL unit.
+    start_code_offset: usize,
+
+    /// The NAL unit, starting with its header.
+    range: std::ops::Range<usize>,
+}
+
+/// Splits an Annex B bitstream at its `00 00 01` & `00 00 00 01` start codes.
+fn nal_units(data: &[u8]) -> Vec<NalUnit> {
+    let mut units = Vec::new();
+    let mut current: Option<(usize, usize)> = None;
+
+    let mut i = 0;
+    while i + 3 <= data.len() {
+        if data[i] == 0 && data[i + 1] == 0 && data[i + 2] == 1 {
+            // Four byte start codes belong to the start code, not to the previous NAL unit.
+            let start_code_offset = if 0 < i && data[i - 1] == 0 { i - 1 } else { i };
+
+            if let Some((offset, start)) = current {
+                units.push(NalUnit {
+                    start_code_offset: offset,
+                    range: start..start_code_offset.max(start),
+                });
+            }
+            current = Some((start_code_offset, i + 3));
+            i += 3;
+        } else {
+            i += 1;
+        }
+    }
+
+    if let Some((offset, start)) = current {
+        units.push(NalUnit {
+            start_code_offset: offset,
+            range: start..data.len(),
+        });
+    }
+
+    units.retain(|unit| !unit.range.is_empty());
+    units
+}
+
+/// A group of NAL units making up a frame.
+struct AccessUnit {
+    /// Absolute offset of the start code of the first NAL unit.
+    start: usize,
+
+    /// Absolute end of the last NAL unit.
+    end: usize,
+
+    is_sync: bool,
+    has_picture: bool,
+}
+
+/// How a NAL unit relates to access units, i.e. frames.
+enum NalKind {
+    /// Slice data; starts a new frame if it's the first slice of a picture.
+    Picture { first_slice: bool, is_sync: bool },
+
+    /// Parameter sets, SEI, access unit delimiters, …: starts a new frame if it follows picture data.
+    Prefix,
+
+    /// Anything else, e.g. end of sequence or filler data.
+    Other,
+}
+
+fn h264_nal_kind(nal: &[u8]) -> NalKind {
+    let nal_unit_type = nal[0] & 0x1F;
+    match nal_unit_type {
+        1..=5 => NalKind::Picture {
+            // `first_mb_in_slice` is zero iff its exponential Golomb code is a single set bit.
+            first_slice: nal.get(1).is_some_and(|byte| byte & 0x80 != 0),
+            is_sync: nal_unit_type == 5,
+        },
+        6..=9 | 14..=18 => NalKind::Prefix,
+        _ => NalKind::Other,
+    }
+}
+
+fn h265_nal_kind(nal: &[u8]) -> NalKind {
+    let nal_unit_type = (nal[0] >> 1) & 0x3F;
+    match nal_unit_type {
+        0..=31 => NalKind::Picture {
+            first_slice: nal.get(2).is_some_and(|byte| byte & 0x80 != 0),
+            // BLA, IDR & CRA pictures.
+            is_sync: (16..=21).contains(&nal_unit_type),
+        },
+        32..=35 | 39 | 41..=44 | 48..=55 => NalKind::Prefix,
+        _ => NalKind::Other,
+    }
+}
+
+impl VideoDataDescription {
+    /// Loads a raw H.264 or H.265 bitstream in the Annex B format.
+    ///
+    /// Frames are spaced according to `frame_rate`. If it's not given, the frame rate is read from
+    /// the bitstream if possible, falling back to [`DEFAULT_FRAME_RATE`] otherwise.
+    ///
+    /// Does not copy any sample data, but instead stores offsets into the buffer.
+    pub fn load_annexb(
+        data: &[u8],
+        codec: VideoCodec,
+        frame_rate: Option<f64>,
+        debug_name: &str,
+    ) -> Result<Self, VideoLoadError> {
+        re_tracing::profile_function!();
+
+        let nal_kind = match codec {
+            VideoCodec::H264 => h264_nal_kind,
+            VideoCodec::H265 => h265_nal_kind,
+            VideoCodec::AV1 | VideoCodec::VP8 | VideoCodec::VP9 => {
+                return Err(VideoLoadError::UnsupportedMimeType {
+                    provided_or_detected_media_type: format!(
+                        "Annex B bitstream of codec {codec:?}"
+                    ),
+                });
+            }
+        };
+
+        let units = nal_units(data);
+
+        let (encoding_details, stream_frame_rate) = encoding_details(data, codec, &units)?;
+        let frame_rate = frame_rate
+            .or(stream_frame_rate)
+            .filter(|frame_rate| frame_rate.is_finite() && 0.0 < *frame_rate)
+            .unwrap_or(DEFAULT_FRAME_RATE);
+        let frame_duration = Time::new(((TIMESCALE as f64 / frame_rate).round() as i64).max(1));
+
+        // Group NAL units into access units, i.e. frames.
+        let mut access_units = Vec::new();
+        let mut current: Option<AccessUnit> = None;
+        for unit in &units {
+            let kind = nal_kind(&data[unit.range.clone()]);
+            let current_has_picture = current.as_ref().is_some_and(|au| au.has_picture);
+            let starts_new_access_unit = match kind {
+                NalKind::Picture { first_slice, .. } => first_slice && current_has_picture,
+                NalKind::Prefix => current_has_picture,
+                NalKind::Other => false,
+            };
+            if starts_new_access_unit {
+                access_units.extend(current.take());
+            }
+
+            let access_unit = current.get_or_insert(AccessUnit {
+                start: unit.start_code_offset,
+                end: unit.range.end,
+                is_sync: false,
+                has_picture: false,
+            });
+            access_unit.end = unit.range.end;
+            if let NalKind::Picture { is_sync, .. } = kind {
+                access_unit.has_picture = true;
+                access_unit.is_sync |= is_sync;
+            }
+        }
+        access_units.extend(current);
+        access_units.retain(|au| au.has_picture);
+
+        let mut samples = StableIndexDeque::with_capacity(access_units.len());
+        for (index, access_unit) in access_units.into_iter().enumerate() {
+            let timestamp = Time::new(index as i64 * frame_duration.0);
+            let byte_span = Span {
+                start: u32::try_from(access_unit.start)
+                    .map_err(|_err| VideoLoadError::InvalidSamples)?,
+                len: u32::try_from(access_unit.end - access_unit.start)
+                    .map_err(|_err| VideoLoadError::InvalidSamples)?,
+            };
+            samples.push_back(SampleMetadata {
+                is_sync: access_unit.is_sync,
+                frame_nr: 0, // filled in by `from_samples`
+                decode_timestamp: timestamp,
+                presentation_timestamp: timestamp,
+                duration: Some(frame_duration),
+                // There's only a single buffer, which is the raw bitstream.
+                buffer_index: 0,
+                byte_span,
+            });
+        }
+
+        let duration = Time::new(samples.num_elements() as i64 * frame_duration.0);
+
+        Ok(Self::from_samples(
+            codec,
+            Some(encoding_details),
+            Timescale::new(TIMESCALE),
+            duration,
+            samples,
+            BTreeMap::new(),
+            debug_name,
+        ))
+    }
+}
+
+/// Determines the encoding details from the first SPS, as well as the frame rate if it's specified.
+fn encoding_details(
+    data: &[u8],
+    codec: VideoCodec,
+    units: &[NalUnit],
+) -> Result<(VideoEncodingDetails, Option<f64>), VideoLoadError> {
+    let is_sps = |nal: &[u8]| match codec {
+        VideoCodec::H264 => nal[0] & 0x1F == nal::UnitType::SeqParameterSet.id(),
+        _ => (nal[0] >> 1) & 0x3F == crate::h265::NAL_UNIT_TYPE_SPS,
+    };
+    let sps = units
+        .iter()
+        .map(|unit| &data[unit.range.clone()])
+        .find(|nal| is_sps(nal))
+        .ok_or(VideoLoadError::NoSequenceParameterSet)?;
+
+    if codec == VideoCodec::H264 {
+        let sps_nal = nal::RefNal::new(sps, &[], true);
+        let sps = nal::sps::SeqParameterSet::from_bits(sps_nal.rbsp_bits())
+            .map_err(VideoLoadError::SpsParsingError)?;
+        let details =
+            encoding_details_from_h264_sps(&sps).map_err(VideoLoadError::SpsParsingError)?;
+
+        // Each frame consists of two fields, hence the factor two.
+        let frame_rate = sps
+            .vui_parameters
+            .as_ref()
+            .and_then(|vui| vui.timing_info.as_ref())
+            .filter(|timing| timing.num_units_in_tick != 0)
+            .map(|timing| {
+                f64::from(timing.time_scale) / (2.0 * f64::from(timing.num_units_in_tick))
+            });
+
+        Ok((details, frame_rate))
+    } else {
+        let details =
+            encoding_details_from_h265_sps(sps).map_err(VideoLoadError::H265SpsParsingError)?;
+        Ok((details, None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Baseline profile SPS of a 320x240 stream, without VUI.
+    const H264_SPS: [u8; 8] = [0x67, 0x42, 0x00, 0x1E, 0xF4, 0x0A, 0x0F, 0xC8];
+
+    fn h264_stream() -> Vec<u8> {
+        [
+            &[0, 0, 0, 1][..],
+            &H264_SPS,
+            &[0, 0, 0, 1, 0x68, 0xCE, 0x38, 0x80], // PPS
+            &[0, 0, 1, 0x65, 0x88, 0x84],          // IDR slice
+            &[0, 0, 1, 0x41, 0x9A],                // P slice
+            &[0, 0, 1, 0x41, 0x9B],                // P slice
+            &[0, 0, 0, 1, 0x09, 0xF0],             // access unit delimiter
+            &[0, 0, 1, 0x65, 0x88, 0x85],          // IDR slice
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn test_nal_units() {
+        let data = h264_stream();
+        let units = nal_units(&data);
+        let headers = units
+            .iter()
+            .map(|unit| (unit.start_code_offset, data[unit.range.start]))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            headers,
+            vec![
+                (0, 0x67),
+                (12, 0x68),
+                (20, 0x65),
+                (26, 0x41),
+                (31, 0x41),
+                (36, 0x09),
+                (42, 0x65)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_load_h264() -> Result<(), VideoLoadError> {
+        let data = h264_stream();
+        let video = VideoDataDescription::load_annexb(&data, VideoCodec::H264, Some(25.0), "test")?;
+
+        assert_eq!(video.timescale, Some(Timescale::new(TIMESCALE)));
+        let details = video.encoding_details.as_ref();
+        assert_eq!(
+            details.map(|details| details.coded_dimensions),
+            Some([320, 240])
+        );
+        assert_eq!(
+            details.map(|details| details.codec_string.as_str()),
+            Some("avc1.42001E")
+        );
+
+        let samples = video
+            .samples
+            .iter()
+            .map(|sample| {
+                (
+                    sample.is_sync,
+                    sample.presentation_timestamp.0,
+                    sample.byte_span.start,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            samples,
+            vec![
+                (true, 0, 0),
+                (false, 3600, 26),
+                (false, 7200, 31),
+                (true, 10800, 36)
+            ]
+        );
+        assert_eq!(video.gops.num_elements(), 2);
+        assert_eq!(video.duration, Some(Time::new(14400)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_default_frame_rate() -> Result<(), VideoLoadError> {
+        let video = VideoDataDescription::load_from_bytes(&h264_stream(), "video/h264", "test")?;
+        assert_eq!(video.samples[1].presentation_timestamp, Time::new(3000));
+
+        let video = VideoDataDescription::load_from_bytes(
+            &h264_stream(),
+            "video/h264; framerate=50",
+            "test",
+        )?;
+        assert_eq!(video.samples[1].presentation_timestamp, Time::new(1800));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_sps() {
+        let data = [0, 0, 1, 0x65, 0x88, 0x84];
+        assert!(matches!(
+            VideoDataDescription::load_annexb(&data, VideoCodec::H264, None, "test"),
+            Err(VideoLoadError::NoSequenceParameterSet)
+        ));
+    }
+}
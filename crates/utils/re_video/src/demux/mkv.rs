@@ -0,0 +1,999 @@
+//! Matroska (`.mkv`) & WebM demuxing.
+//!
+//! See <https://www.matroska.org/technical/elements.html> for the element specification.
+
+use std::collections::BTreeMap;
+
+use re_span::Span;
+
+use super::{SampleMetadata, VideoDataDescription, VideoLoadError};
+
+use crate::{StableIndexDeque, Time, Timescale, TrackId, TrackKind, VideoCodec};
+
+// Element ids, including their length marker.
+const EBML: u32 = 0x1A45_DFA3;
+const DOC_TYPE: u32 = 0x4282;
+const SEGMENT: u32 = 0x1853_8067;
+const INFO: u32 = 0x1549_A966;
+const TIMESTAMP_SCALE: u32 = 0x2A_D7B1;
+const DURATION: u32 = 0x4489;
+const TRACKS: u32 = 0x1654_AE6B;
+const TRACK_ENTRY: u32 = 0xAE;
+const TRACK_NUMBER: u32 = 0xD7;
+const TRACK_TYPE: u32 = 0x83;
+const CODEC_ID: u32 = 0x86;
+const CODEC_PRIVATE: u32 = 0x63A2;
+const DEFAULT_DURATION: u32 = 0x23_E383;
+const VIDEO: u32 = 0xE0;
+const PIXEL_WIDTH: u32 = 0xB0;
+const PIXEL_HEIGHT: u32 = 0xBA;
+const CONTENT_ENCODINGS: u32 = 0x6D80;
+const CLUSTER: u32 = 0x1F43_B675;
+const CLUSTER_TIMESTAMP: u32 = 0xE7;
+const SIMPLE_BLOCK: u32 = 0xA3;
+const BLOCK_GROUP: u32 = 0xA0;
+const BLOCK: u32 = 0xA1;
+const BLOCK_DURATION: u32 = 0x9B;
+const REFERENCE_BLOCK: u32 = 0xFB;
+
+/// Elements that can follow a cluster of unknown size, and thus terminate it.
+const TOP_LEVEL_ELEMENTS: [u32; 8] = [
+    CLUSTER,
+    INFO,
+    TRACKS,
+    0x1C53_BB6B, // Cues
+    0x1254_C367, // Tags
+    0x1043_A770, // Chapters
+    0x1941_A469, // Attachments
+    0x114D_9B74, // SeekHead
+];
+
+/// Matroska track types.
+const TRACK_TYPE_VIDEO: u64 = 1;
+const TRACK_TYPE_AUDIO: u64 = 2;
+const TRACK_TYPE_SUBTITLE: u64 = 17;
+
+/// Default value of the `TimestampScale`: timestamps are in milliseconds.
+const DEFAULT_TIMESTAMP_SCALE_NS: u64 = 1_000_000;
+
+fn invalid(what: impl Into<String>) -> VideoLoadError {
+    VideoLoadError::ParseMatroska(what.into())
+}
+
+// ---
+
+/// Reads EBML elements from a byte buffer, keeping track of absolute offsets.
+struct EbmlReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    end: usize,
+}
+
+/// The header of an EBML element.
+struct ElementHeader {
+    id: u32,
+
+    /// Absolute offset of the header itself.
+    start: usize,
+
+    /// Absolute range of the element's data.
+    ///
+    /// For elements of unknown size, this extends to the end of the parent.
+    data: std::ops::Range<usize>,
+
+    unknown_size: bool,
+}
+
+impl<'a> EbmlReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            pos: 0,
+            end: data.len(),
+        }
+    }
+
+    /// A reader for the children of the given element.
+    fn children(&self, element: &ElementHeader) -> Self {
+        Self {
+            data: self.data,
+            pos: element.data.start,
+            end: element.data.end,
+        }
+    }
+
+    /// Reads a variable size integer, returning its value (with the length marker) and length.
+    fn read_vint(&mut self) -> Option<(u64, usize)> {
+        let first = *self.data.get(self.pos)?;
+        let len = first.leading_zeros() as usize + 1;
+        if 8 < len || self.end < self.pos + len {
+            return None;
+        }
+
+        let value = self.data[self.pos..self.pos + len]
+            .iter()
+            .fold(0u64, |value, &byte| (value << 8) | u64::from(byte));
+        self.pos += len;
+        Some((value, len))
+    }
+
+    fn next_header(&mut self) -> Option<Result<ElementHeader, VideoLoadError>> {
+        if self.end <= self.pos {
+            return None;
+        }
+        let start = self.pos;
+
+        let Some((id, id_len)) = self.read_vint() else {
+            return Some(Err(invalid(format!(
+                "invalid element id at offset {start}"
+            ))));
+        };
+        if 4 < id_len {
+            return Some(Err(invalid(format!(
+                "invalid element id at offset {start}"
+            ))));
+        }
+        let Some((size, size_len)) = self.read_vint() else {
+            return Some(Err(invalid(format!(
+                "invalid element size at offset {start}"
+            ))));
+        };
+
+        // Strip the length marker. All ones means that the size is unknown.
+        let value_bits = 7 * size_len as u32;
+        let size = size & ((1 << value_bits) - 1);
+        let unknown_size = size == (1 << value_bits) - 1;
+
+        let data_start = self.pos;
+        let data_end = if unknown_size {
+            self.end
+        } else {
+            match usize::try_from(size)
+                .ok()
+                .and_then(|size| data_start.checked_add(size))
+            {
+                Some(end) if end <= self.end => end,
+                _ => {
+                    return Some(Err(invalid(format!(
+                        "element {id:#X} at offset {start} exceeds its parent"
+                    ))));
+                }
+            }
+        };
+
+        Some(Ok(ElementHeader {
+            id: id as u32,
+            start,
+            data: data_start..data_end,
+            unknown_size,
+        }))
+    }
+
+    /// Moves past the data of the given element.
+    fn skip(&mut self, element: &ElementHeader) {
+        self.pos = element.data.end;
+    }
+
+    fn bytes(&self, element: &ElementHeader) -> &'a [u8] {
+        &self.data[element.data.clone()]
+    }
+
+    fn uint(&self, element: &ElementHeader) -> Result<u64, VideoLoadError> {
+        let bytes = self.bytes(element);
+        if 8 < bytes.len() {
+            return Err(invalid(format!(
+                "invalid unsigned integer {:#X}",
+                element.id
+            )));
+        }
+        Ok(bytes
+            .iter()
+            .fold(0u64, |value, &byte| (value << 8) | u64::from(byte)))
+    }
+
+    fn float(&self, element: &ElementHeader) -> Result<f64, VideoLoadError> {
+        let bytes = self.bytes(element);
+        match bytes.len() {
+            0 => Ok(0.0),
+            4 => Ok(f32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]).into()),
+            8 => Ok(f64::from_be_bytes([
+                bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+            ])),
+            _ => Err(invalid(format!("invalid float {:#X}", element.id))),
+        }
+    }
+
+    fn string(&self, element: &ElementHeader) -> String {
+        String::from_utf8_lossy(self.bytes(element))
+            .trim_end_matches('\0')
+            .to_owned()
+    }
+}
+
+// ---
+
+#[derive(Default)]
+struct TrackEntry {
+    number: u64,
+    track_type: u64,
+    codec_id: String,
+    codec_private: Option<Vec<u8>>,
+
+    /// In nanoseconds.
+    default_duration: Option<u64>,
+
+    width: u16,
+    height: u16,
+    is_compressed: bool,
+}
+
+fn read_track_entry(
+    reader: &EbmlReader<'_>,
+    element: &ElementHeader,
+) -> Result<TrackEntry, VideoLoadError> {
+    let mut track = TrackEntry::default();
+
+    let mut children = reader.children(element);
+    while let Some(child) = children.next_header() {
+        let child = child?;
+        match child.id {
+            TRACK_NUMBER => track.number = children.uint(&child)?,
+            TRACK_TYPE => track.track_type = children.uint(&child)?,
+            CODEC_ID => track.codec_id = children.string(&child),
+            CODEC_PRIVATE => track.codec_private = Some(children.bytes(&child).to_vec()),
+            DEFAULT_DURATION => track.default_duration = Some(children.uint(&child)?),
+            CONTENT_ENCODINGS => track.is_compressed = true,
+            VIDEO => {
+                let mut video = children.children(&child);
+                while let Some(video_child) = video.next_header() {
+                    let video_child = video_child?;
+                    match video_child.id {
+                        PIXEL_WIDTH => track.width = video.uint(&video_child)? as u16,
+                        PIXEL_HEIGHT => track.height = video.uint(&video_child)? as u16,
+                        _ => {}
+                    }
+                    video.skip(&video_child);
+                }
+            }
+            _ => {}
+        }
+        children.skip(&child);
+    }
+
+    Ok(track)
+}
+
+/// A (simple) block of the video track, before timestamps get resolved.
+struct Block {
+    /// In `TimestampScale` units.
+    timestamp: i64,
+
+    /// In `TimestampScale` units.
+    duration: Option<u64>,
+
+    is_keyframe: bool,
+    byte_span: Span<u32>,
+}
+
+/// Parses a `Block` or `SimpleBlock`, returning `None` if it belongs to another track.
+fn read_block(
+    reader: &EbmlReader<'_>,
+    element: &ElementHeader,
+    video_track: u64,
+    cluster_timestamp: i64,
+) -> Result<Option<Block>, VideoLoadError> {
+    let mut block_reader = reader.children(element);
+    let (track_number, track_number_len) = block_reader
+        .read_vint()
+        .ok_or_else(|| invalid("invalid block track number"))?;
+    let track_number = track_number & ((1 << (7 * track_number_len)) - 1);
+    if track_number != video_track {
+        return Ok(None);
+    }
+
+    let data = reader.bytes(element);
+    let header = data
+        .get(track_number_len..track_number_len + 3)
+        .ok_or_else(|| invalid("truncated block"))?;
+    let relative_timestamp = i16::from_be_bytes([header[0], header[1]]);
+    let flags = header[2];
+
+    let lacing = (flags >> 1) & 0b11;
+    if lacing != 0 {
+        re_log::warn_once!("Laced video blocks are not supported in Matroska files");
+        return Ok(None);
+    }
+
+    let frame_start = element.data.start + track_number_len + 3;
+    let byte_span = Span {
+        start: u32::try_from(frame_start).map_err(|_err| VideoLoadError::InvalidSamples)?,
+        len: u32::try_from(element.data.end - frame_start)
+            .map_err(|_err| VideoLoadError::InvalidSamples)?,
+    };
+
+    let timestamp = cluster_timestamp
+        .checked_add(i64::from(relative_timestamp))
+        .ok_or_else(|| invalid("block timestamp out of range"))?;
+
+    Ok(Some(Block {
+        timestamp,
+        duration: None,
+        is_keyframe: flags & 0x80 != 0,
+        byte_span,
+    }))
+}
+
+fn read_cluster(
+    reader: &mut EbmlReader<'_>,
+    cluster: &ElementHeader,
+    video_track: u64,
+    blocks: &mut Vec<Block>,
+) -> Result<(), VideoLoadError> {
+    let mut cluster_timestamp = 0;
+
+    let mut children = reader.children(cluster);
+    while let Some(child) = children.next_header() {
+        let child = child?;
+
+        if cluster.unknown_size && TOP_LEVEL_ELEMENTS.contains(&child.id) {
+            // The cluster ended, continue reading the segment from there.
+            reader.pos = child.start;
+            return Ok(());
+        }
+
+        match child.id {
+            CLUSTER_TIMESTAMP => {
+                cluster_timestamp = i64::try_from(children.uint(&child)?)
+                    .map_err(|_err| invalid("cluster timestamp out of range"))?;
+            }
+
+            SIMPLE_BLOCK => {
+                blocks.extend(read_block(
+                    &children,
+                    &child,
+                    video_track,
+                    cluster_timestamp,
+                )?);
+            }
+
+            BLOCK_GROUP => {
+                let mut block = None;
+                let mut duration = None;
+                let mut has_references = false;
+
+                let mut group = children.children(&child);
+                while let Some(group_child) = group.next_header() {
+                    let group_child = group_child?;
+                    match group_child.id {
+                        BLOCK => {
+                            block =
+                                read_block(&group, &group_child, video_track, cluster_timestamp)?;
+                        }
+                        BLOCK_DURATION => duration = Some(group.uint(&group_child)?),
+                        REFERENCE_BLOCK => has_references = true,
+                        _ => {}
+                    }
+                    group.skip(&group_child);
+                }
+
+                if let Some(mut block) = block {
+                    // Blocks in groups don't have a keyframe flag.
+                    block.is_keyframe = !has_references;
+                    block.duration = duration;
+                    blocks.push(block);
+                }
+            }
+
+            _ => {}
+        }
+        children.skip(&child);
+    }
+
+    reader.pos = children.pos;
+    Ok(())
+}
+
+// ---
+
+impl VideoDataDescription {
+    /// Loads the first video track of a Matroska (`.mkv`) or WebM file.
+    ///
+    /// Does not copy any sample data, but instead stores offsets into the buffer.
+    pub fn load_mkv(data: &[u8], debug_name: &str) -> Result<Self, VideoLoadError> {
+        re_tracing::profile_function!();
+
+        let mut reader = EbmlReader::new(data);
+
+        let header = reader
+            .next_header()
+            .ok_or_else(|| invalid("empty file"))??;
+        if header.id != EBML {
+            return Err(invalid("missing EBML header"));
+        }
+        let mut header_children = reader.children(&header);
+        while let Some(child) = header_children.next_header() {
+            let child = child?;
+            if child.id == DOC_TYPE {
+                let doc_type = header_children.string(&child);
+                if doc_type != "matroska" && doc_type != "webm" {
+                    return Err(invalid(format!("unsupported document type {doc_type:?}")));
+                }
+            }
+            header_children.skip(&child);
+        }
+        reader.skip(&header);
+
+        let segment = loop {
+            let element = reader
+                .next_header()
+                .ok_or_else(|| invalid("missing segment"))??;
+            if element.id == SEGMENT {
+                break element;
+            }
+            reader.skip(&element);
+        };
+
+        let mut timestamp_scale_ns = DEFAULT_TIMESTAMP_SCALE_NS;
+        let mut segment_duration = None;
+        let mut tracks = Vec::new();
+        let mut video_track: Option<TrackEntry> = None;
+        let mut blocks = Vec::new();
+
+        let mut segment_reader = reader.children(&segment);
+        while let Some(element) = segment_reader.next_header() {
+            let element = element?;
+            match element.id {
+                INFO => {
+                    let mut info = segment_reader.children(&element);
+                    while let Some(child) = info.next_header() {
+                        let child = child?;
+                        match child.id {
+                            TIMESTAMP_SCALE => timestamp_scale_ns = info.uint(&child)?.max(1),
+                            DURATION => segment_duration = Some(info.float(&child)?),
+                            _ => {}
+                        }
+                        info.skip(&child);
+                    }
+                }
+
+                TRACKS => {
+                    let mut entries = segment_reader.children(&element);
+                    while let Some(entry) = entries.next_header() {
+                        let entry = entry?;
+                        if entry.id == TRACK_ENTRY {
+                            let track = read_track_entry(&entries, &entry)?;
+                            tracks.push((track.number, track.track_type));
+                            if video_track.is_none() && track.track_type == TRACK_TYPE_VIDEO {
+                                video_track = Some(track);
+                            }
+                        }
+                        entries.skip(&entry);
+                    }
+                }
+
+                CLUSTER => {
+                    let video_track_number = video_track
+                        .as_ref()
+                        .ok_or(VideoLoadError::NoVideoTrack)?
+                        .number;
+                    read_cluster(
+                        &mut segment_reader,
+                        &element,
+                        video_track_number,
+                        &mut blocks,
+                    )?;
+                    continue;
+                }
+
+                _ => {
+                    if element.unknown_size {
+                        return Err(invalid(format!(
+                            "element {:#X} of unknown size is not supported",
+                            element.id
+                        )));
+                    }
+                }
+            }
+            segment_reader.skip(&element);
+        }
+
+        let track = video_track.ok_or(VideoLoadError::NoVideoTrack)?;
+        if track.is_compressed {
+            return Err(invalid("compressed or encrypted tracks are not supported"));
+        }
+
+        let (codec, encoding_details) = codec_and_encoding_details(&track, data, &blocks)?;
+
+        // Express timestamps in `TimestampScale` units if possible.
+        let (timescale, scale) = if 1_000_000_000 % timestamp_scale_ns == 0 {
+            (Timescale::new(1_000_000_000 / timestamp_scale_ns), 1)
+        } else {
+            let scale = i64::try_from(timestamp_scale_ns)
+                .map_err(|_err| invalid("timestamp scale out of range"))?;
+            (Timescale::NANOSECOND, scale)
+        };
+        let default_duration = track
+            .default_duration
+            .map(|duration| {
+                i64::try_from(duration)
+                    .map(|duration| Time::from_nanos(duration, timescale))
+                    .map_err(|_err| invalid("default duration out of range"))
+            })
+            .transpose()?;
+
+        let samples = samples_from_blocks(&blocks, scale, default_duration)?;
+
+        let duration = segment_duration
+            .map(|duration| Time::new((duration * scale as f64).round() as i64))
+            .or_else(|| {
+                samples
+                    .iter()
+                    .map(|sample| {
+                        let duration = sample.duration.unwrap_or_default();
+                        Time::new(sample.presentation_timestamp.0.saturating_add(duration.0))
+                    })
+                    .max()
+            })
+            .unwrap_or_default();
+
+        let tracks = tracks
+            .into_iter()
+            .map(|(number, track_type)| {
+                let kind = match track_type {
+                    TRACK_TYPE_VIDEO => Some(TrackKind::Video),
+                    TRACK_TYPE_AUDIO => Some(TrackKind::Audio),
+                    TRACK_TYPE_SUBTITLE => Some(TrackKind::Subtitle),
+                    _ => None,
+                };
+                (number as TrackId, kind)
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        Ok(Self::from_samples(
+            codec,
+            encoding_details,
+            timescale,
+            duration,
+            samples,
+            tracks,
+            debug_name,
+        ))
+    }
+}
+
+/// Turns blocks, in decode order and with presentation timestamps, into samples.
+///
+/// Matroska only stores presentation timestamps, so decode timestamps are derived from them:
+/// the sorted presentation timestamps, shifted back just enough to never be after the
+/// presentation timestamp of their sample.
+///
+/// Fails if any of the resulting timestamps doesn't fit in a [`Time`].
+fn samples_from_blocks(
+    blocks: &[Block],
+    scale: i64,
+    default_duration: Option<Time>,
+) -> Result<StableIndexDeque<SampleMetadata>, VideoLoadError> {
+    let out_of_range = || invalid("block timestamp out of range");
+
+    let presentation_timestamps = blocks
+        .iter()
+        .map(|block| block.timestamp.checked_mul(scale).ok_or_else(out_of_range))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut sorted_timestamps = presentation_timestamps.clone();
+    sorted_timestamps.sort_unstable();
+
+    let shift = presentation_timestamps
+        .iter()
+        .zip(&sorted_timestamps)
+        .map(|(pts, sorted)| sorted.checked_sub(*pts).ok_or_else(out_of_range))
+        .try_fold(0, |shift, delta| delta.map(|delta| delta.max(shift)))?;
+
+    // The duration of a frame is the time until the next frame is presented.
+    let next_timestamps = sorted_timestamps
+        .iter()
+        .zip(sorted_timestamps.iter().skip(1))
+        .map(|(pts, next)| (*pts, *next))
+        .collect::<BTreeMap<_, _>>();
+
+    blocks
+        .iter()
+        .zip(presentation_timestamps)
+        .zip(&sorted_timestamps)
+        .map(|((block, pts), sorted)| {
+            let duration = match block.duration {
+                Some(duration) => Some(
+                    i64::try_from(duration)
+                        .ok()
+                        .and_then(|duration| duration.checked_mul(scale))
+                        .ok_or_else(|| invalid("block duration out of range"))?,
+                ),
+                None => next_timestamps
+                    .get(&pts)
+                    .map(|next| next.checked_sub(pts).ok_or_else(out_of_range))
+                    .transpose()?,
+            }
+            .map(Time::new)
+            .or(default_duration);
+
+            Ok(SampleMetadata {
+                is_sync: block.is_keyframe,
+                frame_nr: 0, // filled in by `from_samples`
+                decode_timestamp: Time::new(sorted.checked_sub(shift).ok_or_else(out_of_range)?),
+                presentation_timestamp: Time::new(pts),
+                duration,
+                // There's only a single buffer, which is the raw Matroska data.
+                buffer_index: 0,
+                byte_span: block.byte_span,
+            })
+        })
+        .collect()
+}
+
+fn codec_and_encoding_details(
+    track: &TrackEntry,
+    data: &[u8],
+    blocks: &[Block],
+) -> Result<(VideoCodec, Option<crate::VideoEncodingDetails>), VideoLoadError> {
+    let coded_dimensions = [track.width, track.height];
+
+    // For codecs with a configuration record, this record is identical to the one of the
+    // matching mp4 sample entry, so we build one to share all the mp4 decoding logic.
+    let (codec, sample_entry, config_box) = match track.codec_id.as_str() {
+        "V_MPEG4/ISO/AVC" => (VideoCodec::H264, b"avc1", b"avcC"),
+        "V_MPEGH/ISO/HEVC" => (VideoCodec::H265, b"hvc1", b"hvcC"),
+        "V_AV1" => (VideoCodec::AV1, b"av01", b"av1C"),
+
+        "V_VP8" => {
+            let details = crate::VideoEncodingDetails {
+                codec_string: "vp8".to_owned(),
+                coded_dimensions,
+                bit_depth: Some(8),
+                chroma_subsampling: Some(crate::ChromaSubsamplingModes::Yuv420),
+                stsd: None,
+            };
+            return Ok((VideoCodec::VP8, Some(details)));
+        }
+
+        "V_VP9" => {
+            let first_frame = blocks
+                .first()
+                .and_then(|block| data.get(block.byte_span.range_usize()));
+            let (profile, bit_depth) = first_frame.map_or((0, 8), vp9_profile_and_bit_depth);
+            let details = crate::VideoEncodingDetails {
+                // There's no level information in the container, `10` is the lowest one.
+                codec_string: format!("vp09.{profile:02}.10.{bit_depth:02}"),
+                coded_dimensions,
+                bit_depth: Some(bit_depth),
+                chroma_subsampling: None,
+                stsd: None,
+            };
+            return Ok((VideoCodec::VP9, Some(details)));
+        }
+
+        codec_id => {
+            return Err(VideoLoadError::UnsupportedMatroskaCodec(
+                codec_id.to_owned(),
+            ));
+        }
+    };
+
+    let codec_private = track
+        .codec_private
+        .as_deref()
+        .ok_or(VideoLoadError::InvalidConfigFormat)?;
+    let stsd = stsd_box(sample_entry, config_box, coded_dimensions, codec_private)?;
+    let details = super::mp4::codec_details_from_stds(stsd, coded_dimensions)?;
+
+    Ok((codec, Some(details)))
+}
+
+/// Builds an mp4 `stsd` box holding a single visual sample entry with the given configuration
+/// record.
+fn stsd_box(
+    sample_entry: &[u8; 4],
+    config_box: &[u8; 4],
+    [width, height]: [u16; 2],
+    config: &[u8],
+) -> Result<re_mp4::StsdBox, VideoLoadError> {
+    use re_mp4::ReadBox as _;
+
+    let config_box_size = 8 + config.len();
+    let sample_entry_size = 8 + 78 + config_box_size;
+    let stsd_size = 8 + 8 + sample_entry_size;
+
+    let mut bytes = Vec::with_capacity(stsd_size);
+    bytes.extend((stsd_size as u32).to_be_bytes());
+    bytes.extend(b"stsd");
+    bytes.extend([0; 4]); // version & flags
+    bytes.extend(1u32.to_be_bytes()); // entry count
+
+    bytes.extend((sample_entry_size as u32).to_be_bytes());
+    bytes.extend(sample_entry);
+    bytes.extend([0; 6]); // reserved
+    bytes.extend(1u16.to_be_bytes()); // data reference index
+    bytes.extend([0; 16]); // pre-defined & reserved
+    bytes.extend(width.to_be_bytes());
+    bytes.extend(height.to_be_bytes());
+    bytes.extend(0x0048_0000u32.to_be_bytes()); // horizontal resolution, 72 dpi
+    bytes.extend(0x0048_0000u32.to_be_bytes()); // vertical resolution, 72 dpi
+    bytes.extend([0; 4]); // reserved
+    bytes.extend(1u16.to_be_bytes()); // frame count
+    bytes.extend([0; 32]); // compressor name
+    bytes.extend(0x0018u16.to_be_bytes()); // depth
+    bytes.extend((-1i16).to_be_bytes()); // pre-defined
+
+    bytes.extend((config_box_size as u32).to_be_bytes());
+    bytes.extend(config_box);
+    bytes.extend(config);
+
+    let mut cursor = std::io::Cursor::new(bytes.as_slice());
+    cursor.set_position(8);
+    Ok(re_mp4::StsdBox::read_box(&mut cursor, stsd_size as u64)?)
+}
+
+/// Reads the profile & bit depth from the uncompressed header of a VP9 key frame.
+///
+/// See section 6.2 of the [VP9 bitstream specification](https://storage.googleapis.com/downloads.webmproject.org/docs/vp9/vp9-bitstream-specification-v0.6-20160331-draft.pdf).
+fn vp9_profile_and_bit_depth(frame: &[u8]) -> (u8, u8) {
+    let bit = |index: usize| {
+        frame
+            .get(index / 8)
+            .map_or(0, |byte| (byte >> (7 - index % 8)) & 1)
+    };
+
+    // frame_marker (2 bits), then profile_low_bit & profile_high_bit.
+    let profile = bit(2) | (bit(3) << 1);
+    if profile < 2 {
+        return (profile, 8);
+    }
+
+    // reserved_zero for profile 3, show_existing_frame, frame_type, show_frame,
+    // error_resilient_mode, then the 24 bit sync code before `ten_or_twelve_bit`.
+    let mut index = 4 + usize::from(profile == 3);
+    let show_existing_frame = bit(index);
+    let is_key_frame = bit(index + 1) == 0;
+    if show_existing_frame == 1 || !is_key_frame {
+        return (profile, 10);
+    }
+    index += 4 + 24;
+    (profile, if bit(index) == 1 { 12 } else { 10 })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encodes an element with a known size.
+    fn element(id: u32, data: &[u8]) -> Vec<u8> {
+        let mut bytes = id
+            .to_be_bytes()
+            .into_iter()
+            .skip_while(|&byte| byte == 0)
+            .collect::<Vec<_>>();
+        // 8 byte size.
+        bytes.push(0x01);
+        bytes.extend(&(data.len() as u64).to_be_bytes()[1..]);
+        bytes.extend(data);
+        bytes
+    }
+
+    /// Encodes an element of unknown size.
+    fn unknown_size_element(id: u32, data: &[u8]) -> Vec<u8> {
+        let mut bytes = id.to_be_bytes().to_vec();
+        bytes.push(0xFF);
+        bytes.extend(data);
+        bytes
+    }
+
+    fn simple_block(relative_timestamp: i16, keyframe: bool, frame: &[u8]) -> Vec<u8> {
+        let mut data = vec![0x81]; // track 1
+        data.extend(relative_timestamp.to_be_bytes());
+        data.push(if keyframe { 0x80 } else { 0x00 });
+        data.extend(frame);
+        element(SIMPLE_BLOCK, &data)
+    }
+
+    fn webm(clusters: &[u8]) -> Vec<u8> {
+        let video = [
+            element(PIXEL_WIDTH, &[0x01, 0x40]),
+            element(PIXEL_HEIGHT, &[0xF0]),
+        ]
+        .concat();
+        let video_track = [
+            element(TRACK_NUMBER, &[1]),
+            element(TRACK_TYPE, &[1]),
+            element(CODEC_ID, b"V_VP8"),
+            element(VIDEO, &video),
+        ]
+        .concat();
+        let audio_track = [
+            element(TRACK_NUMBER, &[2]),
+            element(TRACK_TYPE, &[2]),
+            element(CODEC_ID, b"A_OPUS"),
+        ]
+        .concat();
+        let tracks = [
+            element(TRACK_ENTRY, &video_track),
+            element(TRACK_ENTRY, &audio_track),
+        ]
+        .concat();
+
+        let segment = [
+            element(INFO, &element(TIMESTAMP_SCALE, &[0x0F, 0x42, 0x40])),
+            element(TRACKS, &tracks),
+            clusters.to_vec(),
+        ]
+        .concat();
+
+        [
+            element(EBML, &element(DOC_TYPE, b"webm")),
+            unknown_size_element(SEGMENT, &segment),
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn test_load_webm() -> Result<(), VideoLoadError> {
+        // A cluster of unknown size, like the ones written by browsers, followed by a regular one.
+        let first_cluster = [
+            element(CLUSTER_TIMESTAMP, &[0]),
+            simple_block(0, true, b"key0"),
+            simple_block(40, false, b"delta1"),
+        ]
+        .concat();
+        let audio_block = [0x82, 0x00, 0x00, 0x80, 0xAA];
+        let second_cluster = [
+            element(CLUSTER_TIMESTAMP, &[80]),
+            element(SIMPLE_BLOCK, &audio_block),
+            simple_block(0, true, b"key2"),
+        ]
+        .concat();
+        let data = webm(
+            &[
+                unknown_size_element(CLUSTER, &first_cluster),
+                element(CLUSTER, &second_cluster),
+            ]
+            .concat(),
+        );
+
+        let video = VideoDataDescription::load_mkv(&data, "test.webm")?;
+        assert_eq!(video.codec, VideoCodec::VP8);
+        assert_eq!(video.timescale, Some(Timescale::new(1000)));
+        assert_eq!(
+            video
+                .encoding_details
+                .as_ref()
+                .map(|details| details.coded_dimensions),
+            Some([320, 240])
+        );
+        assert_eq!(
+            video.mp4_tracks,
+            BTreeMap::from([(1, Some(TrackKind::Video)), (2, Some(TrackKind::Audio))])
+        );
+
+        let frames = video
+            .samples
+            .iter()
+            .map(|sample| {
+                (
+                    sample.is_sync,
+                    sample.presentation_timestamp.0,
+                    &data[sample.byte_span.range_usize()],
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            frames,
+            vec![
+                (true, 0, b"key0".as_slice()),
+                (false, 40, b"delta1".as_slice()),
+                (true, 80, b"key2".as_slice()),
+            ]
+        );
+        assert_eq!(video.gops.num_elements(), 2);
+        assert_eq!(video.samples[1].duration, Some(Time::new(40)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_timestamps_from_reordered_frames() {
+        // I P B B, in decode order.
+        let blocks = [0, 3, 1, 2]
+            .into_iter()
+            .map(|timestamp| Block {
+                timestamp,
+                duration: None,
+                is_keyframe: timestamp == 0,
+                byte_span: Span::default(),
+            })
+            .collect::<Vec<_>>();
+
+        let samples = samples_from_blocks(&blocks, 10, None).unwrap();
+        let timestamps = samples
+            .iter()
+            .map(|sample| (sample.decode_timestamp.0, sample.presentation_timestamp.0))
+            .collect::<Vec<_>>();
+        assert_eq!(timestamps, vec![(-10, 0), (0, 30), (10, 10), (20, 20)]);
+    }
+
+    #[test]
+    fn test_timestamps_out_of_range() {
+        let cluster = |cluster_timestamp: &[u8]| {
+            element(
+                CLUSTER,
+                &[
+                    element(CLUSTER_TIMESTAMP, cluster_timestamp),
+                    simple_block(1, true, b"key0"),
+                ]
+                .concat(),
+            )
+        };
+        assert!(VideoDataDescription::load_mkv(&webm(&cluster(&[0xFF; 8])), "test.webm").is_err());
+        assert!(
+            VideoDataDescription::load_mkv(&webm(&cluster(&i64::MAX.to_be_bytes())), "test.webm")
+                .is_err()
+        );
+
+        let blocks = |timestamps: &[i64]| {
+            timestamps
+                .iter()
+                .map(|&timestamp| Block {
+                    timestamp,
+                    duration: None,
+                    is_keyframe: true,
+                    byte_span: Span::default(),
+                })
+                .collect::<Vec<_>>()
+        };
+        assert!(samples_from_blocks(&blocks(&[0, i64::MAX / 2]), 10, None).is_err());
+        assert!(samples_from_blocks(&blocks(&[i64::MAX, i64::MIN]), 1, None).is_err());
+
+        let mut long_block = blocks(&[0]);
+        long_block[0].duration = Some(u64::MAX);
+        assert!(samples_from_blocks(&long_block, 1, None).is_err());
+    }
+
+    #[test]
+    fn test_h264_codec_private() -> Result<(), VideoLoadError> {
+        // AVC decoder configuration record of a 320x240 baseline profile stream.
+        let sps = [0x67, 0x42, 0x00, 0x1E, 0xF4, 0x0A, 0x0F, 0xC8];
+        let pps = [0x68, 0xCE, 0x38, 0x80];
+        let codec_private = [
+            &[0x01, 0x42, 0x00, 0x1E, 0xFF, 0xE1, 0x00, sps.len() as u8][..],
+            &sps,
+            &[0x01, 0x00, pps.len() as u8],
+            &pps,
+        ]
+        .concat();
+        let track = TrackEntry {
+            codec_id: "V_MPEG4/ISO/AVC".to_owned(),
+            codec_private: Some(codec_private),
+            width: 320,
+            height: 240,
+            ..Default::default()
+        };
+
+        let (codec, details) = codec_and_encoding_details(&track, &[], &[])?;
+        assert_eq!(codec, VideoCodec::H264);
+        let details = details.ok_or(VideoLoadError::InvalidConfigFormat)?;
+        assert_eq!(details.codec_string, "avc1.42001E");
+        assert_eq!(details.coded_dimensions, [320, 240]);
+        assert!(matches!(
+            details.stsd.map(|stsd| stsd.contents),
+            Some(re_mp4::StsdBoxContent::Avc1(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_not_matroska() {
+        let data = element(EBML, &element(DOC_TYPE, b"foo"));
+        assert!(matches!(
+            VideoDataDescription::load_mkv(&data, "test.mkv"),
+            Err(VideoLoadError::ParseMatroska(_))
+        ));
+    }
+}
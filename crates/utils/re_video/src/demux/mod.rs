@@ -4,7 +4,11 @@
 //!
 //! The entry point is [`VideoDataDescription::load_from_bytes`]
 //! which produces an instance of [`VideoDataDescription`] from any supported video container.
+//!
+//! Supported are mp4, Matroska/WebM, and raw H.264/H.265 Annex B bitstreams.
 
+pub mod annexb;
+pub mod mkv;
 pub mod mp4;
 
 use std::{collections::BTreeMap, ops::Range};
@@ -451,15 +455,39 @@ impl VideoDataDescription {
     /// Loads a video from the given data.
     ///
     /// Does not copy any sample data, but instead stores offsets into the buffer.
+    ///
+    /// Raw H.264/H.265 bitstreams carry no timestamps. Their frame rate can be specified with a
+    /// `framerate` media type parameter, e.g. `video/h264; framerate=25`.
     pub fn load_from_bytes(
         data: &[u8],
         media_type: &str,
         debug_name: &str,
     ) -> Result<Self, VideoLoadError> {
         re_tracing::profile_function!();
-        match media_type {
+
+        let mut parts = media_type.split(';').map(str::trim);
+        let essence = parts.next().unwrap_or_default();
+        let frame_rate = parts.find_map(|parameter| {
+            let (name, value) = parameter.split_once('=')?;
+            if name.trim().eq_ignore_ascii_case("framerate") {
+                value.trim().trim_matches('"').parse::<f64>().ok()
+            } else {
+                None
+            }
+        });
+
+        match essence {
             "video/mp4" => Self::load_mp4(data, debug_name),
 
+            "video/webm" | "video/x-matroska" | "video/matroska" => {
+                Self::load_mkv(data, debug_name)
+            }
+
+            "video/h264" => Self::load_annexb(data, VideoCodec::H264, frame_rate, debug_name),
+            "video/h265" | "video/hevc" => {
+                Self::load_annexb(data, VideoCodec::H265, frame_rate, debug_name)
+            }
+
             media_type => {
                 if media_type.starts_with("video/") {
                     Err(VideoLoadError::UnsupportedMimeType {
@@ -474,6 +502,66 @@ impl VideoDataDescription {
         }
     }
 
+    /// Builds a description from samples in decode order, with frame numbers yet to be determined.
+    ///
+    /// Each sync sample starts a new GOP; samples before the first one aren't part of any GOP.
+    fn from_samples(
+        codec: VideoCodec,
+        encoding_details: Option<VideoEncodingDetails>,
+        timescale: Timescale,
+        duration: Time,
+        mut samples: StableIndexDeque<SampleMetadata>,
+        mp4_tracks: BTreeMap<TrackId, Option<TrackKind>>,
+        debug_name: &str,
+    ) -> Self {
+        let mut gops = StableIndexDeque::<GroupOfPictures>::new();
+        let mut gop_sample_start_index = None;
+        for (index, sample) in samples.iter_indexed() {
+            if sample.is_sync {
+                if let Some(start) = gop_sample_start_index {
+                    gops.push_back(GroupOfPictures {
+                        sample_range: start..index,
+                    });
+                }
+                gop_sample_start_index = Some(index);
+            }
+        }
+        if let Some(start) = gop_sample_start_index {
+            gops.push_back(GroupOfPictures {
+                sample_range: start..samples.next_index(),
+            });
+        }
+
+        {
+            re_tracing::profile_scope!("Calculate frame numbers");
+            let mut samples_sorted_by_pts = samples.iter_mut().collect::<Vec<_>>();
+            samples_sorted_by_pts.sort_by_key(|s| s.presentation_timestamp);
+            for (frame_nr, sample) in samples_sorted_by_pts.into_iter().enumerate() {
+                sample.frame_nr = frame_nr as u32;
+            }
+        }
+
+        let video_data_description = Self {
+            codec,
+            encoding_details,
+            timescale: Some(timescale),
+            duration: Some(duration),
+            samples_statistics: SamplesStatistics::new(&samples),
+            last_time_updated_samples: None,
+            gops,
+            samples,
+            mp4_tracks,
+        };
+
+        if cfg!(debug_assertions) {
+            if let Err(err) = video_data_description.sanity_check() {
+                panic!("VideoDataDescription sanity check for {debug_name} failed: {err}");
+            }
+        }
+
+        video_data_description
+    }
+
     /// Length of the video if known.
     ///
     /// NOTE: This includes the duration of the final frame too!
@@ -827,6 +915,18 @@ pub enum VideoLoadError {
 
     #[error("Failed to parse H.264 SPS from mp4: {0:?}")]
     SpsParsingError(h264_reader::nal::sps::SpsError),
+
+    #[error("Failed to parse Matroska file: {0}")]
+    ParseMatroska(String),
+
+    #[error("Matroska video track uses unsupported codec {0:?}")]
+    UnsupportedMatroskaCodec(String),
+
+    #[error("Failed to parse H.265 SPS: {0}")]
+    H265SpsParsingError(String),
+
+    #[error("Video bitstream is missing a sequence parameter set")]
+    NoSequenceParameterSet,
 }
 
 impl std::fmt::Debug for VideoDataDescription {
@@ -136,7 +136,7 @@ impl VideoDataDescription {
 
         let video_data_description = Self {
             codec,
-            encoding_details: Some(codec_details_from_stds(track, stsd)?),
+            encoding_details: Some(codec_details_from_stds(stsd, [track.width, track.height])?),
             timescale: Some(timescale),
             duration: Some(duration),
             samples_statistics,
@@ -164,9 +164,9 @@ fn unknown_codec_fourcc(mp4: &re_mp4::Mp4, track: &re_mp4::Track) -> re_mp4::Fou
     }
 }
 
-fn codec_details_from_stds(
-    track: &re_mp4::Track,
+pub(crate) fn codec_details_from_stds(
     stsd: re_mp4::StsdBox,
+    coded_dimensions: [u16; 2],
 ) -> Result<VideoEncodingDetails, VideoLoadError> {
     // For AVC we don't have to rely on the stsd box, since we can parse the SPS directly.
     // re_mp4 doesn't have a full SPS parser, so almost certainly we're getting more information out this way,
@@ -192,7 +192,7 @@ fn codec_details_from_stds(
             .contents
             .codec_string()
             .ok_or(VideoLoadError::UnableToDetermineCodecString)?,
-        coded_dimensions: [track.width, track.height],
+        coded_dimensions,
         bit_depth: stsd.contents.bit_depth(),
         chroma_subsampling: subsampling_mode(&stsd),
         stsd: Some(stsd),
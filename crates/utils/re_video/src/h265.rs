@@ -0,0 +1,303 @@
+//! General H.265 utilities.
+
+use crate::{ChromaSubsamplingModes, VideoEncodingDetails};
+
+/// NAL unit type of a sequence parameter set.
+pub const NAL_UNIT_TYPE_SPS: u8 = 33;
+
+/// Reads bits from the raw byte sequence payload of a NAL unit, skipping emulation prevention bytes.
+struct RbspBitReader<'a> {
+    data: &'a [u8],
+    byte: usize,
+    bit: u8,
+    zeros: usize,
+}
+
+impl<'a> RbspBitReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            byte: 0,
+            bit: 0,
+            zeros: 0,
+        }
+    }
+
+    fn read_bit(&mut self) -> Result<u32, String> {
+        if self.bit == 0 {
+            // `00 00 03` is an escaped `00 00`.
+            if self.zeros >= 2 && self.data.get(self.byte) == Some(&0x03) {
+                self.byte += 1;
+                self.zeros = 0;
+            }
+            let byte = *self
+                .data
+                .get(self.byte)
+                .ok_or_else(|| "unexpected end of SPS".to_owned())?;
+            self.zeros = if byte == 0 { self.zeros + 1 } else { 0 };
+        }
+
+        let value = (self.data[self.byte] >> (7 - self.bit)) & 1;
+        self.bit += 1;
+        if self.bit == 8 {
+            self.bit = 0;
+            self.byte += 1;
+        }
+        Ok(u32::from(value))
+    }
+
+    fn read_bits(&mut self, count: u32) -> Result<u32, String> {
+        debug_assert!(count <= 32);
+        let mut value = 0u64;
+        for _ in 0..count {
+            value = (value << 1) | u64::from(self.read_bit()?);
+        }
+        Ok(value as u32)
+    }
+
+    fn skip_bits(&mut self, count: u32) -> Result<(), String> {
+        for _ in 0..count {
+            self.read_bit()?;
+        }
+        Ok(())
+    }
+
+    /// Reads an unsigned exponential Golomb coded value.
+    fn read_ue(&mut self) -> Result<u32, String> {
+        let mut leading_zeros = 0;
+        while self.read_bit()? == 0 {
+            leading_zeros += 1;
+            if 31 < leading_zeros {
+                return Err("invalid exponential Golomb code".to_owned());
+            }
+        }
+        Ok((1 << leading_zeros) - 1 + self.read_bits(leading_zeros)?)
+    }
+}
+
+/// Retrieve [`VideoEncodingDetails`] from a H.265 SPS NAL unit, including its two byte header.
+///
+/// See section 7.3.2.2 of the [H.265 specification](https://www.itu.int/rec/T-REC-H.265).
+pub fn encoding_details_from_h265_sps(nal: &[u8]) -> Result<VideoEncodingDetails, String> {
+    let payload = nal
+        .get(2..)
+        .ok_or_else(|| "SPS NAL unit is too short".to_owned())?;
+    let mut reader = RbspBitReader::new(payload);
+
+    reader.skip_bits(4)?; // sps_video_parameter_set_id
+    let max_sub_layers_minus1 = reader.read_bits(3)?;
+    reader.skip_bits(1)?; // sps_temporal_id_nesting_flag
+
+    // profile_tier_level
+    let profile_space = reader.read_bits(2)?;
+    let tier_flag = reader.read_bits(1)?;
+    let profile_idc = reader.read_bits(5)?;
+    let profile_compatibility_flags = reader.read_bits(32)?;
+    let mut constraint_bytes = [0u8; 6];
+    for byte in &mut constraint_bytes {
+        *byte = reader.read_bits(8)? as u8;
+    }
+    let level_idc = reader.read_bits(8)?;
+
+    let mut sub_layers_present = Vec::new();
+    for _ in 0..max_sub_layers_minus1 {
+        let profile_present = reader.read_bit()? == 1;
+        let level_present = reader.read_bit()? == 1;
+        sub_layers_present.push((profile_present, level_present));
+    }
+    if 0 < max_sub_layers_minus1 {
+        reader.skip_bits(2 * (8 - max_sub_layers_minus1))?; // reserved_zero_2bits
+    }
+    for (profile_present, level_present) in sub_layers_present {
+        if profile_present {
+            reader.skip_bits(88)?;
+        }
+        if level_present {
+            reader.skip_bits(8)?;
+        }
+    }
+
+    reader.read_ue()?; // sps_seq_parameter_set_id
+    let chroma_format_idc = reader.read_ue()?;
+    let separate_colour_plane = chroma_format_idc == 3 && reader.read_bit()? == 1;
+
+    let mut width = reader.read_ue()?;
+    let mut height = reader.read_ue()?;
+    if reader.read_bit()? == 1 {
+        // The conformance window offsets are in chroma samples.
+        let (sub_width, sub_height) = match chroma_format_idc {
+            1 => (2, 2),
+            2 => (2, 1),
+            _ => (1, 1),
+        };
+        let left = reader.read_ue()?;
+        let right = reader.read_ue()?;
+        let top = reader.read_ue()?;
+        let bottom = reader.read_ue()?;
+        let crop_width = left
+            .checked_add(right)
+            .and_then(|offset| offset.checked_mul(sub_width))
+            .ok_or_else(|| format!("invalid conformance window {left}+{right}"))?;
+        let crop_height = top
+            .checked_add(bottom)
+            .and_then(|offset| offset.checked_mul(sub_height))
+            .ok_or_else(|| format!("invalid conformance window {top}+{bottom}"))?;
+        width = width.saturating_sub(crop_width);
+        height = height.saturating_sub(crop_height);
+    }
+    let bit_depth_minus8 = reader.read_ue()?;
+    let bit_depth = bit_depth_minus8
+        .checked_add(8)
+        .ok_or_else(|| format!("invalid bit depth {bit_depth_minus8}+8"))?;
+
+    let chroma_subsampling = match chroma_format_idc {
+        0 => Some(ChromaSubsamplingModes::Monochrome),
+        1 => Some(ChromaSubsamplingModes::Yuv420),
+        2 => Some(ChromaSubsamplingModes::Yuv422),
+        3 if !separate_colour_plane => Some(ChromaSubsamplingModes::Yuv444),
+        _ => None,
+    };
+
+    Ok(VideoEncodingDetails {
+        codec_string: codec_string(
+            profile_space,
+            tier_flag,
+            profile_idc,
+            profile_compatibility_flags,
+            level_idc,
+            constraint_bytes,
+        ),
+        coded_dimensions: [
+            u16::try_from(width).map_err(|_err| format!("invalid width {width}"))?,
+            u16::try_from(height).map_err(|_err| format!("invalid height {height}"))?,
+        ],
+        bit_depth: u8::try_from(bit_depth).ok(),
+        chroma_subsampling,
+        stsd: None,
+    })
+}
+
+/// Codec string as specified in annex E of ISO/IEC 14496-15.
+///
+/// Uses `hev1`, since parameter sets are part of the bitstream.
+fn codec_string(
+    profile_space: u32,
+    tier_flag: u32,
+    profile_idc: u32,
+    profile_compatibility_flags: u32,
+    level_idc: u32,
+    constraint_bytes: [u8; 6],
+) -> String {
+    let profile_space = match profile_space {
+        1 => "A",
+        2 => "B",
+        3 => "C",
+        _ => "",
+    };
+    let tier = if tier_flag == 1 { 'H' } else { 'L' };
+
+    let mut codec_string = format!(
+        "hev1.{profile_space}{profile_idc}.{:X}.{tier}{level_idc}",
+        profile_compatibility_flags.reverse_bits()
+    );
+
+    // Trailing zero bytes may be omitted.
+    let num_constraint_bytes = constraint_bytes
+        .iter()
+        .rposition(|&byte| byte != 0)
+        .map_or(0, |index| index + 1);
+    for byte in &constraint_bytes[..num_constraint_bytes] {
+        codec_string.push_str(&format!(".{byte:X}"));
+    }
+
+    codec_string
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encoding_details_from_h265_sps() -> Result<(), String> {
+        // SPS of a 1920x1080 Main profile stream, as written by x265.
+        let sps = [
+            0x42, 0x01, 0x01, 0x01, 0x60, 0x00, 0x00, 0x03, 0x00, 0x90, 0x00, 0x00, 0x03, 0x00,
+            0x00, 0x03, 0x00, 0x78, 0xA0, 0x03, 0xC0, 0x80, 0x10, 0xE5, 0x96, 0x66, 0x69, 0x24,
+            0xCA, 0xE0, 0x10, 0x00, 0x00, 0x03, 0x00, 0x10, 0x00, 0x00, 0x03, 0x01, 0xE0, 0x80,
+        ];
+
+        let details = encoding_details_from_h265_sps(&sps)?;
+        assert_eq!(details.codec_string, "hev1.1.6.L120.90");
+        assert_eq!(details.coded_dimensions, [1920, 1080]);
+        assert_eq!(details.bit_depth, Some(8));
+        assert_eq!(
+            details.chroma_subsampling,
+            Some(ChromaSubsamplingModes::Yuv420)
+        );
+
+        Ok(())
+    }
+
+    /// Writes a Main profile SPS NAL unit with the given values, and zeros everywhere else.
+    fn sps_nal(
+        width: u32,
+        height: u32,
+        conformance_window: [u32; 4],
+        bit_depth_minus8: u32,
+    ) -> Vec<u8> {
+        fn write_ue(bits: &mut Vec<bool>, value: u32) {
+            let value = u64::from(value) + 1;
+            let num_bits = 64 - value.leading_zeros();
+            bits.extend(std::iter::repeat_n(false, num_bits as usize - 1));
+            bits.extend((0..num_bits).rev().map(|bit| (value >> bit) & 1 == 1));
+        }
+
+        let mut bits = vec![false; 8]; // sps_video_parameter_set_id, max_sub_layers_minus1, …
+        bits.extend([false; 96]); // profile_tier_level
+        write_ue(&mut bits, 0); // sps_seq_parameter_set_id
+        write_ue(&mut bits, 1); // chroma_format_idc
+        write_ue(&mut bits, width);
+        write_ue(&mut bits, height);
+        bits.push(true); // conformance_window_flag
+        for offset in conformance_window {
+            write_ue(&mut bits, offset);
+        }
+        write_ue(&mut bits, bit_depth_minus8);
+        bits.resize(bits.len().next_multiple_of(8), false);
+
+        let mut nal = vec![0x42, 0x01];
+        let mut zeros = 0;
+        for byte in bits.chunks(8) {
+            let byte = byte
+                .iter()
+                .fold(0u8, |byte, &bit| (byte << 1) | u8::from(bit));
+            if zeros >= 2 && byte <= 0x03 {
+                nal.push(0x03); // emulation prevention
+                zeros = 0;
+            }
+            zeros = if byte == 0 { zeros + 1 } else { 0 };
+            nal.push(byte);
+        }
+        nal
+    }
+
+    #[test]
+    fn test_malformed_h265_sps() {
+        let details =
+            encoding_details_from_h265_sps(&sps_nal(1920, 1088, [0, 0, 0, 4], 2)).unwrap();
+        assert_eq!(details.coded_dimensions, [1920, 1080]);
+        assert_eq!(details.bit_depth, Some(10));
+
+        // None of these may overflow.
+        assert!(
+            encoding_details_from_h265_sps(&sps_nal(1920, 1080, [u32::MAX - 1; 4], 0)).is_err()
+        );
+        assert!(
+            encoding_details_from_h265_sps(&sps_nal(1920, 1080, [u32::MAX - 1, 0, 0, 0], 0))
+                .is_err()
+        );
+        assert!(
+            encoding_details_from_h265_sps(&sps_nal(1920, 1080, [0; 4], u32::MAX - 1)).is_err()
+        );
+    }
+}
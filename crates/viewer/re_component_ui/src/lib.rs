@@ -36,9 +36,9 @@ use datatype_uis::{
 
 use re_types::{
     blueprint::components::{
-        BackgroundKind, Corner2D, Enabled, Eye3DKind, ForceDistance, ForceIterations,
-        ForceStrength, GridSpacing, LinkAxis, LockRangeDuringZoom, MapProvider, NearClipPlane,
-        RootContainer, ViewFit, ViewMaximized,
+        BackgroundKind, Corner2D, Enabled, Eye3DKind, FilterIsNotNullMode, ForceDistance,
+        ForceIterations, ForceStrength, GridSpacing, LatestAtScope, LinkAxis, LockRangeDuringZoom,
+        MapProvider, NearClipPlane, RootContainer, TextFilterMode, ViewFit, ViewMaximized,
     },
     components::{
         AggregationPolicy, AlbedoFactor, AxisLength, Color, DepthMeter, DrawOrder, FillMode,
@@ -124,7 +124,9 @@ pub fn create_component_ui_registry() -> re_viewer_context::ComponentUiRegistry
     registry.add_singleline_edit_or_view::<Corner2D>(edit_view_enum);
     registry.add_singleline_edit_or_view::<Eye3DKind>(edit_view_enum);
     registry.add_singleline_edit_or_view::<FillMode>(edit_view_enum);
+    registry.add_singleline_edit_or_view::<FilterIsNotNullMode>(edit_view_enum);
     registry.add_singleline_edit_or_view::<GraphType>(edit_view_enum);
+    registry.add_singleline_edit_or_view::<LatestAtScope>(edit_view_enum);
     registry.add_singleline_edit_or_view::<LinkAxis>(edit_view_enum);
     registry.add_singleline_edit_or_view::<MapProvider>(
         edit_view_enum_with_variant_available::<
@@ -133,6 +135,7 @@ pub fn create_component_ui_registry() -> re_viewer_context::ComponentUiRegistry
         >,
     );
     registry.add_singleline_edit_or_view::<MagnificationFilter>(edit_view_enum);
+    registry.add_singleline_edit_or_view::<TextFilterMode>(edit_view_enum);
     registry.add_singleline_edit_or_view::<TransformRelation>(edit_view_enum);
     registry.add_singleline_edit_or_view::<VideoCodec>(|ctx, ui, value| {
         // Hack to make this field never editable.
@@ -1,10 +1,11 @@
+use itertools::Itertools as _;
 use jiff::SignedDuration;
 use jiff::fmt::friendly::{FractionalUnit, SpanPrinter};
 
 use re_byte_size::SizeBytes as _;
 use re_chunk_store::ChunkStoreConfig;
 use re_entity_db::EntityDb;
-use re_log_types::StoreKind;
+use re_log_types::{StoreKind, TimeType};
 use re_smart_channel::SmartChannelSource;
 use re_ui::UiExt as _;
 use re_viewer_context::{UiLayout, ViewerContext};
@@ -134,19 +135,45 @@ impl crate::DataUi for EntityDb {
             }
 
             {
+                let storage_engine = self.storage_engine();
+                let store = storage_engine.store();
                 let &ChunkStoreConfig {
                     enable_changelog: _,
                     chunk_max_bytes,
                     chunk_max_rows,
                     chunk_max_rows_if_unsorted,
-                } = self.storage_engine().store().config();
+                    ref chunk_max_time_span_per_timeline,
+                } = store.config();
+
+                let max_time_spans = chunk_max_time_span_per_timeline
+                    .iter()
+                    .map(|(timeline, &max_time_span)| {
+                        // Not `EntityDb::timeline_type`: that would lock the storage engine a second time.
+                        let time_type = store
+                            .time_column_type(timeline)
+                            .unwrap_or(TimeType::Sequence);
+                        let max_time_span = match time_type {
+                            TimeType::Sequence => re_format::format_uint(max_time_span),
+                            TimeType::DurationNs | TimeType::TimestampNs => {
+                                re_log_types::Duration::from_nanos(max_time_span as _).to_string()
+                            }
+                        };
+                        format!("{max_time_span} on {timeline}")
+                    })
+                    .collect_vec();
+                drop(storage_engine);
 
                 ui.grid_left_hand_label("Compaction");
                 ui.label(format!(
-                    "{} rows ({} if unsorted) or {}",
+                    "{} rows ({} if unsorted) or {}{}",
                     re_format::format_uint(chunk_max_rows),
                     re_format::format_uint(chunk_max_rows_if_unsorted),
                     re_format::format_bytes(chunk_max_bytes as _),
+                    if max_time_spans.is_empty() {
+                        String::new()
+                    } else {
+                        format!(", or spanning {}", max_time_spans.join(", "))
+                    },
                 ))
                     .on_hover_text(
                         unindent::unindent(&format!("\
@@ -169,6 +196,7 @@ impl crate::DataUi for EntityDb {
                         * {}
                         * {}
                         * {}
+                        * {}
 
                         This compaction process is an ephemeral, in-memory optimization of the Rerun viewer.\
                         It will not modify the recording itself: use the `Save` command of the viewer, or the \
@@ -181,6 +209,7 @@ impl crate::DataUi for EntityDb {
                                                     ChunkStoreConfig::ENV_CHUNK_MAX_ROWS,
                                                     ChunkStoreConfig::ENV_CHUNK_MAX_ROWS_IF_UNSORTED,
                                                     ChunkStoreConfig::ENV_CHUNK_MAX_BYTES,
+                                                    ChunkStoreConfig::ENV_CHUNK_MAX_TIME_SPAN,
                         )),
                     );
                 ui.end_row();
@@ -283,3 +283,70 @@ impl DataFusionAdapter {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_sort_by_row_id() {
+        use arrow::array::RecordBatch;
+        use arrow::datatypes::Schema;
+        use datafusion::datasource::MemTable;
+        use re_types_core::{Loggable as _, RowId};
+
+        let row_ids = [RowId::new(), RowId::new(), RowId::new()];
+        let field = re_sorbet::RowIdColumnDescriptor::from_sorted(false).to_arrow_field();
+        let column_physical_name = field.name().clone();
+
+        // Deliberately out of order.
+        let schema = Arc::new(Schema::new_with_metadata(vec![field], Default::default()));
+        let record_batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(RowId::arrow_from_slice(&[
+                row_ids[1], row_ids[2], row_ids[0],
+            ]))],
+        )
+        .unwrap();
+
+        let session_ctx = Arc::new(SessionContext::new());
+        session_ctx
+            .register_table(
+                "table",
+                Arc::new(MemTable::try_new(schema, vec![vec![record_batch]]).unwrap()),
+            )
+            .unwrap();
+
+        for (direction, expected) in [
+            (
+                crate::table_blueprint::SortDirection::Ascending,
+                vec![row_ids[0], row_ids[1], row_ids[2]],
+            ),
+            (
+                crate::table_blueprint::SortDirection::Descending,
+                vec![row_ids[2], row_ids[1], row_ids[0]],
+            ),
+        ] {
+            let query = DataFusionQuery::new(
+                session_ctx.clone(),
+                TableReference::bare("table"),
+                DataFusionQueryData {
+                    sort_by: Some(SortBy {
+                        column_physical_name: column_physical_name.clone(),
+                        direction,
+                    }),
+                    ..Default::default()
+                },
+            );
+
+            let (sorbet_batches, _) = query.execute().await.unwrap();
+            let sorted_row_ids = sorbet_batches
+                .iter()
+                .flat_map(|batch| RowId::from_arrow(batch.column(0)).unwrap())
+                .collect::<Vec<_>>();
+
+            // Row ids sort in the order they were created in.
+            assert_eq!(sorted_row_ids, expected);
+        }
+    }
+}
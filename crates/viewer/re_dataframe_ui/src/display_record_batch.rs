@@ -380,7 +380,17 @@ impl DisplayColumn {
                     return;
                 }
 
-                ui.label(row_ids[row_index].to_string());
+                let row_id = row_ids[row_index];
+                let timestamp = i64::try_from(row_id.nanos_since_epoch())
+                    .map(re_log_types::Timestamp::from_nanos_since_epoch)
+                    .map(|timestamp| timestamp.format(ctx.app_options().timestamp_format));
+
+                // Show the two halves of the TUID in a human-readable way, and the raw value on hover.
+                match timestamp {
+                    Ok(timestamp) => ui.label(format!("{timestamp} #{}", row_id.inc())),
+                    Err(_) => ui.label(row_id.to_string()),
+                }
+                .on_hover_text(row_id.to_string());
             }
             Self::Timeline {
                 timeline,
@@ -1,8 +1,9 @@
 use std::any::Any;
 
-use re_chunk_store::{ColumnDescriptor, SparseFillStrategy};
+use re_chunk_store::{ColumnDescriptor, IsNotNullMode, SparseFillStrategy};
 use re_dataframe::QueryEngine;
 use re_log_types::EntityPath;
+use re_types::blueprint::components::{FilterIsNotNullMode, LatestAtScope};
 use re_types_core::ViewClassIdentifier;
 use re_ui::{Help, UiExt as _};
 use re_viewer_context::{
@@ -135,7 +136,10 @@ Configure in the selection panel:
             .collect();
 
         let sparse_fill_strategy = if view_query.latest_at_enabled()? {
-            SparseFillStrategy::LatestAtGlobal
+            match view_query.latest_at_scope()? {
+                LatestAtScope::Global => SparseFillStrategy::LatestAtGlobal,
+                LatestAtScope::View => SparseFillStrategy::LatestAtView,
+            }
         } else {
             SparseFillStrategy::None
         };
@@ -145,6 +149,10 @@ Configure in the selection panel:
             filtered_index: Some(*timeline.name()),
             filtered_index_range: Some(view_query.filter_by_range()?),
             filtered_is_not_null: view_query.filter_is_not_null()?,
+            filtered_is_not_null_mode: match view_query.filter_is_not_null_mode()? {
+                FilterIsNotNullMode::Any => IsNotNullMode::Any,
+                FilterIsNotNullMode::All => IsNotNullMode::All,
+            },
             sparse_fill_strategy,
             selection: None,
 
@@ -156,9 +164,10 @@ Configure in the selection panel:
             include_static_columns: re_chunk_store::StaticColumnSelection::Both,
         };
 
-        let view_columns = query_engine
-            .schema_for_query(&dataframe_query)
-            .indices_and_components();
+        // The row id column is part of the view columns so that it can be toggled on, but it is only
+        // ever queried when explicitly selected.
+        let schema = query_engine.schema_for_query(&dataframe_query);
+        let view_columns = schema.row_id_indices_and_components();
         dataframe_query.selection =
             view_query.apply_column_visibility_to_view_columns(ctx, &view_columns)?;
 
@@ -87,23 +87,25 @@ impl Query {
         }
     }
 
-    /// Get the filter column for the filter-is-not-null feature, if active.
+    /// Get the filter columns for the filter-is-not-null feature, if active.
     pub fn filter_is_not_null(
         &self,
-    ) -> Result<Option<ComponentColumnSelector>, ViewSystemExecutionError> {
+    ) -> Result<Vec<ComponentColumnSelector>, ViewSystemExecutionError> {
         Ok(self
             .filter_is_not_null_raw()?
+            .into_iter()
             .filter(|filter_is_not_null| filter_is_not_null.active())
-            .map(|filter| filter.column_selector()))
+            .map(|filter| filter.column_selector())
+            .collect())
     }
 
-    /// Get the raw [`components::FilterIsNotNull`] struct (for ui purposes).
+    /// Get the raw [`components::FilterIsNotNull`] structs (for ui purposes).
     pub fn filter_is_not_null_raw(
         &self,
-    ) -> Result<Option<components::FilterIsNotNull>, ViewSystemExecutionError> {
+    ) -> Result<Vec<components::FilterIsNotNull>, ViewSystemExecutionError> {
         Ok(self
             .query_property
-            .component_or_empty::<components::FilterIsNotNull>(
+            .component_array_or_empty::<components::FilterIsNotNull>(
                 &DataframeQuery::descriptor_filter_is_not_null(),
             )?)
     }
@@ -111,12 +113,36 @@ impl Query {
     pub fn save_filter_is_not_null(
         &self,
         ctx: &ViewerContext<'_>,
-        filter_is_not_null: &components::FilterIsNotNull,
+        filter_is_not_null: &[components::FilterIsNotNull],
     ) {
         self.query_property.save_blueprint_component(
             ctx,
             &DataframeQuery::descriptor_filter_is_not_null(),
-            filter_is_not_null,
+            &filter_is_not_null.to_vec(),
+        );
+    }
+
+    /// How the filter columns of the filter-is-not-null feature are combined.
+    pub fn filter_is_not_null_mode(
+        &self,
+    ) -> Result<components::FilterIsNotNullMode, ViewSystemExecutionError> {
+        Ok(self
+            .query_property
+            .component_or_empty::<components::FilterIsNotNullMode>(
+                &DataframeQuery::descriptor_filter_is_not_null_mode(),
+            )?
+            .unwrap_or_default())
+    }
+
+    pub fn save_filter_is_not_null_mode(
+        &self,
+        ctx: &ViewerContext<'_>,
+        mode: components::FilterIsNotNullMode,
+    ) {
+        self.query_property.save_blueprint_component(
+            ctx,
+            &DataframeQuery::descriptor_filter_is_not_null_mode(),
+            &mode,
         );
     }
 
@@ -137,6 +163,23 @@ impl Query {
         );
     }
 
+    pub fn latest_at_scope(&self) -> Result<components::LatestAtScope, ViewSystemExecutionError> {
+        Ok(self
+            .query_property
+            .component_or_empty::<components::LatestAtScope>(
+                &DataframeQuery::descriptor_latest_at_scope(),
+            )?
+            .unwrap_or_default())
+    }
+
+    pub fn save_latest_at_scope(&self, ctx: &ViewerContext<'_>, scope: components::LatestAtScope) {
+        self.query_property.save_blueprint_component(
+            ctx,
+            &DataframeQuery::descriptor_latest_at_scope(),
+            &scope,
+        );
+    }
+
     pub fn save_selected_columns(
         &self,
         ctx: &ViewerContext<'_>,
@@ -146,7 +189,7 @@ impl Query {
         for column in columns {
             match column {
                 ColumnSelector::RowId => {
-                    // selected_columns.row_id = true.into(); // TODO(#9921)
+                    selected_columns.row_id = Some(true.into());
                 }
 
                 ColumnSelector::Time(desc) => {
@@ -208,7 +251,7 @@ impl Query {
 
         // no selected columns means all columns are visible
         let Some(datatypes::SelectedColumns {
-            // row_id, // TODO(#9921)
+            row_id,
             time_columns,
             component_columns,
         }) = selected_columns.as_deref()
@@ -230,7 +273,7 @@ impl Query {
         let result = view_columns
             .iter()
             .filter(|column| match column {
-                ColumnDescriptor::RowId(_) => false, // TODO(#9921)
+                ColumnDescriptor::RowId(_) => row_id.is_some_and(|row_id| *row_id),
 
                 ColumnDescriptor::Time(desc) => {
                     // we always include the query timeline column because we need it for the dataframe ui
@@ -248,6 +291,27 @@ impl Query {
         Ok(Some(result))
     }
 
+    /// Given some view columns, list the columns that are currently visible.
+    ///
+    /// Same as [`Self::apply_column_visibility_to_view_columns`], except that the default
+    /// selection is returned when the blueprint doesn't provide one: all columns but the row id.
+    pub fn visible_view_columns(
+        &self,
+        ctx: &ViewerContext<'_>,
+        view_columns: &[ColumnDescriptor],
+    ) -> Result<Vec<ColumnSelector>, ViewSystemExecutionError> {
+        Ok(self
+            .apply_column_visibility_to_view_columns(ctx, view_columns)?
+            .unwrap_or_else(|| {
+                view_columns
+                    .iter()
+                    .filter(|column| !matches!(column, ColumnDescriptor::RowId(_)))
+                    .cloned()
+                    .map(Into::into)
+                    .collect()
+            }))
+    }
+
     pub(crate) fn handle_hide_column_actions(
         &self,
         ctx: &ViewerContext<'_>,
@@ -258,10 +322,7 @@ impl Query {
             return Ok(());
         }
 
-        let mut selected_columns: Vec<_> = self
-            .apply_column_visibility_to_view_columns(ctx, view_columns)?
-            .map(|columns| columns.into_iter().collect())
-            .unwrap_or_else(|| view_columns.iter().cloned().map(Into::into).collect());
+        let mut selected_columns = self.visible_view_columns(ctx, view_columns)?;
 
         for action in actions {
             match action {
@@ -301,6 +362,10 @@ impl Query {
 #[cfg(test)]
 mod test {
     use super::Query;
+    use re_chunk_store::ColumnDescriptor;
+    use re_log_types::Timeline;
+    use re_sorbet::{ColumnSelector, IndexColumnDescriptor, RowIdColumnDescriptor};
+    use re_types::blueprint::components::{FilterIsNotNull, FilterIsNotNullMode, LatestAtScope};
     use re_viewer_context::ViewId;
     use re_viewer_context::test_context::TestContext;
 
@@ -322,4 +387,107 @@ mod test {
             assert!(query.latest_at_enabled().unwrap());
         });
     }
+
+    #[test]
+    fn test_filter_is_not_null() {
+        let mut test_context = TestContext::new();
+
+        let view_id = ViewId::random();
+
+        let filters = [
+            FilterIsNotNull::new(true, &"cam/left".into(), "EncodedImage:blob".to_owned()),
+            FilterIsNotNull::new(false, &"cam/right".into(), "EncodedImage:blob".to_owned()),
+            FilterIsNotNull::new(true, &"cam/center".into(), "EncodedImage:blob".to_owned()),
+        ];
+
+        test_context.run_in_egui_central_panel(|ctx, _| {
+            let query = Query::from_blueprint(ctx, view_id);
+            assert!(query.filter_is_not_null().unwrap().is_empty());
+            assert_eq!(
+                query.filter_is_not_null_mode().unwrap(),
+                FilterIsNotNullMode::Any
+            );
+            query.save_filter_is_not_null(ctx, &filters);
+            query.save_filter_is_not_null_mode(ctx, FilterIsNotNullMode::All);
+        });
+        test_context.handle_system_commands();
+
+        test_context.run_in_egui_central_panel(|ctx, _| {
+            let query = Query::from_blueprint(ctx, view_id);
+            assert_eq!(query.filter_is_not_null_raw().unwrap(), filters);
+            assert_eq!(
+                query.filter_is_not_null().unwrap(),
+                vec![filters[0].column_selector(), filters[2].column_selector()]
+            );
+            assert_eq!(
+                query.filter_is_not_null_mode().unwrap(),
+                FilterIsNotNullMode::All
+            );
+        });
+    }
+
+    #[test]
+    fn test_latest_at_scope() {
+        let mut test_context = TestContext::new();
+
+        let view_id = ViewId::random();
+
+        test_context.run_in_egui_central_panel(|ctx, _| {
+            let query = Query::from_blueprint(ctx, view_id);
+            assert_eq!(query.latest_at_scope().unwrap(), LatestAtScope::Global);
+            query.save_latest_at_scope(ctx, LatestAtScope::View);
+        });
+        test_context.handle_system_commands();
+
+        test_context.run_in_egui_central_panel(|ctx, _| {
+            let query = Query::from_blueprint(ctx, view_id);
+            assert_eq!(query.latest_at_scope().unwrap(), LatestAtScope::View);
+        });
+    }
+
+    #[test]
+    fn test_row_id_column_visibility() {
+        let mut test_context = TestContext::new();
+
+        let view_id = ViewId::random();
+
+        let timeline = Timeline::new_sequence("frame");
+        let view_columns = [
+            ColumnDescriptor::RowId(RowIdColumnDescriptor::from_sorted(false)),
+            ColumnDescriptor::Time(IndexColumnDescriptor::from(timeline)),
+        ];
+
+        test_context.run_in_egui_central_panel(|ctx, _| {
+            let query = Query::from_blueprint(ctx, view_id);
+            query.save_timeline_name(ctx, timeline.name());
+
+            // The row id column is hidden by default.
+            assert!(
+                query
+                    .apply_column_visibility_to_view_columns(ctx, &view_columns)
+                    .unwrap()
+                    .is_none()
+            );
+            assert_eq!(
+                query.visible_view_columns(ctx, &view_columns).unwrap(),
+                vec![ColumnSelector::from(view_columns[1].clone())]
+            );
+
+            query.save_selected_columns(ctx, [ColumnSelector::RowId]);
+        });
+        test_context.handle_system_commands();
+
+        test_context.run_in_egui_central_panel(|ctx, _| {
+            let query = Query::from_blueprint(ctx, view_id);
+
+            // The query timeline is always visible.
+            assert_eq!(
+                query.visible_view_columns(ctx, &view_columns).unwrap(),
+                vec![
+                    ColumnSelector::RowId,
+                    ColumnSelector::from(view_columns[1].clone())
+                ]
+            );
+        });
+    }
 }
@@ -1,12 +1,14 @@
 use crate::view_query::Query;
 use egui::PopupCloseBehavior;
 use egui::containers::menu::{MenuButton, MenuConfig};
+use itertools::Itertools as _;
 use re_chunk_store::ColumnDescriptor;
 use re_log_types::{
     EntityPath, ResolvedTimeRange, TimeInt, TimeType, Timeline, TimelineName, TimestampFormat,
 };
-use re_sorbet::ColumnSelector;
+use re_sorbet::{ColumnSelector, ComponentColumnSelector};
 use re_types::blueprint::components;
+use re_types_core::{ComponentDescriptorSet, ComponentIdentifier};
 use re_ui::{TimeDragValue, UiExt as _, list_item};
 use re_viewer_context::{ViewId, ViewSystemExecutionError, ViewerContext};
 use std::collections::{BTreeSet, HashSet};
@@ -168,21 +170,29 @@ impl Query {
         // Read stuff
         //
 
-        let original_filter_is_not_null = self.filter_is_not_null_raw()?;
+        let original_filters = self.filter_is_not_null_raw()?;
+        let original_mode = self.filter_is_not_null_mode()?;
 
-        let (mut active, filter) = original_filter_is_not_null
-            .as_ref()
-            .map(|filter| (filter.active(), Some(filter.column_selector())))
-            .unwrap_or((false, None));
+        let mut active = original_filters.iter().any(|filter| filter.active());
+        let mut filters = original_filters
+            .iter()
+            .map(|filter| Some(filter.column_selector()))
+            .collect_vec();
+        if filters.is_empty() {
+            filters.push(None);
+        }
+        let mut mode = original_mode;
 
         //
         // Filter active?
         //
 
-        let before_active = active;
+        let mut changed = false;
         ui.add_enabled_ui(timeline.is_some(), |ui| {
-            ui.re_checkbox(&mut active, "Filter rows where column is not null:")
-                .on_disabled_hover_text("Select an existing timeline to edit this property");
+            changed |= ui
+                .re_checkbox(&mut active, "Filter rows where column is not null:")
+                .on_disabled_hover_text("Select an existing timeline to edit this property")
+                .changed();
         });
 
         //
@@ -193,118 +203,126 @@ impl Query {
             ui.add_enabled_ui(false, |ui| {
                 ui.spacing_mut().item_spacing.y = 0.0;
 
-                ui.list_item_flat_noninteractive(
-                    list_item::PropertyContent::new("Entity").value_text(
-                        filter
-                            .as_ref()
-                            .map(|f| f.entity_path.clone())
-                            .unwrap_or_else(EntityPath::root)
-                            .to_string(),
-                    ),
-                )
-                .on_disabled_hover_text("Select an existing timeline to edit this property");
+                for filter in &filters {
+                    ui.list_item_flat_noninteractive(
+                        list_item::PropertyContent::new("Entity").value_text(
+                            filter
+                                .as_ref()
+                                .map(|f| f.entity_path.clone())
+                                .unwrap_or_else(EntityPath::root)
+                                .to_string(),
+                        ),
+                    )
+                    .on_disabled_hover_text("Select an existing timeline to edit this property");
 
-                ui.list_item_flat_noninteractive(
-                    list_item::PropertyContent::new("Component")
-                        .value_text(filter.as_ref().map(|f| f.component.as_str()).unwrap_or("-")),
-                )
-                .on_disabled_hover_text("Select an existing timeline to edit this property");
+                    ui.list_item_flat_noninteractive(
+                        list_item::PropertyContent::new("Component").value_text(
+                            filter.as_ref().map(|f| f.component.as_str()).unwrap_or("-"),
+                        ),
+                    )
+                    .on_disabled_hover_text("Select an existing timeline to edit this property");
+                }
             });
 
             return Ok(());
         };
 
         //
-        // Filter entity
+        // UI for filter entities and components
         //
 
         let all_entities = all_pov_entities_for_view(ctx, view_id, timeline);
 
-        let mut filter_entity = filter
-            .as_ref()
-            .and_then(|filter| {
-                all_entities
-                    .contains(&filter.entity_path)
-                    .then_some(filter.entity_path.clone())
-            })
-            .or_else(|| all_entities.iter().next().cloned())
-            .unwrap_or_else(EntityPath::root);
+        let removable = filters.len() > 1;
+        let mut removed = None;
+        let mut added = false;
+        let columns = ui
+            .add_enabled_ui(active, |ui| {
+                ui.spacing_mut().item_spacing.y = 0.0;
 
-        //
-        // Filter component
-        //
+                let columns = filters
+                    .iter()
+                    .enumerate()
+                    .map(|(index, filter)| {
+                        let (column, column_changed, remove) = filter_is_not_null_column_ui(
+                            ctx,
+                            ui,
+                            timeline,
+                            &all_entities,
+                            index,
+                            filter.as_ref(),
+                            removable,
+                        );
+                        changed |= column_changed;
+                        if remove {
+                            removed = Some(index);
+                        }
+                        column
+                    })
+                    .collect_vec();
 
-        let all_components = ctx
-            .recording_engine()
-            .store()
-            .all_components_on_timeline_sorted(timeline, &filter_entity)
-            .unwrap_or_default();
+                ui.list_item_flat_noninteractive(
+                    list_item::PropertyContent::new("Add column").action_button(
+                        &re_ui::icons::ADD,
+                        "Add column",
+                        || added = true,
+                    ),
+                );
 
-        // If the currently saved component, we auto-switch it to a reasonable one.
-        let mut filter_component = filter
-            .and_then(|component_sel| {
-                all_components
-                    .iter()
-                    .any(|descr| descr.component.as_str() == component_sel.component)
-                    .then_some(component_sel.component.into())
+                columns
             })
-            .or_else(|| all_components.iter().map(|descr| descr.component).next());
+            .inner;
 
         //
-        // UI for filter entity and component
+        // Mode
         //
 
-        let before_filter_entity = filter_entity.clone();
-        let before_filter_component = filter_component;
-        ui.add_enabled_ui(active, |ui| {
-            ui.spacing_mut().item_spacing.y = 0.0;
-
-            ui.list_item_flat_noninteractive(list_item::PropertyContent::new("Entity").value_fn(
-                |ui, _| {
-                    egui::ComboBox::new("pov_entity", "")
-                        .selected_text(filter_entity.to_string())
-                        .show_ui(ui, |ui| {
-                            for entity in all_entities {
-                                let label = entity.to_string();
-                                ui.selectable_value(&mut filter_entity, entity, label);
-                            }
-                        });
-                },
-            ));
-
-            ui.list_item_flat_noninteractive(
-                list_item::PropertyContent::new("Component").value_fn(|ui, _| {
-                    egui::ComboBox::new("pov_component", "")
-                        .selected_text(filter_component.map_or("-", |c| c.as_str()))
-                        .show_ui(ui, |ui| {
-                            for descr in all_components {
-                                ui.selectable_value(
-                                    &mut filter_component,
-                                    Some(descr.component),
-                                    descr.component.as_str(),
-                                );
-                            }
-                        });
-                }),
-            );
-        });
+        if filters.len() > 1 {
+            ui.add_enabled_ui(active, |ui| {
+                changed |= ui
+                    .re_radio_value(
+                        &mut mode,
+                        components::FilterIsNotNullMode::Any,
+                        "Keep rows where any column is not null",
+                    )
+                    .changed();
+                changed |= ui
+                    .re_radio_value(
+                        &mut mode,
+                        components::FilterIsNotNullMode::All,
+                        "Keep rows where all columns are not null",
+                    )
+                    .changed();
+            });
+        }
 
         // Save filter if changed
-        if before_active != active
-            || before_filter_entity != filter_entity
-            || before_filter_component != filter_component
-        {
-            // Filters out the placeholder component.
-            if let Some(filter_component) = filter_component {
-                let filter_is_not_null = components::FilterIsNotNull::new(
-                    active,
-                    &filter_entity,
-                    filter_component.to_string(),
-                );
+        if changed || added || removed.is_some() {
+            let mut columns = columns;
+            if let Some(removed) = removed {
+                columns.remove(removed);
+            }
+            if added {
+                let (entity_path, _, component) =
+                    resolve_filter_is_not_null_column(ctx, timeline, &all_entities, None);
+                columns.push(component.map(|component| (entity_path, component)));
+            }
 
-                if original_filter_is_not_null.as_ref() != Some(&filter_is_not_null) {
-                    self.save_filter_is_not_null(ctx, &filter_is_not_null);
-                }
+            // Filters out the placeholder components.
+            let filter_is_not_null = columns
+                .into_iter()
+                .flatten()
+                .map(|(entity_path, component)| {
+                    components::FilterIsNotNull::new(active, &entity_path, component.to_string())
+                })
+                .collect_vec();
+
+            if !filter_is_not_null.is_empty() && original_filters != filter_is_not_null {
+                self.save_filter_is_not_null(ctx, &filter_is_not_null);
+            }
+
+            if original_mode != mode {
+                self.save_filter_is_not_null_mode(ctx, mode);
             }
         }
 
@@ -335,12 +353,19 @@ impl Query {
     ) -> Result<(), ViewSystemExecutionError> {
         // Gather our selected columns.
         let selected_columns: HashSet<_> = self
-            .apply_column_visibility_to_view_columns(ctx, view_columns)?
-            .map(|columns| columns.into_iter().collect())
-            .unwrap_or_else(|| view_columns.iter().cloned().map(Into::into).collect());
-
-        let visible_count = selected_columns.len();
-        let hidden_count = view_columns.len() - visible_count;
+            .visible_view_columns(ctx, view_columns)?
+            .into_iter()
+            .collect();
+
+        // The row id column has its own toggle and isn't accounted for in the summary.
+        let has_row_id = view_columns
+            .iter()
+            .any(|column| matches!(column, ColumnDescriptor::RowId(_)));
+        let show_row_id = selected_columns.contains(&ColumnSelector::RowId);
+        let column_count = view_columns.len() - usize::from(has_row_id);
+
+        let visible_count = selected_columns.len() - usize::from(show_row_id);
+        let hidden_count = column_count - visible_count;
         let visible_count_label = format!("{visible_count} visible, {hidden_count} hidden");
 
         let mut new_selected_columns = selected_columns.clone();
@@ -357,23 +382,26 @@ impl Query {
                 .checkbox_indeterminate(&mut all_enabled, &visible_count_label, indeterminate)
                 .changed()
             {
-                if all_enabled {
-                    self.save_all_columns_selected(ctx);
-                } else {
-                    self.save_all_columns_unselected(ctx);
+                match (all_enabled, show_row_id) {
+                    (true, false) => self.save_all_columns_selected(ctx),
+                    (true, true) => self
+                        .save_selected_columns(ctx, view_columns.iter().cloned().map(Into::into)),
+                    (false, false) => self.save_all_columns_unselected(ctx),
+                    (false, true) => self.save_selected_columns(ctx, [ColumnSelector::RowId]),
                 }
             }
 
             ui.add_space(12.0);
 
-            // TODO(#9921): add support for showing Row ID column
-            if false {
-                let mut show_row_id = view_columns
-                    .iter()
-                    .any(|d| matches!(d, ColumnDescriptor::RowId(_)));
+            //
+            // Row ID column
+            //
+
+            if has_row_id {
+                let mut show_row_id = show_row_id;
                 if ui
-                    .re_checkbox(&mut show_row_id, "RowID")
-                    .on_disabled_hover_text("The query timeline must always be visible")
+                    .re_checkbox(&mut show_row_id, "Row ID")
+                    .on_hover_text("The unique ID of the most recent row at each index value")
                     .changed()
                 {
                     if show_row_id {
@@ -473,7 +501,9 @@ impl Query {
 
         // save changes of column visibility
         if new_selected_columns != selected_columns {
-            if new_selected_columns.len() == view_columns.len() {
+            if !new_selected_columns.contains(&ColumnSelector::RowId)
+                && new_selected_columns.len() == column_count
+            {
                 // length match is a guaranteed match because the `selected_columns` sets are built
                 // from filtering out the view columns
                 self.save_all_columns_selected(ctx);
@@ -517,6 +547,39 @@ impl Query {
             self.save_latest_at_enabled(ctx, latest_at);
         }
 
+        let mut scope = self.latest_at_scope()?;
+        let changed = ui
+            .add_enabled_ui(latest_at, |ui| {
+                ui.indent("latest_at_scope", |ui| {
+                    let global = ui
+                        .re_radio_value(
+                            &mut scope,
+                            components::LatestAtScope::Global,
+                            "From entire recording",
+                        )
+                        .on_hover_text(
+                            "Cells may be filled with values from outside the view's range",
+                        );
+                    let view = ui
+                        .re_radio_value(
+                            &mut scope,
+                            components::LatestAtScope::View,
+                            "From view only",
+                        )
+                        .on_hover_text(
+                            "Cells are only filled with values from within the view's range",
+                        );
+
+                    global.changed() || view.changed()
+                })
+                .inner
+            })
+            .inner;
+
+        if changed {
+            self.save_latest_at_scope(ctx, scope);
+        }
+
         Ok(())
     }
 }
@@ -548,6 +611,110 @@ fn all_pov_entities_for_view(
     all_entities
 }
 
+/// Resolves a (possibly stale or missing) filter column against the current view contents.
+///
+/// Returns the entity, all its components on the timeline, and the component. If the saved entity
+/// or component doesn't exist anymore, we auto-switch it to a reasonable one.
+fn resolve_filter_is_not_null_column(
+    ctx: &ViewerContext<'_>,
+    timeline: &TimelineName,
+    all_entities: &BTreeSet<EntityPath>,
+    filter: Option<&ComponentColumnSelector>,
+) -> (
+    EntityPath,
+    ComponentDescriptorSet,
+    Option<ComponentIdentifier>,
+) {
+    let filter_entity = filter
+        .and_then(|filter| {
+            all_entities
+                .contains(&filter.entity_path)
+                .then_some(filter.entity_path.clone())
+        })
+        .or_else(|| all_entities.iter().next().cloned())
+        .unwrap_or_else(EntityPath::root);
+
+    let all_components = ctx
+        .recording_engine()
+        .store()
+        .all_components_on_timeline_sorted(timeline, &filter_entity)
+        .unwrap_or_default();
+
+    let filter_component = filter
+        .and_then(|component_sel| {
+            all_components
+                .iter()
+                .any(|descr| descr.component.as_str() == component_sel.component)
+                .then_some(component_sel.component.as_str().into())
+        })
+        .or_else(|| all_components.iter().map(|descr| descr.component).next());
+
+    (filter_entity, all_components, filter_component)
+}
+
+/// UI for a single column of the filter-is-not-null feature.
+///
+/// Returns the selected column (`None` for the placeholder component), whether it was changed,
+/// and whether it should be removed.
+fn filter_is_not_null_column_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    timeline: &TimelineName,
+    all_entities: &BTreeSet<EntityPath>,
+    index: usize,
+    filter: Option<&ComponentColumnSelector>,
+    removable: bool,
+) -> (Option<(EntityPath, ComponentIdentifier)>, bool, bool) {
+    let (mut filter_entity, all_components, mut filter_component) =
+        resolve_filter_is_not_null_column(ctx, timeline, all_entities, filter);
+
+    let before_filter_entity = filter_entity.clone();
+    let before_filter_component = filter_component;
+    let mut remove = false;
+
+    ui.list_item_flat_noninteractive(
+        list_item::PropertyContent::new("Entity")
+            .action_button_with_enabled(&re_ui::icons::REMOVE, "Remove column", removable, || {
+                remove = true;
+            })
+            .value_fn(|ui, _| {
+                egui::ComboBox::new(("pov_entity", index), "")
+                    .selected_text(filter_entity.to_string())
+                    .show_ui(ui, |ui| {
+                        for entity in all_entities {
+                            let label = entity.to_string();
+                            ui.selectable_value(&mut filter_entity, entity.clone(), label);
+                        }
+                    });
+            }),
+    );
+
+    ui.list_item_flat_noninteractive(list_item::PropertyContent::new("Component").value_fn(
+        |ui, _| {
+            egui::ComboBox::new(("pov_component", index), "")
+                .selected_text(filter_component.map_or("-", |c| c.as_str()))
+                .show_ui(ui, |ui| {
+                    for descr in all_components {
+                        ui.selectable_value(
+                            &mut filter_component,
+                            Some(descr.component),
+                            descr.component.as_str(),
+                        );
+                    }
+                });
+        },
+    ));
+
+    let changed =
+        before_filter_entity != filter_entity || before_filter_component != filter_component;
+
+    (
+        filter_component.map(|component| (filter_entity, component)),
+        changed,
+        remove,
+    )
+}
+
 fn time_boundary_ui(
     ui: &mut egui::Ui,
     time_drag_value: &TimeDragValue,
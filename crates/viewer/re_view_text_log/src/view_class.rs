@@ -1,20 +1,31 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
+use egui::ahash::HashMap;
 use re_data_ui::item_ui;
-use re_log_types::{EntityPath, TimelineName};
+
+use re_log_types::{EntityPath, EntityPathHash, TimelineName};
 use re_types::View as _;
-use re_types::{ViewClassIdentifier, components::TextLogLevel};
-use re_ui::{DesignTokens, Help, UiExt as _};
+use re_types::{
+    DeserializationError, ViewClassIdentifier,
+    blueprint::{
+        archetypes::{TextLogColumns, TextLogFilter},
+        components::{TextFilterMode, TextLogColumn},
+    },
+    components::{Text, TextLogLevel},
+};
+use re_ui::{DesignTokens, Help, UiExt as _, icons, list_item};
+use re_view::view_property_ui;
 use re_viewer_context::{
-    IdentifiedViewSystem as _, ViewClass, ViewClassRegistryError, ViewId, ViewQuery,
-    ViewSpawnHeuristics, ViewState, ViewStateExt as _, ViewSystemExecutionError, ViewerContext,
-    level_to_rich_text,
+    IdentifiedViewSystem as _, ViewClass, ViewClassExt as _, ViewClassRegistryError, ViewContext,
+    ViewId, ViewQuery, ViewSpawnHeuristics, ViewState, ViewStateExt as _, ViewSystemExecutionError,
+    ViewerContext, level_to_rich_text,
 };
+use re_viewport_blueprint::{ViewProperty, ViewPropertyQueryError};
 
 use super::visualizer_system::{Entry, TextLogSystem};
 
 // TODO(andreas): This should be a blueprint component.
-#[derive(Clone, PartialEq, Eq, Default)]
+#[derive(Clone, Default)]
 pub struct TextViewState {
     /// Keeps track of the latest time selection made by the user.
     ///
@@ -24,7 +35,13 @@ pub struct TextViewState {
 
     pub filters: ViewTextFilters,
 
+    /// Keys of all the structured fields seen so far, each of which gets its own column.
+    field_keys: BTreeSet<String>,
+
     monospace: bool,
+
+    /// Compiled from the [`TextLogFilter`] view property, and only recompiled when it changes.
+    entry_filter: EntryFilter,
 }
 
 impl ViewState for TextViewState {
@@ -106,35 +123,31 @@ Filter message types and toggle column visibility in a selection panel.",
 
     fn selection_ui(
         &self,
-        _ctx: &ViewerContext<'_>,
+        ctx: &ViewerContext<'_>,
         ui: &mut egui::Ui,
         state: &mut dyn ViewState,
         _space_origin: &EntityPath,
-        _view_id: ViewId,
+        view_id: ViewId,
     ) -> Result<(), ViewSystemExecutionError> {
         let state = state.downcast_mut::<TextViewState>()?;
 
-        let ViewTextFilters {
-            col_timelines,
-            col_entity_path,
-            col_log_level,
-            row_log_levels,
-        } = &mut state.filters;
+        let columns_property = ViewProperty::from_archetype::<TextLogColumns>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query,
+            view_id,
+        );
+        let columns = LogColumns::from_property(ctx, &columns_property, &state.field_keys)?;
 
         ui.selection_grid("log_config").show(ui, |ui| {
             ui.grid_left_hand_label("Columns");
             ui.vertical(|ui| {
-                for (timeline, visible) in col_timelines {
-                    ui.re_checkbox(visible, timeline.to_string());
-                }
-                ui.re_checkbox(col_entity_path, "Entity path");
-                ui.re_checkbox(col_log_level, "Log level");
+                columns_ui(ctx, ui, &columns_property, columns);
             });
             ui.end_row();
 
             ui.grid_left_hand_label("Level Filter");
             ui.vertical(|ui| {
-                for (log_level, visible) in row_log_levels {
+                for (log_level, visible) in &mut state.filters.row_log_levels {
                     ui.re_checkbox(visible, level_to_rich_text(ui, log_level));
                 }
             });
@@ -148,6 +161,11 @@ Filter message types and toggle column visibility in a selection panel.",
             ui.end_row();
         });
 
+        list_item::list_item_scope(ui, "text_log_selection_ui", |ui| {
+            let ctx = self.view_context(ctx, view_id, state);
+            view_property_ui::<TextLogFilter>(&ctx, ui, self);
+        });
+
         Ok(())
     }
 
@@ -157,15 +175,45 @@ Filter message types and toggle column visibility in a selection panel.",
         ui: &mut egui::Ui,
         state: &mut dyn ViewState,
 
-        _query: &ViewQuery<'_>,
+        query: &ViewQuery<'_>,
         system_output: re_viewer_context::SystemExecutionOutput,
     ) -> Result<(), ViewSystemExecutionError> {
         re_tracing::profile_function!();
 
         let tokens = ui.tokens();
+
+        let filter_patterns = {
+            let view_ctx = self.view_context(ctx, query.view_id, state);
+            let filter_property = ViewProperty::from_archetype::<TextLogFilter>(
+                ctx.blueprint_db(),
+                ctx.blueprint_query,
+                query.view_id,
+            );
+            EntryFilterPatterns::from_property(&view_ctx, self, &filter_property)?
+        };
+
         let state = state.downcast_mut::<TextViewState>()?;
+        state.entry_filter.update(filter_patterns);
         let text = system_output.view_systems.get::<TextLogSystem>()?;
 
+        for entry in &text.entries {
+            for field in &entry.fields {
+                if !state.field_keys.contains(field.key()) {
+                    state.field_keys.insert(field.key().to_owned());
+                }
+            }
+        }
+
+        let columns = {
+            let columns_property = ViewProperty::from_archetype::<TextLogColumns>(
+                ctx.blueprint_db(),
+                ctx.blueprint_query,
+                query.view_id,
+            );
+            LogColumns::from_property(ctx, &columns_property, &state.field_keys)?
+        };
+        let columns = columns.visible().collect::<Vec<_>>();
+
         // TODO(andreas): Should filter text entries in the part-system instead.
         // this likely requires a way to pass state into a context.
         let entries = text
@@ -175,6 +223,7 @@ Filter message types and toggle column visibility in a selection panel.",
                 te.level
                     .as_ref()
                     .is_none_or(|lvl| state.filters.is_log_level_visible(lvl))
+                    && state.entry_filter.matches(te)
             })
             .collect::<Vec<_>>();
 
@@ -184,7 +233,7 @@ Filter message types and toggle column visibility in a selection panel.",
         }
         .show(ui, |ui| {
             // Update filters if necessary.
-            state.filters.update(ctx, &entries);
+            state.filters.update(&entries);
 
             let time = ctx
                 .rec_cfg
@@ -204,10 +253,14 @@ Filter message types and toggle column visibility in a selection panel.",
 
             state.latest_time = time;
 
+            if state.entry_filter.is_active() || !state.entry_filter.errors.is_empty() {
+                filter_matches_ui(ctx, ui, &state.entry_filter, &entries, time);
+            }
+
             ui.with_layout(egui::Layout::top_down(egui::Align::Center), |ui| {
                 egui::ScrollArea::horizontal().show(ui, |ui| {
                     re_tracing::profile_scope!("render table");
-                    table_ui(ctx, ui, state, &entries, scroll_to_row);
+                    table_ui(ctx, ui, state, &columns, &entries, scroll_to_row);
                 })
             });
         });
@@ -218,31 +271,13 @@ Filter message types and toggle column visibility in a selection panel.",
 
 // --- Filters ---
 
-// TODO(cmc): implement "body contains <value>" filter.
-// TODO(cmc): beyond filters, it'd be nice to be able to swap columns at some point.
-#[derive(Debug, Clone, PartialEq, Eq)]
+// Columns are configured through the `TextLogColumns` view property instead, see [`LogColumns`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct ViewTextFilters {
-    // Column filters: which columns should be visible?
-    // Timelines are special: each one has a dedicated column.
-    pub col_timelines: BTreeMap<TimelineName, bool>,
-    pub col_entity_path: bool,
-    pub col_log_level: bool,
-
     // Row filters: which rows should be visible?
     pub row_log_levels: BTreeMap<TextLogLevel, bool>,
 }
 
-impl Default for ViewTextFilters {
-    fn default() -> Self {
-        Self {
-            col_entity_path: true,
-            col_log_level: true,
-            col_timelines: Default::default(),
-            row_log_levels: Default::default(),
-        }
-    }
-}
-
 impl ViewTextFilters {
     pub fn is_log_level_visible(&self, level: &str) -> bool {
         self.row_log_levels.get(level).copied().unwrap_or(true)
@@ -250,26 +285,416 @@ impl ViewTextFilters {
 
     // Checks whether new values are available for any of the filters, and updates everything
     // accordingly.
-    fn update(&mut self, ctx: &ViewerContext<'_>, entries: &[&Entry]) {
+    fn update(&mut self, entries: &[&Entry]) {
         re_tracing::profile_function!();
 
+        let Self { row_log_levels } = self;
+
+        for level in entries.iter().filter_map(|te| te.level.as_ref()) {
+            row_log_levels.entry(level.clone()).or_insert(true);
+        }
+    }
+}
+
+// --- Columns ---
+
+/// A column of the text log table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LogColumn {
+    Timeline(TimelineName),
+    EntityPath,
+    Level,
+    Body,
+    Field(String),
+}
+
+impl LogColumn {
+    /// Parses the identifier used by [`TextLogColumn`].
+    fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "entity_path" => Some(Self::EntityPath),
+            "level" => Some(Self::Level),
+            "body" => Some(Self::Body),
+            _ => {
+                if let Some(timeline) = id.strip_prefix("timeline:") {
+                    Some(Self::Timeline(TimelineName::new(timeline)))
+                } else {
+                    id.strip_prefix("field:")
+                        .map(|key| Self::Field(key.to_owned()))
+                }
+            }
+        }
+    }
+
+    /// The identifier used by [`TextLogColumn`].
+    fn id(&self) -> String {
+        match self {
+            Self::Timeline(timeline) => format!("timeline:{timeline}"),
+            Self::EntityPath => "entity_path".to_owned(),
+            Self::Level => "level".to_owned(),
+            Self::Body => "body".to_owned(),
+            Self::Field(key) => format!("field:{key}"),
+        }
+    }
+
+    fn label(&self) -> String {
+        match self {
+            Self::Timeline(timeline) => timeline.to_string(),
+            Self::EntityPath => "Entity path".to_owned(),
+            Self::Level => "Level".to_owned(),
+            Self::Body => "Body".to_owned(),
+            Self::Field(key) => key.clone(),
+        }
+    }
+}
+
+/// All available columns in display order, and whether they are visible, as configured by the
+/// [`TextLogColumns`] view property.
+struct LogColumns {
+    columns: Vec<(LogColumn, bool)>,
+}
+
+impl LogColumns {
+    fn from_property(
+        ctx: &ViewerContext<'_>,
+        property: &ViewProperty,
+        field_keys: &BTreeSet<String>,
+    ) -> Result<Self, DeserializationError> {
+        let order = property
+            .component_array_or_empty::<TextLogColumn>(&TextLogColumns::descriptor_order())?;
+        let hidden = property
+            .component_array_or_empty::<TextLogColumn>(&TextLogColumns::descriptor_hidden())?;
+
+        // Timelines are special: each one has a dedicated column.
+        let available = ctx
+            .recording()
+            .timelines()
+            .into_keys()
+            .map(LogColumn::Timeline)
+            .chain([LogColumn::EntityPath, LogColumn::Level, LogColumn::Body])
+            .chain(field_keys.iter().cloned().map(LogColumn::Field));
+
+        Ok(Self::new(available, &order, &hidden))
+    }
+
+    /// Columns listed in `order` come first, followed by the remaining ones in their default order.
+    ///
+    /// Unknown or unavailable columns are ignored.
+    fn new(
+        available: impl IntoIterator<Item = LogColumn>,
+        order: &[TextLogColumn],
+        hidden: &[TextLogColumn],
+    ) -> Self {
+        let mut available = available.into_iter().collect::<Vec<_>>();
+
+        let mut columns = Vec::with_capacity(available.len());
+        for column in order
+            .iter()
+            .filter_map(|id| LogColumn::from_id(id.as_str()))
+        {
+            if let Some(index) = available.iter().position(|c| c == &column) {
+                columns.push(available.remove(index));
+            }
+        }
+        columns.extend(available);
+
+        let hidden = hidden
+            .iter()
+            .filter_map(|id| LogColumn::from_id(id.as_str()))
+            .collect::<Vec<_>>();
+
+        Self {
+            columns: columns
+                .into_iter()
+                .map(|column| {
+                    let visible = !hidden.contains(&column);
+                    (column, visible)
+                })
+                .collect(),
+        }
+    }
+
+    fn visible(&self) -> impl Iterator<Item = &LogColumn> {
+        self.columns
+            .iter()
+            .filter_map(|(column, visible)| visible.then_some(column))
+    }
+
+    fn save(&self, ctx: &ViewerContext<'_>, property: &ViewProperty) {
+        let order = self
+            .columns
+            .iter()
+            .map(|(column, _)| TextLogColumn::from(column.id()))
+            .collect::<Vec<_>>();
+        let hidden = self
+            .columns
+            .iter()
+            .filter(|(_, visible)| !visible)
+            .map(|(column, _)| TextLogColumn::from(column.id()))
+            .collect::<Vec<_>>();
+
+        property.save_blueprint_component(ctx, &TextLogColumns::descriptor_order(), &order);
+        property.save_blueprint_component(ctx, &TextLogColumns::descriptor_hidden(), &hidden);
+    }
+}
+
+/// Lets the user show, hide, and reorder columns, saving any change to the blueprint.
+fn columns_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    property: &ViewProperty,
+    mut columns: LogColumns,
+) {
+    let num_columns = columns.columns.len();
+    let mut changed = false;
+    let mut swap_with_next = None;
+
+    for (index, (column, visible)) in columns.columns.iter_mut().enumerate() {
+        ui.horizontal(|ui| {
+            changed |= ui.re_checkbox(visible, column.label()).changed();
+
+            let up_button = ui.small_icon_button_widget(&icons::ARROW_UP, "Move left");
+            if ui
+                .add_enabled(0 < index, up_button)
+                .on_hover_text("Move this column to the left")
+                .clicked()
+            {
+                swap_with_next = Some(index - 1);
+            }
+
+            let down_button = ui.small_icon_button_widget(&icons::ARROW_DOWN, "Move right");
+            if ui
+                .add_enabled(index + 1 < num_columns, down_button)
+                .on_hover_text("Move this column to the right")
+                .clicked()
+            {
+                swap_with_next = Some(index);
+            }
+        });
+    }
+
+    if let Some(index) = swap_with_next {
+        columns.columns.swap(index, index + 1);
+        changed = true;
+    }
+
+    if changed {
+        columns.save(ctx, property);
+    }
+}
+
+// --- Entry filter ---
+
+/// The patterns of the [`TextLogFilter`] view property.
+#[derive(Clone, PartialEq)]
+struct EntryFilterPatterns {
+    body: Text,
+    entity_path: Text,
+    mode: TextFilterMode,
+}
+
+impl EntryFilterPatterns {
+    fn from_property(
+        ctx: &ViewContext<'_>,
+        view: &TextView,
+        property: &ViewProperty,
+    ) -> Result<Self, ViewPropertyQueryError> {
+        Ok(Self {
+            body: property.component_or_fallback::<Text>(
+                ctx,
+                view,
+                &TextLogFilter::descriptor_body(),
+            )?,
+            entity_path: property.component_or_fallback::<Text>(
+                ctx,
+                view,
+                &TextLogFilter::descriptor_entity_path(),
+            )?,
+            mode: property.component_or_fallback::<TextFilterMode>(
+                ctx,
+                view,
+                &TextLogFilter::descriptor_mode(),
+            )?,
+        })
+    }
+}
+
+/// Filters entries by their body and entity path, as configured by the [`TextLogFilter`] view property.
+#[derive(Clone, Default)]
+struct EntryFilter {
+    /// The patterns this filter was compiled from.
+    patterns: Option<EntryFilterPatterns>,
+
+    body: Option<TextMatcher>,
+    entity_path: Option<TextMatcher>,
+
+    /// Whether each entity path seen so far matches [`Self::entity_path`].
+    ///
+    /// There are usually few distinct entity paths, so this saves formatting them for every entry.
+    entity_path_matches: HashMap<EntityPathHash, bool>,
+
+    /// Filters that couldn't be parsed and are therefore ignored.
+    errors: Vec<String>,
+}
+
+impl EntryFilter {
+    fn new(body: &str, entity_path: &str, mode: TextFilterMode) -> Self {
+        let mut errors = Vec::new();
+
+        let mut matcher = |name: &str, pattern: &str| {
+            TextMatcher::new(pattern, mode).unwrap_or_else(|err| {
+                errors.push(format!("Invalid {name} filter: {err}"));
+                None
+            })
+        };
+        let body = matcher("body", body);
+        let entity_path = matcher("entity path", entity_path);
+
+        Self {
+            patterns: None,
+            body,
+            entity_path,
+            entity_path_matches: Default::default(),
+            errors,
+        }
+    }
+
+    /// Recompiles the filter if the patterns changed.
+    fn update(&mut self, patterns: EntryFilterPatterns) {
+        if self.patterns.as_ref() != Some(&patterns) {
+            *self = Self::new(
+                patterns.body.as_str(),
+                patterns.entity_path.as_str(),
+                patterns.mode,
+            );
+            self.patterns = Some(patterns);
+        }
+    }
+
+    /// Whether any entries may be filtered out.
+    fn is_active(&self) -> bool {
+        self.body.is_some() || self.entity_path.is_some()
+    }
+
+    fn matches(&mut self, entry: &Entry) -> bool {
+        self.matches_parts(&entry.body, &entry.entity_path)
+    }
+
+    fn matches_parts(&mut self, body: &str, entity_path: &EntityPath) -> bool {
         let Self {
-            col_timelines,
-            col_entity_path: _,
-            col_log_level: _,
-            row_log_levels,
+            body: body_matcher,
+            entity_path: entity_path_matcher,
+            entity_path_matches,
+            ..
         } = self;
 
-        for &timeline in ctx.recording().timelines().keys() {
-            col_timelines.entry(timeline).or_insert(true);
+        body_matcher.as_ref().is_none_or(|m| m.is_match(body))
+            && entity_path_matcher.as_ref().is_none_or(|m| {
+                *entity_path_matches
+                    .entry(entity_path.hash())
+                    .or_insert_with(|| m.is_match(&entity_path.to_string()))
+            })
+    }
+}
+
+#[derive(Clone)]
+enum TextMatcher {
+    /// Lowercase needle, matched case-insensitively.
+    Substring(String),
+    Regex(regex::Regex),
+}
+
+impl TextMatcher {
+    /// Returns `None` if the pattern is empty, i.e. nothing should be filtered.
+    fn new(pattern: &str, mode: TextFilterMode) -> Result<Option<Self>, regex::Error> {
+        if pattern.is_empty() {
+            return Ok(None);
         }
 
-        for level in entries.iter().filter_map(|te| te.level.as_ref()) {
-            row_log_levels.entry(level.clone()).or_insert(true);
+        Ok(Some(match mode {
+            TextFilterMode::Substring => Self::Substring(pattern.to_lowercase()),
+            TextFilterMode::Regex => Self::Regex(regex::Regex::new(pattern)?),
+        }))
+    }
+
+    fn is_match(&self, text: &str) -> bool {
+        match self {
+            Self::Substring(needle) => contains_lowercase(text, needle),
+            Self::Regex(regex) => regex.is_match(text),
         }
     }
 }
 
+/// Case-insensitive [`str::contains`], without allocating.
+///
+/// `needle` must already be lowercase.
+fn contains_lowercase(haystack: &str, needle: &str) -> bool {
+    haystack.char_indices().any(|(start, _)| {
+        let mut haystack = haystack[start..].chars().flat_map(char::to_lowercase);
+        needle.chars().all(|c| haystack.next() == Some(c))
+    })
+}
+
+/// Shows the number of matching entries, and buttons to move the time cursor to the
+/// previous/next match.
+fn filter_matches_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    entry_filter: &EntryFilter,
+    entries: &[&Entry],
+    time: i64,
+) {
+    for error in &entry_filter.errors {
+        ui.error_label(error);
+    }
+
+    if !entry_filter.is_active() {
+        return;
+    }
+
+    let previous = entries
+        .iter()
+        .rev()
+        .find(|te| !te.time.is_static() && te.time.as_i64() < time);
+    let next = entries
+        .iter()
+        .find(|te| !te.time.is_static() && time < te.time.as_i64());
+
+    ui.horizontal(|ui| {
+        let previous_button = ui.small_icon_button_widget(&icons::ARROW_UP, "Previous match");
+        let previous_clicked = ui
+            .add_enabled(previous.is_some(), previous_button)
+            .on_hover_text("Move the time cursor to the previous matching entry")
+            .clicked();
+
+        let next_button = ui.small_icon_button_widget(&icons::ARROW_DOWN, "Next match");
+        let next_clicked = ui
+            .add_enabled(next.is_some(), next_button)
+            .on_hover_text("Move the time cursor to the next matching entry")
+            .clicked();
+
+        let target = if previous_clicked {
+            previous
+        } else if next_clicked {
+            next
+        } else {
+            None
+        };
+        if let Some(target) = target {
+            let mut time_ctrl = ctx.rec_cfg.time_ctrl.write();
+            time_ctrl.set_time(target.time);
+            time_ctrl.pause();
+        }
+
+        let num_matches = entries.len();
+        ui.label(if num_matches == 1 {
+            "1 matching entry".to_owned()
+        } else {
+            format!("{num_matches} matching entries")
+        });
+    });
+}
+
 // ---
 
 /// `scroll_to_row` indicates how far down we want to scroll in terms of logical rows,
@@ -279,19 +704,13 @@ fn table_ui(
     ctx: &ViewerContext<'_>,
     ui: &mut egui::Ui,
     state: &TextViewState,
+    columns: &[&LogColumn],
     entries: &[&Entry],
     scroll_to_row: Option<usize>,
 ) {
     let tokens = ui.tokens();
     let table_style = re_ui::TableStyle::Dense;
 
-    let timelines = state
-        .filters
-        .col_timelines
-        .iter()
-        .filter_map(|(timeline, visible)| visible.then_some(timeline))
-        .collect::<Vec<_>>();
-
     use egui_extras::Column;
 
     let (global_timeline, global_time) = {
@@ -314,43 +733,27 @@ fn table_ui(
     let mut body_clip_rect = None;
     let mut current_time_y = None; // where to draw the current time indicator cursor
 
-    {
-        // timeline(s)
-        table_builder =
-            table_builder.columns(Column::auto().clip(true).at_least(32.0), timelines.len());
-
-        // entity path
-        if state.filters.col_entity_path {
-            table_builder = table_builder.column(Column::auto().clip(true).at_least(32.0));
-        }
-        // log level
-        if state.filters.col_log_level {
-            table_builder = table_builder.column(Column::auto().at_least(30.0));
-        }
-        // body
-        table_builder = table_builder.column(Column::remainder().at_least(100.0));
+    for column in columns {
+        table_builder = table_builder.column(match column {
+            LogColumn::Timeline(_) | LogColumn::EntityPath | LogColumn::Field(_) => {
+                Column::auto().clip(true).at_least(32.0)
+            }
+            LogColumn::Level => Column::auto().at_least(30.0),
+            LogColumn::Body => Column::remainder().at_least(100.0),
+        });
     }
     table_builder
         .header(tokens.deprecated_table_header_height(), |mut header| {
             re_ui::DesignTokens::setup_table_header(&mut header);
-            for timeline in &timelines {
-                header.col(|ui| {
-                    item_ui::timeline_button(ctx, ui, timeline);
-                });
-            }
-            if state.filters.col_entity_path {
-                header.col(|ui| {
-                    ui.strong("Entity path");
-                });
-            }
-            if state.filters.col_log_level {
+            for column in columns {
                 header.col(|ui| {
-                    ui.strong("Level");
+                    if let LogColumn::Timeline(timeline) = column {
+                        item_ui::timeline_button(ctx, ui, timeline);
+                    } else {
+                        ui.strong(column.label());
+                    }
                 });
             }
-            header.col(|ui| {
-                ui.strong("Body");
-            });
         })
         .body(|mut body| {
             tokens.setup_table_body(&mut body, table_style);
@@ -365,73 +768,81 @@ fn table_ui(
             body.heterogeneous_rows(row_heights, |mut row| {
                 let entry = &entries[row.index()];
 
-                // timeline(s)
-                for &timeline in &timelines {
-                    row.col(|ui| {
-                        let row_time = entry
-                            .timepoint
-                            .get(timeline)
-                            .map(re_log_types::TimeInt::from)
-                            .unwrap_or(re_log_types::TimeInt::STATIC);
-                        item_ui::time_button(ctx, ui, timeline, row_time);
-
-                        if let Some(global_time) = global_time {
-                            if timeline == global_timeline.name() {
-                                #[allow(clippy::comparison_chain)]
-                                if global_time < row_time {
-                                    // We've past the global time - it is thus above this row.
-                                    if current_time_y.is_none() {
-                                        current_time_y = Some(ui.max_rect().top());
+                for column in columns {
+                    match column {
+                        LogColumn::Timeline(timeline) => {
+                            row.col(|ui| {
+                                let row_time = entry
+                                    .timepoint
+                                    .get(timeline)
+                                    .map(re_log_types::TimeInt::from)
+                                    .unwrap_or(re_log_types::TimeInt::STATIC);
+                                item_ui::time_button(ctx, ui, timeline, row_time);
+
+                                if let Some(global_time) = global_time {
+                                    if timeline == global_timeline.name() {
+                                        #[allow(clippy::comparison_chain)]
+                                        if global_time < row_time {
+                                            // We've past the global time - it is thus above this row.
+                                            if current_time_y.is_none() {
+                                                current_time_y = Some(ui.max_rect().top());
+                                            }
+                                        } else if global_time == row_time {
+                                            // This row is exactly at the current time.
+                                            // We could draw the current time exactly onto this row, but that would look bad,
+                                            // so let's draw it under instead. It looks better in the "following" mode.
+                                            current_time_y = Some(ui.max_rect().bottom());
+                                        }
                                     }
-                                } else if global_time == row_time {
-                                    // This row is exactly at the current time.
-                                    // We could draw the current time exactly onto this row, but that would look bad,
-                                    // so let's draw it under instead. It looks better in the "following" mode.
-                                    current_time_y = Some(ui.max_rect().bottom());
                                 }
-                            }
+                            });
                         }
-                    });
-                }
 
-                // path
-                if state.filters.col_entity_path {
-                    row.col(|ui| {
-                        item_ui::entity_path_button(
-                            ctx,
-                            &query,
-                            ctx.recording(),
-                            ui,
-                            None,
-                            &entry.entity_path,
-                        );
-                    });
-                }
+                        LogColumn::EntityPath => {
+                            row.col(|ui| {
+                                item_ui::entity_path_button(
+                                    ctx,
+                                    &query,
+                                    ctx.recording(),
+                                    ui,
+                                    None,
+                                    &entry.entity_path,
+                                );
+                            });
+                        }
 
-                // level
-                if state.filters.col_log_level {
-                    row.col(|ui| {
-                        if let Some(lvl) = &entry.level {
-                            ui.label(level_to_rich_text(ui, lvl));
-                        } else {
-                            ui.label("-");
+                        LogColumn::Level => {
+                            row.col(|ui| {
+                                if let Some(lvl) = &entry.level {
+                                    ui.label(level_to_rich_text(ui, lvl));
+                                } else {
+                                    ui.label("-");
+                                }
+                            });
                         }
-                    });
-                }
 
-                // body
-                row.col(|ui| {
-                    let mut text = egui::RichText::new(entry.body.as_str());
+                        LogColumn::Body => {
+                            row.col(|ui| {
+                                let mut text = egui::RichText::new(entry.body.as_str());
 
-                    if state.monospace {
-                        text = text.monospace();
-                    }
-                    if let Some(color) = entry.color {
-                        text = text.color(color);
-                    }
+                                if state.monospace {
+                                    text = text.monospace();
+                                }
+                                if let Some(color) = entry.color {
+                                    text = text.color(color);
+                                }
 
-                    ui.label(text);
-                });
+                                ui.label(text);
+                            });
+                        }
+
+                        LogColumn::Field(key) => {
+                            row.col(|ui| {
+                                ui.label(entry.field(key).unwrap_or("-"));
+                            });
+                        }
+                    }
+                }
             });
         });
 
@@ -453,7 +864,104 @@ fn calc_row_height(tokens: &DesignTokens, table_style: re_ui::TableStyle, entry:
     num_rows as f32 * tokens.table_row_height(table_style)
 }
 
+re_viewer_context::impl_component_fallback_provider!(TextView => []);
+
 #[test]
 fn test_help_view() {
     re_viewer_context::test_context::TestContext::test_help_view(|ctx| TextView.help(ctx));
 }
+
+#[test]
+fn test_entry_filter() {
+    let path = EntityPath::from("world/robot/camera");
+
+    let mut filter = EntryFilter::new("", "", TextFilterMode::Substring);
+    assert!(!filter.is_active());
+    assert!(filter.matches_parts("anything", &path));
+
+    let mut filter = EntryFilter::new("error", "robot", TextFilterMode::Substring);
+    assert!(filter.is_active());
+    assert!(filter.matches_parts("Connection ERROR", &path));
+    assert!(!filter.matches_parts("All good", &path));
+    assert!(!filter.matches_parts("error", &EntityPath::from("world/map")));
+
+    let mut filter = EntryFilter::new(r"^frame \d+$", r"camera$", TextFilterMode::Regex);
+    assert!(filter.matches_parts("frame 42", &path));
+    assert!(!filter.matches_parts("Frame 42", &path));
+    assert!(!filter.matches_parts("frame 42", &EntityPath::from("world/camera/info")));
+
+    // Invalid patterns are reported and ignored.
+    let mut filter = EntryFilter::new("(unclosed", "", TextFilterMode::Regex);
+    assert!(!filter.is_active());
+    assert_eq!(filter.errors.len(), 1);
+    assert!(filter.matches_parts("anything", &path));
+
+    // Recompiled only when the patterns change.
+    let patterns = |body: &str| EntryFilterPatterns {
+        body: body.into(),
+        entity_path: "robot".into(),
+        mode: TextFilterMode::Substring,
+    };
+    filter.update(patterns("error"));
+    assert!(filter.errors.is_empty());
+    assert!(filter.matches_parts("error", &path));
+    assert!(!filter.matches_parts("error", &EntityPath::from("world/map")));
+    filter.update(patterns("warning"));
+    assert!(!filter.matches_parts("error", &path));
+    assert!(filter.matches_parts("warning", &path));
+}
+
+#[test]
+fn test_contains_lowercase() {
+    assert!(contains_lowercase("Connection ERROR", "error"));
+    assert!(contains_lowercase("Connection ERROR", "n e"));
+    assert!(!contains_lowercase("Connection", "connections"));
+    assert!(!contains_lowercase("", "a"));
+    assert!(contains_lowercase("Größe ÜBER", "über"));
+    assert!(contains_lowercase("ΣΊΣΥΦΟΣ", "σίσυφοσ"));
+    assert!(!contains_lowercase("ÜBER", "uber"));
+}
+
+#[test]
+fn test_log_columns() {
+    let available = || {
+        [
+            LogColumn::Timeline(TimelineName::log_time()),
+            LogColumn::EntityPath,
+            LogColumn::Level,
+            LogColumn::Body,
+            LogColumn::Field("user".to_owned()),
+        ]
+    };
+    let ids = |columns: &LogColumns| columns.visible().map(LogColumn::id).collect::<Vec<_>>();
+
+    // Everything is shown in the default order by default.
+    let columns = LogColumns::new(available(), &[], &[]);
+    assert_eq!(
+        ids(&columns),
+        [
+            "timeline:log_time",
+            "entity_path",
+            "level",
+            "body",
+            "field:user"
+        ]
+    );
+
+    // Listed columns come first, unknown ones are ignored.
+    let columns = LogColumns::new(
+        available(),
+        &[
+            "field:user".into(),
+            "body".into(),
+            "field:unknown".into(),
+            "bogus".into(),
+        ],
+        &["level".into(), "timeline:log_time".into()],
+    );
+    assert_eq!(ids(&columns), ["field:user", "body", "entity_path"]);
+
+    for (column, _) in &columns.columns {
+        assert_eq!(LogColumn::from_id(&column.id()).as_ref(), Some(column));
+    }
+}
@@ -2,11 +2,12 @@ use itertools::izip;
 use re_chunk_store::ResolvedTimeRange;
 use re_entity_db::EntityPath;
 use re_log_types::{TimeInt, TimePoint};
-use re_query::{clamped_zip_1x2, range_zip_1x2};
+use re_query::{clamped_zip_1x2, range_zip_1x4};
 use re_types::{
     Archetype as _,
     archetypes::TextLog,
-    components::{Color, Text, TextLogLevel},
+    components::{Color, Text, TextLogField, TextLogLevel},
+    datatypes::Utf8,
 };
 use re_view::{RangeResultsExt as _, range_with_blueprint_resolved_data};
 use re_viewer_context::{
@@ -22,6 +23,19 @@ pub struct Entry {
     pub color: Option<Color>,
     pub body: Text,
     pub level: Option<TextLogLevel>,
+
+    /// Structured key-value fields, shared by all entries logged in the same row.
+    pub fields: Vec<TextLogField>,
+}
+
+impl Entry {
+    /// The value of the structured field `key`, if any.
+    pub fn field(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|field| field.key() == key)
+            .map(|field| field.value())
+    }
 }
 
 /// A text scene, with everything needed to render it.
@@ -107,16 +121,26 @@ impl TextLogSystem {
         let all_texts = results.iter_as(timeline, TextLog::descriptor_text());
         let all_levels = results.iter_as(timeline, TextLog::descriptor_level());
         let all_colors = results.iter_as(timeline, TextLog::descriptor_color());
+        let all_fields = results.iter_as(timeline, TextLog::descriptor_fields());
 
-        let all_frames = range_zip_1x2(
+        re_types::static_assert_struct_has_fields!(
+            re_types::datatypes::Utf8Pair,
+            first: Utf8,
+            second: Utf8,
+        );
+
+        let all_frames = range_zip_1x4(
             all_texts.slice::<String>(),
             all_levels.slice::<String>(),
             all_colors.slice::<u32>(),
+            all_fields.slice_from_struct_field::<String>("first"),
+            all_fields.slice_from_struct_field::<String>("second"),
         );
 
         let all_frames = izip!(all_timepoints, all_frames);
 
-        for (timepoint, ((data_time, _row_id), bodies, levels, colors)) in all_frames {
+        for (timepoint, ((data_time, _row_id), bodies, levels, colors, keys, values)) in all_frames
+        {
             let levels = levels.as_deref().unwrap_or(&[]).iter().cloned().map(Some);
             let colors = colors
                 .unwrap_or(&[])
@@ -125,6 +149,11 @@ impl TextLogSystem {
                 .map(Into::into)
                 .map(Some);
 
+            let fields: Vec<TextLogField> =
+                izip!(keys.unwrap_or_default(), values.unwrap_or_default())
+                    .map(|(key, value)| TextLogField::new(Utf8(key), Utf8(value)))
+                    .collect();
+
             let level_default_fn = || None;
             let color_default_fn = || None;
 
@@ -139,6 +168,7 @@ impl TextLogSystem {
                     color,
                     body: text.clone().into(),
                     level: level.clone().map(Into::into),
+                    fields: fields.clone(),
                 });
             }
         }
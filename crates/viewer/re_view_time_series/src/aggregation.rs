@@ -186,11 +186,134 @@ impl MinMaxAggregator {
     }
 }
 
+/// Implements aggregation behaviors for `Median`.
+pub struct MedianAggregator;
+
+impl MedianAggregator {
+    /// Adjacent plot points may have the same `PlotPoint::time`,
+    /// if data was logged multiple times on the same time stamp.
+    #[inline]
+    pub fn aggregate(aggregation_window_size: f64, points: &[PlotPoint]) -> Vec<PlotPoint> {
+        // NOTE: `round()` since this can only handle discrete window sizes.
+        let window_size = usize::max(1, aggregation_window_size.round() as usize);
+
+        let min_time = points.first().map_or(i64::MIN, |p| p.time);
+        let max_time = points.last().map_or(i64::MAX, |p| p.time);
+
+        let mut aggregated = Vec::with_capacity(points.len() / window_size);
+        let mut values = Vec::new();
+
+        let mut i = 0;
+        while i < points.len() {
+            // How many points to combine together this time.
+            let mut j = 1;
+            while i + j < points.len() && are_aggregatable(&points[i], &points[i + j], window_size)
+            {
+                j += 1;
+            }
+
+            let window = &points[i..i + j];
+
+            values.clear();
+            values.extend(window.iter().map(|p| p.value));
+            values.sort_unstable_by(f64::total_cmp);
+
+            let mid = values.len() / 2;
+            let median = if values.len() % 2 == 0 {
+                (values[mid - 1] + values[mid]) * 0.5
+            } else {
+                values[mid]
+            };
+
+            let mut acc = points[i].clone();
+            acc.value = median;
+            acc.attrs.radius_ui =
+                window.iter().map(|p| p.attrs.radius_ui).sum::<f32>() / window.len() as f32;
+            aggregated.push(acc);
+
+            i += j;
+        }
+
+        // Force align the start and end timestamps to prevent jarring visual glitches.
+        if let Some(p) = aggregated.first_mut() {
+            p.time = min_time;
+        }
+        if let Some(p) = aggregated.last_mut() {
+            p.time = max_time;
+        }
+
+        aggregated
+    }
+}
+
+/// Implements aggregation behaviors for `Envelope`.
+pub struct EnvelopeAggregator;
+
+impl EnvelopeAggregator {
+    /// Yields one point per window at the average of its values, with [`PlotPoint::envelope`] set
+    /// to their minimum and maximum.
+    ///
+    /// Adjacent plot points may have the same `PlotPoint::time`,
+    /// if data was logged multiple times on the same time stamp.
+    #[inline]
+    pub fn aggregate(aggregation_window_size: f64, points: &[PlotPoint]) -> Vec<PlotPoint> {
+        // NOTE: `round()` since this can only handle discrete window sizes.
+        let window_size = usize::max(1, aggregation_window_size.round() as usize);
+
+        let min_time = points.first().map_or(i64::MIN, |p| p.time);
+        let max_time = points.last().map_or(i64::MAX, |p| p.time);
+
+        let mut aggregated = Vec::with_capacity(points.len() / window_size);
+
+        let mut i = 0;
+        while i < points.len() {
+            // How many points to combine together this time.
+            let mut j = 0;
+
+            let mut acc = points[i + j].clone();
+            let mut min = acc.value;
+            let mut max = acc.value;
+            j += 1;
+
+            while i + j < points.len() && are_aggregatable(&points[i], &points[i + j], window_size)
+            {
+                let point = &points[i + j];
+
+                acc.value += point.value;
+                acc.attrs.radius_ui += point.attrs.radius_ui;
+                min = f64::min(min, point.value);
+                max = f64::max(max, point.value);
+
+                j += 1;
+            }
+
+            acc.value /= j as f64;
+            acc.attrs.radius_ui /= j as f32;
+            acc.envelope = Some((min, max));
+
+            aggregated.push(acc);
+
+            i += j;
+        }
+
+        // Force align the start and end timestamps to prevent jarring visual glitches.
+        if let Some(p) = aggregated.first_mut() {
+            p.time = min_time;
+        }
+        if let Some(p) = aggregated.last_mut() {
+            p.time = max_time;
+        }
+
+        aggregated
+    }
+}
+
 /// Are two [`PlotPoint`]s safe to aggregate?
 fn are_aggregatable(point1: &PlotPoint, point2: &PlotPoint, window_size: usize) -> bool {
     let PlotPoint {
         time,
         value: _,
+        envelope: _,
         attrs,
     } = point1;
     let PlotPointAttrs {
@@ -205,3 +328,43 @@ fn are_aggregatable(point1: &PlotPoint, point2: &PlotPoint, window_size: usize)
         && *color == point2.attrs.color
         && *kind == point2.attrs.kind
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PlotSeriesKind;
+
+    fn points(values: &[f64]) -> Vec<PlotPoint> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(time, &value)| PlotPoint {
+                time: time as i64,
+                value,
+                envelope: None,
+                attrs: PlotPointAttrs {
+                    color: egui::Color32::WHITE,
+                    radius_ui: 1.0,
+                    kind: PlotSeriesKind::Continuous,
+                },
+            })
+            .collect()
+    }
+
+    #[test]
+    fn median() {
+        let aggregated = MedianAggregator::aggregate(2.0, &points(&[1.0, 100.0, 2.0, 3.0, 4.0]));
+        let values = aggregated.iter().map(|p| p.value).collect::<Vec<_>>();
+        assert_eq!(values, [2.0, 3.5]);
+    }
+
+    #[test]
+    fn envelope() {
+        let aggregated = EnvelopeAggregator::aggregate(2.0, &points(&[1.0, 5.0, 3.0, 4.0]));
+        let values = aggregated
+            .iter()
+            .map(|p| (p.value, p.envelope))
+            .collect::<Vec<_>>();
+        assert_eq!(values, [(3.0, Some((1.0, 5.0))), (4.0, Some((4.0, 4.0)))]);
+    }
+}
@@ -0,0 +1,647 @@
+//! Series computed from other series, as configured by the [`re_types::blueprint::archetypes::DerivedSeries`] view property.
+
+use egui::ahash::HashSet;
+
+use re_log_types::EntityPath;
+use re_types::DeserializationError;
+use re_types::blueprint::{archetypes::DerivedSeries, components::SeriesExpression};
+use re_viewer_context::{ViewId, ViewerContext, external::re_entity_db::InstancePath};
+use re_viewport_blueprint::ViewProperty;
+
+/// Samples of a series, sorted by time.
+pub type Samples = Vec<(i64, f64)>;
+
+/// How deeply expressions may nest negations, parentheses and function calls.
+const MAX_NESTING_DEPTH: usize = 64;
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum ExpressionError {
+    #[error("unexpected {found} at position {position}, expected {expected}")]
+    Unexpected {
+        found: String,
+        position: usize,
+        expected: &'static str,
+    },
+
+    #[error("unknown function {0:?}, expected `d/dt`, `derivative` or `rolling_mean`")]
+    UnknownFunction(String),
+
+    #[error("invalid window size {0:?}, expected a positive integer")]
+    InvalidWindow(String),
+
+    #[error("no data for series {0}")]
+    MissingSeries(InstancePath),
+
+    #[error("expression doesn't reference any series")]
+    NoSeries,
+
+    #[error("expression is nested more than {MAX_NESTING_DEPTH} levels deep")]
+    TooDeeplyNested,
+}
+
+/// A derived series: a label and the expression it's computed from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DerivedSeriesDef {
+    pub label: String,
+    pub expression: Expression,
+}
+
+impl DerivedSeriesDef {
+    /// Parses `<label> = <expression>`, or just `<expression>`, in which case the expression itself is the label.
+    pub fn parse(definition: &str) -> Result<Self, ExpressionError> {
+        let (label, expression) = match definition.split_once('=') {
+            Some((label, expression)) => (label.trim(), expression),
+            None => (definition.trim(), definition),
+        };
+
+        Ok(Self {
+            label: label.to_owned(),
+            expression: Expression::parse(expression)?,
+        })
+    }
+}
+
+/// An expression of the [`DerivedSeries`] view property, along with the result of parsing it.
+pub struct ParsedExpression {
+    pub expression: String,
+    pub definition: Result<DerivedSeriesDef, ExpressionError>,
+}
+
+/// Parses the expressions of the [`DerivedSeries`] view property, in order.
+pub fn load_definitions(
+    ctx: &ViewerContext<'_>,
+    view_id: ViewId,
+) -> Result<Vec<ParsedExpression>, DeserializationError> {
+    let property = ViewProperty::from_archetype::<DerivedSeries>(
+        ctx.blueprint_db(),
+        ctx.blueprint_query,
+        view_id,
+    );
+    let expressions = property
+        .component_array_or_empty::<SeriesExpression>(&DerivedSeries::descriptor_expressions())?;
+
+    Ok(expressions
+        .into_iter()
+        .map(|expression| {
+            let expression = expression.as_str().to_owned();
+            let definition = DerivedSeriesDef::parse(&expression);
+            ParsedExpression {
+                expression,
+                definition,
+            }
+        })
+        .collect())
+}
+
+/// All the series referenced by valid definitions, keyed by [`samples_key`].
+pub fn referenced_series(parsed_expressions: &[ParsedExpression]) -> HashSet<InstancePath> {
+    parsed_expressions
+        .iter()
+        .filter_map(|parsed| parsed.definition.as_ref().ok())
+        .flat_map(|definition| definition.expression.series())
+        .map(samples_key)
+        .collect()
+}
+
+/// The id of a derived series in the plot, based on its label.
+pub fn plot_item_id(label: &str) -> egui::Id {
+    egui::Id::new(("derived_series", label))
+}
+
+/// Derived series reference series either by entity (`/a`) or by instance (`/a[0]`).
+///
+/// Entities with a single series are keyed by instance 0, so that both forms refer to the same samples.
+pub fn samples_key(instance_path: &InstancePath) -> InstancePath {
+    let index = instance_path
+        .instance
+        .specific_index()
+        .map_or(0, |instance| instance.get());
+    InstancePath::instance(instance_path.entity_path.clone(), index.into())
+}
+
+/// An expression over other series.
+///
+/// Supported syntax:
+/// * series references, by entity path: `/robot/speed`, optionally with an instance index: `/robot/joints[2]`
+/// * numbers: `2`, `0.5`, `1e-3`
+/// * arithmetic: `a + b`, `a - b`, `a * b`, `a / b`, `-a`, `(a)`
+/// * derivative with respect to time: `d/dt(a)` or `derivative(a)`, per second on temporal timelines
+/// * moving average over the last `n` samples: `rolling_mean(a, n)`
+///
+/// Since entity paths may contain `-` and `/`, operators following a series reference must be
+/// separated from it by whitespace.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expression {
+    Constant(f64),
+    Series(InstancePath),
+    Negate(Box<Expression>),
+    Binary(BinaryOp, Box<Expression>, Box<Expression>),
+    Derivative(Box<Expression>),
+    RollingMean(Box<Expression>, usize),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl BinaryOp {
+    fn apply(self, lhs: f64, rhs: f64) -> f64 {
+        match self {
+            Self::Add => lhs + rhs,
+            Self::Sub => lhs - rhs,
+            Self::Mul => lhs * rhs,
+            Self::Div => lhs / rhs,
+        }
+    }
+}
+
+/// The result of evaluating an [`Expression`].
+enum Value {
+    Constant(f64),
+    Series(Samples),
+}
+
+impl Expression {
+    pub fn parse(input: &str) -> Result<Self, ExpressionError> {
+        let mut parser = Parser {
+            input,
+            position: 0,
+            depth: 0,
+        };
+        let expression = parser.expression()?;
+        parser.skip_whitespace();
+        if parser.position < input.len() {
+            return Err(parser.unexpected("an operator"));
+        }
+        Ok(expression)
+    }
+
+    /// All the series this expression references.
+    pub fn series(&self) -> Vec<&InstancePath> {
+        let mut series = Vec::new();
+        self.visit_series(&mut |instance_path| series.push(instance_path));
+        series
+    }
+
+    fn visit_series<'a>(&'a self, visit: &mut impl FnMut(&'a InstancePath)) {
+        match self {
+            Self::Constant(_) => {}
+            Self::Series(instance_path) => visit(instance_path),
+            Self::Negate(expr) | Self::Derivative(expr) | Self::RollingMean(expr, _) => {
+                expr.visit_series(visit);
+            }
+            Self::Binary(_, lhs, rhs) => {
+                lhs.visit_series(visit);
+                rhs.visit_series(visit);
+            }
+        }
+    }
+
+    /// Computes the samples of this expression.
+    ///
+    /// `seconds_per_time_unit` scales derivatives, so that they are per second on temporal timelines.
+    pub fn evaluate<'a>(
+        &self,
+        samples_of: &impl Fn(&InstancePath) -> Option<&'a [(i64, f64)]>,
+        seconds_per_time_unit: f64,
+    ) -> Result<Samples, ExpressionError> {
+        match self.evaluate_value(samples_of, seconds_per_time_unit)? {
+            Value::Constant(_) => Err(ExpressionError::NoSeries),
+            Value::Series(samples) => Ok(samples),
+        }
+    }
+
+    fn evaluate_value<'a>(
+        &self,
+        samples_of: &impl Fn(&InstancePath) -> Option<&'a [(i64, f64)]>,
+        seconds_per_time_unit: f64,
+    ) -> Result<Value, ExpressionError> {
+        Ok(match self {
+            Self::Constant(value) => Value::Constant(*value),
+
+            Self::Series(instance_path) => Value::Series(
+                samples_of(instance_path)
+                    .ok_or_else(|| ExpressionError::MissingSeries(instance_path.clone()))?
+                    .to_vec(),
+            ),
+
+            Self::Negate(expr) => match expr.evaluate_value(samples_of, seconds_per_time_unit)? {
+                Value::Constant(value) => Value::Constant(-value),
+                Value::Series(samples) => Value::Series(
+                    samples
+                        .into_iter()
+                        .map(|(time, value)| (time, -value))
+                        .collect(),
+                ),
+            },
+
+            Self::Binary(op, lhs, rhs) => {
+                let lhs = lhs.evaluate_value(samples_of, seconds_per_time_unit)?;
+                let rhs = rhs.evaluate_value(samples_of, seconds_per_time_unit)?;
+                match (lhs, rhs) {
+                    (Value::Constant(lhs), Value::Constant(rhs)) => {
+                        Value::Constant(op.apply(lhs, rhs))
+                    }
+                    (Value::Series(lhs), Value::Constant(rhs)) => Value::Series(
+                        lhs.into_iter()
+                            .map(|(time, lhs)| (time, op.apply(lhs, rhs)))
+                            .collect(),
+                    ),
+                    (Value::Constant(lhs), Value::Series(rhs)) => Value::Series(
+                        rhs.into_iter()
+                            .map(|(time, rhs)| (time, op.apply(lhs, rhs)))
+                            .collect(),
+                    ),
+                    (Value::Series(lhs), Value::Series(rhs)) => {
+                        Value::Series(join_latest_at(&lhs, &rhs, *op))
+                    }
+                }
+            }
+
+            Self::Derivative(expr) => {
+                match expr.evaluate_value(samples_of, seconds_per_time_unit)? {
+                    Value::Constant(_) => Value::Constant(0.0),
+                    Value::Series(samples) => {
+                        Value::Series(derivative(&samples, seconds_per_time_unit))
+                    }
+                }
+            }
+
+            Self::RollingMean(expr, window) => {
+                match expr.evaluate_value(samples_of, seconds_per_time_unit)? {
+                    Value::Constant(value) => Value::Constant(value),
+                    Value::Series(samples) => Value::Series(rolling_mean(&samples, *window)),
+                }
+            }
+        })
+    }
+}
+
+/// Combines each sample of `lhs` with the latest sample of `rhs` at or before the same time.
+///
+/// Samples of `lhs` that come before the first sample of `rhs` are dropped.
+fn join_latest_at(lhs: &[(i64, f64)], rhs: &[(i64, f64)], op: BinaryOp) -> Samples {
+    let mut joined = Vec::with_capacity(lhs.len());
+
+    let mut rhs_index = 0;
+    let mut rhs_value = None;
+    for &(time, lhs_value) in lhs {
+        while rhs_index < rhs.len() && rhs[rhs_index].0 <= time {
+            rhs_value = Some(rhs[rhs_index].1);
+            rhs_index += 1;
+        }
+
+        if let Some(rhs_value) = rhs_value {
+            joined.push((time, op.apply(lhs_value, rhs_value)));
+        }
+    }
+
+    joined
+}
+
+/// Finite differences between consecutive samples, at the time of the later one.
+fn derivative(samples: &[(i64, f64)], seconds_per_time_unit: f64) -> Samples {
+    samples
+        .windows(2)
+        .filter(|w| w[0].0 != w[1].0)
+        .map(|w| {
+            let dt = (w[1].0 - w[0].0) as f64 * seconds_per_time_unit;
+            (w[1].0, (w[1].1 - w[0].1) / dt)
+        })
+        .collect()
+}
+
+/// Average over the last `window` samples, including the current one.
+fn rolling_mean(samples: &[(i64, f64)], window: usize) -> Samples {
+    let mut sum = 0.0;
+    samples
+        .iter()
+        .enumerate()
+        .map(|(i, &(time, value))| {
+            sum += value;
+            if window <= i {
+                sum -= samples[i - window].1;
+            }
+            (time, sum / usize::min(i + 1, window) as f64)
+        })
+        .collect()
+}
+
+// --- Parsing ---
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+
+    /// Current nesting depth, see [`MAX_NESTING_DEPTH`].
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn rest(&self) -> &str {
+        &self.input[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        let whitespace_len = rest.len() - rest.trim_start().len();
+        self.position += whitespace_len;
+    }
+
+    /// Consumes `token` if the input continues with it, after any whitespace.
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.position += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes characters for as long as `predicate` holds.
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &str {
+        let start = self.position;
+        let len = self
+            .rest()
+            .find(|c: char| !predicate(c))
+            .unwrap_or(self.rest().len());
+        self.position += len;
+        &self.input[start..self.position]
+    }
+
+    fn unexpected(&self, expected: &'static str) -> ExpressionError {
+        let found = self
+            .rest()
+            .chars()
+            .next()
+            .map_or_else(|| "end of input".to_owned(), |c| format!("{c:?}"));
+        ExpressionError::Unexpected {
+            found,
+            position: self.position,
+            expected,
+        }
+    }
+
+    fn expect(&mut self, token: &'static str) -> Result<(), ExpressionError> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.unexpected(token))
+        }
+    }
+
+    /// `term (('+' | '-') term)*`
+    fn expression(&mut self) -> Result<Expression, ExpressionError> {
+        let mut expression = self.term()?;
+        loop {
+            let op = if self.eat("+") {
+                BinaryOp::Add
+            } else if self.eat("-") {
+                BinaryOp::Sub
+            } else {
+                return Ok(expression);
+            };
+            expression = Expression::Binary(op, Box::new(expression), Box::new(self.term()?));
+        }
+    }
+
+    /// `unary (('*' | '/') unary)*`
+    fn term(&mut self) -> Result<Expression, ExpressionError> {
+        let mut expression = self.unary()?;
+        loop {
+            let op = if self.eat("*") {
+                BinaryOp::Mul
+            } else if self.eat("/") {
+                BinaryOp::Div
+            } else {
+                return Ok(expression);
+            };
+            expression = Expression::Binary(op, Box::new(expression), Box::new(self.unary()?));
+        }
+    }
+
+    /// `'-' unary | primary`
+    fn unary(&mut self) -> Result<Expression, ExpressionError> {
+        // All nesting (negations, parentheses, function calls) recurses through here.
+        if MAX_NESTING_DEPTH <= self.depth {
+            return Err(ExpressionError::TooDeeplyNested);
+        }
+
+        self.depth += 1;
+        let expression = if self.eat("-") {
+            self.unary()
+                .map(|expression| Expression::Negate(Box::new(expression)))
+        } else {
+            self.primary()
+        };
+        self.depth -= 1;
+
+        expression
+    }
+
+    /// A number, a series reference, a function call, or a parenthesized expression.
+    fn primary(&mut self) -> Result<Expression, ExpressionError> {
+        self.skip_whitespace();
+
+        if self.eat("(") {
+            let expression = self.expression()?;
+            self.expect(")")?;
+            return Ok(expression);
+        }
+
+        if self.eat("d/dt") {
+            return Ok(Expression::Derivative(Box::new(self.single_argument()?)));
+        }
+
+        match self.rest().chars().next() {
+            Some('/') => self.series(),
+
+            Some(c) if c.is_ascii_digit() || c == '.' => {
+                let start = self.position;
+                self.take_while(|c| c.is_ascii_alphanumeric() || c == '.');
+                // Exponents may be negative, e.g. `1e-3`.
+                if self.input[start..self.position].ends_with(['e', 'E'])
+                    && self.rest().starts_with('-')
+                {
+                    self.position += 1;
+                    self.take_while(|c| c.is_ascii_digit());
+                }
+
+                let number = &self.input[start..self.position];
+                number.parse().map(Expression::Constant).map_err(|_err| {
+                    ExpressionError::Unexpected {
+                        found: format!("{number:?}"),
+                        position: start,
+                        expected: "a number",
+                    }
+                })
+            }
+
+            Some(c) if c.is_alphabetic() => {
+                let name = self
+                    .take_while(|c| c.is_alphanumeric() || c == '_')
+                    .to_owned();
+                match name.as_str() {
+                    "derivative" => Ok(Expression::Derivative(Box::new(self.single_argument()?))),
+                    "rolling_mean" => {
+                        self.expect("(")?;
+                        let expression = self.expression()?;
+                        self.expect(",")?;
+                        self.skip_whitespace();
+                        let window = self.take_while(|c| !c.is_whitespace() && c != ')');
+                        let window = window
+                            .parse()
+                            .ok()
+                            .filter(|&window| 0 < window)
+                            .ok_or_else(|| ExpressionError::InvalidWindow(window.to_owned()))?;
+                        self.expect(")")?;
+                        Ok(Expression::RollingMean(Box::new(expression), window))
+                    }
+                    _ => Err(ExpressionError::UnknownFunction(name)),
+                }
+            }
+
+            _ => Err(self.unexpected("a number, a series or a function")),
+        }
+    }
+
+    /// `'(' expression ')'`
+    fn single_argument(&mut self) -> Result<Expression, ExpressionError> {
+        self.expect("(")?;
+        let expression = self.expression()?;
+        self.expect(")")?;
+        Ok(expression)
+    }
+
+    /// An entity path, optionally followed by an instance index: `/robot/joints[2]`.
+    fn series(&mut self) -> Result<Expression, ExpressionError> {
+        let path = self.take_while(|c| c.is_alphanumeric() || matches!(c, '/' | '_' | '-' | '.'));
+        let entity_path = EntityPath::parse_forgiving(path);
+
+        if self.rest().starts_with('[') {
+            self.position += 1;
+            let index = self.take_while(|c| c.is_ascii_digit());
+            let index = index
+                .parse::<u64>()
+                .map_err(|_err| self.unexpected("an instance index"))?;
+            self.expect("]")?;
+            Ok(Expression::Series(InstancePath::instance(
+                entity_path,
+                index.into(),
+            )))
+        } else {
+            Ok(Expression::Series(InstancePath::entity_all(entity_path)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(path: &str) -> Expression {
+        Expression::Series(InstancePath::entity_all(EntityPath::parse_forgiving(path)))
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Expression::parse("/a - /b/c * 2").unwrap(),
+            Expression::Binary(
+                BinaryOp::Sub,
+                Box::new(series("/a")),
+                Box::new(Expression::Binary(
+                    BinaryOp::Mul,
+                    Box::new(series("/b/c")),
+                    Box::new(Expression::Constant(2.0)),
+                )),
+            )
+        );
+
+        assert_eq!(
+            Expression::parse("d/dt(rolling_mean(/left-arm, 10))").unwrap(),
+            Expression::Derivative(Box::new(Expression::RollingMean(
+                Box::new(series("/left-arm")),
+                10
+            )))
+        );
+
+        assert_eq!(
+            Expression::parse("/joints[2] / 1e-3").unwrap(),
+            Expression::Binary(
+                BinaryOp::Div,
+                Box::new(Expression::Series(InstancePath::instance(
+                    EntityPath::parse_forgiving("/joints"),
+                    2.into()
+                ))),
+                Box::new(Expression::Constant(1e-3)),
+            )
+        );
+
+        assert!(Expression::parse("/a +").is_err());
+        assert!(Expression::parse("/a ) ").is_err());
+        assert!(Expression::parse("foo(/a)").is_err());
+        assert!(Expression::parse("rolling_mean(/a, 0)").is_err());
+
+        let nested = |depth: usize, open: &str, close: &str| {
+            Expression::parse(&format!("{}/a{}", open.repeat(depth), close.repeat(depth)))
+        };
+        assert!(nested(MAX_NESTING_DEPTH - 1, "(", ")").is_ok());
+        assert!(nested(MAX_NESTING_DEPTH - 1, "-", "").is_ok());
+        assert!(nested(MAX_NESTING_DEPTH / 2, "d/dt(", ")").is_ok());
+        assert_eq!(
+            nested(MAX_NESTING_DEPTH, "(", ")"),
+            Err(ExpressionError::TooDeeplyNested)
+        );
+        assert_eq!(
+            nested(100_000, "-", ""),
+            Err(ExpressionError::TooDeeplyNested)
+        );
+        assert_eq!(
+            nested(100_000, "rolling_mean(", ", 2)"),
+            Err(ExpressionError::TooDeeplyNested)
+        );
+
+        let def = DerivedSeriesDef::parse("speed = d/dt(/position)").unwrap();
+        assert_eq!(def.label, "speed");
+        assert_eq!(
+            def.expression.series(),
+            [&InstancePath::entity_all(EntityPath::from("position"))]
+        );
+    }
+
+    #[test]
+    fn evaluate() {
+        let a = vec![(0, 1.0), (1, 2.0), (2, 4.0), (4, 8.0)];
+        let b = vec![(1, 10.0), (3, 20.0)];
+        let samples_of = |instance_path: &InstancePath| {
+            if instance_path.entity_path == EntityPath::from("a") {
+                Some(a.as_slice())
+            } else if instance_path.entity_path == EntityPath::from("b") {
+                Some(b.as_slice())
+            } else {
+                None
+            }
+        };
+        let eval = |expression: &str| {
+            Expression::parse(expression)
+                .unwrap()
+                .evaluate(&samples_of, 1.0)
+        };
+
+        assert_eq!(eval("/b - /a"), Ok(vec![(1, 8.0), (3, 16.0)]));
+        assert_eq!(eval("/a - /b"), Ok(vec![(1, -8.0), (2, -6.0), (4, -12.0)]));
+        assert_eq!(eval("d/dt(/a)"), Ok(vec![(1, 1.0), (2, 2.0), (4, 2.0)]));
+        assert_eq!(
+            eval("rolling_mean(/a, 2) * 2"),
+            Ok(vec![(0, 2.0), (1, 3.0), (2, 6.0), (4, 12.0)])
+        );
+        assert_eq!(eval("1 + 2"), Err(ExpressionError::NoSeries));
+        assert!(matches!(eval("/c"), Err(ExpressionError::MissingSeries(_))));
+    }
+}
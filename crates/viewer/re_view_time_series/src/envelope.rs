@@ -0,0 +1,115 @@
+use std::ops::RangeInclusive;
+
+use egui::{Color32, Id, Mesh, Shape, Ui};
+use egui_plot::{PlotBounds, PlotGeometry, PlotItem, PlotItemBase, PlotPoint, PlotTransform};
+
+/// A shaded band between a lower and an upper bound, drawn as a single triangle mesh.
+///
+/// Unlike [`egui_plot::Polygon`], this supports non-convex shapes, so the whole envelope of a
+/// series is a single plot item no matter how many points it has.
+pub struct EnvelopeBand {
+    base: PlotItemBase,
+    id: Id,
+    highlight: bool,
+    color: Color32,
+    fill_color: Color32,
+
+    /// `(time, min, max)` for every step of the band, sorted by time.
+    steps: Vec<(f64, f64, f64)>,
+}
+
+impl EnvelopeBand {
+    pub fn new(
+        name: impl Into<String>,
+        id: Id,
+        color: Color32,
+        fill_color: Color32,
+        steps: Vec<(f64, f64, f64)>,
+    ) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()),
+            id,
+            highlight: false,
+            color,
+            fill_color,
+            steps,
+        }
+    }
+
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+}
+
+impl PlotItem for EnvelopeBand {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        if self.steps.len() < 2 {
+            return;
+        }
+
+        // A triangle strip: vertices `2i` and `2i + 1` are the bottom and top of step `i`.
+        let mut mesh = Mesh::default();
+        mesh.reserve_vertices(2 * self.steps.len());
+        mesh.reserve_triangles(2 * (self.steps.len() - 1));
+
+        for (i, &(time, min, max)) in self.steps.iter().enumerate() {
+            for value in [min, max] {
+                let pos = transform.position_from_point(&PlotPoint::new(time, value));
+                mesh.colored_vertex(pos, self.fill_color);
+            }
+
+            if 0 < i {
+                let i = 2 * i as u32;
+                mesh.add_triangle(i - 2, i - 1, i);
+                mesh.add_triangle(i - 1, i + 1, i);
+            }
+        }
+
+        shapes.push(Shape::mesh(mesh));
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        false
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for &(time, min, max) in &self.steps {
+            bounds.extend_with(&PlotPoint::new(time, min));
+            bounds.extend_with(&PlotPoint::new(time, max));
+        }
+        bounds
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+
+    fn id(&self) -> Id {
+        self.id
+    }
+}
@@ -5,6 +5,8 @@
 #![warn(clippy::iter_over_hash_type)] //  TODO(#6198): enable everywhere
 
 mod aggregation;
+mod derived_series;
+mod envelope;
 mod line_visualizer_system;
 mod point_visualizer_system;
 mod series_query;
@@ -62,6 +64,10 @@ impl Eq for PlotPointAttrs {}
 struct PlotPoint {
     time: i64,
     value: f64,
+
+    /// Minimum and maximum of the aggregated values, if aggregated with [`AggregationPolicy::Envelope`].
+    envelope: Option<(f64, f64)>,
+
     attrs: PlotPointAttrs,
 }
 
@@ -95,6 +101,11 @@ pub struct PlotSeries {
     pub kind: PlotSeriesKind,
     pub points: Vec<(i64, f64)>,
 
+    /// Minimum and maximum value around each of the [`PlotSeries::points`], drawn as a shaded band.
+    ///
+    /// Only set when aggregating with [`AggregationPolicy::Envelope`], empty otherwise.
+    pub envelope: Vec<(f64, f64)>,
+
     /// Earliest time an entity was recorded at on the current timeline.
     pub min_time: i64,
 
@@ -1,3 +1,4 @@
+use egui::ahash::{HashMap, HashSet};
 use itertools::Itertools as _;
 
 use re_chunk_store::{RangeQuery, RowId};
@@ -15,9 +16,10 @@ use re_viewer_context::{
     auto_color_for_entity_path,
 };
 
+use crate::derived_series::{self, Samples};
 use crate::series_query::{
-    allocate_plot_points, collect_colors, collect_radius_ui, collect_scalars, collect_series_name,
-    collect_series_visibility, determine_num_series,
+    allocate_plot_points, collect_colors, collect_radius_ui, collect_referenced_samples,
+    collect_scalars, collect_series_name, collect_series_visibility, determine_num_series,
 };
 use crate::util::{determine_time_per_pixel, determine_time_range, points_to_series};
 use crate::view_class::TimeSeriesViewState;
@@ -27,6 +29,9 @@ use crate::{PlotPoint, PlotPointAttrs, PlotSeries, PlotSeriesKind};
 #[derive(Default, Debug)]
 pub struct SeriesLinesSystem {
     pub all_series: Vec<PlotSeries>,
+
+    /// Samples of the series referenced by derived series, keyed by [`derived_series::samples_key`].
+    pub samples: HashMap<InstancePath, Samples>,
 }
 
 impl IdentifiedViewSystem for SeriesLinesSystem {
@@ -35,7 +40,7 @@ impl IdentifiedViewSystem for SeriesLinesSystem {
     }
 }
 
-const DEFAULT_STROKE_WIDTH: f32 = 0.75;
+pub(crate) const DEFAULT_STROKE_WIDTH: f32 = 0.75;
 
 impl VisualizerSystem for SeriesLinesSystem {
     fn visualizer_query_info(&self) -> VisualizerQueryInfo {
@@ -57,7 +62,11 @@ impl VisualizerSystem for SeriesLinesSystem {
     ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
         re_tracing::profile_function!();
 
-        self.load_scalars(ctx, query);
+        let referenced_series = derived_series::referenced_series(
+            &derived_series::load_definitions(ctx.viewer_ctx, query.view_id)?,
+        );
+
+        self.load_scalars(ctx, query, &referenced_series);
         Ok(Vec::new())
     }
 
@@ -112,7 +121,12 @@ impl TypedComponentFallbackProvider<SeriesVisible> for SeriesLinesSystem {
 re_viewer_context::impl_component_fallback_provider!(SeriesLinesSystem => [Color, StrokeWidth, Name, SeriesVisible]);
 
 impl SeriesLinesSystem {
-    fn load_scalars(&mut self, ctx: &ViewContext<'_>, query: &ViewQuery<'_>) {
+    fn load_scalars(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        query: &ViewQuery<'_>,
+        referenced_series: &HashSet<InstancePath>,
+    ) {
         re_tracing::profile_function!();
 
         let plot_mem =
@@ -121,28 +135,36 @@ impl SeriesLinesSystem {
 
         let data_results = query.iter_visible_data_results(Self::identifier());
 
+        let mut samples = HashMap::default();
+
         let parallel_loading = true;
         if parallel_loading {
             use rayon::prelude::*;
             re_tracing::profile_wait!("load_series");
-            for mut one_series in data_results
+            for (mut one_series, one_samples) in data_results
                 .collect_vec()
                 .par_iter()
-                .map(|data_result| -> Vec<PlotSeries> {
-                    let mut series = vec![];
-                    self.load_series(
-                        ctx,
-                        query,
-                        plot_mem.as_ref(),
-                        time_per_pixel,
-                        data_result,
-                        &mut series,
-                    );
-                    series
-                })
+                .map(
+                    |data_result| -> (Vec<PlotSeries>, HashMap<InstancePath, Samples>) {
+                        let mut series = vec![];
+                        let mut samples = HashMap::default();
+                        self.load_series(
+                            ctx,
+                            query,
+                            plot_mem.as_ref(),
+                            time_per_pixel,
+                            data_result,
+                            referenced_series,
+                            &mut series,
+                            &mut samples,
+                        );
+                        (series, samples)
+                    },
+                )
                 .collect::<Vec<_>>()
             {
                 self.all_series.append(&mut one_series);
+                samples.extend(one_samples);
             }
         } else {
             let mut series = vec![];
@@ -153,11 +175,15 @@ impl SeriesLinesSystem {
                     plot_mem.as_ref(),
                     time_per_pixel,
                     data_result,
+                    referenced_series,
                     &mut series,
+                    &mut samples,
                 );
             }
             self.all_series = series;
         }
+
+        self.samples = samples;
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -168,7 +194,9 @@ impl SeriesLinesSystem {
         plot_mem: Option<&egui_plot::PlotMemory>,
         time_per_pixel: f64,
         data_result: &re_viewer_context::DataResult,
+        referenced_series: &HashSet<InstancePath>,
         all_series: &mut Vec<PlotSeries>,
+        samples: &mut HashMap<InstancePath, Samples>,
     ) {
         re_tracing::profile_function!();
 
@@ -216,6 +244,7 @@ impl SeriesLinesSystem {
             let default_point = PlotPoint {
                 time: 0,
                 value: 0.0,
+                envelope: None,
                 attrs: PlotPointAttrs {
                     color: fallback_color.into(),
                     radius_ui: 0.5 * *fallback_stroke_width.0,
@@ -289,6 +318,7 @@ impl SeriesLinesSystem {
                     points.extend(cleared_indices.iter().map(|(data_time, _)| PlotPoint {
                         time: data_time.as_i64(),
                         value: 0.0,
+                        envelope: None,
                         attrs: PlotPointAttrs {
                             color: egui::Color32::TRANSPARENT,
                             radius_ui: 0.0,
@@ -340,6 +370,8 @@ impl SeriesLinesSystem {
                     )
                 };
 
+                collect_referenced_samples(&instance_path, &points, referenced_series, samples);
+
                 points_to_series(
                     instance_path,
                     time_per_pixel,
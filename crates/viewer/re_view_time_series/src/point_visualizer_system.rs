@@ -1,3 +1,4 @@
+use egui::ahash::{HashMap, HashSet};
 use itertools::Itertools as _;
 
 use re_types::{
@@ -13,9 +14,11 @@ use re_viewer_context::{
 
 use crate::{
     PlotPoint, PlotPointAttrs, PlotSeries, PlotSeriesKind, ScatterAttrs,
+    derived_series::{self, Samples},
     series_query::{
         all_scalars_indices, allocate_plot_points, collect_colors, collect_radius_ui,
-        collect_scalars, collect_series_name, collect_series_visibility, determine_num_series,
+        collect_referenced_samples, collect_scalars, collect_series_name,
+        collect_series_visibility, determine_num_series,
     },
     util::{determine_time_per_pixel, determine_time_range, points_to_series},
     view_class::TimeSeriesViewState,
@@ -25,6 +28,9 @@ use crate::{
 #[derive(Default, Debug)]
 pub struct SeriesPointsSystem {
     pub all_series: Vec<PlotSeries>,
+
+    /// Samples of the series referenced by derived series, keyed by [`derived_series::samples_key`].
+    pub samples: HashMap<InstancePath, Samples>,
 }
 
 impl IdentifiedViewSystem for SeriesPointsSystem {
@@ -58,7 +64,11 @@ impl VisualizerSystem for SeriesPointsSystem {
     ) -> Result<Vec<re_renderer::QueueableDrawData>, ViewSystemExecutionError> {
         re_tracing::profile_function!();
 
-        self.load_scalars(ctx, query);
+        let referenced_series = derived_series::referenced_series(
+            &derived_series::load_definitions(ctx.viewer_ctx, query.view_id)?,
+        );
+
+        self.load_scalars(ctx, query, &referenced_series);
         Ok(Vec::new())
     }
 
@@ -113,7 +123,12 @@ impl TypedComponentFallbackProvider<SeriesVisible> for SeriesPointsSystem {
 re_viewer_context::impl_component_fallback_provider!(SeriesPointsSystem => [Color, MarkerSize, Name, SeriesVisible]);
 
 impl SeriesPointsSystem {
-    fn load_scalars(&mut self, ctx: &ViewContext<'_>, query: &ViewQuery<'_>) {
+    fn load_scalars(
+        &mut self,
+        ctx: &ViewContext<'_>,
+        query: &ViewQuery<'_>,
+        referenced_series: &HashSet<InstancePath>,
+    ) {
         re_tracing::profile_function!();
 
         let plot_mem =
@@ -122,28 +137,36 @@ impl SeriesPointsSystem {
 
         let data_results = query.iter_visible_data_results(Self::identifier());
 
+        let mut samples = HashMap::default();
+
         let parallel_loading = true;
         if parallel_loading {
             use rayon::prelude::*;
             re_tracing::profile_wait!("load_series");
-            for mut one_series in data_results
+            for (mut one_series, one_samples) in data_results
                 .collect_vec()
                 .par_iter()
-                .map(|data_result| -> Vec<PlotSeries> {
-                    let mut series = vec![];
-                    self.load_series(
-                        ctx,
-                        query,
-                        plot_mem.as_ref(),
-                        time_per_pixel,
-                        data_result,
-                        &mut series,
-                    );
-                    series
-                })
+                .map(
+                    |data_result| -> (Vec<PlotSeries>, HashMap<InstancePath, Samples>) {
+                        let mut series = vec![];
+                        let mut samples = HashMap::default();
+                        self.load_series(
+                            ctx,
+                            query,
+                            plot_mem.as_ref(),
+                            time_per_pixel,
+                            data_result,
+                            referenced_series,
+                            &mut series,
+                            &mut samples,
+                        );
+                        (series, samples)
+                    },
+                )
                 .collect::<Vec<_>>()
             {
                 self.all_series.append(&mut one_series);
+                samples.extend(one_samples);
             }
         } else {
             let mut series = vec![];
@@ -154,11 +177,15 @@ impl SeriesPointsSystem {
                     plot_mem.as_ref(),
                     time_per_pixel,
                     data_result,
+                    referenced_series,
                     &mut series,
+                    &mut samples,
                 );
             }
             self.all_series = series;
         }
+
+        self.samples = samples;
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -169,7 +196,9 @@ impl SeriesPointsSystem {
         plot_mem: Option<&egui_plot::PlotMemory>,
         time_per_pixel: f64,
         data_result: &re_viewer_context::DataResult,
+        referenced_series: &HashSet<InstancePath>,
         all_series: &mut Vec<PlotSeries>,
+        samples: &mut HashMap<InstancePath, Samples>,
     ) {
         re_tracing::profile_function!();
 
@@ -216,6 +245,7 @@ impl SeriesPointsSystem {
             let default_point = PlotPoint {
                 time: 0,
                 value: 0.0,
+                envelope: None,
                 attrs: PlotPointAttrs {
                     color: fallback_color.into(),
                     // NOTE: arguably, the `MarkerSize` value should be twice the `radius_ui`. We do
@@ -373,6 +403,8 @@ impl SeriesPointsSystem {
                     )
                 };
 
+                collect_referenced_samples(&instance_path, &points, referenced_series, samples);
+
                 points_to_series(
                     instance_path,
                     time_per_pixel,
@@ -1,15 +1,23 @@
 //! Shared functionality for querying time series data.
 
+use egui::ahash::{HashMap, HashSet};
 use itertools::Itertools as _;
 
 use re_chunk_store::RangeQuery;
-use re_log_types::{EntityPath, TimeInt};
+use re_log_types::{EntityPath, TimeInt, TimeType};
 use re_types::external::arrow::datatypes::DataType as ArrowDatatype;
 use re_types::{ComponentDescriptor, Loggable as _, RowId, components};
 use re_view::{ChunksWithDescriptor, HybridRangeResults, RangeResultsExt as _, clamped_or_nothing};
-use re_viewer_context::{QueryContext, TypedComponentFallbackProvider, auto_color_egui};
+use re_viewer_context::external::re_entity_db::InstancePath;
+use re_viewer_context::{
+    QueryContext, TypedComponentFallbackProvider, ViewContext, ViewQuery, ViewStateExt as _,
+    auto_color_egui,
+};
 
-use crate::{PlotPoint, PlotSeriesKind};
+use crate::derived_series::{self, ExpressionError, ParsedExpression, Samples};
+use crate::util::points_to_series;
+use crate::view_class::TimeSeriesViewState;
+use crate::{PlotPoint, PlotPointAttrs, PlotSeries, PlotSeriesKind};
 
 type PlotPointsPerSeries = smallvec::SmallVec<[Vec<PlotPoint>; 1]>;
 
@@ -311,6 +319,120 @@ pub fn collect_radius_ui(
     }
 }
 
+/// Keeps the samples of a logged series if a derived series references it.
+///
+/// `samples` is keyed by [`derived_series::samples_key`].
+pub fn collect_referenced_samples(
+    instance_path: &InstancePath,
+    points: &[PlotPoint],
+    referenced_series: &HashSet<InstancePath>,
+    samples: &mut HashMap<InstancePath, Samples>,
+) {
+    let samples_key = derived_series::samples_key(instance_path);
+    if referenced_series.contains(&samples_key) {
+        samples.insert(
+            samples_key,
+            points
+                .iter()
+                .filter(|point| point.attrs.kind != PlotSeriesKind::Clear)
+                .map(|point| (point.time, point.value))
+                .collect(),
+        );
+    }
+}
+
+/// Computes derived series from the samples of the series they reference.
+///
+/// `samples` is keyed by [`derived_series::samples_key`].
+/// Returns the errors of the expressions that couldn't be evaluated, keyed by expression.
+pub fn collect_derived_series(
+    ctx: &ViewContext<'_>,
+    view_query: &ViewQuery<'_>,
+    parsed_expressions: &[ParsedExpression],
+    samples: &HashMap<InstancePath, Samples>,
+    time_per_pixel: f64,
+    radius_ui: f32,
+    all_series: &mut Vec<PlotSeries>,
+) -> HashMap<String, ExpressionError> {
+    re_tracing::profile_function!();
+
+    // Derivatives are per second on temporal timelines.
+    let seconds_per_time_unit = match ctx.viewer_ctx.rec_cfg.time_ctrl.read().time_type() {
+        TimeType::Sequence => 1.0,
+        TimeType::DurationNs | TimeType::TimestampNs => 1e-9,
+    };
+    let samples_of = |instance_path: &InstancePath| {
+        samples
+            .get(&derived_series::samples_key(instance_path))
+            .map(Vec::as_slice)
+    };
+
+    let state = ctx.view_state.downcast_ref::<TimeSeriesViewState>().ok();
+    let mut errors = HashMap::default();
+
+    for parsed in parsed_expressions {
+        // Parse errors are reported by the selection panel already.
+        let Ok(definition) = &parsed.definition else {
+            continue;
+        };
+
+        let values = match definition
+            .expression
+            .evaluate(&samples_of, seconds_per_time_unit)
+        {
+            Ok(values) => values,
+            Err(err) => {
+                errors.insert(parsed.expression.clone(), err);
+                continue;
+            }
+        };
+
+        // Hovering a derived series hovers the first series it references.
+        let Some(instance_path) = definition.expression.series().first().copied().cloned() else {
+            errors.insert(parsed.expression.clone(), ExpressionError::NoSeries);
+            continue;
+        };
+
+        let color = auto_color_egui(
+            (re_log_types::hash::Hash64::hash(&definition.label).hash64() % u16::MAX as u64) as u16,
+        );
+        let points = values
+            .into_iter()
+            .map(|(time, value)| PlotPoint {
+                time,
+                value,
+                envelope: None,
+                attrs: PlotPointAttrs {
+                    color,
+                    radius_ui,
+                    kind: PlotSeriesKind::Continuous,
+                },
+            })
+            .collect();
+
+        let id = derived_series::plot_item_id(&definition.label);
+        let visible = state.is_none_or(|state| !state.hidden_derived_series.contains(&id));
+
+        let first_new_series = all_series.len();
+        points_to_series(
+            instance_path,
+            time_per_pixel,
+            visible,
+            points,
+            ctx.recording_engine().store(),
+            view_query,
+            definition.label.clone(),
+            components::AggregationPolicy::default(),
+            all_series,
+        );
+        for series in &mut all_series[first_new_series..] {
+            series.id = id;
+        }
+    }
+
+    errors
+}
+
 pub fn all_scalars_indices<'a>(
     query: &'a RangeQuery,
     all_scalar_chunks: &'a ChunksWithDescriptor<'_>,
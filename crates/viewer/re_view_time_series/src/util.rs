@@ -7,7 +7,7 @@ use re_viewer_context::{ViewQuery, ViewerContext, external::re_entity_db::Instan
 
 use crate::{
     PlotPoint, PlotSeries, PlotSeriesKind, ScatterAttrs,
-    aggregation::{AverageAggregator, MinMaxAggregator},
+    aggregation::{AverageAggregator, EnvelopeAggregator, MedianAggregator, MinMaxAggregator},
 };
 
 /// Find the number of time units per physical pixel.
@@ -117,6 +117,7 @@ pub fn points_to_series(
             radius_ui: points[0].attrs.radius_ui,
             kind,
             points: vec![(points[0].time, points[0].value)],
+            envelope: points[0].envelope.into_iter().collect(),
             instance_path,
             aggregator,
             aggregation_factor,
@@ -148,7 +149,7 @@ pub fn apply_aggregation(
     // MinMax does zig-zag between min and max, which causes a very jagged look.
     // It can be mitigated by lowering the aggregation duration, but that causes
     // a lot more work for the tessellator and renderer.
-    // TODO(#4969): output a thicker line instead of zig-zagging.
+    // `Envelope` avoids this by drawing the min-max range as a filled band instead.
     let aggregation_duration = time_per_pixel; // aggregate all points covering one physical pixel
 
     // So it can be displayed in the UI by the ViewClass.
@@ -182,6 +183,10 @@ pub fn apply_aggregation(
             AggregationPolicy::MinMaxAverage => {
                 MinMaxAggregator::MinMaxAverage.aggregate(aggregation_duration, &points)
             }
+            AggregationPolicy::Median => MedianAggregator::aggregate(aggregation_duration, &points),
+            AggregationPolicy::Envelope => {
+                EnvelopeAggregator::aggregate(aggregation_duration, &points)
+            }
         }
     } else {
         points
@@ -228,6 +233,7 @@ fn add_series_runs(
         color: attrs.color,
         radius_ui: attrs.radius_ui,
         points: Vec::with_capacity(num_points),
+        envelope: Vec::new(),
         kind: attrs.kind,
         instance_path: instance_path.clone(),
         aggregator,
@@ -240,6 +246,7 @@ fn add_series_runs(
             // Same attributes, just add to the current series.
 
             series.points.push((p.time, p.value));
+            series.envelope.extend(p.envelope);
         } else {
             // Attributes changed since last point, break up the current run into a
             // its own series, and start the next one.
@@ -255,6 +262,7 @@ fn add_series_runs(
                     radius_ui: attrs.radius_ui,
                     kind: attrs.kind,
                     points: Vec::with_capacity(num_points - i),
+                    envelope: Vec::new(),
                     instance_path: instance_path.clone(),
                     aggregator,
                     aggregation_factor,
@@ -267,6 +275,7 @@ fn add_series_runs(
 
             #[allow(clippy::unwrap_used)] // prev_series.points can't be empty here
             let prev_point = *prev_series.points.last().unwrap();
+            let prev_envelope = prev_series.envelope.last().copied();
             all_series.push(prev_series);
 
             // If the previous point was continuous and the current point is continuous
@@ -274,10 +283,12 @@ fn add_series_runs(
             // are actually split from a data standpoint.
             if cur_continuous && prev_continuous {
                 series.points.push(prev_point);
+                series.envelope.extend(prev_envelope);
             }
 
             // Add the point that triggered the split to the new segment.
             series.points.push((p.time, p.value));
+            series.envelope.extend(p.envelope);
         }
     }
 
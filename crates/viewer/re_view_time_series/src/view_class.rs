@@ -10,8 +10,8 @@ use re_types::{
     ComponentBatch as _, View as _, ViewClassIdentifier,
     archetypes::{SeriesLines, SeriesPoints},
     blueprint::{
-        archetypes::{PlotLegend, ScalarAxis, TimeAxis},
-        components::{Corner2D, LinkAxis, LockRangeDuringZoom},
+        archetypes::{DerivedSeries, PlotLegend, ScalarAxis, TimeAxis},
+        components::{Corner2D, LinkAxis, LockRangeDuringZoom, SeriesExpression},
     },
     components::{AggregationPolicy, Range1D, SeriesVisible, Visible},
     datatypes::TimeRange,
@@ -32,8 +32,12 @@ use re_viewer_context::{
 use re_viewport_blueprint::ViewProperty;
 
 use crate::{
-    PlotSeriesKind, line_visualizer_system::SeriesLinesSystem,
+    PlotSeriesKind, derived_series,
+    envelope::EnvelopeBand,
+    line_visualizer_system::{self, SeriesLinesSystem},
     point_visualizer_system::SeriesPointsSystem,
+    series_query::collect_derived_series,
+    util::determine_time_per_pixel,
 };
 
 // ---
@@ -65,6 +69,14 @@ pub struct TimeSeriesViewState {
     /// forwarded to the default providers.
     pub(crate) default_names_for_entities: HashMap<EntityPath, String>,
 
+    /// Plot item ids of the derived series hidden via the legend.
+    ///
+    /// Unlike logged series, derived series have no entity to store their visibility on.
+    pub(crate) hidden_derived_series: HashSet<egui::Id>,
+
+    /// Why derived series couldn't be computed last frame, keyed by expression.
+    derived_series_errors: HashMap<String, derived_series::ExpressionError>,
+
     /// Whether to reset the plot bounds next frame.
     reset_bounds_next_frame: bool,
 }
@@ -83,6 +95,8 @@ impl Default for TimeSeriesViewState {
             scalar_range: [0.0, 0.0].into(),
             time_offset: 0,
             default_names_for_entities: Default::default(),
+            hidden_derived_series: Default::default(),
+            derived_series_errors: Default::default(),
             reset_bounds_next_frame: false,
         }
     }
@@ -214,6 +228,21 @@ impl ViewClass for TimeSeriesView {
             view_property_ui::<ScalarAxis>(&ctx, ui, self);
         });
 
+        let parsed_expressions = derived_series::load_definitions(ctx, view_id)?;
+        ui.selection_grid("time_series_config").show(ui, |ui| {
+            ui.grid_left_hand_label("Derived series");
+            ui.vertical(|ui| {
+                derived_series_ui(
+                    ctx,
+                    ui,
+                    view_id,
+                    &parsed_expressions,
+                    &state.derived_series_errors,
+                );
+            });
+            ui.end_row();
+        });
+
         Ok(())
     }
 
@@ -407,10 +436,33 @@ impl ViewClass for TimeSeriesView {
         let line_series = system_output.view_systems.get::<SeriesLinesSystem>()?;
         let point_series = system_output.view_systems.get::<SeriesPointsSystem>()?;
 
-        let all_plot_series: Vec<_> = std::iter::empty()
+        let mut derived_series = Vec::new();
+        let derived_series_errors = {
+            let mut samples = point_series.samples.clone();
+            samples.extend(line_series.samples.clone());
+
+            let plot_mem = egui_plot::PlotMemory::load(ctx.egui_ctx(), crate::plot_id(view_id));
+            collect_derived_series(
+                &view_ctx,
+                query,
+                &derived_series::load_definitions(ctx, view_id)?,
+                &samples,
+                determine_time_per_pixel(ctx, plot_mem.as_ref()),
+                0.5 * line_visualizer_system::DEFAULT_STROKE_WIDTH,
+                &mut derived_series,
+            )
+        };
+        state.derived_series_errors = derived_series_errors;
+
+        let logged_plot_series: Vec<_> = std::iter::empty()
             .chain(line_series.all_series.iter())
             .chain(point_series.all_series.iter())
             .collect();
+        let all_plot_series: Vec<_> = logged_plot_series
+            .iter()
+            .copied()
+            .chain(derived_series.iter())
+            .collect();
 
         // Note that a several plot items can point to the same entity path and in some cases even to the same instance path!
         // (e.g. when plotting both lines & points with the same entity/instance path)
@@ -560,7 +612,7 @@ impl ViewClass for TimeSeriesView {
 
             // Needed by for the visualizers' fallback provider.
             state.default_names_for_entities = EntityPath::short_names_with_disambiguation(
-                all_plot_series
+                logged_plot_series
                     .iter()
                     .map(|series| series.instance_path.entity_path.clone())
                     // `short_names_with_disambiguation` expects no duplicate entities
@@ -644,10 +696,11 @@ impl ViewClass for TimeSeriesView {
         update_series_visibility_overrides_from_plot(
             ctx,
             query,
-            &all_plot_series,
+            &logged_plot_series,
             ui.ctx(),
             plot_id,
         );
+        update_derived_series_visibility_from_plot(state, &derived_series, ui.ctx(), plot_id);
 
         if let Some(mut time_x) = time_x {
             let interact_radius = ui.style().interaction.resize_grab_radius_side;
@@ -779,6 +832,95 @@ fn update_series_visibility_overrides_from_plot(
     }
 }
 
+fn update_derived_series_visibility_from_plot(
+    state: &mut TimeSeriesViewState,
+    derived_series: &[crate::PlotSeries],
+    egui_ctx: &egui::Context,
+    plot_id: egui::Id,
+) {
+    let Some(plot_memory) = egui_plot::PlotMemory::load(egui_ctx, plot_id) else {
+        return;
+    };
+
+    state.hidden_derived_series = derived_series
+        .iter()
+        .map(|series| series.id)
+        .filter(|id| plot_memory.hidden_items.contains(id))
+        .collect();
+}
+
+/// Lists the expressions of the [`DerivedSeries`] view property for editing, along with any
+/// parse or evaluation errors.
+fn derived_series_ui(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    view_id: ViewId,
+    parsed_expressions: &[derived_series::ParsedExpression],
+    evaluation_errors: &HashMap<String, derived_series::ExpressionError>,
+) {
+    let mut expressions = parsed_expressions
+        .iter()
+        .map(|parsed| parsed.expression.clone())
+        .collect::<Vec<_>>();
+    let mut changed = false;
+    let mut remove = None;
+
+    for (index, (expression, parsed)) in expressions.iter_mut().zip(parsed_expressions).enumerate()
+    {
+        ui.horizontal(|ui| {
+            changed |= ui
+                .add(egui::TextEdit::singleline(expression).hint_text("label = d/dt(/path)"))
+                .changed();
+            if ui
+                .small_icon_button(&icons::REMOVE, "Remove")
+                .on_hover_text("Remove this derived series")
+                .clicked()
+            {
+                remove = Some(index);
+            }
+        });
+
+        let error = match &parsed.definition {
+            Ok(_) => evaluation_errors.get(&parsed.expression),
+            Err(err) => Some(err),
+        };
+        if let Some(err) = error {
+            ui.error_label(err.to_string());
+        }
+    }
+
+    if ui
+        .small_icon_button(&icons::ADD, "Add")
+        .on_hover_text("Add a series computed from other series, e.g. `d/dt(/robot/position)`")
+        .clicked()
+    {
+        expressions.push(String::new());
+        changed = true;
+    }
+
+    if let Some(index) = remove {
+        expressions.remove(index);
+        changed = true;
+    }
+
+    if changed {
+        let expressions = expressions
+            .into_iter()
+            .map(SeriesExpression::from)
+            .collect::<Vec<_>>();
+        let property = ViewProperty::from_archetype::<DerivedSeries>(
+            ctx.blueprint_db(),
+            ctx.blueprint_query,
+            view_id,
+        );
+        property.save_blueprint_component(
+            ctx,
+            &DerivedSeries::descriptor_expressions(),
+            &expressions,
+        );
+    }
+}
+
 fn add_series_to_plot(
     plot_ui: &mut egui_plot::PlotUi<'_>,
     highlights: &ViewHighlights,
@@ -826,13 +968,19 @@ fn add_series_to_plot(
         let highlight = interaction_highlight.any();
 
         match series.kind {
-            PlotSeriesKind::Continuous => plot_ui.line(
-                Line::new(&series.label, points)
-                    .color(color)
-                    .width(2.0 * series.radius_ui)
-                    .highlight(highlight)
-                    .id(series.id),
-            ),
+            PlotSeriesKind::Continuous => {
+                if series.visible {
+                    add_envelope_to_plot(plot_ui, series, highlight, time_offset, scalar_range);
+                }
+
+                plot_ui.line(
+                    Line::new(&series.label, points)
+                        .color(color)
+                        .width(2.0 * series.radius_ui)
+                        .highlight(highlight)
+                        .id(series.id),
+                );
+            }
             PlotSeriesKind::Scatter(scatter_attrs) => plot_ui.points(
                 Points::new(&series.label, points)
                     .color(color)
@@ -847,6 +995,41 @@ fn add_series_to_plot(
     }
 }
 
+/// Draws the [`crate::PlotSeries::envelope`] as a shaded band behind the series' line.
+fn add_envelope_to_plot(
+    plot_ui: &mut egui_plot::PlotUi<'_>,
+    series: &crate::PlotSeries,
+    highlight: bool,
+    time_offset: i64,
+    scalar_range: &mut Range1D,
+) {
+    if series.envelope.len() != series.points.len() {
+        return;
+    }
+
+    let fill_color = series
+        .color
+        .gamma_multiply(if highlight { 0.5 } else { 0.25 });
+
+    for &(min, max) in &series.envelope {
+        *scalar_range.start_mut() = scalar_range.start().min(min);
+        *scalar_range.end_mut() = scalar_range.end().max(max);
+    }
+
+    let steps = series
+        .points
+        .iter()
+        .zip(&series.envelope)
+        .map(|(&(time, _), &(min, max))| ((time - time_offset) as f64, min, max))
+        .collect();
+
+    // Shares the series' name and id, so that it shows up and gets toggled with the series' legend entry.
+    plot_ui.add(
+        EnvelopeBand::new(&series.label, series.id, series.color, fill_color, steps)
+            .highlight(highlight),
+    );
+}
+
 fn format_y_axis(mark: egui_plot::GridMark) -> String {
     // Example: If the step to the next tick is `0.01`, we should use 2 decimals of precision:
     let num_decimals = -mark.step_size.log10().round() as usize;
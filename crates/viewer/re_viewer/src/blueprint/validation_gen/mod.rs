@@ -17,12 +17,14 @@ pub use re_types::blueprint::components::Enabled;
 pub use re_types::blueprint::components::Eye3DKind;
 pub use re_types::blueprint::components::FilterByRange;
 pub use re_types::blueprint::components::FilterIsNotNull;
+pub use re_types::blueprint::components::FilterIsNotNullMode;
 pub use re_types::blueprint::components::ForceDistance;
 pub use re_types::blueprint::components::ForceIterations;
 pub use re_types::blueprint::components::ForceStrength;
 pub use re_types::blueprint::components::GridColumns;
 pub use re_types::blueprint::components::GridSpacing;
 pub use re_types::blueprint::components::IncludedContent;
+pub use re_types::blueprint::components::LatestAtScope;
 pub use re_types::blueprint::components::LinkAxis;
 pub use re_types::blueprint::components::LockRangeDuringZoom;
 pub use re_types::blueprint::components::MapProvider;
@@ -32,7 +34,10 @@ pub use re_types::blueprint::components::QueryExpression;
 pub use re_types::blueprint::components::RootContainer;
 pub use re_types::blueprint::components::RowShare;
 pub use re_types::blueprint::components::SelectedColumns;
+pub use re_types::blueprint::components::SeriesExpression;
 pub use re_types::blueprint::components::TensorDimensionIndexSlider;
+pub use re_types::blueprint::components::TextFilterMode;
+pub use re_types::blueprint::components::TextLogColumn;
 pub use re_types::blueprint::components::TimelineName;
 pub use re_types::blueprint::components::ViewClass;
 pub use re_types::blueprint::components::ViewFit;
@@ -61,12 +66,14 @@ pub fn is_valid_blueprint(blueprint: &EntityDb) -> bool {
         && validate_component::<Eye3DKind>(blueprint)
         && validate_component::<FilterByRange>(blueprint)
         && validate_component::<FilterIsNotNull>(blueprint)
+        && validate_component::<FilterIsNotNullMode>(blueprint)
         && validate_component::<ForceDistance>(blueprint)
         && validate_component::<ForceIterations>(blueprint)
         && validate_component::<ForceStrength>(blueprint)
         && validate_component::<GridColumns>(blueprint)
         && validate_component::<GridSpacing>(blueprint)
         && validate_component::<IncludedContent>(blueprint)
+        && validate_component::<LatestAtScope>(blueprint)
         && validate_component::<LinkAxis>(blueprint)
         && validate_component::<LockRangeDuringZoom>(blueprint)
         && validate_component::<MapProvider>(blueprint)
@@ -76,7 +83,10 @@ pub fn is_valid_blueprint(blueprint: &EntityDb) -> bool {
         && validate_component::<RootContainer>(blueprint)
         && validate_component::<RowShare>(blueprint)
         && validate_component::<SelectedColumns>(blueprint)
+        && validate_component::<SeriesExpression>(blueprint)
         && validate_component::<TensorDimensionIndexSlider>(blueprint)
+        && validate_component::<TextFilterMode>(blueprint)
+        && validate_component::<TextLogColumn>(blueprint)
         && validate_component::<TimelineName>(blueprint)
         && validate_component::<ViewClass>(blueprint)
         && validate_component::<ViewFit>(blueprint)
@@ -26,7 +26,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // There are other ways of "feeding" the viewer though - all you need is a `re_smart_channel::Receiver`.
     let (rx_log, rx_table) = re_grpc_server::spawn_with_recv(
         "0.0.0.0:9877".parse()?,
-        "75%".parse()?,
+        re_grpc_server::ServerOptions {
+            memory_limit: "75%".parse()?,
+            ..Default::default()
+        },
         re_grpc_server::shutdown::never(),
     );
 
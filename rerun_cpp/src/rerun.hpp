@@ -45,6 +45,7 @@ namespace rerun {
     using components::Position3D;
     using components::Radius;
     using components::Text;
+    using components::TextLogField;
     using components::TextLogLevel;
     using components::TransformRelation;
     using components::TriangleIndices;
@@ -20,7 +20,7 @@
 namespace rerun::archetypes {
     /// **Archetype**: A video binary.
     ///
-    /// Only MP4 containers are currently supported.
+    /// Supported are MP4 and Matroska/WebM containers, as well as raw H.264/H.265 Annex B bitstreams.
     ///
     /// See <https://rerun.io/docs/reference/video> for codec support and more general information.
     ///
@@ -114,6 +114,10 @@ namespace rerun::archetypes {
         ///
         /// Supported values:
         /// * `video/mp4`
+        /// * `video/x-matroska`
+        /// * `video/webm`
+        /// * `video/h264`
+        /// * `video/h265`
         ///
         /// If omitted, the viewer will try to guess from the data blob.
         /// If it cannot guess, it won't be able to render the asset.
@@ -210,6 +214,10 @@ namespace rerun::archetypes {
         ///
         /// Supported values:
         /// * `video/mp4`
+        /// * `video/x-matroska`
+        /// * `video/webm`
+        /// * `video/h264`
+        /// * `video/h265`
         ///
         /// If omitted, the viewer will try to guess from the data blob.
         /// If it cannot guess, it won't be able to render the asset.
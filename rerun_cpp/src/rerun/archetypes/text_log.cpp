@@ -14,12 +14,14 @@ namespace rerun::archetypes {
                               .value_or_throw();
         archetype.color =
             ComponentBatch::empty<rerun::components::Color>(Descriptor_color).value_or_throw();
+        archetype.fields = ComponentBatch::empty<rerun::components::TextLogField>(Descriptor_fields)
+                               .value_or_throw();
         return archetype;
     }
 
     Collection<ComponentColumn> TextLog::columns(const Collection<uint32_t>& lengths_) {
         std::vector<ComponentColumn> columns;
-        columns.reserve(3);
+        columns.reserve(4);
         if (text.has_value()) {
             columns.push_back(text.value().partitioned(lengths_).value_or_throw());
         }
@@ -29,6 +31,9 @@ namespace rerun::archetypes {
         if (color.has_value()) {
             columns.push_back(color.value().partitioned(lengths_).value_or_throw());
         }
+        if (fields.has_value()) {
+            columns.push_back(fields.value().partitioned(lengths_).value_or_throw());
+        }
         return columns;
     }
 
@@ -42,6 +47,9 @@ namespace rerun::archetypes {
         if (color.has_value()) {
             return columns(std::vector<uint32_t>(color.value().length(), 1));
         }
+        if (fields.has_value()) {
+            return columns(std::vector<uint32_t>(fields.value().length(), 1));
+        }
         return Collection<ComponentColumn>();
     }
 } // namespace rerun::archetypes
@@ -53,7 +61,7 @@ namespace rerun {
     ) {
         using namespace archetypes;
         std::vector<ComponentBatch> cells;
-        cells.reserve(3);
+        cells.reserve(4);
 
         if (archetype.text.has_value()) {
             cells.push_back(archetype.text.value());
@@ -64,6 +72,9 @@ namespace rerun {
         if (archetype.color.has_value()) {
             cells.push_back(archetype.color.value());
         }
+        if (archetype.fields.has_value()) {
+            cells.push_back(archetype.fields.value());
+        }
 
         return rerun::take_ownership(std::move(cells));
     }
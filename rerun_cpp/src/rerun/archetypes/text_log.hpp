@@ -8,6 +8,7 @@
 #include "../component_column.hpp"
 #include "../components/color.hpp"
 #include "../components/text.hpp"
+#include "../components/text_log_field.hpp"
 #include "../components/text_log_level.hpp"
 #include "../result.hpp"
 
@@ -89,6 +90,11 @@ namespace rerun::archetypes {
         /// Optional color to use for the log line in the Rerun Viewer.
         std::optional<ComponentBatch> color;
 
+        /// Optional structured key-value fields attached to the message.
+        ///
+        /// These are shown as extra columns in the Rerun Viewer.
+        std::optional<ComponentBatch> fields;
+
       public:
         /// The name of the archetype as used in `ComponentDescriptor`s.
         static constexpr const char ArchetypeName[] = "rerun.archetypes.TextLog";
@@ -105,6 +111,11 @@ namespace rerun::archetypes {
         static constexpr auto Descriptor_color = ComponentDescriptor(
             ArchetypeName, "TextLog:color", Loggable<rerun::components::Color>::ComponentType
         );
+        /// `ComponentDescriptor` for the `fields` field.
+        static constexpr auto Descriptor_fields = ComponentDescriptor(
+            ArchetypeName, "TextLog:fields",
+            Loggable<rerun::components::TextLogField>::ComponentType
+        );
 
       public:
         TextLog() = default;
@@ -172,6 +183,14 @@ namespace rerun::archetypes {
             return std::move(*this);
         }
 
+        /// Optional structured key-value fields attached to the message.
+        ///
+        /// These are shown as extra columns in the Rerun Viewer.
+        TextLog with_fields(const Collection<rerun::components::TextLogField>& _fields) && {
+            fields = ComponentBatch::from_loggable(_fields, Descriptor_fields).value_or_throw();
+            return std::move(*this);
+        }
+
         /// Partitions the component data into multiple sub-batches.
         ///
         /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
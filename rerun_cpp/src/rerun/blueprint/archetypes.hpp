@@ -5,6 +5,7 @@
 #include "blueprint/archetypes/background.hpp"
 #include "blueprint/archetypes/container_blueprint.hpp"
 #include "blueprint/archetypes/dataframe_query.hpp"
+#include "blueprint/archetypes/derived_series.hpp"
 #include "blueprint/archetypes/entity_behavior.hpp"
 #include "blueprint/archetypes/eye_controls3d.hpp"
 #include "blueprint/archetypes/force_center.hpp"
@@ -22,6 +23,8 @@
 #include "blueprint/archetypes/tensor_scalar_mapping.hpp"
 #include "blueprint/archetypes/tensor_slice_selection.hpp"
 #include "blueprint/archetypes/tensor_view_fit.hpp"
+#include "blueprint/archetypes/text_log_columns.hpp"
+#include "blueprint/archetypes/text_log_filter.hpp"
 #include "blueprint/archetypes/time_axis.hpp"
 #include "blueprint/archetypes/view_blueprint.hpp"
 #include "blueprint/archetypes/view_contents.hpp"
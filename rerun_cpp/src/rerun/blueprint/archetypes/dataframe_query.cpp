@@ -21,11 +21,21 @@ namespace rerun::blueprint::archetypes {
                 Descriptor_filter_is_not_null
             )
                 .value_or_throw();
+        archetype.filter_is_not_null_mode =
+            ComponentBatch::empty<rerun::blueprint::components::FilterIsNotNullMode>(
+                Descriptor_filter_is_not_null_mode
+            )
+                .value_or_throw();
         archetype.apply_latest_at =
             ComponentBatch::empty<rerun::blueprint::components::ApplyLatestAt>(
                 Descriptor_apply_latest_at
             )
                 .value_or_throw();
+        archetype.latest_at_scope =
+            ComponentBatch::empty<rerun::blueprint::components::LatestAtScope>(
+                Descriptor_latest_at_scope
+            )
+                .value_or_throw();
         archetype.select =
             ComponentBatch::empty<rerun::blueprint::components::SelectedColumns>(Descriptor_select)
                 .value_or_throw();
@@ -34,7 +44,7 @@ namespace rerun::blueprint::archetypes {
 
     Collection<ComponentColumn> DataframeQuery::columns(const Collection<uint32_t>& lengths_) {
         std::vector<ComponentColumn> columns;
-        columns.reserve(5);
+        columns.reserve(7);
         if (timeline.has_value()) {
             columns.push_back(timeline.value().partitioned(lengths_).value_or_throw());
         }
@@ -44,9 +54,17 @@ namespace rerun::blueprint::archetypes {
         if (filter_is_not_null.has_value()) {
             columns.push_back(filter_is_not_null.value().partitioned(lengths_).value_or_throw());
         }
+        if (filter_is_not_null_mode.has_value()) {
+            columns.push_back(
+                filter_is_not_null_mode.value().partitioned(lengths_).value_or_throw()
+            );
+        }
         if (apply_latest_at.has_value()) {
             columns.push_back(apply_latest_at.value().partitioned(lengths_).value_or_throw());
         }
+        if (latest_at_scope.has_value()) {
+            columns.push_back(latest_at_scope.value().partitioned(lengths_).value_or_throw());
+        }
         if (select.has_value()) {
             columns.push_back(select.value().partitioned(lengths_).value_or_throw());
         }
@@ -63,9 +81,15 @@ namespace rerun::blueprint::archetypes {
         if (filter_is_not_null.has_value()) {
             return columns(std::vector<uint32_t>(filter_is_not_null.value().length(), 1));
         }
+        if (filter_is_not_null_mode.has_value()) {
+            return columns(std::vector<uint32_t>(filter_is_not_null_mode.value().length(), 1));
+        }
         if (apply_latest_at.has_value()) {
             return columns(std::vector<uint32_t>(apply_latest_at.value().length(), 1));
         }
+        if (latest_at_scope.has_value()) {
+            return columns(std::vector<uint32_t>(latest_at_scope.value().length(), 1));
+        }
         if (select.has_value()) {
             return columns(std::vector<uint32_t>(select.value().length(), 1));
         }
@@ -81,7 +105,7 @@ namespace rerun {
         ) {
         using namespace blueprint::archetypes;
         std::vector<ComponentBatch> cells;
-        cells.reserve(5);
+        cells.reserve(7);
 
         if (archetype.timeline.has_value()) {
             cells.push_back(archetype.timeline.value());
@@ -92,9 +116,15 @@ namespace rerun {
         if (archetype.filter_is_not_null.has_value()) {
             cells.push_back(archetype.filter_is_not_null.value());
         }
+        if (archetype.filter_is_not_null_mode.has_value()) {
+            cells.push_back(archetype.filter_is_not_null_mode.value());
+        }
         if (archetype.apply_latest_at.has_value()) {
             cells.push_back(archetype.apply_latest_at.value());
         }
+        if (archetype.latest_at_scope.has_value()) {
+            cells.push_back(archetype.latest_at_scope.value());
+        }
         if (archetype.select.has_value()) {
             cells.push_back(archetype.select.value());
         }
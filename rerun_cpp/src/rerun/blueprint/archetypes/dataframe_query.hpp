@@ -6,6 +6,8 @@
 #include "../../blueprint/components/apply_latest_at.hpp"
 #include "../../blueprint/components/filter_by_range.hpp"
 #include "../../blueprint/components/filter_is_not_null.hpp"
+#include "../../blueprint/components/filter_is_not_null_mode.hpp"
+#include "../../blueprint/components/latest_at_scope.hpp"
 #include "../../blueprint/components/selected_columns.hpp"
 #include "../../blueprint/components/timeline_name.hpp"
 #include "../../collection.hpp"
@@ -34,12 +36,24 @@ namespace rerun::blueprint::archetypes {
         /// Note: will be unset as soon as `timeline` is changed.
         std::optional<ComponentBatch> filter_by_range;
 
-        /// If provided, only show rows which contains a logged event for the specified component.
+        /// If provided, only show rows which contains a logged event for the specified components.
+        ///
+        /// See `filter_is_not_null_mode` for how multiple components are combined.
         std::optional<ComponentBatch> filter_is_not_null;
 
+        /// Whether rows need a logged event for any or all of the components of `filter_is_not_null`.
+        ///
+        /// If unset, rows with a logged event for any of the components are shown.
+        std::optional<ComponentBatch> filter_is_not_null_mode;
+
         /// Should empty cells be filled with latest-at queries?
         std::optional<ComponentBatch> apply_latest_at;
 
+        /// Which data should empty cells be filled from, if `apply_latest_at` is enabled?
+        ///
+        /// If unset, the latest value in the entire recording is used.
+        std::optional<ComponentBatch> latest_at_scope;
+
         /// Selected columns. If unset, all columns are selected.
         std::optional<ComponentBatch> select;
 
@@ -62,11 +76,21 @@ namespace rerun::blueprint::archetypes {
             ArchetypeName, "DataframeQuery:filter_is_not_null",
             Loggable<rerun::blueprint::components::FilterIsNotNull>::ComponentType
         );
+        /// `ComponentDescriptor` for the `filter_is_not_null_mode` field.
+        static constexpr auto Descriptor_filter_is_not_null_mode = ComponentDescriptor(
+            ArchetypeName, "DataframeQuery:filter_is_not_null_mode",
+            Loggable<rerun::blueprint::components::FilterIsNotNullMode>::ComponentType
+        );
         /// `ComponentDescriptor` for the `apply_latest_at` field.
         static constexpr auto Descriptor_apply_latest_at = ComponentDescriptor(
             ArchetypeName, "DataframeQuery:apply_latest_at",
             Loggable<rerun::blueprint::components::ApplyLatestAt>::ComponentType
         );
+        /// `ComponentDescriptor` for the `latest_at_scope` field.
+        static constexpr auto Descriptor_latest_at_scope = ComponentDescriptor(
+            ArchetypeName, "DataframeQuery:latest_at_scope",
+            Loggable<rerun::blueprint::components::LatestAtScope>::ComponentType
+        );
         /// `ComponentDescriptor` for the `select` field.
         static constexpr auto Descriptor_select = ComponentDescriptor(
             ArchetypeName, "DataframeQuery:select",
@@ -110,9 +134,11 @@ namespace rerun::blueprint::archetypes {
             return std::move(*this);
         }
 
-        /// If provided, only show rows which contains a logged event for the specified component.
+        /// If provided, only show rows which contains a logged event for the specified components.
+        ///
+        /// See `filter_is_not_null_mode` for how multiple components are combined.
         DataframeQuery with_filter_is_not_null(
-            const rerun::blueprint::components::FilterIsNotNull& _filter_is_not_null
+            const Collection<rerun::blueprint::components::FilterIsNotNull>& _filter_is_not_null
         ) && {
             filter_is_not_null =
                 ComponentBatch::from_loggable(_filter_is_not_null, Descriptor_filter_is_not_null)
@@ -120,6 +146,20 @@ namespace rerun::blueprint::archetypes {
             return std::move(*this);
         }
 
+        /// Whether rows need a logged event for any or all of the components of `filter_is_not_null`.
+        ///
+        /// If unset, rows with a logged event for any of the components are shown.
+        DataframeQuery with_filter_is_not_null_mode(
+            const rerun::blueprint::components::FilterIsNotNullMode& _filter_is_not_null_mode
+        ) && {
+            filter_is_not_null_mode = ComponentBatch::from_loggable(
+                                          _filter_is_not_null_mode,
+                                          Descriptor_filter_is_not_null_mode
+            )
+                                          .value_or_throw();
+            return std::move(*this);
+        }
+
         /// Should empty cells be filled with latest-at queries?
         DataframeQuery with_apply_latest_at(
             const rerun::blueprint::components::ApplyLatestAt& _apply_latest_at
@@ -130,6 +170,18 @@ namespace rerun::blueprint::archetypes {
             return std::move(*this);
         }
 
+        /// Which data should empty cells be filled from, if `apply_latest_at` is enabled?
+        ///
+        /// If unset, the latest value in the entire recording is used.
+        DataframeQuery with_latest_at_scope(
+            const rerun::blueprint::components::LatestAtScope& _latest_at_scope
+        ) && {
+            latest_at_scope =
+                ComponentBatch::from_loggable(_latest_at_scope, Descriptor_latest_at_scope)
+                    .value_or_throw();
+            return std::move(*this);
+        }
+
         /// Selected columns. If unset, all columns are selected.
         DataframeQuery with_select(const rerun::blueprint::components::SelectedColumns& _select
         ) && {
@@ -0,0 +1,51 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/derived_series.fbs".
+
+#include "derived_series.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    DerivedSeries DerivedSeries::clear_fields() {
+        auto archetype = DerivedSeries();
+        archetype.expressions = ComponentBatch::empty<rerun::blueprint::components::SeriesExpression>(
+                                    Descriptor_expressions
+        )
+                                    .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> DerivedSeries::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(1);
+        if (expressions.has_value()) {
+            columns.push_back(expressions.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> DerivedSeries::columns() {
+        if (expressions.has_value()) {
+            return columns(std::vector<uint32_t>(expressions.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::DerivedSeries>::as_batches(
+            const blueprint::archetypes::DerivedSeries& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(1);
+
+        if (archetype.expressions.has_value()) {
+            cells.push_back(archetype.expressions.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
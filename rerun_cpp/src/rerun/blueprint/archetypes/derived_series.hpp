@@ -0,0 +1,92 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/derived_series.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/series_expression.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Series of a time series view that are computed from other series, rather than logged.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct DerivedSeries {
+        /// The expressions of the derived series, one series per expression.
+        std::optional<ComponentBatch> expressions;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.DerivedSeries";
+
+        /// `ComponentDescriptor` for the `expressions` field.
+        static constexpr auto Descriptor_expressions = ComponentDescriptor(
+            ArchetypeName, "DerivedSeries:expressions",
+            Loggable<rerun::blueprint::components::SeriesExpression>::ComponentType
+        );
+
+      public:
+        DerivedSeries() = default;
+        DerivedSeries(DerivedSeries&& other) = default;
+        DerivedSeries(const DerivedSeries& other) = default;
+        DerivedSeries& operator=(const DerivedSeries& other) = default;
+        DerivedSeries& operator=(DerivedSeries&& other) = default;
+
+        /// Update only some specific fields of a `DerivedSeries`.
+        static DerivedSeries update_fields() {
+            return DerivedSeries();
+        }
+
+        /// Clear all the fields of a `DerivedSeries`.
+        static DerivedSeries clear_fields();
+
+        /// The expressions of the derived series, one series per expression.
+        DerivedSeries with_expressions(
+            const Collection<rerun::blueprint::components::SeriesExpression>& _expressions
+        ) && {
+            expressions =
+                ComponentBatch::from_loggable(_expressions, Descriptor_expressions).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::DerivedSeries> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::DerivedSeries& archetype
+        );
+    };
+} // namespace rerun
@@ -0,0 +1,62 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/text_log_columns.fbs".
+
+#include "text_log_columns.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    TextLogColumns TextLogColumns::clear_fields() {
+        auto archetype = TextLogColumns();
+        archetype.order =
+            ComponentBatch::empty<rerun::blueprint::components::TextLogColumn>(Descriptor_order)
+                .value_or_throw();
+        archetype.hidden =
+            ComponentBatch::empty<rerun::blueprint::components::TextLogColumn>(Descriptor_hidden)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> TextLogColumns::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(2);
+        if (order.has_value()) {
+            columns.push_back(order.value().partitioned(lengths_).value_or_throw());
+        }
+        if (hidden.has_value()) {
+            columns.push_back(hidden.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> TextLogColumns::columns() {
+        if (order.has_value()) {
+            return columns(std::vector<uint32_t>(order.value().length(), 1));
+        }
+        if (hidden.has_value()) {
+            return columns(std::vector<uint32_t>(hidden.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::TextLogColumns>::as_batches(
+            const blueprint::archetypes::TextLogColumns& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(2);
+
+        if (archetype.order.has_value()) {
+            cells.push_back(archetype.order.value());
+        }
+        if (archetype.hidden.has_value()) {
+            cells.push_back(archetype.hidden.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
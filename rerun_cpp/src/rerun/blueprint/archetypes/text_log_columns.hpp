@@ -0,0 +1,115 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/text_log_columns.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/text_log_column.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Configuration for the columns of a text log view.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct TextLogColumns {
+        /// The order in which columns are shown.
+        ///
+        /// Columns that aren't listed are shown after the listed ones, in their default order.
+        std::optional<ComponentBatch> order;
+
+        /// Columns that aren't shown.
+        ///
+        /// By default, all columns are shown.
+        std::optional<ComponentBatch> hidden;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.TextLogColumns";
+
+        /// `ComponentDescriptor` for the `order` field.
+        static constexpr auto Descriptor_order = ComponentDescriptor(
+            ArchetypeName, "TextLogColumns:order",
+            Loggable<rerun::blueprint::components::TextLogColumn>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `hidden` field.
+        static constexpr auto Descriptor_hidden = ComponentDescriptor(
+            ArchetypeName, "TextLogColumns:hidden",
+            Loggable<rerun::blueprint::components::TextLogColumn>::ComponentType
+        );
+
+      public:
+        TextLogColumns() = default;
+        TextLogColumns(TextLogColumns&& other) = default;
+        TextLogColumns(const TextLogColumns& other) = default;
+        TextLogColumns& operator=(const TextLogColumns& other) = default;
+        TextLogColumns& operator=(TextLogColumns&& other) = default;
+
+        /// Update only some specific fields of a `TextLogColumns`.
+        static TextLogColumns update_fields() {
+            return TextLogColumns();
+        }
+
+        /// Clear all the fields of a `TextLogColumns`.
+        static TextLogColumns clear_fields();
+
+        /// The order in which columns are shown.
+        ///
+        /// Columns that aren't listed are shown after the listed ones, in their default order.
+        TextLogColumns with_order(
+            const Collection<rerun::blueprint::components::TextLogColumn>& _order
+        ) && {
+            order = ComponentBatch::from_loggable(_order, Descriptor_order).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Columns that aren't shown.
+        ///
+        /// By default, all columns are shown.
+        TextLogColumns with_hidden(
+            const Collection<rerun::blueprint::components::TextLogColumn>& _hidden
+        ) && {
+            hidden = ComponentBatch::from_loggable(_hidden, Descriptor_hidden).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::TextLogColumns> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::TextLogColumns& archetype
+        );
+    };
+} // namespace rerun
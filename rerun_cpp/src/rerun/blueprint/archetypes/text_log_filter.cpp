@@ -0,0 +1,72 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/text_log_filter.fbs".
+
+#include "text_log_filter.hpp"
+
+#include "../../collection_adapter_builtins.hpp"
+
+namespace rerun::blueprint::archetypes {
+    TextLogFilter TextLogFilter::clear_fields() {
+        auto archetype = TextLogFilter();
+        archetype.body =
+            ComponentBatch::empty<rerun::components::Text>(Descriptor_body).value_or_throw();
+        archetype.entity_path =
+            ComponentBatch::empty<rerun::components::Text>(Descriptor_entity_path).value_or_throw();
+        archetype.mode =
+            ComponentBatch::empty<rerun::blueprint::components::TextFilterMode>(Descriptor_mode)
+                .value_or_throw();
+        return archetype;
+    }
+
+    Collection<ComponentColumn> TextLogFilter::columns(const Collection<uint32_t>& lengths_) {
+        std::vector<ComponentColumn> columns;
+        columns.reserve(3);
+        if (body.has_value()) {
+            columns.push_back(body.value().partitioned(lengths_).value_or_throw());
+        }
+        if (entity_path.has_value()) {
+            columns.push_back(entity_path.value().partitioned(lengths_).value_or_throw());
+        }
+        if (mode.has_value()) {
+            columns.push_back(mode.value().partitioned(lengths_).value_or_throw());
+        }
+        return columns;
+    }
+
+    Collection<ComponentColumn> TextLogFilter::columns() {
+        if (body.has_value()) {
+            return columns(std::vector<uint32_t>(body.value().length(), 1));
+        }
+        if (entity_path.has_value()) {
+            return columns(std::vector<uint32_t>(entity_path.value().length(), 1));
+        }
+        if (mode.has_value()) {
+            return columns(std::vector<uint32_t>(mode.value().length(), 1));
+        }
+        return Collection<ComponentColumn>();
+    }
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+
+    Result<Collection<ComponentBatch>>
+        AsComponents<blueprint::archetypes::TextLogFilter>::as_batches(
+            const blueprint::archetypes::TextLogFilter& archetype
+        ) {
+        using namespace blueprint::archetypes;
+        std::vector<ComponentBatch> cells;
+        cells.reserve(3);
+
+        if (archetype.body.has_value()) {
+            cells.push_back(archetype.body.value());
+        }
+        if (archetype.entity_path.has_value()) {
+            cells.push_back(archetype.entity_path.value());
+        }
+        if (archetype.mode.has_value()) {
+            cells.push_back(archetype.mode.value());
+        }
+
+        return rerun::take_ownership(std::move(cells));
+    }
+} // namespace rerun
@@ -0,0 +1,130 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/text_log_filter.fbs".
+
+#pragma once
+
+#include "../../blueprint/components/text_filter_mode.hpp"
+#include "../../collection.hpp"
+#include "../../component_batch.hpp"
+#include "../../component_column.hpp"
+#include "../../components/text.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <utility>
+#include <vector>
+
+namespace rerun::blueprint::archetypes {
+    /// **Archetype**: Configuration for filtering the entries of a text log view.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct TextLogFilter {
+        /// Only entries whose body matches this filter are shown.
+        ///
+        /// If unset or empty, entries aren't filtered by their body.
+        std::optional<ComponentBatch> body;
+
+        /// Only entries whose entity path matches this filter are shown.
+        ///
+        /// If unset or empty, entries aren't filtered by their entity path.
+        std::optional<ComponentBatch> entity_path;
+
+        /// How the filters are matched.
+        ///
+        /// Defaults to case-insensitive substring matching.
+        std::optional<ComponentBatch> mode;
+
+      public:
+        /// The name of the archetype as used in `ComponentDescriptor`s.
+        static constexpr const char ArchetypeName[] = "rerun.blueprint.archetypes.TextLogFilter";
+
+        /// `ComponentDescriptor` for the `body` field.
+        static constexpr auto Descriptor_body = ComponentDescriptor(
+            ArchetypeName, "TextLogFilter:body", Loggable<rerun::components::Text>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `entity_path` field.
+        static constexpr auto Descriptor_entity_path = ComponentDescriptor(
+            ArchetypeName, "TextLogFilter:entity_path",
+            Loggable<rerun::components::Text>::ComponentType
+        );
+        /// `ComponentDescriptor` for the `mode` field.
+        static constexpr auto Descriptor_mode = ComponentDescriptor(
+            ArchetypeName, "TextLogFilter:mode",
+            Loggable<rerun::blueprint::components::TextFilterMode>::ComponentType
+        );
+
+      public:
+        TextLogFilter() = default;
+        TextLogFilter(TextLogFilter&& other) = default;
+        TextLogFilter(const TextLogFilter& other) = default;
+        TextLogFilter& operator=(const TextLogFilter& other) = default;
+        TextLogFilter& operator=(TextLogFilter&& other) = default;
+
+        /// Update only some specific fields of a `TextLogFilter`.
+        static TextLogFilter update_fields() {
+            return TextLogFilter();
+        }
+
+        /// Clear all the fields of a `TextLogFilter`.
+        static TextLogFilter clear_fields();
+
+        /// Only entries whose body matches this filter are shown.
+        ///
+        /// If unset or empty, entries aren't filtered by their body.
+        TextLogFilter with_body(const rerun::components::Text& _body) && {
+            body = ComponentBatch::from_loggable(_body, Descriptor_body).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Only entries whose entity path matches this filter are shown.
+        ///
+        /// If unset or empty, entries aren't filtered by their entity path.
+        TextLogFilter with_entity_path(const rerun::components::Text& _entity_path) && {
+            entity_path = ComponentBatch::from_loggable(_entity_path, Descriptor_entity_path)
+                              .value_or_throw();
+            return std::move(*this);
+        }
+
+        /// How the filters are matched.
+        ///
+        /// Defaults to case-insensitive substring matching.
+        TextLogFilter with_mode(const rerun::blueprint::components::TextFilterMode& _mode) && {
+            mode = ComponentBatch::from_loggable(_mode, Descriptor_mode).value_or_throw();
+            return std::move(*this);
+        }
+
+        /// Partitions the component data into multiple sub-batches.
+        ///
+        /// Specifically, this transforms the existing `ComponentBatch` data into `ComponentColumn`s
+        /// instead, via `ComponentBatch::partitioned`.
+        ///
+        /// This makes it possible to use `RecordingStream::send_columns` to send columnar data directly into Rerun.
+        ///
+        /// The specified `lengths` must sum to the total length of the component batch.
+        Collection<ComponentColumn> columns(const Collection<uint32_t>& lengths_);
+
+        /// Partitions the component data into unit-length sub-batches.
+        ///
+        /// This is semantically similar to calling `columns` with `std::vector<uint32_t>(n, 1)`,
+        /// where `n` is automatically guessed.
+        Collection<ComponentColumn> columns();
+    };
+
+} // namespace rerun::blueprint::archetypes
+
+namespace rerun {
+    /// \private
+    template <typename T>
+    struct AsComponents;
+
+    /// \private
+    template <>
+    struct AsComponents<blueprint::archetypes::TextLogFilter> {
+        /// Serialize all set component batches.
+        static Result<Collection<ComponentBatch>> as_batches(
+            const blueprint::archetypes::TextLogFilter& archetype
+        );
+    };
+} // namespace rerun
@@ -15,12 +15,14 @@
 #include "blueprint/components/eye3d_kind.hpp"
 #include "blueprint/components/filter_by_range.hpp"
 #include "blueprint/components/filter_is_not_null.hpp"
+#include "blueprint/components/filter_is_not_null_mode.hpp"
 #include "blueprint/components/force_distance.hpp"
 #include "blueprint/components/force_iterations.hpp"
 #include "blueprint/components/force_strength.hpp"
 #include "blueprint/components/grid_columns.hpp"
 #include "blueprint/components/grid_spacing.hpp"
 #include "blueprint/components/included_content.hpp"
+#include "blueprint/components/latest_at_scope.hpp"
 #include "blueprint/components/link_axis.hpp"
 #include "blueprint/components/lock_range_during_zoom.hpp"
 #include "blueprint/components/map_provider.hpp"
@@ -30,7 +32,10 @@
 #include "blueprint/components/root_container.hpp"
 #include "blueprint/components/row_share.hpp"
 #include "blueprint/components/selected_columns.hpp"
+#include "blueprint/components/series_expression.hpp"
 #include "blueprint/components/tensor_dimension_index_slider.hpp"
+#include "blueprint/components/text_filter_mode.hpp"
+#include "blueprint/components/text_log_column.hpp"
 #include "blueprint/components/timeline_name.hpp"
 #include "blueprint/components/view_class.hpp"
 #include "blueprint/components/view_fit.hpp"
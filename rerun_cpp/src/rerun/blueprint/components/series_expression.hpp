@@ -0,0 +1,85 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/series_expression.fbs".
+
+#pragma once
+
+#include "../../datatypes/utf8.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+#include <string>
+#include <utility>
+
+namespace rerun::blueprint::components {
+    /// **Component**: A series computed from other series of a time series view.
+    ///
+    /// Written as `<label> = <expression>`, e.g. `speed = d/dt(/robot/position)`.
+    /// The label is optional and defaults to the expression itself.
+    ///
+    /// Expressions reference series by entity path, optionally with an instance index (`/robot/joints[2]`),
+    /// and support numbers, `+`, `-`, `*`, `/`, parentheses, `d/dt(x)` and `rolling_mean(x, n)`.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct SeriesExpression {
+        rerun::datatypes::Utf8 expression;
+
+      public:
+        SeriesExpression() = default;
+
+        SeriesExpression(rerun::datatypes::Utf8 expression_) : expression(std::move(expression_)) {}
+
+        SeriesExpression& operator=(rerun::datatypes::Utf8 expression_) {
+            expression = std::move(expression_);
+            return *this;
+        }
+
+        SeriesExpression(std::string value_) : expression(std::move(value_)) {}
+
+        SeriesExpression& operator=(std::string value_) {
+            expression = std::move(value_);
+            return *this;
+        }
+
+        /// Cast to the underlying Utf8 datatype
+        operator rerun::datatypes::Utf8() const {
+            return expression;
+        }
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Utf8) == sizeof(blueprint::components::SeriesExpression));
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::SeriesExpression> {
+        static constexpr std::string_view ComponentType =
+            "rerun.blueprint.components.SeriesExpression";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Utf8>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::blueprint:: components::SeriesExpression` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::SeriesExpression* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::Utf8>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::Utf8>::to_arrow(
+                    &instances->expression,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
@@ -0,0 +1,86 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/cpp/mod.rs
+// Based on "crates/store/re_types/definitions/rerun/blueprint/components/text_log_column.fbs".
+
+#pragma once
+
+#include "../../datatypes/utf8.hpp"
+#include "../../result.hpp"
+
+#include <cstdint>
+#include <memory>
+#include <string>
+#include <utility>
+
+namespace rerun::blueprint::components {
+    /// **Component**: A column of a text log view.
+    ///
+    /// One of:
+    /// * `"entity_path"`: the entity path of the entry.
+    /// * `"level"`: the log level of the entry.
+    /// * `"body"`: the body of the entry.
+    /// * `"timeline:<name>"`: the time of the entry on the timeline `<name>`.
+    /// * `"field:<key>"`: the value of the structured field `<key>` of the entry.
+    ///
+    /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    ///
+    struct TextLogColumn {
+        rerun::datatypes::Utf8 column;
+
+      public:
+        TextLogColumn() = default;
+
+        TextLogColumn(rerun::datatypes::Utf8 column_) : column(std::move(column_)) {}
+
+        TextLogColumn& operator=(rerun::datatypes::Utf8 column_) {
+            column = std::move(column_);
+            return *this;
+        }
+
+        TextLogColumn(std::string value_) : column(std::move(value_)) {}
+
+        TextLogColumn& operator=(std::string value_) {
+            column = std::move(value_);
+            return *this;
+        }
+
+        /// Cast to the underlying Utf8 datatype
+        operator rerun::datatypes::Utf8() const {
+            return column;
+        }
+    };
+} // namespace rerun::blueprint::components
+
+namespace rerun {
+    static_assert(sizeof(rerun::datatypes::Utf8) == sizeof(blueprint::components::TextLogColumn));
+
+    /// \private
+    template <>
+    struct Loggable<blueprint::components::TextLogColumn> {
+        static constexpr std::string_view ComponentType =
+            "rerun.blueprint.components.TextLogColumn";
+
+        /// Returns the arrow data type this type corresponds to.
+        static const std::shared_ptr<arrow::DataType>& arrow_datatype() {
+            return Loggable<rerun::datatypes::Utf8>::arrow_datatype();
+        }
+
+        /// Serializes an array of `rerun::blueprint:: components::TextLogColumn` into an arrow array.
+        static Result<std::shared_ptr<arrow::Array>> to_arrow(
+            const blueprint::components::TextLogColumn* instances, size_t num_instances
+        ) {
+            if (num_instances == 0) {
+                return Loggable<rerun::datatypes::Utf8>::to_arrow(nullptr, 0);
+            } else if (instances == nullptr) {
+                return rerun::Error(
+                    ErrorCode::UnexpectedNullArgument,
+                    "Passed array instances is null when num_elements> 0."
+                );
+            } else {
+                return Loggable<rerun::datatypes::Utf8>::to_arrow(
+                    &instances->column,
+                    num_instances
+                );
+            }
+        }
+    };
+} // namespace rerun
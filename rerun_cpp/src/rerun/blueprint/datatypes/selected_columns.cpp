@@ -3,6 +3,7 @@
 
 #include "selected_columns.hpp"
 
+#include "../../datatypes/bool.hpp"
 #include "../../datatypes/utf8.hpp"
 #include "component_column_selector.hpp"
 
@@ -15,6 +16,7 @@ namespace rerun {
     const std::shared_ptr<arrow::DataType>&
         Loggable<blueprint::datatypes::SelectedColumns>::arrow_datatype() {
         static const auto datatype = arrow::struct_({
+            arrow::field("row_id", Loggable<rerun::datatypes::Bool>::arrow_datatype(), true),
             arrow::field(
                 "time_columns",
                 arrow::list(
@@ -73,7 +75,23 @@ namespace rerun {
         }
 
         {
-            auto field_builder = static_cast<arrow::ListBuilder*>(builder->field_builder(0));
+            auto field_builder = static_cast<arrow::BooleanBuilder*>(builder->field_builder(0));
+            ARROW_RETURN_NOT_OK(field_builder->Reserve(static_cast<int64_t>(num_elements)));
+            for (size_t elem_idx = 0; elem_idx < num_elements; elem_idx += 1) {
+                const auto& element = elements[elem_idx];
+                if (element.row_id.has_value()) {
+                    RR_RETURN_NOT_OK(Loggable<rerun::datatypes::Bool>::fill_arrow_array_builder(
+                        field_builder,
+                        &element.row_id.value(),
+                        1
+                    ));
+                } else {
+                    ARROW_RETURN_NOT_OK(field_builder->AppendNull());
+                }
+            }
+        }
+        {
+            auto field_builder = static_cast<arrow::ListBuilder*>(builder->field_builder(1));
             auto value_builder = static_cast<arrow::StringBuilder*>(field_builder->value_builder());
             ARROW_RETURN_NOT_OK(field_builder->Reserve(static_cast<int64_t>(num_elements)));
             ARROW_RETURN_NOT_OK(value_builder->Reserve(static_cast<int64_t>(num_elements * 2)));
@@ -91,7 +109,7 @@ namespace rerun {
             }
         }
         {
-            auto field_builder = static_cast<arrow::ListBuilder*>(builder->field_builder(1));
+            auto field_builder = static_cast<arrow::ListBuilder*>(builder->field_builder(2));
             auto value_builder = static_cast<arrow::StructBuilder*>(field_builder->value_builder());
             ARROW_RETURN_NOT_OK(field_builder->Reserve(static_cast<int64_t>(num_elements)));
             ARROW_RETURN_NOT_OK(value_builder->Reserve(static_cast<int64_t>(num_elements * 2)));
@@ -4,12 +4,14 @@
 #pragma once
 
 #include "../../collection.hpp"
+#include "../../datatypes/bool.hpp"
 #include "../../datatypes/utf8.hpp"
 #include "../../result.hpp"
 #include "component_column_selector.hpp"
 
 #include <cstdint>
 #include <memory>
+#include <optional>
 
 namespace arrow {
     class Array;
@@ -23,6 +25,11 @@ namespace rerun::blueprint::datatypes {
     /// ⚠ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
     ///
     struct SelectedColumns {
+        /// Whether the row id column should be included.
+        ///
+        /// Defaults to `false`, e.g. for blueprints saved before this field was introduced.
+        std::optional<rerun::datatypes::Bool> row_id;
+
         /// The time columns to include
         rerun::Collection<rerun::datatypes::Utf8> time_columns;
 
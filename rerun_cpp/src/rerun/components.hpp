@@ -64,6 +64,7 @@
 #include "components/tensor_width_dimension.hpp"
 #include "components/texcoord2d.hpp"
 #include "components/text.hpp"
+#include "components/text_log_field.hpp"
 #include "components/text_log_level.hpp"
 #include "components/timestamp.hpp"
 #include "components/transform_mat3x3.hpp"
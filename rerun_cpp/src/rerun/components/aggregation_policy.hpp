@@ -46,6 +46,16 @@ namespace rerun::components {
 
         /// Find both the minimum and maximum values in the range, then use the average of those.
         MinMaxAverage = 6,
+
+        /// Use the median of all values in the range.
+        ///
+        /// Unlike `Average`, this is not skewed by outliers.
+        Median = 7,
+
+        /// Shade the band between the minimum and maximum values in the range, with a line through the average of all values.
+        ///
+        /// This shows both the trend and the spread of long, noisy signals.
+        Envelope = 8,
     };
 } // namespace rerun::components
 
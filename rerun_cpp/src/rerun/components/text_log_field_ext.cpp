@@ -0,0 +1,20 @@
+//#define EDIT_EXTENSION
+
+#ifdef EDIT_EXTENSION
+#include "text_log_field.hpp"
+
+namespace rerun {
+    namespace components {
+
+        // <CODEGEN_COPY_TO_HEADER>
+
+        /// Create a new field from a key and a value.
+        TextLogField(rerun::datatypes::Utf8 key, rerun::datatypes::Utf8 value)
+            : field(std::move(key), std::move(value)) {}
+
+        // </CODEGEN_COPY_TO_HEADER>
+
+    } // namespace components
+} // namespace rerun
+
+#endif
@@ -118,6 +118,7 @@ from .components import (
     Radius as Radius,
     Scale3D as Scale3D,
     TensorDimensionIndexSelection as TensorDimensionIndexSelection,
+    TextLogField as TextLogField,
     TextLogLevel as TextLogLevel,
     TransformRelation as TransformRelation,
     VideoCodec as VideoCodec,
@@ -25,7 +25,7 @@ class AssetVideo(AssetVideoExt, Archetype):
     """
     **Archetype**: A video binary.
 
-    Only MP4 containers are currently supported.
+    Supported are MP4 and Matroska/WebM containers, as well as raw H.264/H.265 Annex B bitstreams.
 
     See <https://rerun.io/docs/reference/video> for codec support and more general information.
 
@@ -149,6 +149,10 @@ class AssetVideo(AssetVideoExt, Archetype):
 
             Supported values:
             * `video/mp4`
+            * `video/x-matroska`
+            * `video/webm`
+            * `video/h264`
+            * `video/h265`
 
             If omitted, the viewer will try to guess from the data blob.
             If it cannot guess, it won't be able to render the asset.
@@ -200,6 +204,10 @@ class AssetVideo(AssetVideoExt, Archetype):
 
             Supported values:
             * `video/mp4`
+            * `video/x-matroska`
+            * `video/webm`
+            * `video/h264`
+            * `video/h265`
 
             If omitted, the viewer will try to guess from the data blob.
             If it cannot guess, it won't be able to render the asset.
@@ -265,6 +273,10 @@ class AssetVideo(AssetVideoExt, Archetype):
     #
     # Supported values:
     # * `video/mp4`
+    # * `video/x-matroska`
+    # * `video/webm`
+    # * `video/h264`
+    # * `video/h265`
     #
     # If omitted, the viewer will try to guess from the data blob.
     # If it cannot guess, it won't be able to render the asset.
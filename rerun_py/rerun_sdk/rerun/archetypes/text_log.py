@@ -62,6 +62,7 @@ class TextLog(Archetype):
         *,
         level: datatypes.Utf8Like | None = None,
         color: datatypes.Rgba32Like | None = None,
+        fields: datatypes.Utf8PairArrayLike | None = None,
     ) -> None:
         """
         Create a new instance of the TextLog archetype.
@@ -76,12 +77,16 @@ class TextLog(Archetype):
             This can be used to filter the log messages in the Rerun Viewer.
         color:
             Optional color to use for the log line in the Rerun Viewer.
+        fields:
+            Optional structured key-value fields attached to the message.
+
+            These are shown as extra columns in the Rerun Viewer.
 
         """
 
         # You can define your own __init__ function as a member of TextLogExt in text_log_ext.py
         with catch_and_log_exceptions(context=self.__class__.__name__):
-            self.__attrs_init__(text=text, level=level, color=color)
+            self.__attrs_init__(text=text, level=level, color=color, fields=fields)
             return
         self.__attrs_clear__()
 
@@ -91,6 +96,7 @@ class TextLog(Archetype):
             text=None,
             level=None,
             color=None,
+            fields=None,
         )
 
     @classmethod
@@ -108,6 +114,7 @@ class TextLog(Archetype):
         text: datatypes.Utf8Like | None = None,
         level: datatypes.Utf8Like | None = None,
         color: datatypes.Rgba32Like | None = None,
+        fields: datatypes.Utf8PairArrayLike | None = None,
     ) -> TextLog:
         """
         Update only some specific fields of a `TextLog`.
@@ -124,6 +131,10 @@ class TextLog(Archetype):
             This can be used to filter the log messages in the Rerun Viewer.
         color:
             Optional color to use for the log line in the Rerun Viewer.
+        fields:
+            Optional structured key-value fields attached to the message.
+
+            These are shown as extra columns in the Rerun Viewer.
 
         """
 
@@ -133,6 +144,7 @@ class TextLog(Archetype):
                 "text": text,
                 "level": level,
                 "color": color,
+                "fields": fields,
             }
 
             if clear_unset:
@@ -156,6 +168,7 @@ class TextLog(Archetype):
         text: datatypes.Utf8ArrayLike | None = None,
         level: datatypes.Utf8ArrayLike | None = None,
         color: datatypes.Rgba32ArrayLike | None = None,
+        fields: datatypes.Utf8PairArrayLike | None = None,
     ) -> ComponentColumnList:
         """
         Construct a new column-oriented component bundle.
@@ -175,6 +188,10 @@ class TextLog(Archetype):
             This can be used to filter the log messages in the Rerun Viewer.
         color:
             Optional color to use for the log line in the Rerun Viewer.
+        fields:
+            Optional structured key-value fields attached to the message.
+
+            These are shown as extra columns in the Rerun Viewer.
 
         """
 
@@ -184,13 +201,14 @@ class TextLog(Archetype):
                 text=text,
                 level=level,
                 color=color,
+                fields=fields,
             )
 
         batches = inst.as_component_batches()
         if len(batches) == 0:
             return ComponentColumnList([])
 
-        kwargs = {"TextLog:text": text, "TextLog:level": level, "TextLog:color": color}
+        kwargs = {"TextLog:text": text, "TextLog:level": level, "TextLog:color": color, "TextLog:fields": fields}
         columns = []
 
         for batch in batches:
@@ -249,5 +267,16 @@ class TextLog(Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    fields: components.TextLogFieldBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.TextLogFieldBatch._converter,  # type: ignore[misc]
+    )
+    # Optional structured key-value fields attached to the message.
+    #
+    # These are shown as extra columns in the Rerun Viewer.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     __str__ = Archetype.__str__
     __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -30,6 +30,7 @@ from .api import (
 )
 from .archetypes import (
     Background as Background,
+    DerivedSeries as DerivedSeries,
     EntityBehavior as EntityBehavior,
     EyeControls3D as EyeControls3D,
     LineGrid3D as LineGrid3D,
@@ -37,6 +38,8 @@ from .archetypes import (
     ScalarAxis as ScalarAxis,
     TensorScalarMapping as TensorScalarMapping,
     TensorSliceSelection as TensorSliceSelection,
+    TextLogColumns as TextLogColumns,
+    TextLogFilter as TextLogFilter,
     VisibleTimeRanges as VisibleTimeRanges,
     VisualBounds2D as VisualBounds2D,
     VisualizerOverrides as VisualizerOverrides,
@@ -47,6 +50,7 @@ from .components import (
     Eye3DKind as Eye3DKind,
     LockRangeDuringZoom as LockRangeDuringZoom,
     MapProvider as MapProvider,
+    TextFilterMode as TextFilterMode,
 )
 from .containers import (
     Grid as Grid,
@@ -5,6 +5,7 @@ from __future__ import annotations
 from .background import Background
 from .container_blueprint import ContainerBlueprint
 from .dataframe_query import DataframeQuery
+from .derived_series import DerivedSeries
 from .entity_behavior import EntityBehavior
 from .eye_controls3d import EyeControls3D
 from .force_center import ForceCenter
@@ -22,6 +23,8 @@ from .scalar_axis import ScalarAxis
 from .tensor_scalar_mapping import TensorScalarMapping
 from .tensor_slice_selection import TensorSliceSelection
 from .tensor_view_fit import TensorViewFit
+from .text_log_columns import TextLogColumns
+from .text_log_filter import TextLogFilter
 from .time_axis import TimeAxis
 from .view_blueprint import ViewBlueprint
 from .view_contents import ViewContents
@@ -34,6 +37,7 @@ __all__ = [
     "Background",
     "ContainerBlueprint",
     "DataframeQuery",
+    "DerivedSeries",
     "EntityBehavior",
     "EyeControls3D",
     "ForceCenter",
@@ -51,6 +55,8 @@ __all__ = [
     "TensorScalarMapping",
     "TensorSliceSelection",
     "TensorViewFit",
+    "TextLogColumns",
+    "TextLogFilter",
     "TimeAxis",
     "ViewBlueprint",
     "ViewContents",
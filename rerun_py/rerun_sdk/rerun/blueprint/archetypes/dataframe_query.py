@@ -34,7 +34,9 @@ class DataframeQuery(DataframeQueryExt, Archetype):
             timeline=None,
             filter_by_range=None,
             filter_is_not_null=None,
+            filter_is_not_null_mode=None,
             apply_latest_at=None,
+            latest_at_scope=None,
             select=None,
         )
 
@@ -52,8 +54,10 @@ class DataframeQuery(DataframeQueryExt, Archetype):
         clear_unset: bool = False,
         timeline: datatypes.Utf8Like | None = None,
         filter_by_range: blueprint_datatypes.FilterByRangeLike | None = None,
-        filter_is_not_null: blueprint_datatypes.FilterIsNotNullLike | None = None,
+        filter_is_not_null: blueprint_datatypes.FilterIsNotNullArrayLike | None = None,
+        filter_is_not_null_mode: blueprint_components.FilterIsNotNullModeLike | None = None,
         apply_latest_at: datatypes.BoolLike | None = None,
+        latest_at_scope: blueprint_components.LatestAtScopeLike | None = None,
         select: blueprint_datatypes.SelectedColumnsLike | None = None,
     ) -> DataframeQuery:
         """
@@ -72,9 +76,19 @@ class DataframeQuery(DataframeQueryExt, Archetype):
 
             Note: will be unset as soon as `timeline` is changed.
         filter_is_not_null:
-            If provided, only show rows which contains a logged event for the specified component.
+            If provided, only show rows which contains a logged event for the specified components.
+
+            See `filter_is_not_null_mode` for how multiple components are combined.
+        filter_is_not_null_mode:
+            Whether rows need a logged event for any or all of the components of `filter_is_not_null`.
+
+            If unset, rows with a logged event for any of the components are shown.
         apply_latest_at:
             Should empty cells be filled with latest-at queries?
+        latest_at_scope:
+            Which data should empty cells be filled from, if `apply_latest_at` is enabled?
+
+            If unset, the latest value in the entire recording is used.
         select:
             Selected columns. If unset, all columns are selected.
 
@@ -86,7 +100,9 @@ class DataframeQuery(DataframeQueryExt, Archetype):
                 "timeline": timeline,
                 "filter_by_range": filter_by_range,
                 "filter_is_not_null": filter_is_not_null,
+                "filter_is_not_null_mode": filter_is_not_null_mode,
                 "apply_latest_at": apply_latest_at,
+                "latest_at_scope": latest_at_scope,
                 "select": select,
             }
 
@@ -131,7 +147,20 @@ class DataframeQuery(DataframeQueryExt, Archetype):
         default=None,
         converter=blueprint_components.FilterIsNotNullBatch._converter,  # type: ignore[misc]
     )
-    # If provided, only show rows which contains a logged event for the specified component.
+    # If provided, only show rows which contains a logged event for the specified components.
+    #
+    # See `filter_is_not_null_mode` for how multiple components are combined.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    filter_is_not_null_mode: blueprint_components.FilterIsNotNullModeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.FilterIsNotNullModeBatch._converter,  # type: ignore[misc]
+    )
+    # Whether rows need a logged event for any or all of the components of `filter_is_not_null`.
+    #
+    # If unset, rows with a logged event for any of the components are shown.
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
@@ -144,6 +173,17 @@ class DataframeQuery(DataframeQueryExt, Archetype):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    latest_at_scope: blueprint_components.LatestAtScopeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.LatestAtScopeBatch._converter,  # type: ignore[misc]
+    )
+    # Which data should empty cells be filled from, if `apply_latest_at` is enabled?
+    #
+    # If unset, the latest value in the entire recording is used.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
     select: blueprint_components.SelectedColumnsBatch | None = field(
         metadata={"component": True},
         default=None,
@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+from collections.abc import Sequence
 from typing import Any
 
 from ... import datatypes
@@ -17,8 +18,12 @@ class DataframeQueryExt:
         filter_by_range: tuple[datatypes.TimeInt, datatypes.TimeInt]
         | blueprint_datatypes.FilterByRangeLike
         | None = None,
-        filter_is_not_null: blueprint_datatypes.ComponentColumnSelectorLike | None = None,
+        filter_is_not_null: blueprint_datatypes.ComponentColumnSelectorLike
+        | Sequence[blueprint_datatypes.ComponentColumnSelectorLike]
+        | None = None,
+        filter_is_not_null_mode: blueprint_components.FilterIsNotNullModeLike | None = None,
         apply_latest_at: bool = False,
+        latest_at_scope: blueprint_components.LatestAtScopeLike | None = None,
         select: list[blueprint_datatypes.ComponentColumnSelectorLike | datatypes.Utf8Like | str] | None = None,
     ) -> None:
         """
@@ -34,11 +39,21 @@ class DataframeQueryExt:
 
         filter_is_not_null:
             If provided, the dataframe will only contain rows corresponding to timestamps at which an event was logged
-            for the provided column.
+            for the provided column(s).
+
+        filter_is_not_null_mode:
+            If multiple columns are provided to `filter_is_not_null`, whether an event must have been logged for
+            any (the default) or all of them.
 
         apply_latest_at:
             Should empty cells be filled with latest-at queries?
 
+        latest_at_scope:
+            Which data should empty cells be filled from, if `apply_latest_at` is enabled?
+
+            Use `"View"` to only fill with values from within the view's contents and range.
+            If unset, the latest value in the entire recording is used.
+
         select:
             Selected columns. If unset, all columns are selected.
 
@@ -49,12 +64,18 @@ class DataframeQueryExt:
             filter_by_range = blueprint_components.FilterByRange(start, end)
 
         if filter_is_not_null is not None:
-            if isinstance(filter_is_not_null, str):
-                column = blueprint_datatypes.ComponentColumnSelector(spec=filter_is_not_null)
-            else:
-                column = filter_is_not_null
+            if isinstance(filter_is_not_null, (str, blueprint_datatypes.ComponentColumnSelector)):
+                filter_is_not_null = [filter_is_not_null]
 
-            new_filter_is_not_null = blueprint_components.FilterIsNotNull(active=True, column=column)
+            new_filter_is_not_null = [
+                blueprint_components.FilterIsNotNull(
+                    active=True,
+                    column=blueprint_datatypes.ComponentColumnSelector(spec=column)
+                    if isinstance(column, str)
+                    else column,
+                )
+                for column in filter_is_not_null
+            ]
         else:
             new_filter_is_not_null = None
 
@@ -63,7 +84,9 @@ class DataframeQueryExt:
                 timeline=timeline,
                 filter_by_range=filter_by_range,
                 filter_is_not_null=new_filter_is_not_null,
+                filter_is_not_null_mode=filter_is_not_null_mode,
                 apply_latest_at=apply_latest_at,
+                latest_at_scope=latest_at_scope,
                 select=select,
             )
             return
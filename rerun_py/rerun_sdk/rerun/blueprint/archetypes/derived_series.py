@@ -0,0 +1,109 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/derived_series.fbs".
+
+# You can extend this class by creating a "DerivedSeriesExt" class in "derived_series_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["DerivedSeries"]
+
+
+@define(str=False, repr=False, init=False)
+class DerivedSeries(Archetype):
+    """
+    **Archetype**: Series of a time series view that are computed from other series, rather than logged.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(self: Any, *, expressions: datatypes.Utf8ArrayLike | None = None) -> None:
+        """
+        Create a new instance of the DerivedSeries archetype.
+
+        Parameters
+        ----------
+        expressions:
+            The expressions of the derived series, one series per expression.
+
+        """
+
+        # You can define your own __init__ function as a member of DerivedSeriesExt in derived_series_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(expressions=expressions)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            expressions=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> DerivedSeries:
+        """Produce an empty DerivedSeries, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        expressions: datatypes.Utf8ArrayLike | None = None,
+    ) -> DerivedSeries:
+        """
+        Update only some specific fields of a `DerivedSeries`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        expressions:
+            The expressions of the derived series, one series per expression.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "expressions": expressions,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> DerivedSeries:
+        """Clear all the fields of a `DerivedSeries`."""
+        return cls.from_fields(clear_unset=True)
+
+    expressions: blueprint_components.SeriesExpressionBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.SeriesExpressionBatch._converter,  # type: ignore[misc]
+    )
+    # The expressions of the derived series, one series per expression.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,142 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/text_log_columns.fbs".
+
+# You can extend this class by creating a "TextLogColumnsExt" class in "text_log_columns_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["TextLogColumns"]
+
+
+@define(str=False, repr=False, init=False)
+class TextLogColumns(Archetype):
+    """
+    **Archetype**: Configuration for the columns of a text log view.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        order: datatypes.Utf8ArrayLike | None = None,
+        hidden: datatypes.Utf8ArrayLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the TextLogColumns archetype.
+
+        Parameters
+        ----------
+        order:
+            The order in which columns are shown.
+
+            Columns that aren't listed are shown after the listed ones, in their default order.
+        hidden:
+            Columns that aren't shown.
+
+            By default, all columns are shown.
+
+        """
+
+        # You can define your own __init__ function as a member of TextLogColumnsExt in text_log_columns_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(order=order, hidden=hidden)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            order=None,
+            hidden=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> TextLogColumns:
+        """Produce an empty TextLogColumns, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        order: datatypes.Utf8ArrayLike | None = None,
+        hidden: datatypes.Utf8ArrayLike | None = None,
+    ) -> TextLogColumns:
+        """
+        Update only some specific fields of a `TextLogColumns`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        order:
+            The order in which columns are shown.
+
+            Columns that aren't listed are shown after the listed ones, in their default order.
+        hidden:
+            Columns that aren't shown.
+
+            By default, all columns are shown.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "order": order,
+                "hidden": hidden,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> TextLogColumns:
+        """Clear all the fields of a `TextLogColumns`."""
+        return cls.from_fields(clear_unset=True)
+
+    order: blueprint_components.TextLogColumnBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.TextLogColumnBatch._converter,  # type: ignore[misc]
+    )
+    # The order in which columns are shown.
+    #
+    # Columns that aren't listed are shown after the listed ones, in their default order.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    hidden: blueprint_components.TextLogColumnBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.TextLogColumnBatch._converter,  # type: ignore[misc]
+    )
+    # Columns that aren't shown.
+    #
+    # By default, all columns are shown.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -0,0 +1,165 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/archetypes/text_log_filter.fbs".
+
+# You can extend this class by creating a "TextLogFilterExt" class in "text_log_filter_ext.py".
+
+from __future__ import annotations
+
+from typing import Any
+
+from attrs import define, field
+
+from ... import components, datatypes
+from ..._baseclasses import (
+    Archetype,
+)
+from ...blueprint import components as blueprint_components
+from ...error_utils import catch_and_log_exceptions
+
+__all__ = ["TextLogFilter"]
+
+
+@define(str=False, repr=False, init=False)
+class TextLogFilter(Archetype):
+    """
+    **Archetype**: Configuration for filtering the entries of a text log view.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    def __init__(
+        self: Any,
+        *,
+        body: datatypes.Utf8Like | None = None,
+        entity_path: datatypes.Utf8Like | None = None,
+        mode: blueprint_components.TextFilterModeLike | None = None,
+    ) -> None:
+        """
+        Create a new instance of the TextLogFilter archetype.
+
+        Parameters
+        ----------
+        body:
+            Only entries whose body matches this filter are shown.
+
+            If unset or empty, entries aren't filtered by their body.
+        entity_path:
+            Only entries whose entity path matches this filter are shown.
+
+            If unset or empty, entries aren't filtered by their entity path.
+        mode:
+            How the filters are matched.
+
+            Defaults to case-insensitive substring matching.
+
+        """
+
+        # You can define your own __init__ function as a member of TextLogFilterExt in text_log_filter_ext.py
+        with catch_and_log_exceptions(context=self.__class__.__name__):
+            self.__attrs_init__(body=body, entity_path=entity_path, mode=mode)
+            return
+        self.__attrs_clear__()
+
+    def __attrs_clear__(self) -> None:
+        """Convenience method for calling `__attrs_init__` with all `None`s."""
+        self.__attrs_init__(
+            body=None,
+            entity_path=None,
+            mode=None,
+        )
+
+    @classmethod
+    def _clear(cls) -> TextLogFilter:
+        """Produce an empty TextLogFilter, bypassing `__init__`."""
+        inst = cls.__new__(cls)
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def from_fields(
+        cls,
+        *,
+        clear_unset: bool = False,
+        body: datatypes.Utf8Like | None = None,
+        entity_path: datatypes.Utf8Like | None = None,
+        mode: blueprint_components.TextFilterModeLike | None = None,
+    ) -> TextLogFilter:
+        """
+        Update only some specific fields of a `TextLogFilter`.
+
+        Parameters
+        ----------
+        clear_unset:
+            If true, all unspecified fields will be explicitly cleared.
+        body:
+            Only entries whose body matches this filter are shown.
+
+            If unset or empty, entries aren't filtered by their body.
+        entity_path:
+            Only entries whose entity path matches this filter are shown.
+
+            If unset or empty, entries aren't filtered by their entity path.
+        mode:
+            How the filters are matched.
+
+            Defaults to case-insensitive substring matching.
+
+        """
+
+        inst = cls.__new__(cls)
+        with catch_and_log_exceptions(context=cls.__name__):
+            kwargs = {
+                "body": body,
+                "entity_path": entity_path,
+                "mode": mode,
+            }
+
+            if clear_unset:
+                kwargs = {k: v if v is not None else [] for k, v in kwargs.items()}  # type: ignore[misc]
+
+            inst.__attrs_init__(**kwargs)
+            return inst
+
+        inst.__attrs_clear__()
+        return inst
+
+    @classmethod
+    def cleared(cls) -> TextLogFilter:
+        """Clear all the fields of a `TextLogFilter`."""
+        return cls.from_fields(clear_unset=True)
+
+    body: components.TextBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.TextBatch._converter,  # type: ignore[misc]
+    )
+    # Only entries whose body matches this filter are shown.
+    #
+    # If unset or empty, entries aren't filtered by their body.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    entity_path: components.TextBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=components.TextBatch._converter,  # type: ignore[misc]
+    )
+    # Only entries whose entity path matches this filter are shown.
+    #
+    # If unset or empty, entries aren't filtered by their entity path.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    mode: blueprint_components.TextFilterModeBatch | None = field(
+        metadata={"component": True},
+        default=None,
+        converter=blueprint_components.TextFilterModeBatch._converter,  # type: ignore[misc]
+    )
+    # How the filters are matched.
+    #
+    # Defaults to case-insensitive substring matching.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
+    __str__ = Archetype.__str__
+    __repr__ = Archetype.__repr__  # type: ignore[assignment]
@@ -15,12 +15,19 @@ from .enabled import Enabled, EnabledBatch
 from .eye3d_kind import Eye3DKind, Eye3DKindArrayLike, Eye3DKindBatch, Eye3DKindLike
 from .filter_by_range import FilterByRange, FilterByRangeBatch
 from .filter_is_not_null import FilterIsNotNull, FilterIsNotNullBatch
+from .filter_is_not_null_mode import (
+    FilterIsNotNullMode,
+    FilterIsNotNullModeArrayLike,
+    FilterIsNotNullModeBatch,
+    FilterIsNotNullModeLike,
+)
 from .force_distance import ForceDistance, ForceDistanceBatch
 from .force_iterations import ForceIterations, ForceIterationsBatch
 from .force_strength import ForceStrength, ForceStrengthBatch
 from .grid_columns import GridColumns, GridColumnsBatch
 from .grid_spacing import GridSpacing, GridSpacingBatch
 from .included_content import IncludedContent, IncludedContentBatch
+from .latest_at_scope import LatestAtScope, LatestAtScopeArrayLike, LatestAtScopeBatch, LatestAtScopeLike
 from .link_axis import LinkAxis, LinkAxisArrayLike, LinkAxisBatch, LinkAxisLike
 from .lock_range_during_zoom import LockRangeDuringZoom, LockRangeDuringZoomBatch
 from .map_provider import MapProvider, MapProviderArrayLike, MapProviderBatch, MapProviderLike
@@ -30,7 +37,15 @@ from .query_expression import QueryExpression, QueryExpressionBatch
 from .root_container import RootContainer, RootContainerBatch
 from .row_share import RowShare, RowShareBatch
 from .selected_columns import SelectedColumns, SelectedColumnsBatch
+from .series_expression import SeriesExpression, SeriesExpressionBatch
 from .tensor_dimension_index_slider import TensorDimensionIndexSlider, TensorDimensionIndexSliderBatch
+from .text_filter_mode import (
+    TextFilterMode,
+    TextFilterModeArrayLike,
+    TextFilterModeBatch,
+    TextFilterModeLike,
+)
+from .text_log_column import TextLogColumn, TextLogColumnBatch
 from .timeline_name import TimelineName, TimelineNameBatch
 from .view_class import ViewClass, ViewClassBatch
 from .view_fit import ViewFit, ViewFitArrayLike, ViewFitBatch, ViewFitLike
@@ -77,6 +92,10 @@ __all__ = [
     "FilterByRangeBatch",
     "FilterIsNotNull",
     "FilterIsNotNullBatch",
+    "FilterIsNotNullMode",
+    "FilterIsNotNullModeArrayLike",
+    "FilterIsNotNullModeBatch",
+    "FilterIsNotNullModeLike",
     "ForceDistance",
     "ForceDistanceBatch",
     "ForceIterations",
@@ -89,6 +108,10 @@ __all__ = [
     "GridSpacingBatch",
     "IncludedContent",
     "IncludedContentBatch",
+    "LatestAtScope",
+    "LatestAtScopeArrayLike",
+    "LatestAtScopeBatch",
+    "LatestAtScopeLike",
     "LinkAxis",
     "LinkAxisArrayLike",
     "LinkAxisBatch",
@@ -113,8 +136,16 @@ __all__ = [
     "RowShareBatch",
     "SelectedColumns",
     "SelectedColumnsBatch",
+    "SeriesExpression",
+    "SeriesExpressionBatch",
     "TensorDimensionIndexSlider",
     "TensorDimensionIndexSliderBatch",
+    "TextFilterMode",
+    "TextFilterModeArrayLike",
+    "TextFilterModeBatch",
+    "TextFilterModeLike",
+    "TextLogColumn",
+    "TextLogColumnBatch",
     "TimelineName",
     "TimelineNameBatch",
     "ViewClass",
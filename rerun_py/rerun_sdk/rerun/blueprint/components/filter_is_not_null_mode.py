@@ -0,0 +1,69 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/filter_is_not_null_mode.fbs".
+
+# You can extend this class by creating a "FilterIsNotNullModeExt" class in "filter_is_not_null_mode_ext.py".
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import Literal, Union
+
+import pyarrow as pa
+
+from ..._baseclasses import (
+    BaseBatch,
+    ComponentBatchMixin,
+)
+
+__all__ = ["FilterIsNotNullMode", "FilterIsNotNullModeArrayLike", "FilterIsNotNullModeBatch", "FilterIsNotNullModeLike"]
+
+
+from enum import Enum
+
+
+class FilterIsNotNullMode(Enum):
+    """**Component**: How the columns of a dataframe's filter is not null feature are combined."""
+
+    Any = 1
+    """Keep rows where any of the columns contains non-null data."""
+
+    All = 2
+    """Keep rows where all of the columns contain non-null data."""
+
+    @classmethod
+    def auto(cls, val: str | int | FilterIsNotNullMode) -> FilterIsNotNullMode:
+        """Best-effort converter, including a case-insensitive string matcher."""
+        if isinstance(val, FilterIsNotNullMode):
+            return val
+        if isinstance(val, int):
+            return cls(val)
+        try:
+            return cls[val]
+        except KeyError:
+            val_lower = val.lower()
+            for variant in cls:
+                if variant.name.lower() == val_lower:
+                    return variant
+        raise ValueError(f"Cannot convert {val} to {cls.__name__}")
+
+    def __str__(self) -> str:
+        """Returns the variant name."""
+        return self.name
+
+
+FilterIsNotNullModeLike = Union[FilterIsNotNullMode, Literal["All", "Any", "all", "any"], int]
+FilterIsNotNullModeArrayLike = Union[FilterIsNotNullModeLike, Sequence[FilterIsNotNullModeLike]]
+
+
+class FilterIsNotNullModeBatch(BaseBatch[FilterIsNotNullModeArrayLike], ComponentBatchMixin):
+    _ARROW_DATATYPE = pa.uint8()
+    _COMPONENT_TYPE: str = "rerun.blueprint.components.FilterIsNotNullMode"
+
+    @staticmethod
+    def _native_to_pa_array(data: FilterIsNotNullModeArrayLike, data_type: pa.DataType) -> pa.Array:
+        if isinstance(data, (FilterIsNotNullMode, int, str)):
+            data = [data]
+
+        pa_data = [FilterIsNotNullMode.auto(v).value if v is not None else None for v in data]  # type: ignore[redundant-expr]
+
+        return pa.array(pa_data, type=data_type)
@@ -0,0 +1,69 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/latest_at_scope.fbs".
+
+# You can extend this class by creating a "LatestAtScopeExt" class in "latest_at_scope_ext.py".
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import Literal, Union
+
+import pyarrow as pa
+
+from ..._baseclasses import (
+    BaseBatch,
+    ComponentBatchMixin,
+)
+
+__all__ = ["LatestAtScope", "LatestAtScopeArrayLike", "LatestAtScopeBatch", "LatestAtScopeLike"]
+
+
+from enum import Enum
+
+
+class LatestAtScope(Enum):
+    """**Component**: Which data empty cells of a dataframe may be filled from, when latest-at filling is enabled."""
+
+    Global = 1
+    """Fill with the latest value in the entire recording, even if it lies outside of the view's range."""
+
+    View = 2
+    """Only fill with values that are part of the view's contents and range."""
+
+    @classmethod
+    def auto(cls, val: str | int | LatestAtScope) -> LatestAtScope:
+        """Best-effort converter, including a case-insensitive string matcher."""
+        if isinstance(val, LatestAtScope):
+            return val
+        if isinstance(val, int):
+            return cls(val)
+        try:
+            return cls[val]
+        except KeyError:
+            val_lower = val.lower()
+            for variant in cls:
+                if variant.name.lower() == val_lower:
+                    return variant
+        raise ValueError(f"Cannot convert {val} to {cls.__name__}")
+
+    def __str__(self) -> str:
+        """Returns the variant name."""
+        return self.name
+
+
+LatestAtScopeLike = Union[LatestAtScope, Literal["Global", "View", "global", "view"], int]
+LatestAtScopeArrayLike = Union[LatestAtScopeLike, Sequence[LatestAtScopeLike]]
+
+
+class LatestAtScopeBatch(BaseBatch[LatestAtScopeArrayLike], ComponentBatchMixin):
+    _ARROW_DATATYPE = pa.uint8()
+    _COMPONENT_TYPE: str = "rerun.blueprint.components.LatestAtScope"
+
+    @staticmethod
+    def _native_to_pa_array(data: LatestAtScopeArrayLike, data_type: pa.DataType) -> pa.Array:
+        if isinstance(data, (LatestAtScope, int, str)):
+            data = [data]
+
+        pa_data = [LatestAtScope.auto(v).value if v is not None else None for v in data]  # type: ignore[redundant-expr]
+
+        return pa.array(pa_data, type=data_type)
@@ -0,0 +1,41 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/series_expression.fbs".
+
+# You can extend this class by creating a "SeriesExpressionExt" class in "series_expression_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["SeriesExpression", "SeriesExpressionBatch"]
+
+
+class SeriesExpression(datatypes.Utf8, ComponentMixin):
+    """
+    **Component**: A series computed from other series of a time series view.
+
+    Written as `<label> = <expression>`, e.g. `speed = d/dt(/robot/position)`.
+    The label is optional and defaults to the expression itself.
+
+    Expressions reference series by entity path, optionally with an instance index (`/robot/joints[2]`),
+    and support numbers, `+`, `-`, `*`, `/`, parentheses, `d/dt(x)` and `rolling_mean(x, n)`.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of SeriesExpressionExt in series_expression_ext.py
+
+    # Note: there are no fields here because SeriesExpression delegates to datatypes.Utf8
+
+
+class SeriesExpressionBatch(datatypes.Utf8Batch, ComponentBatchMixin):
+    _COMPONENT_TYPE: str = "rerun.blueprint.components.SeriesExpression"
+
+
+# This is patched in late to avoid circular dependencies.
+SeriesExpression._BATCH_TYPE = SeriesExpressionBatch  # type: ignore[assignment]
@@ -0,0 +1,69 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/text_filter_mode.fbs".
+
+# You can extend this class by creating a "TextFilterModeExt" class in "text_filter_mode_ext.py".
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import Literal, Union
+
+import pyarrow as pa
+
+from ..._baseclasses import (
+    BaseBatch,
+    ComponentBatchMixin,
+)
+
+__all__ = ["TextFilterMode", "TextFilterModeArrayLike", "TextFilterModeBatch", "TextFilterModeLike"]
+
+
+from enum import Enum
+
+
+class TextFilterMode(Enum):
+    """**Component**: How a text filter is matched against text."""
+
+    Substring = 1
+    """Matches text that contains the filter, ignoring case."""
+
+    Regex = 2
+    """Matches text against the filter as a regular expression."""
+
+    @classmethod
+    def auto(cls, val: str | int | TextFilterMode) -> TextFilterMode:
+        """Best-effort converter, including a case-insensitive string matcher."""
+        if isinstance(val, TextFilterMode):
+            return val
+        if isinstance(val, int):
+            return cls(val)
+        try:
+            return cls[val]
+        except KeyError:
+            val_lower = val.lower()
+            for variant in cls:
+                if variant.name.lower() == val_lower:
+                    return variant
+        raise ValueError(f"Cannot convert {val} to {cls.__name__}")
+
+    def __str__(self) -> str:
+        """Returns the variant name."""
+        return self.name
+
+
+TextFilterModeLike = Union[TextFilterMode, Literal["Regex", "Substring", "regex", "substring"], int]
+TextFilterModeArrayLike = Union[TextFilterModeLike, Sequence[TextFilterModeLike]]
+
+
+class TextFilterModeBatch(BaseBatch[TextFilterModeArrayLike], ComponentBatchMixin):
+    _ARROW_DATATYPE = pa.uint8()
+    _COMPONENT_TYPE: str = "rerun.blueprint.components.TextFilterMode"
+
+    @staticmethod
+    def _native_to_pa_array(data: TextFilterModeArrayLike, data_type: pa.DataType) -> pa.Array:
+        if isinstance(data, (TextFilterMode, int, str)):
+            data = [data]
+
+        pa_data = [TextFilterMode.auto(v).value if v is not None else None for v in data]  # type: ignore[redundant-expr]
+
+        return pa.array(pa_data, type=data_type)
@@ -0,0 +1,42 @@
+# DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/python/mod.rs
+# Based on "crates/store/re_types/definitions/rerun/blueprint/components/text_log_column.fbs".
+
+# You can extend this class by creating a "TextLogColumnExt" class in "text_log_column_ext.py".
+
+from __future__ import annotations
+
+from ... import datatypes
+from ..._baseclasses import (
+    ComponentBatchMixin,
+    ComponentMixin,
+)
+
+__all__ = ["TextLogColumn", "TextLogColumnBatch"]
+
+
+class TextLogColumn(datatypes.Utf8, ComponentMixin):
+    """
+    **Component**: A column of a text log view.
+
+    One of:
+    * `"entity_path"`: the entity path of the entry.
+    * `"level"`: the log level of the entry.
+    * `"body"`: the body of the entry.
+    * `"timeline:<name>"`: the time of the entry on the timeline `<name>`.
+    * `"field:<key>"`: the value of the structured field `<key>` of the entry.
+
+    ⚠️ **This type is _unstable_ and may change significantly in a way that the data won't be backwards compatible.**
+    """
+
+    _BATCH_TYPE = None
+    # You can define your own __init__ function as a member of TextLogColumnExt in text_log_column_ext.py
+
+    # Note: there are no fields here because TextLogColumn delegates to datatypes.Utf8
+
+
+class TextLogColumnBatch(datatypes.Utf8Batch, ComponentBatchMixin):
+    _COMPONENT_TYPE: str = "rerun.blueprint.components.TextLogColumn"
+
+
+# This is patched in late to avoid circular dependencies.
+TextLogColumn._BATCH_TYPE = TextLogColumnBatch  # type: ignore[assignment]
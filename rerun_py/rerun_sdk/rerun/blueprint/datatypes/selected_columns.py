@@ -21,6 +21,15 @@ from .selected_columns_ext import SelectedColumnsExt
 __all__ = ["SelectedColumns", "SelectedColumnsArrayLike", "SelectedColumnsBatch", "SelectedColumnsLike"]
 
 
+def _selected_columns__row_id__special_field_converter_override(x: datatypes.BoolLike | None) -> datatypes.Bool | None:
+    if x is None:
+        return None
+    elif isinstance(x, datatypes.Bool):
+        return x
+    else:
+        return datatypes.Bool(x)
+
+
 @define(init=False)
 class SelectedColumns(SelectedColumnsExt):
     """
@@ -41,6 +50,15 @@ class SelectedColumns(SelectedColumnsExt):
     #
     # (Docstring intentionally commented out to hide this field from the docs)
 
+    row_id: datatypes.Bool | None = field(
+        default=None, converter=_selected_columns__row_id__special_field_converter_override
+    )
+    # Whether the row id column should be included.
+    #
+    # Defaults to `false`, e.g. for blueprints saved before this field was introduced.
+    #
+    # (Docstring intentionally commented out to hide this field from the docs)
+
 
 if TYPE_CHECKING:
     SelectedColumnsLike = Union[
@@ -57,6 +75,7 @@ SelectedColumnsArrayLike = Union[
 
 class SelectedColumnsBatch(BaseBatch[SelectedColumnsArrayLike]):
     _ARROW_DATATYPE = pa.struct([
+        pa.field("row_id", pa.bool_(), nullable=True, metadata={}),
         pa.field(
             "time_columns",
             pa.list_(pa.field("item", pa.utf8(), nullable=False, metadata={})),
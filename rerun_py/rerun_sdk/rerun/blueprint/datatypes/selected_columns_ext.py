@@ -19,6 +19,8 @@ class SelectedColumnsExt:
     def __init__(
         self: Any,
         columns: Sequence[blueprint_datatypes.ComponentColumnSelectorLike | datatypes.Utf8Like],
+        *,
+        row_id: bool = False,
     ) -> None:
         """
         Create a new instance of the `SelectedColumns` datatype.
@@ -36,6 +38,8 @@ class SelectedColumnsExt:
             The column must be either of the timeline, or component kind. Timeline columns can be specified using a
             `str` without any `:`, or an `Utf8`. Component columns can be specified using either a `str` in the form of
             `"/entity/path:Component"`, or a `ComponentColumnSelector`.
+        row_id:
+            Whether to include the row id column.
 
         """
 
@@ -56,7 +60,7 @@ class SelectedColumnsExt:
             else:
                 raise ValueError(f"Unexpected column type: {column}")
 
-        self.__attrs_init__(time_columns=time_columns, component_columns=component_columns)
+        self.__attrs_init__(row_id=row_id, time_columns=time_columns, component_columns=component_columns)
 
     @staticmethod
     def native_to_pa_array_override(input_data: SelectedColumnsArrayLike, data_type: pa.DataType) -> pa.Array:
@@ -74,12 +78,17 @@ class SelectedColumnsExt:
                 # …but it could be that we're a sequence of SelectedColumns/inputs to SelectedColumns
                 data = [d if isinstance(d, SelectedColumns) else SelectedColumns(d) for d in input_data]
 
+        row_id = pa.array(
+            [d.row_id.value if d.row_id is not None else None for d in data],
+            type=data_type.field(0).type,
+        )
+
         time_columns = pa.ListArray.from_arrays(
             offsets=_compute_offsets(d.time_columns for d in data),
             values=Utf8Batch(
                 list(itertools.chain.from_iterable(d.time_columns for d in data)),
             ).as_arrow_array(),
-            type=data_type.field(0).type,
+            type=data_type.field(1).type,
         )
 
         component_columns = pa.ListArray.from_arrays(
@@ -87,11 +96,12 @@ class SelectedColumnsExt:
             values=ComponentColumnSelectorBatch(
                 list(itertools.chain.from_iterable(d.component_columns for d in data)),
             ).as_arrow_array(),
-            type=data_type.field(1).type,
+            type=data_type.field(2).type,
         )
 
         return pa.StructArray.from_arrays(
             [
+                row_id,
                 time_columns,
                 component_columns,
             ],
@@ -104,6 +104,7 @@ class TimeSeriesView(View):
         axis_x: blueprint_archetypes.TimeAxis | None = None,
         axis_y: blueprint_archetypes.ScalarAxis | None = None,
         plot_legend: blueprint_archetypes.PlotLegend | blueprint_components.Corner2D | None = None,
+        derived_series: blueprint_archetypes.DerivedSeries | None = None,
         time_ranges: blueprint_archetypes.VisibleTimeRanges
         | datatypes.VisibleTimeRangeLike
         | Sequence[datatypes.VisibleTimeRangeLike]
@@ -150,6 +151,8 @@ class TimeSeriesView(View):
             Configures the vertical axis of the plot.
         plot_legend:
             Configures the legend of the plot.
+        derived_series:
+            Series computed from the other series of the view.
         time_ranges:
             Configures which range on each timeline is shown by this view (unless specified differently per entity).
 
@@ -174,6 +177,11 @@ class TimeSeriesView(View):
                 plot_legend = blueprint_archetypes.PlotLegend(plot_legend)
             properties["PlotLegend"] = plot_legend
 
+        if derived_series is not None:
+            if not isinstance(derived_series, blueprint_archetypes.DerivedSeries):
+                derived_series = blueprint_archetypes.DerivedSeries(derived_series)
+            properties["DerivedSeries"] = derived_series
+
         if time_ranges is not None:
             if not isinstance(time_ranges, blueprint_archetypes.VisibleTimeRanges):
                 time_ranges = blueprint_archetypes.VisibleTimeRanges(time_ranges)
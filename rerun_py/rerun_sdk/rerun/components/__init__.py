@@ -79,6 +79,7 @@ from .tensor_height_dimension import TensorHeightDimension, TensorHeightDimensio
 from .tensor_width_dimension import TensorWidthDimension, TensorWidthDimensionBatch
 from .texcoord2d import Texcoord2D, Texcoord2DBatch
 from .text import Text, TextBatch
+from .text_log_field import TextLogField, TextLogFieldBatch
 from .text_log_level import TextLogLevel, TextLogLevelBatch
 from .timestamp import Timestamp, TimestampBatch
 from .transform_mat3x3 import TransformMat3x3, TransformMat3x3Batch
@@ -244,6 +245,8 @@ __all__ = [
     "Texcoord2DBatch",
     "Text",
     "TextBatch",
+    "TextLogField",
+    "TextLogFieldBatch",
     "TextLogLevel",
     "TextLogLevelBatch",
     "Timestamp",
@@ -52,6 +52,20 @@ class AggregationPolicy(Enum):
     MinMaxAverage = 6
     """Find both the minimum and maximum values in the range, then use the average of those."""
 
+    Median = 7
+    """
+    Use the median of all values in the range.
+
+    Unlike `Average`, this is not skewed by outliers.
+    """
+
+    Envelope = 8
+    """
+    Shade the band between the minimum and maximum values in the range, with a line through the average of all values.
+
+    This shows both the trend and the spread of long, noisy signals.
+    """
+
     @classmethod
     def auto(cls, val: str | int | AggregationPolicy) -> AggregationPolicy:
         """Best-effort converter, including a case-insensitive string matcher."""
@@ -77,13 +91,17 @@ AggregationPolicyLike = Union[
     AggregationPolicy,
     Literal[
         "Average",
+        "Envelope",
         "Max",
+        "Median",
         "Min",
         "MinMax",
         "MinMaxAverage",
         "Off",
         "average",
+        "envelope",
         "max",
+        "median",
         "min",
         "minmax",
         "minmaxaverage",
@@ -15,7 +15,7 @@ use pyo3::{Bound, Py, PyAny, PyRef, PyResult, Python, pyclass, pymethods};
 use tracing::instrument;
 
 use re_chunk::ComponentIdentifier;
-use re_chunk_store::{QueryExpression, SparseFillStrategy, ViewContentsSelector};
+use re_chunk_store::{IsNotNullMode, QueryExpression, SparseFillStrategy, ViewContentsSelector};
 use re_dataframe::{QueryCache, QueryEngine};
 use re_datafusion::DataframeQueryTableProvider;
 use re_log_types::{EntityPath, EntityPathFilter, ResolvedTimeRange};
@@ -77,7 +77,8 @@ impl PyDataframeQueryView {
                 filtered_index_range: None,
                 filtered_index_values: None,
                 using_index_values: None,
-                filtered_is_not_null: None,
+                filtered_is_not_null: Vec::new(),
+                filtered_is_not_null_mode: IsNotNullMode::Any,
                 sparse_fill_strategy: SparseFillStrategy::None,
                 selection: None,
             },
@@ -329,7 +330,8 @@ impl PyDataframeQueryView {
         let column = column.into_selector()?;
 
         Ok(self.clone_with_new_query(py, |query_expression| {
-            query_expression.filtered_is_not_null = Some(column);
+            query_expression.filtered_is_not_null = vec![column];
+            query_expression.filtered_is_not_null_mode = IsNotNullMode::Any;
         }))
     }
 
@@ -6,7 +6,7 @@ use pyo3::types::PyDict;
 use pyo3::{Bound, Py, PyAny, PyResult, pyclass, pymethods};
 use re_chunk::ComponentIdentifier;
 use re_chunk_store::{
-    ChunkStoreHandle, QueryExpression, SparseFillStrategy, StaticColumnSelection,
+    ChunkStoreHandle, IsNotNullMode, QueryExpression, SparseFillStrategy, StaticColumnSelection,
     ViewContentsSelector,
 };
 use re_dataframe::{QueryEngine, StorageEngine};
@@ -230,7 +230,8 @@ impl PyRecording {
             filtered_index_range: None,
             filtered_index_values: None,
             using_index_values: None,
-            filtered_is_not_null: None,
+            filtered_is_not_null: Vec::new(),
+            filtered_is_not_null_mode: IsNotNullMode::Any,
             sparse_fill_strategy: SparseFillStrategy::None,
             selection: None,
         };
@@ -7,7 +7,7 @@ use pyo3::prelude::{PyAnyMethods as _, PyTupleMethods as _};
 use pyo3::types::PyTuple;
 use pyo3::{Bound, PyRef, PyResult, Python, pyclass, pymethods};
 
-use re_chunk_store::{QueryExpression, SparseFillStrategy};
+use re_chunk_store::{IsNotNullMode, QueryExpression, SparseFillStrategy};
 use re_log_types::ResolvedTimeRange;
 use re_sorbet::{ColumnDescriptor, ColumnSelector};
 
@@ -484,7 +484,8 @@ impl PyRecordingView {
         let column = column.into_selector();
 
         let mut query_expression = self.query_expression.clone();
-        query_expression.filtered_is_not_null = Some(column?);
+        query_expression.filtered_is_not_null = vec![column?];
+        query_expression.filtered_is_not_null_mode = IsNotNullMode::Any;
 
         Ok(Self {
             recording: self.recording.clone(),
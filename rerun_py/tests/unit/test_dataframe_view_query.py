@@ -70,6 +70,18 @@ def test_selected_columns() -> None:
         blueprint_components.ComponentColumnSelector("/entity/path:Component"),
         blueprint_components.ComponentColumnSelector("/world/robot:Position3D"),
     ]
+    assert columns.row_id == datatypes.Bool(False)
+
+
+def test_selected_columns_row_id() -> None:
+    columns = blueprint_components.SelectedColumns(["t"], row_id=True)
+
+    assert columns.row_id == datatypes.Bool(True)
+    assert columns.time_columns == [datatypes.Utf8("t")]
+    assert columns.component_columns == []
+
+    batch = blueprint_components.SelectedColumnsBatch(columns).as_arrow_array()
+    assert batch.field(0).to_pylist() == [True]
 
 
 def test_selected_columns_batch() -> None:
@@ -128,6 +140,7 @@ def test_dataframe_query_property() -> None:
         filter_by_range=(TimeInt(seq=1), TimeInt(seq=10)),
         filter_is_not_null="/entity/path:Component",
         apply_latest_at=True,
+        latest_at_scope="view",
         select=[
             "t",
             "/entity/path:Component",
@@ -146,6 +159,7 @@ def test_dataframe_query_property() -> None:
     )
 
     assert query.apply_latest_at == blueprint_components.ApplyLatestAtBatch(blueprint_components.ApplyLatestAt(True))
+    assert query.latest_at_scope == blueprint_components.LatestAtScopeBatch(blueprint_components.LatestAtScope.View)
 
     assert query.select == blueprint_components.SelectedColumnsBatch(
         blueprint_components.SelectedColumns([
@@ -186,3 +200,34 @@ def test_dataframe_query_property_explicit() -> None:
             blueprint_components.ComponentColumnSelector(entity_path="/world/robot", component="Position3D"),
         ]),
     )
+
+
+def test_dataframe_query_property_multiple_filter_is_not_null() -> None:
+    query = DataframeQuery(
+        timeline="frame",
+        filter_is_not_null=[
+            "/cam/left:Blob",
+            blueprint_components.ComponentColumnSelector(entity_path="/cam/right", component="Blob"),
+        ],
+        filter_is_not_null_mode="all",
+    )
+
+    assert query.filter_is_not_null == blueprint_components.FilterIsNotNullBatch([
+        blueprint_components.FilterIsNotNull(
+            active=True,
+            column=blueprint_components.ComponentColumnSelector(
+                entity_path="/cam/left",
+                component="Blob",
+            ),
+        ),
+        blueprint_components.FilterIsNotNull(
+            active=True,
+            column=blueprint_components.ComponentColumnSelector(
+                entity_path="/cam/right",
+                component="Blob",
+            ),
+        ),
+    ])
+    assert query.filter_is_not_null_mode == blueprint_components.FilterIsNotNullModeBatch(
+        blueprint_components.FilterIsNotNullMode.All,
+    )